@@ -0,0 +1,37 @@
+use sec::shared::universe::UniverseError;
+use thiserror::Error;
+
+/// Error indicating that the command line could not be parsed.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// `-h` or `--help` was given; not a failure, but parsing stops to print the usage.
+    #[error("[CliError] Help requested")]
+    HelpRequested,
+    /// No command was given.
+    #[error("[CliError] Missing command")]
+    MissingCommand,
+    /// The command is not one the CLI knows.
+    #[error("[CliError] Unknown command '{0}'")]
+    UnknownCommand(String),
+    /// An option or command argument lacks its value or has an invalid one.
+    #[error("[CliError] Invalid value for '{option}': {details}")]
+    InvalidValue {
+        /// The offending option or command.
+        option: String,
+        /// A human-readable description of what is wrong with the value.
+        details: String,
+    },
+    /// The universe options of `batch` are invalid.
+    #[error(transparent)]
+    Universe(#[from] UniverseError),
+}
+
+impl CliError {
+    /// Creates a [`CliError::InvalidValue`] for `option`.
+    pub fn invalid_value(option: &str, details: impl Into<String>) -> Self {
+        Self::InvalidValue {
+            option: option.to_string(),
+            details: details.into(),
+        }
+    }
+}
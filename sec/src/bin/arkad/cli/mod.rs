@@ -0,0 +1,372 @@
+pub mod cli_error;
+
+use std::fmt::{self, Display, Formatter};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use sec::shared::config::ConfigLayer;
+use sec::shared::response_fixtures::ResponseFixtures;
+use sec::shared::universe::UniverseArgs;
+use sec::shared::user_agent::UserAgent;
+use tracing_subscriber::filter::LevelFilter;
+
+pub use cli_error::CliError;
+
+use crate::constants::DEFAULT_OUTPUT_DIRECTORY;
+
+/// Describes the commands and options [`Cli::parse`] accepts.
+pub const USAGE: &str = "\
+Usage: arkad [OPTIONS] <COMMAND> [ARGS]
+
+Commands:
+  extract <CIK>           Run the Extract phase for one CIK
+  etl <CIK>...            Run the full Extract -> Transform pipeline for the given CIKs
+  batch [UNIVERSE]        Run the full pipeline for a CIK universe, resuming from checkpoints
+  inspect <CIK>           Print the parsed company data of one CIK
+  validate-cik <CIK>...   Validate CIKs without contacting the SEC
+
+Options:
+  --config <PATH>         TOML configuration file (default: $ARKAD_CONFIG, if set)
+  --user-agent <AGENT>    SEC user agent, 'Company Name email@domain.com'
+  --output <FORMAT>       Output format: 'pretty', 'json', or 'ndjson' (default: pretty)
+  --log-level <LEVEL>     Log level on stderr: 'off', 'error', 'warn', 'info', 'debug', or
+                          'trace' (default: warn)
+  --concurrency <N>       Pipelines in flight at once (default: 16)
+  --max-state-retries <N> Recomputations of a failed pipeline state, on top of the SEC
+                          client's own request retries (default: 0)
+  --output-dir <DIR>      Directory run reports and checkpoints are written to
+                          (default: arkad-output)
+  --cache-dir <DIR>       Cache SEC responses in DIR and revalidate them instead of downloading
+                          them again (default: no cache)
+  --fixtures <DIR>        Answer SEC requests from the responses recorded in DIR, without
+                          contacting the SEC
+  --record-fixtures <DIR> Record every SEC response into DIR, for later runs with --fixtures
+  -h, --help              Print this help
+
+Settings given as options override those of the ARKAD_* environment variables, which override
+those of the configuration file.
+
+Universe (batch):
+  --input <PATH|->        Read the CIK universe from PATH, or from stdin if '-'
+                          (default: the bundled S&P 500 universe)
+  --format <FORMAT>       Format of the universe: 'plain', 'csv', or 'company-tickers'
+  --ticker <TICKERS>      Only run companies with one of the comma-separated tickers
+  --exchange <EXCHANGES>  Only run companies listed on one of the comma-separated exchanges
+  --cik-range <FROM-TO>   Only run CIKs from FROM to TO, inclusive; repeatable";
+
+/// How command results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable lines.
+    #[default]
+    Pretty,
+    /// One JSON document once the command has finished.
+    Json,
+    /// One JSON object per line as results become available.
+    Ndjson,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pretty => write!(f, "pretty"),
+            Self::Json => write!(f, "json"),
+            Self::Ndjson => write!(f, "ndjson"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = CliError;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            "ndjson" => Ok(Self::Ndjson),
+            unknown => Err(CliError::invalid_value(
+                "--output",
+                format!("unknown format '{unknown}', expected 'pretty', 'json', or 'ndjson'"),
+            )),
+        }
+    }
+}
+
+/// Options shared by every command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalOptions {
+    /// The configuration file to load instead of the one named by `ARKAD_CONFIG`.
+    pub config_file: Option<PathBuf>,
+    /// The settings overriding those of the configuration file and the environment.
+    pub overrides: ConfigLayer,
+    /// How results are written to stdout.
+    pub output: OutputFormat,
+    /// The most verbose level logged to stderr.
+    pub log_level: LevelFilter,
+    /// The directory run reports and checkpoints are written to.
+    pub output_dir: PathBuf,
+    /// The directory SEC responses are cached in, if any.
+    pub cache_dir: Option<PathBuf>,
+    /// The recorded SEC responses replayed, or the directory SEC responses are recorded in, if
+    /// any.
+    pub response_fixtures: Option<ResponseFixtures>,
+}
+
+impl Default for GlobalOptions {
+    fn default() -> Self {
+        Self {
+            config_file: None,
+            overrides: ConfigLayer::new(),
+            output: OutputFormat::default(),
+            log_level: LevelFilter::WARN,
+            output_dir: PathBuf::from(DEFAULT_OUTPUT_DIRECTORY),
+            cache_dir: None,
+            response_fixtures: None,
+        }
+    }
+}
+
+/// A command of the CLI with its arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Runs the Extract phase for one CIK.
+    Extract { cik: String },
+    /// Runs the full pipeline for the given CIKs.
+    Etl { ciks: Vec<String> },
+    /// Runs the full pipeline for a CIK universe.
+    Batch { universe: UniverseArgs },
+    /// Prints the parsed company data of one CIK.
+    Inspect { cik: String },
+    /// Validates CIKs offline.
+    ValidateCik { ciks: Vec<String> },
+}
+
+/// The parsed command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cli {
+    /// The options shared by every command.
+    pub options: GlobalOptions,
+    /// The command to run.
+    pub command: Command,
+}
+
+impl Cli {
+    /// Parses the command-line arguments `args`, without the program name.
+    ///
+    /// Global options may appear anywhere, as `--option value` or `--option=value`; every other
+    /// argument belongs to the command.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut options = GlobalOptions::default();
+        let mut command_args = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (option, inline_value) = match arg.split_once('=') {
+                Some((option, value)) if option.starts_with("--") => {
+                    (option.to_string(), Some(value.to_string()))
+                }
+                _ => (arg.clone(), None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| CliError::invalid_value(&option, "missing value"))
+            };
+            match option.as_str() {
+                "-h" | "--help" => return Err(CliError::HelpRequested),
+                "--config" => options.config_file = Some(PathBuf::from(value()?)),
+                "--user-agent" => {
+                    let user_agent = value()?;
+                    UserAgent::new(&user_agent)
+                        .map_err(|e| CliError::invalid_value(&option, e.to_string()))?;
+                    options.overrides.user_agent = Some(user_agent);
+                }
+                "--output" => options.output = value()?.parse()?,
+                "--log-level" => {
+                    options.log_level = value()?
+                        .parse::<LevelFilter>()
+                        .map_err(|e| CliError::invalid_value(&option, e.to_string()))?;
+                }
+                "--concurrency" => {
+                    let concurrency = value()?.parse::<NonZeroUsize>().map_err(|_| {
+                        CliError::invalid_value(&option, "expected a positive number")
+                    })?;
+                    options.overrides.max_in_flight = Some(concurrency.get());
+                }
+                "--max-state-retries" => {
+                    let max_state_retries = value()?.parse().map_err(|_| {
+                        CliError::invalid_value(&option, "expected a non-negative number")
+                    })?;
+                    options.overrides.max_state_retries = Some(max_state_retries);
+                }
+                "--output-dir" => options.output_dir = PathBuf::from(value()?),
+                "--cache-dir" => options.cache_dir = Some(PathBuf::from(value()?)),
+                "--fixtures" => {
+                    options.response_fixtures = Some(ResponseFixtures::replay(value()?));
+                }
+                "--record-fixtures" => {
+                    options.response_fixtures = Some(ResponseFixtures::record(value()?));
+                }
+                _ => command_args.push(arg),
+            }
+        }
+
+        let mut command_args = command_args.into_iter();
+        let command = match command_args.next().as_deref() {
+            Some("extract") => Command::Extract {
+                cik: single_cik("extract", command_args)?,
+            },
+            Some("etl") => Command::Etl {
+                ciks: many_ciks("etl", command_args)?,
+            },
+            Some("batch") => Command::Batch {
+                universe: UniverseArgs::parse(command_args)?,
+            },
+            Some("inspect") => Command::Inspect {
+                cik: single_cik("inspect", command_args)?,
+            },
+            Some("validate-cik") => Command::ValidateCik {
+                ciks: many_ciks("validate-cik", command_args)?,
+            },
+            Some(unknown) => return Err(CliError::UnknownCommand(unknown.to_string())),
+            None => return Err(CliError::MissingCommand),
+        };
+        Ok(Self { options, command })
+    }
+}
+
+/// Takes the one CIK argument of `command`.
+fn single_cik(command: &str, mut args: impl Iterator<Item = String>) -> Result<String, CliError> {
+    match (args.next(), args.next()) {
+        (Some(cik), None) => Ok(cik),
+        (None, _) => Err(CliError::invalid_value(command, "expected a CIK")),
+        (Some(_), Some(extra)) => Err(CliError::invalid_value(
+            command,
+            format!("expected a single CIK, got also '{extra}'"),
+        )),
+    }
+}
+
+/// Takes the one or more CIK arguments of `command`.
+fn many_ciks(command: &str, args: impl Iterator<Item = String>) -> Result<Vec<String>, CliError> {
+    let ciks: Vec<_> = args.collect();
+    if ciks.is_empty() {
+        return Err(CliError::invalid_value(
+            command,
+            "expected at least one CIK",
+        ));
+    }
+    Ok(ciks)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, CliError> {
+        Cli::parse(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn should_accept_global_options_after_command_when_parsing() {
+        let expected_result = Cli {
+            options: GlobalOptions {
+                output: OutputFormat::Ndjson,
+                overrides: ConfigLayer::new().with_max_in_flight(4),
+                ..GlobalOptions::default()
+            },
+            command: Command::Etl {
+                ciks: vec!["320193".to_string(), "789019".to_string()],
+            },
+        };
+
+        let result = parse(&[
+            "--output=ndjson",
+            "etl",
+            "320193",
+            "--concurrency",
+            "4",
+            "789019",
+        ])
+        .expect("Well-formed arguments should always parse");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_replay_fixtures_when_parsing_fixtures_option() {
+        let expected_result = Some(ResponseFixtures::replay("tests/fixtures"));
+
+        let result = parse(&["--fixtures", "tests/fixtures", "inspect", "320193"])
+            .expect("Well-formed arguments should always parse")
+            .options
+            .response_fixtures;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_pass_universe_options_to_batch_when_parsing() {
+        let expected_result = Command::Batch {
+            universe: UniverseArgs::parse(["--input", "-"].map(String::from))
+                .expect("Well-formed universe arguments should always parse"),
+        };
+
+        let result = parse(&["batch", "--input", "-"])
+            .expect("Well-formed arguments should always parse")
+            .command;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_collect_config_overrides_when_parsing() {
+        let expected_result = GlobalOptions {
+            config_file: Some(PathBuf::from("arkad.toml")),
+            overrides: ConfigLayer::new()
+                .with_user_agent("Sample Company contact@example.com")
+                .with_max_state_retries(2),
+            ..GlobalOptions::default()
+        };
+
+        let result = parse(&[
+            "--config",
+            "arkad.toml",
+            "--user-agent=Sample Company contact@example.com",
+            "--max-state-retries",
+            "2",
+            "validate-cik",
+            "1",
+        ])
+        .expect("Well-formed arguments should always parse")
+        .options;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_user_agent_is_not_sec_compliant() {
+        let result = parse(&["--user-agent", "nobody", "validate-cik", "1"]);
+
+        let expected_result = true;
+
+        let result = matches!(result, Err(CliError::InvalidValue { option, .. }) if option == "--user-agent");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_single_cik_command_gets_several() {
+        let expected_result = Err(CliError::invalid_value(
+            "inspect",
+            "expected a single CIK, got also '789019'",
+        ));
+
+        let result = parse(&["inspect", "320193", "789019"]);
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+
+use chrono::Utc;
+use sec::shared::batch::{BatchRunner, RunReport};
+use sec::shared::checkpoint::{CheckpointSink, FileSystemCheckpointSink};
+use sec::shared::config::ArkadConfig;
+use sec::shared::http_client::SecClient as _;
+use sec::shared::universe::{CikUniverse, UniverseArgs};
+
+use super::{CommandResult, drive, exit_code, sec_client};
+use crate::cli::GlobalOptions;
+use crate::constants::{CHECKPOINT_SUBDIRECTORY, REPORT_SUBDIRECTORY};
+use crate::launchers::Pipelines;
+
+/// Top-level batch events.
+enum BatchEvent {
+    CancellationRequested,
+    CikRejected,
+    ReportExported,
+    ReportExportFailed,
+    RateLimiterSummary,
+}
+
+impl Display for BatchEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CancellationRequested => write!(f, "batch_cancellation_requested"),
+            Self::CikRejected => write!(f, "batch_cik_rejected"),
+            Self::ReportExported => write!(f, "batch_report_exported"),
+            Self::ReportExportFailed => write!(f, "batch_report_export_failed"),
+            Self::RateLimiterSummary => write!(f, "batch_rate_limiter_summary"),
+        }
+    }
+}
+
+/// Runs the full pipeline for every CIK of `universe`, checkpointing under the output directory
+/// and exporting the run report there once the batch has finished.
+pub async fn run(
+    options: &GlobalOptions,
+    config: &ArkadConfig,
+    universe: &UniverseArgs,
+) -> CommandResult {
+    let universe = universe.load().await?;
+    log_rejected(&universe);
+
+    let checkpoint_sink: Arc<dyn CheckpointSink> = Arc::new(FileSystemCheckpointSink::new(
+        options.output_dir.join(CHECKPOINT_SUBDIRECTORY),
+    ));
+    let sec_client = sec_client(options, config);
+    let pipelines =
+        Pipelines::new(sec_client.clone(), config.clone()).with_checkpoint_sink(checkpoint_sink);
+    let runner = BatchRunner::new(pipelines).with_max_in_flight(config.max_in_flight());
+
+    // On Ctrl-C, let every pipeline finish its in-flight state and stop before the next one, and
+    // launch no further pipelines; their checkpoints are kept, so the next run resumes them.
+    tokio::spawn({
+        let cancellation_token = runner.cancellation_token().clone();
+        async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                tracing::warn!(
+                    event = %BatchEvent::CancellationRequested,
+                    message = "Received Ctrl-C, stopping pipelines after their current state",
+                );
+                cancellation_token.cancel();
+            }
+        }
+    });
+
+    let report = drive(options, &runner, universe.into_ciks()).await;
+
+    let metrics = sec_client.rate_limiter().metrics();
+    tracing::info!(
+        event = %BatchEvent::RateLimiterSummary,
+        message = %format!(
+            "SEC requests paced at {:.2} requests/second after {} throttling responses",
+            metrics.requests_per_second(),
+            metrics.throttle_events,
+        ),
+        interval_ms = metrics.current_interval.as_millis(),
+        throttle_events = metrics.throttle_events,
+    );
+
+    let report_directory = options.output_dir.join(REPORT_SUBDIRECTORY);
+    match RunReport::new(&report, Utc::now())
+        .write_to(&report_directory)
+        .await
+    {
+        Ok(()) => tracing::info!(
+            event = %BatchEvent::ReportExported,
+            message = %format!("Run report exported to '{}'", report_directory.display()),
+            directory = %report_directory.display(),
+        ),
+        Err(e) => tracing::warn!(
+            event = %BatchEvent::ReportExportFailed,
+            message = %e.to_string(),
+            directory = %report_directory.display(),
+        ),
+    }
+
+    Ok(exit_code(&report))
+}
+
+/// Logs every entry of `universe` whose CIK failed validation, before the batch starts.
+fn log_rejected(universe: &CikUniverse) {
+    for rejected in universe.rejected() {
+        tracing::warn!(
+            event = %BatchEvent::CikRejected,
+            message = %rejected.error.to_string(),
+            cik = %rejected.raw_cik,
+            position = rejected.position,
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use sec::shared::batch::BatchRunner;
+
+use sec::shared::config::ArkadConfig;
+
+use super::{CommandResult, drive, exit_code, sec_client};
+use crate::cli::GlobalOptions;
+use crate::launchers::Pipelines;
+
+/// Runs the full Extract -> Transform pipeline for `ciks`, at most the configured number at once.
+pub async fn run(
+    options: &GlobalOptions,
+    config: &ArkadConfig,
+    ciks: Vec<String>,
+) -> CommandResult {
+    let runner = BatchRunner::new(Pipelines::new(sec_client(options, config), config.clone()))
+        .with_max_in_flight(config.max_in_flight());
+    let report = drive(options, &runner, ciks).await;
+    Ok(exit_code(&report))
+}
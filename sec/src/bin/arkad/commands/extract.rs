@@ -0,0 +1,17 @@
+use sec::shared::batch::BatchRunner;
+
+use sec::shared::config::ArkadConfig;
+
+use super::{CommandResult, drive, exit_code, sec_client};
+use crate::cli::GlobalOptions;
+use crate::launchers::Extractions;
+
+/// Runs the Extract phase for `cik`.
+pub async fn run(options: &GlobalOptions, config: &ArkadConfig, cik: String) -> CommandResult {
+    let runner = BatchRunner::new(Extractions::new(
+        sec_client(options, config),
+        config.clone(),
+    ));
+    let report = drive(options, &runner, [cik]).await;
+    Ok(exit_code(&report))
+}
@@ -0,0 +1,73 @@
+use std::process::ExitCode;
+
+use futures_util::StreamExt;
+use sec::implementations::states::transform::parse_company_facts::{self, ParseCompanyFacts};
+use sec::prelude::*;
+use sec::shared::batch::{EtlPipelineLauncher, PipelineLauncher};
+use sec::shared::config::ArkadConfig;
+use sec::shared::financial::company_data::{CompanyData, CompanyDataSnapshot};
+use serde_json::json;
+
+use super::{CommandResult, sec_client};
+use crate::cli::{GlobalOptions, OutputFormat};
+use crate::output::print_line;
+
+/// Runs the pipeline of `cik` up to `ParseCompanyFacts` and prints the parsed company data.
+///
+/// `pretty` prints the company and one line per fact, `json` the full [`CompanyDataSnapshot`],
+/// and `ndjson` one fact per line.
+pub async fn run(options: &GlobalOptions, config: &ArkadConfig, cik: &str) -> CommandResult {
+    let (_, mut stream) = EtlPipelineLauncher::new(sec_client(options, config))
+        .with_config(config.clone())
+        .launch(cik, StreamOptions::new())
+        .await;
+
+    let mut company_data = None;
+    while let Some(result) = stream.next().await {
+        let item = result?;
+        if item.event == StreamEvent::StateCompleted
+            && item.state_name == parse_company_facts::STATE_NAME
+        {
+            company_data = item
+                .data
+                .downcast_ref::<ParseCompanyFacts>()
+                .and_then(|state| state.output_data())
+                .map(|output| output.company_data().clone());
+            break;
+        }
+    }
+    let Some(company_data) = company_data else {
+        return Err(format!(
+            "The pipeline for CIK '{cik}' ended without parsing its company facts"
+        )
+        .into());
+    };
+
+    print_company_data(options.output, &company_data)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Writes `company_data` to stdout in `format`, its facts ordered by concept.
+fn print_company_data(
+    format: OutputFormat,
+    company_data: &CompanyData,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let snapshot = CompanyDataSnapshot::from(company_data);
+    match format {
+        OutputFormat::Pretty => {
+            println!("{company_data}");
+            for (concept, fact) in &snapshot.facts {
+                println!("  {concept:<40} {fact}");
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&snapshot)?),
+        OutputFormat::Ndjson => {
+            for (concept, fact) in &snapshot.facts {
+                print_line(
+                    &json!({ "cik": snapshot.cik.to_string(), "concept": concept, "fact": fact }),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
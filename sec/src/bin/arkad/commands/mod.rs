@@ -0,0 +1,149 @@
+pub mod batch;
+pub mod etl;
+pub mod extract;
+pub mod inspect;
+pub mod validate_cik;
+
+use std::error::Error;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use futures_util::StreamExt;
+use sec::shared::batch::{BatchReport, BatchRunner, PipelineLauncher};
+use sec::shared::config::ArkadConfig;
+use sec::shared::http_client::implementations::sec_client::SecClient;
+use sec::shared::response_cache::FileSystemResponseCache;
+
+use crate::cli::{Cli, Command, GlobalOptions};
+use crate::output;
+
+/// The result of a command: the process exit code, or the error the command stopped on.
+pub type CommandResult = Result<ExitCode, Box<dyn Error + Send + Sync>>;
+
+/// Runs the command of `cli` with the loaded `config`.
+pub async fn run(cli: Cli, config: &ArkadConfig) -> CommandResult {
+    let Cli { options, command } = cli;
+    match command {
+        Command::Extract { cik } => extract::run(&options, config, cik).await,
+        Command::Etl { ciks } => etl::run(&options, config, ciks).await,
+        Command::Batch { universe } => batch::run(&options, config, &universe).await,
+        Command::Inspect { cik } => inspect::run(&options, config, &cik).await,
+        Command::ValidateCik { ciks } => validate_cik::run(&options, &ciks),
+    }
+}
+
+/// Creates the SEC client every command shares, identifying itself, pacing its requests, and
+/// addressing the SEC as `config` says, caching responses in `--cache-dir` if given, and
+/// replaying or recording the fixtures of `--fixtures` or `--record-fixtures` if given.
+fn sec_client(options: &GlobalOptions, config: &ArkadConfig) -> SecClient {
+    let sec_client = SecClient::from_config(config);
+    let sec_client = match &options.cache_dir {
+        Some(cache_dir) => {
+            sec_client.with_response_cache(Arc::new(FileSystemResponseCache::new(cache_dir)))
+        }
+        None => sec_client,
+    };
+    match &options.response_fixtures {
+        Some(response_fixtures) => sec_client.with_response_fixtures(response_fixtures.clone()),
+        None => sec_client,
+    }
+}
+
+/// Runs `ciks` on `runner`, printing every event and the final report in the configured format.
+async fn drive<L, I>(options: &GlobalOptions, runner: &BatchRunner<L>, ciks: I) -> BatchReport
+where
+    L: PipelineLauncher + 'static,
+    I: IntoIterator,
+    I::Item: Into<String>,
+    I::IntoIter: Send + 'static,
+{
+    let mut run = runner.run(ciks);
+    while let Some(item) = run.next().await {
+        if let Err(e) = output::print_item(options.output, &item) {
+            tracing::error!(message = %format!("Event could not be written: {e}"), cik = %item.cik);
+        }
+    }
+    let report = run.into_report();
+    if let Err(e) = output::print_report(options.output, &report) {
+        tracing::error!(message = %format!("Report could not be written: {e}"));
+    }
+    report
+}
+
+/// Exits successfully only if every pipeline of `report` succeeded, so scripts can tell a run
+/// with a failed, cancelled, or skipped pipeline apart by its exit status alone.
+fn exit_code(report: &BatchReport) -> ExitCode {
+    if report.successes().count() == report.outcomes().len() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use sec::prelude::StreamEvent;
+    use sec::shared::batch::{PipelineFailure, PipelineOutcome, PipelineStatus};
+
+    use super::*;
+
+    fn report_of(statuses: Vec<PipelineStatus>) -> BatchReport {
+        let mut report = BatchReport::new();
+        for (index, status) in statuses.into_iter().enumerate() {
+            report.record(PipelineOutcome {
+                cik: index.to_string(),
+                execution_id: None,
+                duration: Duration::ZERO,
+                status,
+            });
+        }
+        report
+    }
+
+    fn failed() -> PipelineStatus {
+        PipelineStatus::Failed(PipelineFailure {
+            event: StreamEvent::StateFailed,
+            state_name: "Validate CIK Format".to_string(),
+            kind: "InvalidCikFormat",
+            message: "Invalid CIK".to_string(),
+            error_chain: vec!["Invalid CIK".to_string()],
+            missing_concepts: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn should_exit_successfully_when_every_pipeline_succeeded() {
+        let report = report_of(vec![PipelineStatus::Succeeded, PipelineStatus::Succeeded]);
+
+        let expected_result = ExitCode::SUCCESS;
+
+        let result = exit_code(&report);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_exit_with_failure_when_any_pipeline_failed() {
+        let report = report_of(vec![PipelineStatus::Succeeded, failed()]);
+
+        let expected_result = ExitCode::FAILURE;
+
+        let result = exit_code(&report);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_exit_with_failure_when_any_pipeline_was_cancelled() {
+        let report = report_of(vec![PipelineStatus::Succeeded, PipelineStatus::Cancelled]);
+
+        let expected_result = ExitCode::FAILURE;
+
+        let result = exit_code(&report);
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,52 @@
+use std::process::ExitCode;
+
+use sec::shared::cik::Cik;
+use serde_json::json;
+
+use super::CommandResult;
+use crate::cli::{GlobalOptions, OutputFormat};
+use crate::output::print_line;
+
+/// Validates `ciks` without contacting the SEC, printing the normalized form of each valid CIK
+/// and the reason each invalid one was rejected.
+///
+/// Fails if any CIK is invalid.
+pub fn run(options: &GlobalOptions, ciks: &[String]) -> CommandResult {
+    let results: Vec<_> = ciks
+        .iter()
+        .map(|raw_cik| {
+            let result = Cik::new(raw_cik);
+            let record = match &result {
+                Ok(cik) => json!({ "input": raw_cik, "valid": true, "cik": cik.to_string() }),
+                Err(e) => json!({ "input": raw_cik, "valid": false, "error": e.to_string() }),
+            };
+            (raw_cik, result, record)
+        })
+        .collect();
+
+    match options.output {
+        OutputFormat::Pretty => {
+            for (raw_cik, result, _) in &results {
+                match result {
+                    Ok(cik) => println!("{raw_cik:<12} valid    {cik}"),
+                    Err(e) => println!("{raw_cik:<12} invalid  {e}"),
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let records: Vec<_> = results.iter().map(|(_, _, record)| record).collect();
+            println!("{}", serde_json::to_string_pretty(&records)?);
+        }
+        OutputFormat::Ndjson => {
+            for (_, _, record) in &results {
+                print_line(record)?;
+            }
+        }
+    }
+
+    if results.iter().all(|(_, result, _)| result.is_ok()) {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
@@ -0,0 +1,14 @@
+/// Directory run reports and checkpoints are written to unless `--output-dir` is given, relative
+/// to the working directory.
+pub const DEFAULT_OUTPUT_DIRECTORY: &str = "arkad-output";
+
+/// Subdirectory of the output directory the pipelines of `batch` checkpoint to.
+///
+/// A pipeline interrupted mid-run leaves its last checkpoint here and resumes from it on the
+/// next run; checkpoints of successful runs are removed.
+pub const CHECKPOINT_SUBDIRECTORY: &str = "checkpoints";
+
+/// Subdirectory of the output directory the run report of `batch` is exported to.
+///
+/// Every run replaces the JSON, CSV, and HTML report of the previous one.
+pub const REPORT_SUBDIRECTORY: &str = "reports";
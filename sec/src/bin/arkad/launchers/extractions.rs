@@ -0,0 +1,52 @@
+use std::future::ready;
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use sec::implementations::states::extract::execute_sec_request;
+use sec::prelude::*;
+use sec::shared::batch::{EtlPipelineLauncher, PipelineLauncher, PipelineOutcome};
+use sec::shared::config::ArkadConfig;
+use sec::shared::http_client::implementations::sec_client::SecClient;
+use uuid::Uuid;
+
+use super::log_outcome;
+
+/// Launches the Extract phase of every CIK of the batch.
+///
+/// Drives the full pipeline but ends its stream once `ExecuteSecRequest` has completed, so the
+/// Transform phase is never started.
+#[derive(Debug)]
+pub struct Extractions {
+    launcher: EtlPipelineLauncher,
+}
+
+impl Extractions {
+    /// Creates the launcher of extractions sharing `sec_client` and `config`.
+    pub fn new(sec_client: SecClient, config: ArkadConfig) -> Self {
+        Self {
+            launcher: EtlPipelineLauncher::new(sec_client).with_config(config),
+        }
+    }
+}
+
+#[async_trait]
+impl PipelineLauncher for Extractions {
+    async fn launch(&self, cik: &str, options: StreamOptions) -> (Uuid, StateMachineStream) {
+        let (execution_id, stream) = self.launcher.launch(cik, options).await;
+        let mut extracted = false;
+        let stream = stream.take_while(move |result| {
+            let keep = !extracted;
+            extracted = matches!(
+                result,
+                Ok(item) if item.event == StreamEvent::StateCompleted
+                    && item.state_name == execute_sec_request::STATE_NAME
+            );
+            ready(keep)
+        });
+        (execution_id, Box::pin(stream))
+    }
+
+    async fn finish(&self, outcome: &PipelineOutcome) {
+        log_outcome(outcome);
+    }
+}
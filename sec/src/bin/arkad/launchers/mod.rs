@@ -0,0 +1,69 @@
+pub mod extractions;
+pub mod pipelines;
+
+use std::fmt::{self, Display, Formatter};
+
+use sec::shared::batch::{PipelineOutcome, PipelineStatus};
+
+pub use extractions::Extractions;
+pub use pipelines::Pipelines;
+
+/// Events emitted by the pipeline runner (consumer-level, not part of the library).
+pub enum PipelineEvent {
+    Resumed,
+    ResumeFailed,
+    CheckpointCleanupFailed,
+    Complete,
+    Cancelled,
+    Failed,
+}
+
+impl Display for PipelineEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resumed => write!(f, "pipeline_resumed"),
+            Self::ResumeFailed => write!(f, "pipeline_resume_failed"),
+            Self::CheckpointCleanupFailed => write!(f, "checkpoint_cleanup_failed"),
+            Self::Complete => write!(f, "pipeline_complete"),
+            Self::Cancelled => write!(f, "pipeline_cancelled"),
+            Self::Failed => write!(f, "pipeline_failed"),
+        }
+    }
+}
+
+/// Logs how the pipeline of `outcome` ended.
+pub fn log_outcome(outcome: &PipelineOutcome) {
+    let cik = &outcome.cik;
+    let execution_id = outcome.execution_id.unwrap_or_default();
+    let pipeline_duration = outcome.duration;
+    match &outcome.status {
+        PipelineStatus::Succeeded => {
+            tracing::info!(
+                event = %PipelineEvent::Complete,
+                message = %format!("Pipeline for CIK '{cik}' completed in {pipeline_duration:.2?}"),
+                execution_id = %execution_id,
+                cik = %cik,
+                duration_ms = pipeline_duration.as_millis(),
+            );
+        }
+        PipelineStatus::Cancelled => {
+            tracing::warn!(
+                event = %PipelineEvent::Cancelled,
+                message = %format!("Pipeline for CIK '{cik}' cancelled after {pipeline_duration:.2?}"),
+                execution_id = %execution_id,
+                cik = %cik,
+                duration_ms = pipeline_duration.as_millis(),
+            );
+        }
+        PipelineStatus::Failed(_) => {
+            tracing::warn!(
+                event = %PipelineEvent::Failed,
+                message = %format!("Pipeline for CIK '{cik}' failed after {pipeline_duration:.2?}"),
+                execution_id = %execution_id,
+                cik = %cik,
+                duration_ms = pipeline_duration.as_millis(),
+            );
+        }
+        PipelineStatus::Skipped => {}
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sec::implementations::states::extract::ExtractSuperState;
+use sec::implementations::states::extract::execute_sec_request::{self, ExecuteSecRequest};
+use sec::implementations::states::extract::prepare_sec_request::{self, PrepareSecRequest};
+use sec::implementations::states::extract::validate_cik_format::{self, ValidateCikFormat};
+use sec::implementations::states::transform::TransformSuperState;
+use sec::implementations::states::transform::create_financial_statements::{
+    self, CreateFinancialStatements,
+};
+use sec::implementations::states::transform::parse_company_facts::{self, ParseCompanyFacts};
+use sec::prelude::*;
+use sec::shared::batch::{PipelineLauncher, PipelineOutcome, PipelineStatus};
+use sec::shared::checkpoint::checkpoint_error::ErrorReason;
+use sec::shared::checkpoint::{Checkpoint, CheckpointError, CheckpointSink, CheckpointWriter};
+use sec::shared::config::ArkadConfig;
+use sec::shared::environment::PipelineEnvironment;
+use sec::shared::http_client::implementations::sec_client::SecClient;
+use uuid::Uuid;
+
+use super::{PipelineEvent, log_outcome};
+
+/// Launches the full Extract + Transform pipeline of every CIK of the batch.
+///
+/// Creates an `ExtractSuperState` and calls `into_stream()` -- the framework
+/// automatically chains through Transform via the cross-`SuperState` transition.
+///
+/// With a checkpoint sink, each pipeline checkpoints after every completed state under its raw
+/// CIK, resumes from that checkpoint on the next run, and removes it once the run succeeds.
+/// A cancelled pipeline keeps its checkpoint, so the next run resumes where it stopped.
+#[derive(Debug)]
+pub struct Pipelines {
+    sec_client: SecClient,
+    config: ArkadConfig,
+    checkpoint_sink: Option<Arc<dyn CheckpointSink>>,
+}
+
+impl Pipelines {
+    /// Creates the launcher of pipelines sharing `sec_client` and `config`, without
+    /// checkpointing.
+    pub const fn new(sec_client: SecClient, config: ArkadConfig) -> Self {
+        Self {
+            sec_client,
+            config,
+            checkpoint_sink: None,
+        }
+    }
+
+    /// Creates the environment of the pipeline running under `execution_id`.
+    fn environment(&self, execution_id: Uuid) -> PipelineEnvironment {
+        PipelineEnvironment::new(self.sec_client.clone())
+            .with_config(self.config.clone())
+            .with_execution_id(execution_id)
+    }
+
+    /// Sets the sink the pipelines checkpoint to and resume from.
+    pub fn with_checkpoint_sink(mut self, checkpoint_sink: Arc<dyn CheckpointSink>) -> Self {
+        self.checkpoint_sink = Some(checkpoint_sink);
+        self
+    }
+
+    /// Creates a stream that drives the full ETL pipeline (Extract → Transform).
+    fn start_stream(
+        &self,
+        cik: &str,
+        execution_id: Uuid,
+        options: StreamOptions,
+    ) -> StateMachineStream {
+        let environment = self.environment(execution_id);
+        let state = ExtractSuperState::<ValidateCikFormat>::new(cik, environment);
+        state.into_stream_with(execution_id, options)
+    }
+
+    /// Creates a stream that continues the pipeline from `checkpoint`.
+    ///
+    /// The resumed stream keeps the checkpoint's execution ID so its events line up with the
+    /// interrupted run.
+    fn resume_stream(
+        &self,
+        checkpoint: &Checkpoint,
+        options: StreamOptions,
+    ) -> Result<StateMachineStream, CheckpointError> {
+        let execution_id = checkpoint.execution_id();
+        let environment = self.environment(execution_id);
+        let stream = match checkpoint.state_name() {
+            validate_cik_format::STATE_NAME => {
+                ExtractSuperState::<ValidateCikFormat>::resume_from(checkpoint, environment)?
+                    .into_stream_with(execution_id, options)
+            }
+            prepare_sec_request::STATE_NAME => {
+                ExtractSuperState::<PrepareSecRequest>::resume_from(checkpoint, environment)?
+                    .into_stream_with(execution_id, options)
+            }
+            execute_sec_request::STATE_NAME => {
+                ExtractSuperState::<ExecuteSecRequest>::resume_from(checkpoint, environment)?
+                    .into_stream_with(execution_id, options)
+            }
+            parse_company_facts::STATE_NAME => {
+                TransformSuperState::<ParseCompanyFacts>::resume_from(checkpoint, environment)?
+                    .into_stream_with(execution_id, options)
+            }
+            create_financial_statements::STATE_NAME => TransformSuperState::<
+                CreateFinancialStatements,
+            >::resume_from(
+                checkpoint, environment
+            )?
+            .into_stream_with(execution_id, options),
+            unknown => {
+                return Err(CheckpointError::new(ErrorReason::UnknownState {
+                    state_name: unknown.to_string(),
+                }));
+            }
+        };
+        Ok(stream)
+    }
+
+    /// Loads the checkpoint of the pipeline of `cik`, if a sink is configured and one was left
+    /// behind.
+    ///
+    /// A checkpoint that cannot be loaded is logged and ignored, so the pipeline starts over.
+    async fn load_checkpoint(&self, cik: &str) -> Option<Checkpoint> {
+        let sink = self.checkpoint_sink.as_ref()?;
+        match sink.load(cik).await {
+            Ok(checkpoint) => checkpoint,
+            Err(e) => {
+                tracing::warn!(
+                    event = %PipelineEvent::ResumeFailed,
+                    message = %e.to_string(),
+                    cik = %cik,
+                );
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PipelineLauncher for Pipelines {
+    /// Opens the pipeline of `cik`, resuming from its checkpoint when one is available.
+    async fn launch(&self, cik: &str, mut options: StreamOptions) -> (Uuid, StateMachineStream) {
+        if let Some(sink) = self.checkpoint_sink.clone() {
+            options = options.with_checkpoint_writer(CheckpointWriter::new(sink, cik.to_string()));
+        }
+
+        if let Some(checkpoint) = self.load_checkpoint(cik).await {
+            let execution_id = checkpoint.execution_id();
+            match self.resume_stream(&checkpoint, options.clone()) {
+                Ok(stream) => {
+                    tracing::info!(
+                        event = %PipelineEvent::Resumed,
+                        message = %format!("Pipeline for CIK '{cik}' resumed at '{}'", checkpoint.state_name()),
+                        execution_id = %execution_id,
+                        cik = %cik,
+                        state = %checkpoint.state_name(),
+                    );
+                    return (execution_id, stream);
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        event = %PipelineEvent::ResumeFailed,
+                        message = %e.to_string(),
+                        execution_id = %execution_id,
+                        cik = %cik,
+                        state = %checkpoint.state_name(),
+                    );
+                }
+            }
+        }
+
+        let execution_id = Uuid::new_v4();
+        (execution_id, self.start_stream(cik, execution_id, options))
+    }
+
+    /// Logs how the pipeline ended and removes the checkpoint of a successful run.
+    async fn finish(&self, outcome: &PipelineOutcome) {
+        if matches!(outcome.status, PipelineStatus::Succeeded)
+            && let Some(sink) = &self.checkpoint_sink
+            && let Err(e) = sink.remove(&outcome.cik).await
+        {
+            tracing::warn!(
+                event = %PipelineEvent::CheckpointCleanupFailed,
+                message = %e.to_string(),
+                execution_id = %outcome.execution_id.unwrap_or_default(),
+                cik = %outcome.cik,
+            );
+        }
+        log_outcome(outcome);
+    }
+}
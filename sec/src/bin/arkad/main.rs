@@ -0,0 +1,60 @@
+mod cli;
+mod commands;
+mod constants;
+mod launchers;
+mod output;
+
+use std::io::stderr;
+use std::process::ExitCode;
+
+use cli::{Cli, CliError, USAGE};
+use sec::shared::config::ArkadConfig;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// Exit code of an invalid command line or configuration.
+const USAGE_EXIT_CODE: u8 = 2;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = match Cli::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(CliError::HelpRequested) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(USAGE_EXIT_CODE);
+        }
+    };
+
+    let config = match ArkadConfig::load(
+        cli.options.config_file.as_deref(),
+        cli.options.overrides.clone(),
+    )
+    .await
+    {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(USAGE_EXIT_CODE);
+        }
+    };
+
+    // Initialize non-blocking JSON structured logging on stderr, keeping stdout for results
+    let (non_blocking, _guard) = tracing_appender::non_blocking(stderr());
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(cli.options.log_level)
+        .with_span_events(FmtSpan::NONE)
+        .with_target(false)
+        .with_current_span(false)
+        .flatten_event(true)
+        .with_writer(non_blocking)
+        .init();
+
+    commands::run(cli, &config).await.unwrap_or_else(|e| {
+        eprintln!("{e}");
+        ExitCode::FAILURE
+    })
+}
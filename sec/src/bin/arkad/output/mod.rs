@@ -0,0 +1,78 @@
+use std::io::{self, Write};
+
+use chrono::Utc;
+use sec::shared::batch::{BatchItem, BatchReport, PipelineStatus, RunReport, RunReportError};
+use sec::shared::journal::JournalEntry;
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+
+/// Writes one event of a running batch to stdout.
+///
+/// `pretty` prints one line per event and `ndjson` one [`JournalEntry`] per line, tagged with
+/// the CIK and execution ID of its pipeline; `json` prints nothing until the batch has finished.
+pub fn print_item(format: OutputFormat, item: &BatchItem) -> io::Result<()> {
+    match format {
+        OutputFormat::Pretty => match &item.result {
+            Ok(stream_item) => println!(
+                "{:<10} {:<28} {} ({:.2?})",
+                item.cik, stream_item.event, stream_item.path, stream_item.event_duration
+            ),
+            Err(e) => println!("{:<10} {:<28} {}: {}", item.cik, e.event, e.path, e.source),
+        },
+        OutputFormat::Ndjson => {
+            let mut line = serde_json::to_value(JournalEntry::from(&item.result))?;
+            if let Some(fields) = line.as_object_mut() {
+                fields.insert("cik".to_string(), item.cik.clone().into());
+                fields.insert(
+                    "execution_id".to_string(),
+                    item.execution_id.to_string().into(),
+                );
+            }
+            print_line(&line)?;
+        }
+        OutputFormat::Json => {}
+    }
+    Ok(())
+}
+
+/// Writes the summary of a finished batch to stdout.
+///
+/// `pretty` prints one line per pipeline and a tally, `json` the batch's [`RunReport`]; `ndjson`
+/// has already printed every event and prints nothing more.
+pub fn print_report(format: OutputFormat, report: &BatchReport) -> Result<(), RunReportError> {
+    match format {
+        OutputFormat::Pretty => {
+            println!();
+            for outcome in report.outcomes() {
+                let details = match &outcome.status {
+                    PipelineStatus::Failed(failure) => format!(" in '{}'", failure.state_name),
+                    _ => String::new(),
+                };
+                println!(
+                    "{:<10} {:<10} {:.2?}{details}",
+                    outcome.cik,
+                    outcome.status.label(),
+                    outcome.duration
+                );
+            }
+            let failures: usize = report.failures_by_kind().values().map(Vec::len).sum();
+            println!(
+                "\n{} succeeded, {failures} failed, {} cancelled in {:.2?}",
+                report.successes().count(),
+                report.cancellations().count() + report.skipped().count(),
+                report.elapsed()
+            );
+        }
+        OutputFormat::Json => println!("{}", RunReport::new(report, Utc::now()).to_json()?),
+        OutputFormat::Ndjson => {}
+    }
+    Ok(())
+}
+
+/// Writes `value` to stdout as one line of compact JSON.
+pub fn print_line(value: &impl Serialize) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    serde_json::to_writer(&mut stdout, value)?;
+    writeln!(stdout)
+}
@@ -1,476 +0,0 @@
-/// S&P 500 company CIKs sourced from SEC EDGAR.
-///
-/// Source: <https://www.sec.gov/files/company_tickers.json>
-///
-/// Plus 2 test CIKs for error handling verification.
-pub const CIKS: [&str; 469] = [
-    "ABC",        // Invalid CIK (test)
-    "9999999999", // Nonsense CIK (test)
-    "320193",     // Apple Inc. (AAPL)
-    "1551152",    // AbbVie Inc. (ABBV)
-    "1800",       // ABBOTT LABORATORIES (ABT)
-    "1467373",    // Accenture plc (ACN)
-    "796343",     // ADOBE INC. (ADBE)
-    "6281",       // ANALOG DEVICES INC (ADI)
-    "7084",       // Archer-Daniels-Midland Co (ADM)
-    "8670",       // AUTOMATIC DATA PROCESSING INC (ADP)
-    "769397",     // Autodesk, Inc. (ADSK)
-    "1002910",    // AMEREN CORP (AEE)
-    "4904",       // AMERICAN ELECTRIC POWER CO INC (AEP)
-    "874761",     // AES CORP (AES)
-    "4977",       // AFLAC INC (AFL)
-    "5272",       // AMERICAN INTERNATIONAL GROUP, INC. (AIG)
-    "1267238",    // ASSURANT, INC. (AIZ)
-    "354190",     // Arthur J. Gallagher & Co. (AJG)
-    "1086222",    // AKAMAI TECHNOLOGIES INC (AKAM)
-    "915913",     // ALBEMARLE CORP (ALB)
-    "1097149",    // ALIGN TECHNOLOGY INC (ALGN)
-    "899051",     // ALLSTATE CORP (ALL)
-    "1579241",    // Allegion plc (ALLE)
-    "6951",       // APPLIED MATERIALS INC /DE (AMAT)
-    "1748790",    // Amcor plc (AMCR)
-    "2488",       // ADVANCED MICRO DEVICES INC (AMD)
-    "1037868",    // AMETEK INC/ (AME)
-    "318154",     // AMGEN INC (AMGN)
-    "820027",     // AMERIPRISE FINANCIAL INC (AMP)
-    "1053507",    // AMERICAN TOWER CORP /MA/ (AMT)
-    "1018724",    // AMAZON COM INC (AMZN)
-    "1596532",    // Arista Networks, Inc. (ANET)
-    "315293",     // Aon plc (AON)
-    "91142",      // SMITH A O CORP (AOS)
-    "1841666",    // APA Corp (APA)
-    "2969",       // Air Products & Chemicals, Inc. (APD)
-    "820313",     // AMPHENOL CORP /DE/ (APH)
-    "1521332",    // Aptiv PLC (APTV)
-    "1035443",    // ALEXANDRIA REAL ESTATE EQUITIES, INC. (ARE)
-    "731802",     // ATMOS ENERGY CORP (ATO)
-    "915912",     // AVALONBAY COMMUNITIES INC (AVB)
-    "1730168",    // Broadcom Inc. (AVGO)
-    "8818",       // Avery Dennison Corp (AVY)
-    "1410636",    // American Water Works Company, Inc. (AWK)
-    "4962",       // AMERICAN EXPRESS CO (AXP)
-    "866787",     // AUTOZONE INC (AZO)
-    "12927",      // BOEING CO (BA)
-    "70858",      // BANK OF AMERICA CORP /DE/ (BAC)
-    "10456",      // BAXTER INTERNATIONAL INC (BAX)
-    "764478",     // BEST BUY CO INC (BBY)
-    "10795",      // BECTON DICKINSON & CO (BDX)
-    "38777",      // FRANKLIN RESOURCES INC (BEN)
-    "12208",      // BIO-RAD LABORATORIES, INC. (BIO)
-    "1390777",    // Bank of New York Mellon Corp (BK)
-    "1075531",    // Booking Holdings Inc. (BKNG)
-    "1701605",    // Baker Hughes Co (BKR)
-    "1316835",    // Builders FirstSource, Inc. (BLDR)
-    "2012383",    // BlackRock, Inc. (BLK)
-    "14272",      // BRISTOL MYERS SQUIBB CO (BMY)
-    "1383312",    // BROADRIDGE FINANCIAL SOLUTIONS, INC. (BR)
-    "1067983",    // BERKSHIRE HATHAWAY INC (BRK-B)
-    "79282",      // BROWN & BROWN, INC. (BRO)
-    "885725",     // BOSTON SCIENTIFIC CORP (BSX)
-    "908255",     // BORGWARNER INC (BWA)
-    "1037540",    // BXP, Inc. (BXP)
-    "831001",     // CITIGROUP INC (C)
-    "23217",      // CONAGRA BRANDS INC. (CAG)
-    "721371",     // CARDINAL HEALTH INC (CAH)
-    "1783180",    // CARRIER GLOBAL Corp (CARR)
-    "18230",      // CATERPILLAR INC (CAT)
-    "896159",     // Chubb Ltd (CB)
-    "1374310",    // Cboe Global Markets, Inc. (CBOE)
-    "1138118",    // CBRE GROUP, INC. (CBRE)
-    "1051470",    // CROWN CASTLE INC. (CCI)
-    "815097",     // CARNIVAL CORP (CCL)
-    "813672",     // CADENCE DESIGN SYSTEMS INC (CDNS)
-    "1402057",    // CDW Corp (CDW)
-    "1306830",    // Celanese Corp (CE)
-    "1868275",    // Constellation Energy Corp (CEG)
-    "1324404",    // CF Industries Holdings, Inc. (CF)
-    "759944",     // CITIZENS FINANCIAL GROUP INC/RI (CFG)
-    "313927",     // CHURCH & DWIGHT CO INC /DE/ (CHD)
-    "1043277",    // C. H. ROBINSON WORLDWIDE, INC. (CHRW)
-    "1091667",    // CHARTER COMMUNICATIONS, INC. /MO/ (CHTR)
-    "1739940",    // Cigna Group (CI)
-    "20286",      // CINCINNATI FINANCIAL CORP (CINF)
-    "21665",      // COLGATE PALMOLIVE CO (CL)
-    "21076",      // CLOROX CO /DE/ (CLX)
-    "1166691",    // COMCAST CORP (CMCSA)
-    "1156375",    // CME GROUP INC. (CME)
-    "1058090",    // CHIPOTLE MEXICAN GRILL INC (CMG)
-    "26172",      // CUMMINS INC (CMI)
-    "811156",     // CMS ENERGY CORP (CMS)
-    "1071739",    // CENTENE CORP (CNC)
-    "1130310",    // CENTERPOINT ENERGY INC (CNP)
-    "927628",     // CAPITAL ONE FINANCIAL CORP (COF)
-    "711404",     // COOPER COMPANIES, INC. (COO)
-    "1163165",    // CONOCOPHILLIPS (COP)
-    "909832",     // COSTCO WHOLESALE CORP /NEW (COST)
-    "1175454",    // CORPAY, INC. (CPAY)
-    "16732",      // CAMPBELL'S Co (CPB)
-    "900075",     // COPART INC (CPRT)
-    "906345",     // CAMDEN PROPERTY TRUST (CPT)
-    "1100682",    // CHARLES RIVER LABORATORIES INTERNATIONAL, INC. (CRL)
-    "1108524",    // Salesforce, Inc. (CRM)
-    "1535527",    // CrowdStrike Holdings, Inc. (CRWD)
-    "858877",     // CISCO SYSTEMS, INC. (CSCO)
-    "1057352",    // COSTAR GROUP, INC. (CSGP)
-    "277948",     // CSX CORP (CSX)
-    "723254",     // CINTAS CORP (CTAS)
-    "858470",     // Coterra Energy Inc. (CTRA)
-    "1058290",    // COGNIZANT TECHNOLOGY SOLUTIONS CORP (CTSH)
-    "1755672",    // Corteva, Inc. (CTVA)
-    "64803",      // CVS HEALTH Corp (CVS)
-    "93410",      // CHEVRON CORP (CVX)
-    "1590895",    // Caesars Entertainment, Inc. (CZR)
-    "715957",     // DOMINION ENERGY, INC (D)
-    "27904",      // DELTA AIR LINES, INC. (DAL)
-    "1666700",    // DuPont de Nemours, Inc. (DD)
-    "315189",     // DEERE & CO (DE)
-    "910521",     // DECKERS OUTDOOR CORP (DECK)
-    "29534",      // DOLLAR GENERAL CORP (DG)
-    "1022079",    // QUEST DIAGNOSTICS INC (DGX)
-    "882184",     // HORTON D R INC /DE/ (DHI)
-    "313616",     // DANAHER CORP /DE/ (DHR)
-    "1744489",    // Walt Disney Co (DIS)
-    "935703",     // DOLLAR TREE, INC. (DLTR)
-    "29905",      // DOVER Corp (DOV)
-    "1751788",    // DOW INC. (DOW)
-    "1286681",    // DOMINOS PIZZA INC (DPZ)
-    "940944",     // DARDEN RESTAURANTS INC (DRI)
-    "936340",     // DTE ENERGY CO (DTE)
-    "1326160",    // Duke Energy CORP (DUK)
-    "927066",     // DAVITA INC. (DVA)
-    "1090012",    // DEVON ENERGY CORP/DE (DVN)
-    "1093557",    // DEXCOM INC (DXCM)
-    "712515",     // ELECTRONIC ARTS INC. (EA)
-    "1065088",    // EBAY INC (EBAY)
-    "31462",      // ECOLAB INC. (ECL)
-    "1047862",    // CONSOLIDATED EDISON INC (ED)
-    "33185",      // EQUIFAX INC (EFX)
-    "827052",     // EDISON INTERNATIONAL (EIX)
-    "1001250",    // ESTEE LAUDER COMPANIES INC (EL)
-    "915389",     // EASTMAN CHEMICAL CO (EMN)
-    "32604",      // EMERSON ELECTRIC CO (EMR)
-    "1463101",    // Enphase Energy, Inc. (ENPH)
-    "821189",     // EOG RESOURCES INC (EOG)
-    "1352010",    // EPAM Systems, Inc. (EPAM)
-    "1101239",    // EQUINIX INC (EQIX)
-    "906107",     // EQUITY RESIDENTIAL (EQR)
-    "33213",      // EQT Corp (EQT)
-    "72741",      // EVERSOURCE ENERGY (ES)
-    "920522",     // ESSEX PROPERTY TRUST, INC. (ESS)
-    "1551182",    // Eaton Corp plc (ETN)
-    "65984",      // ENTERGY CORP /DE/ (ETR)
-    "1711269",    // Evergy, Inc. (EVRG)
-    "1099800",    // Edwards Lifesciences Corp (EW)
-    "1109357",    // EXELON CORP (EXC)
-    "746515",     // EXPEDITORS INTERNATIONAL OF WASHINGTON INC (EXPD)
-    "1324424",    // Expedia Group, Inc. (EXPE)
-    "1289490",    // Extra Space Storage Inc. (EXR)
-    "37996",      // FORD MOTOR CO (F)
-    "1539838",    // Diamondback Energy, Inc. (FANG)
-    "815556",     // FASTENAL CO (FAST)
-    "831259",     // FREEPORT-MCMORAN INC (FCX)
-    "1013237",    // FACTSET RESEARCH SYSTEMS INC (FDS)
-    "1048911",    // FEDEX CORP (FDX)
-    "1031296",    // FIRSTENERGY CORP (FE)
-    "1048695",    // F5, INC. (FFIV)
-    "814547",     // FAIR ISAAC CORP (FICO)
-    "1136893",    // Fidelity National Information Services, Inc. (FIS)
-    "798354",     // FISERV INC (FISV)
-    "35527",      // FIFTH THIRD BANCORP (FITB)
-    "37785",      // FMC CORP (FMC)
-    "1754301",    // Fox Corp (FOX)
-    "1754301",    // Fox Corp (FOXA)
-    "34903",      // FEDERAL REALTY INVESTMENT TRUST (FRT)
-    "1274494",    // FIRST SOLAR, INC. (FSLR)
-    "1262039",    // Fortinet, Inc. (FTNT)
-    "1659166",    // Fortive Corp (FTV)
-    "40533",      // GENERAL DYNAMICS CORP (GD)
-    "1609711",    // GoDaddy Inc. (GDDY)
-    "40545",      // GENERAL ELECTRIC CO (GE)
-    "1932393",    // GE HealthCare Technologies Inc. (GEHC)
-    "849399",     // Gen Digital Inc. (GEN)
-    "882095",     // GILEAD SCIENCES, INC. (GILD)
-    "40704",      // GENERAL MILLS INC (GIS)
-    "320335",     // GLOBE LIFE INC. (GL)
-    "24741",      // CORNING INC /NY (GLW)
-    "1467858",    // General Motors Co (GM)
-    "1474735",    // GENERAC HOLDINGS INC. (GNRC)
-    "1652044",    // Alphabet Inc. (GOOG)
-    "1652044",    // Alphabet Inc. (GOOGL)
-    "40987",      // GENUINE PARTS CO (GPC)
-    "1123360",    // GLOBAL PAYMENTS INC (GPN)
-    "1121788",    // GARMIN LTD (GRMN)
-    "886982",     // GOLDMAN SACHS GROUP INC (GS)
-    "277135",     // W.W. GRAINGER, INC. (GWW)
-    "45012",      // HALLIBURTON CO (HAL)
-    "46080",      // HASBRO, INC. (HAS)
-    "49196",      // HUNTINGTON BANCSHARES INC /MD/ (HBAN)
-    "860730",     // HCA Healthcare, Inc. (HCA)
-    "354950",     // HOME DEPOT, INC. (HD)
-    "859737",     // HOLOGIC INC (HOLX)
-    "773840",     // HONEYWELL INTERNATIONAL INC (HON)
-    "1645590",    // Hewlett Packard Enterprise Co (HPE)
-    "47217",      // HP INC (HPQ)
-    "48465",      // HORMEL FOODS CORP /DE/ (HRL)
-    "1000228",    // HENRY SCHEIN INC (HSIC)
-    "1070750",    // HOST HOTELS & RESORTS, INC. (HST)
-    "47111",      // HERSHEY CO (HSY)
-    "48898",      // HUBBELL INC (HUBB)
-    "49071",      // HUMANA INC (HUM)
-    "4281",       // Howmet Aerospace Inc. (HWM)
-    "51143",      // INTERNATIONAL BUSINESS MACHINES CORP (IBM)
-    "1571949",    // Intercontinental Exchange, Inc. (ICE)
-    "874716",     // IDEXX LABORATORIES INC /DE (IDXX)
-    "832101",     // IDEX CORP /DE/ (IEX)
-    "51253",      // INTERNATIONAL FLAVORS & FRAGRANCES INC (IFF)
-    "879169",     // INCYTE CORP (INCY)
-    "50863",      // INTEL CORP (INTC)
-    "896878",     // INTUIT INC. (INTU)
-    "1687229",    // Invitation Homes Inc. (INVH)
-    "51434",      // INTERNATIONAL PAPER CO /NEW/ (IP)
-    "1478242",    // IQVIA HOLDINGS INC. (IQV)
-    "1699150",    // Ingersoll Rand Inc. (IR)
-    "1020569",    // IRON MOUNTAIN INC (IRM)
-    "1035267",    // INTUITIVE SURGICAL INC (ISRG)
-    "749251",     // GARTNER INC (IT)
-    "49826",      // ILLINOIS TOOL WORKS INC (ITW)
-    "914208",     // Invesco Ltd. (IVZ)
-    "728535",     // HUNT J B TRANSPORT SERVICES INC (JBHT)
-    "833444",     // Johnson Controls International plc (JCI)
-    "779152",     // JACK HENRY & ASSOCIATES INC (JKHY)
-    "200406",     // JOHNSON & JOHNSON (JNJ)
-    "19617",      // JPMORGAN CHASE & CO (JPM)
-    "1418135",    // Keurig Dr Pepper Inc. (KDP)
-    "91576",      // KEYCORP /NEW/ (KEY)
-    "1601046",    // Keysight Technologies, Inc. (KEYS)
-    "1637459",    // Kraft Heinz Co (KHC)
-    "879101",     // KIMCO REALTY CORP (KIM)
-    "319201",     // KLA CORP (KLAC)
-    "55785",      // KIMBERLY CLARK CORP (KMB)
-    "1506307",    // KINDER MORGAN, INC. (KMI)
-    "1170010",    // CARMAX INC (KMX)
-    "21344",      // COCA COLA CO (KO)
-    "56873",      // KROGER CO (KR)
-    "1944048",    // Kenvue Inc. (KVUE)
-    "60086",      // LOEWS CORP (L)
-    "1336920",    // Leidos Holdings, Inc. (LDOS)
-    "920760",     // LENNAR CORP /NEW/ (LEN)
-    "920148",     // LABCORP HOLDINGS INC. (LH)
-    "202058",     // L3HARRIS TECHNOLOGIES, INC. /DE/ (LHX)
-    "1707925",    // LINDE PLC (LIN)
-    "1065696",    // LKQ CORP (LKQ)
-    "59478",      // ELI LILLY & Co (LLY)
-    "936468",     // LOCKHEED MARTIN CORP (LMT)
-    "352541",     // ALLIANT ENERGY CORP (LNT)
-    "60667",      // LOWES COMPANIES INC (LOW)
-    "707549",     // LAM RESEARCH CORP (LRCX)
-    "1397187",    // lululemon athletica inc. (LULU)
-    "92380",      // SOUTHWEST AIRLINES CO (LUV)
-    "1300514",    // LAS VEGAS SANDS CORP (LVS)
-    "1679273",    // Lamb Weston Holdings, Inc. (LW)
-    "1489393",    // LyondellBasell Industries N.V. (LYB)
-    "1335258",    // Live Nation Entertainment, Inc. (LYV)
-    "1141391",    // Mastercard Inc (MA)
-    "912595",     // MID AMERICA APARTMENT COMMUNITIES INC. (MAA)
-    "1048286",    // MARRIOTT INTERNATIONAL INC /MD/ (MAR)
-    "62996",      // MASCO CORP /DE/ (MAS)
-    "63908",      // MCDONALDS CORP (MCD)
-    "827054",     // MICROCHIP TECHNOLOGY INC (MCHP)
-    "927653",     // MCKESSON CORP (MCK)
-    "1059556",    // MOODYS CORP /DE/ (MCO)
-    "1103982",    // Mondelez International, Inc. (MDLZ)
-    "1613103",    // Medtronic plc (MDT)
-    "1099219",    // METLIFE INC (MET)
-    "1326801",    // Meta Platforms, Inc. (META)
-    "789570",     // MGM Resorts International (MGM)
-    "851968",     // MOHAWK INDUSTRIES INC (MHK)
-    "63754",      // MCCORMICK & CO INC (MKC)
-    "1278021",    // MARKETAXESS HOLDINGS INC (MKTX)
-    "916076",     // MARTIN MARIETTA MATERIALS INC (MLM)
-    "66740",      // 3M CO (MMM)
-    "865752",     // Monster Beverage Corp (MNST)
-    "764180",     // ALTRIA GROUP, INC. (MO)
-    "1179929",    // MOLINA HEALTHCARE, INC. (MOH)
-    "1285785",    // MOSAIC CO (MOS)
-    "1510295",    // Marathon Petroleum Corp (MPC)
-    "1280452",    // MONOLITHIC POWER SYSTEMS INC (MPWR)
-    "310158",     // Merck & Co., Inc. (MRK)
-    "1682852",    // Moderna, Inc. (MRNA)
-    "895421",     // MORGAN STANLEY (MS)
-    "1408198",    // MSCI Inc. (MSCI)
-    "789019",     // MICROSOFT CORP (MSFT)
-    "68505",      // Motorola Solutions, Inc. (MSI)
-    "36270",      // M&T BANK CORP (MTB)
-    "891103",     // Match Group, Inc. (MTCH)
-    "1037646",    // METTLER TOLEDO INTERNATIONAL INC/ (MTD)
-    "723125",     // MICRON TECHNOLOGY INC (MU)
-    "1513761",    // Norwegian Cruise Line Holdings Ltd. (NCLH)
-    "1120193",    // NASDAQ, INC. (NDAQ)
-    "72331",      // NORDSON CORP (NDSN)
-    "753308",     // NEXTERA ENERGY INC (NEE)
-    "1164727",    // NEWMONT Corp /DE/ (NEM)
-    "1065280",    // NETFLIX INC (NFLX)
-    "1111711",    // NISOURCE INC. (NI)
-    "320187",     // NIKE, Inc. (NKE)
-    "1133421",    // NORTHROP GRUMMAN CORP /DE/ (NOC)
-    "1373715",    // ServiceNow, Inc. (NOW)
-    "1013871",    // NRG ENERGY, INC. (NRG)
-    "702165",     // NORFOLK SOUTHERN CORP (NSC)
-    "1002047",    // NetApp, Inc. (NTAP)
-    "73124",      // NORTHERN TRUST CORP (NTRS)
-    "73309",      // NUCOR CORP (NUE)
-    "1045810",    // NVIDIA CORP (NVDA)
-    "906163",     // NVR INC (NVR)
-    "1564708",    // NEWS CORP (NWSA)
-    "1413447",    // NXP Semiconductors N.V. (NXPI)
-    "726728",     // REALTY INCOME CORP (O)
-    "878927",     // OLD DOMINION FREIGHT LINE, INC. (ODFL)
-    "1821825",    // Organon & Co. (OGN)
-    "1039684",    // ONEOK INC /NEW/ (OKE)
-    "29989",      // OMNICOM GROUP INC. (OMC)
-    "1097864",    // ON SEMICONDUCTOR CORP (ON)
-    "1341439",    // ORACLE CORP (ORCL)
-    "898173",     // O REILLY AUTOMOTIVE INC (ORLY)
-    "1781335",    // Otis Worldwide Corp (OTIS)
-    "797468",     // OCCIDENTAL PETROLEUM CORP /DE/ (OXY)
-    "1590955",    // Paycom Software, Inc. (PAYC)
-    "723531",     // PAYCHEX INC (PAYX)
-    "75362",      // PACCAR INC (PCAR)
-    "1004980",    // PG&E Corp (PCG)
-    "788784",     // PUBLIC SERVICE ENTERPRISE GROUP INC (PEG)
-    "77476",      // PEPSICO INC (PEP)
-    "78003",      // PFIZER INC (PFE)
-    "1126328",    // PRINCIPAL FINANCIAL GROUP INC (PFG)
-    "80424",      // PROCTER & GAMBLE Co (PG)
-    "80661",      // PROGRESSIVE CORP/OH/ (PGR)
-    "76334",      // Parker-Hannifin Corp (PH)
-    "822416",     // PULTEGROUP INC/MI/ (PHM)
-    "75677",      // PACKAGING CORP OF AMERICA (PKG)
-    "1045609",    // Prologis, Inc. (PLD)
-    "1413329",    // Philip Morris International Inc. (PM)
-    "713676",     // PNC FINANCIAL SERVICES GROUP, INC. (PNC)
-    "77360",      // PENTAIR plc (PNR)
-    "764622",     // PINNACLE WEST CAPITAL CORP (PNW)
-    "945841",     // POOL CORP (POOL)
-    "79879",      // PPG INDUSTRIES INC (PPG)
-    "922224",     // PPL Corp (PPL)
-    "1137774",    // PRUDENTIAL FINANCIAL INC (PRU)
-    "1393311",    // Public Storage (PSA)
-    "1534701",    // Phillips 66 (PSX)
-    "857005",     // PTC INC. (PTC)
-    "78239",      // PVH CORP. /DE/ (PVH)
-    "1050915",    // QUANTA SERVICES, INC. (PWR)
-    "1633917",    // PayPal Holdings, Inc. (PYPL)
-    "804328",     // QUALCOMM INC/DE (QCOM)
-    "1604778",    // Qorvo, Inc. (QRVO)
-    "884887",     // ROYAL CARIBBEAN CRUISES LTD (RCL)
-    "910606",     // REGENCY CENTERS CORP (REG)
-    "872589",     // REGENERON PHARMACEUTICALS, INC. (REGN)
-    "1281761",    // REGIONS FINANCIAL CORP (RF)
-    "315213",     // ROBERT HALF INC. (RHI)
-    "720005",     // RAYMOND JAMES FINANCIAL INC (RJF)
-    "1037038",    // RALPH LAUREN CORP (RL)
-    "943819",     // RESMED INC (RMD)
-    "1024478",    // ROCKWELL AUTOMATION, INC (ROK)
-    "84839",      // ROLLINS INC (ROL)
-    "882835",     // ROPER TECHNOLOGIES INC (ROP)
-    "745732",     // ROSS STORES, INC. (ROST)
-    "1060391",    // REPUBLIC SERVICES, INC. (RSG)
-    "101829",     // RTX Corp (RTX)
-    "31791",      // REVVITY, INC. (RVTY)
-    "1034054",    // SBA COMMUNICATIONS CORP (SBAC)
-    "829224",     // STARBUCKS CORP (SBUX)
-    "316709",     // SCHWAB CHARLES CORP (SCHW)
-    "1012100",    // SEALED AIR CORP/DE (SEE)
-    "89800",      // SHERWIN WILLIAMS CO (SHW)
-    "91419",      // J M SMUCKER Co (SJM)
-    "87347",      // SLB LIMITED/NV (SLB)
-    "1375365",    // Super Micro Computer, Inc. (SMCI)
-    "91440",      // Snap-on Inc (SNA)
-    "883241",     // SYNOPSYS INC (SNPS)
-    "92122",      // SOUTHERN CO (SO)
-    "1063761",    // SIMON PROPERTY GROUP INC. (SPG)
-    "64040",      // S&P Global Inc. (SPGI)
-    "1032208",    // SEMPRA (SRE)
-    "1757898",    // STERIS plc (STE)
-    "1022671",    // STEEL DYNAMICS INC (STLD)
-    "93751",      // STATE STREET CORP (STT)
-    "1137789",    // Seagate Technology Holdings plc (STX)
-    "16918",      // CONSTELLATION BRANDS, INC. (STZ)
-    "93556",      // STANLEY BLACK & DECKER, INC. (SWK)
-    "4127",       // SKYWORKS SOLUTIONS, INC. (SWKS)
-    "1601712",    // Synchrony Financial (SYF)
-    "310764",     // STRYKER CORP (SYK)
-    "96021",      // SYSCO CORP (SYY)
-    "732717",     // AT&T INC. (T)
-    "24545",      // MOLSON COORS BEVERAGE CO (TAP)
-    "1260221",    // TransDigm Group INC (TDG)
-    "1094285",    // TELEDYNE TECHNOLOGIES INC (TDY)
-    "842023",     // BIO-TECHNE Corp (TECH)
-    "1385157",    // TE Connectivity plc (TEL)
-    "97210",      // TERADYNE, INC (TER)
-    "92230",      // TRUIST FINANCIAL CORP (TFC)
-    "96943",      // TELEFLEX INC (TFX)
-    "27419",      // TARGET CORP (TGT)
-    "97745",      // THERMO FISHER SCIENTIFIC INC. (TMO)
-    "1283699",    // T-Mobile US, Inc. (TMUS)
-    "1116132",    // TAPESTRY, INC. (TPR)
-    "1389170",    // Targa Resources Corp. (TRGP)
-    "864749",     // TRIMBLE INC. (TRMB)
-    "1113169",    // PRICE T ROWE GROUP INC (TROW)
-    "86312",      // TRAVELERS COMPANIES, INC. (TRV)
-    "916365",     // TRACTOR SUPPLY CO /DE/ (TSCO)
-    "1318605",    // Tesla, Inc. (TSLA)
-    "100493",     // TYSON FOODS, INC. (TSN)
-    "1466258",    // Trane Technologies plc (TT)
-    "946581",     // TAKE TWO INTERACTIVE SOFTWARE INC (TTWO)
-    "97476",      // TEXAS INSTRUMENTS INC (TXN)
-    "217346",     // TEXTRON INC (TXT)
-    "860731",     // TYLER TECHNOLOGIES INC (TYL)
-    "100517",     // United Airlines Holdings, Inc. (UAL)
-    "74208",      // UDR, Inc. (UDR)
-    "352915",     // UNIVERSAL HEALTH SERVICES INC (UHS)
-    "1403568",    // Ulta Beauty, Inc. (ULTA)
-    "731766",     // UNITEDHEALTH GROUP INC (UNH)
-    "100885",     // UNION PACIFIC CORP (UNP)
-    "1090727",    // UNITED PARCEL SERVICE INC (UPS)
-    "1067701",    // UNITED RENTALS, INC. (URI)
-    "36104",      // US BANCORP DE (USB)
-    "1403161",    // VISA INC. (V)
-    "103379",     // V F CORP (VFC)
-    "1705696",    // VICI PROPERTIES INC. (VICI)
-    "1035002",    // VALERO ENERGY CORP/TX (VLO)
-    "1967680",    // Veralto Corp (VLTO)
-    "1396009",    // Vulcan Materials CO (VMC)
-    "1442145",    // Verisk Analytics, Inc. (VRSK)
-    "1014473",    // VERISIGN INC/CA (VRSN)
-    "875320",     // VERTEX PHARMACEUTICALS INC / MA (VRTX)
-    "740260",     // Ventas, Inc. (VTR)
-    "1792044",    // Viatris Inc (VTRS)
-    "732712",     // VERIZON COMMUNICATIONS INC (VZ)
-    "943452",     // WESTINGHOUSE AIR BRAKE TECHNOLOGIES CORP (WAB)
-    "1000697",    // WATERS CORP /DE/ (WAT)
-    "1437107",    // Warner Bros. Discovery, Inc. (WBD)
-    "106040",     // WESTERN DIGITAL CORP (WDC)
-    "783325",     // WEC ENERGY GROUP, INC. (WEC)
-    "766704",     // WELLTOWER INC. (WELL)
-    "72971",      // WELLS FARGO & COMPANY/MN (WFC)
-    "106640",     // WHIRLPOOL CORP /DE/ (WHR)
-    "823768",     // WASTE MANAGEMENT INC (WM)
-    "107263",     // WILLIAMS COMPANIES, INC. (WMB)
-    "104169",     // Walmart Inc. (WMT)
-    "11544",      // BERKLEY W R CORP (WRB)
-    "105770",     // WEST PHARMACEUTICAL SERVICES INC (WST)
-    "1140536",    // WILLIS TOWERS WATSON PLC (WTW)
-    "106535",     // WEYERHAEUSER CO (WY)
-    "1174922",    // WYNN RESORTS LTD (WYNN)
-    "72903",      // XCEL ENERGY INC (XEL)
-    "34088",      // EXXON MOBIL CORP (XOM)
-    "818479",     // DENTSPLY SIRONA Inc. (XRAY)
-    "1524472",    // Xylem Inc. (XYL)
-    "1041061",    // YUM BRANDS INC (YUM)
-    "1136869",    // ZIMMER BIOMET HOLDINGS, INC. (ZBH)
-    "877212",     // ZEBRA TECHNOLOGIES CORP (ZBRA)
-    "109380",     // ZIONS BANCORPORATION, NATIONAL ASSOCIATION /UT/ (ZION)
-    "1555280",    // Zoetis Inc. (ZTS)
-];
@@ -93,9 +93,10 @@ impl TryFrom<ErrorKind> for State {
         match value {
             ErrorKind::StateMachine(sm) => match sm {
                 StateMachine::State(state) => Ok(state),
-                StateMachine::Transition(_) | StateMachine::InvalidConfiguration => {
-                    Err(ErrorKind::DowncastNotPossible)
-                }
+                StateMachine::Transition(_)
+                | StateMachine::InvalidConfiguration
+                | StateMachine::Cancelled
+                | StateMachine::Replayed(_) => Err(ErrorKind::DowncastNotPossible),
             },
             _ => Err(ErrorKind::DowncastNotPossible),
         }
@@ -112,9 +113,10 @@ impl TryFrom<ErrorKind> for Transition {
         match value {
             ErrorKind::StateMachine(sm) => match sm {
                 StateMachine::Transition(transition) => Ok(transition),
-                StateMachine::State(_) | StateMachine::InvalidConfiguration => {
-                    Err(ErrorKind::DowncastNotPossible)
-                }
+                StateMachine::State(_)
+                | StateMachine::InvalidConfiguration
+                | StateMachine::Cancelled
+                | StateMachine::Replayed(_) => Err(ErrorKind::DowncastNotPossible),
             },
             _ => Err(ErrorKind::DowncastNotPossible),
         }
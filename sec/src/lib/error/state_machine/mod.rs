@@ -33,8 +33,8 @@ use std::fmt::{self, Display, Formatter};
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 /// Error occurring at the state machine level.
 ///
-/// Groups the three ways execution can fail: a misconfigured machine, a failure inside a state,
-/// or a failure transitioning between states. The latter two wrap the [`State`] and [`Transition`]
+/// Groups the ways execution can end unsuccessfully: a misconfigured machine, a failure inside a
+/// state, a failure transitioning between states, or cancellation. The two failures wrap the [`State`] and [`Transition`]
 /// errors respectively.
 pub enum StateMachine {
     /// Invalid configuration of the state machine.
@@ -45,6 +45,13 @@ pub enum StateMachine {
 
     /// A failure occurred while transitioning between states.
     Transition(Transition),
+
+    /// Execution was cancelled before the state machine reached its final state.
+    Cancelled,
+
+    /// An error re-emitted from a recorded [`Journal`](crate::shared::journal::Journal), holding
+    /// the message of the error originally recorded.
+    Replayed(String),
 }
 
 impl Display for StateMachine {
@@ -65,6 +72,10 @@ impl Display for StateMachine {
             Self::InvalidConfiguration => {
                 write!(f, "Invalid configuration of the state machine")
             }
+            Self::Cancelled => {
+                write!(f, "Execution of the state machine was cancelled")
+            }
+            Self::Replayed(message) => write!(f, "{message}"),
         }
     }
 }
@@ -255,4 +266,15 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_display_original_message_when_error_is_replayed() {
+        let message = "Execution of the state machine was cancelled".to_string();
+
+        let expected_result = StateMachine::Cancelled.to_string();
+
+        let result = StateMachine::Replayed(message).to_string();
+
+        assert_eq!(result, expected_result);
+    }
 }
@@ -1,10 +1,10 @@
 //! # State Errors
 //!
 //! Provides the [`State`] error covering failures inside a state's own logic: CIK validation, SEC
-//! request execution, data completeness, and input/context/output handling.
+//! request execution, data completeness, timeouts, and input/context/output handling.
 //!
 //! The richer variants ([`InvalidCikFormat`], [`FailedRequestExecution`],
-//! [`IncompleteCompanyFacts`]) wrap a domain error with the failing state's name; the rest are
+//! [`IncompleteCompanyFacts`], [`StateTimedOut`]) wrap a domain error with the failing state's name; the rest are
 //! plain markers. [`State`] is wrapped by [`StateMachine`](super::StateMachine) for propagation.
 //!
 //! ## Modules
@@ -12,6 +12,7 @@
 //! - [`invalid_cik_format`]: The [`InvalidCikFormat`] error wrapping a CIK validation failure.
 //! - [`failed_request_execution`]: The [`FailedRequestExecution`] error wrapping a failed SEC request.
 //! - [`incomplete_company_facts`]: The [`IncompleteCompanyFacts`] error for a response missing required fields.
+//! - [`state_timed_out`]: The [`StateTimedOut`] error for a computation exceeding its timeout.
 
 use thiserror::Error;
 
@@ -21,6 +22,8 @@ pub mod incomplete_company_facts;
 pub use incomplete_company_facts::IncompleteCompanyFacts;
 pub mod invalid_cik_format;
 pub use invalid_cik_format::InvalidCikFormat;
+pub mod state_timed_out;
+pub use state_timed_out::StateTimedOut;
 
 #[non_exhaustive]
 #[derive(Debug, Error, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -42,6 +45,10 @@ pub enum State {
     #[error("[StateError] A state level error occurred, Caused by: {0}")]
     IncompleteCompanyFacts(#[source] IncompleteCompanyFacts),
 
+    /// Indicates that the output computation of a `State` exceeded its timeout.
+    #[error("[StateError] A state level error occurred, Caused by: {0}")]
+    StateTimedOut(#[source] StateTimedOut),
+
     /// Indicates that input data of a `State` is invalid and cannot be used to compute the output data.
     #[error(
         "[StateError] A state level error occurred, Reason: Invalid input data provided to state"
@@ -67,11 +74,99 @@ pub enum State {
     ContextUpdateFailed,
 }
 
+impl State {
+    /// Returns `true` if recomputing the state may succeed where this attempt failed.
+    ///
+    /// Transient SEC request failures (transport errors, rate limiting, server errors) the client
+    /// has not already retried, and timed out computations, are retryable. A request whose client
+    /// retries are exhausted is not, so state-level retries never multiply the client's attempts.
+    /// Every other variant stems from the state's input, context, or data and would fail again on
+    /// the next attempt.
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        match self {
+            Self::FailedRequestExecution(e) => e.domain_error().is_retryable(),
+            Self::StateTimedOut(_) => true,
+            Self::InvalidCikFormat(_)
+            | Self::IncompleteCompanyFacts(_)
+            | Self::InvalidInput
+            | Self::InvalidContext
+            | Self::FailedOutputComputation
+            | Self::StateDataUpdateFailed
+            | Self::ContextUpdateFailed => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{fmt::Debug, hash::Hash};
 
+    use pretty_assertions::assert_eq;
+
     use super::*;
+    use crate::shared::http_client::implementations::sec_client::error::{
+        ErrorReason as FailedSecRequestReason, FailedSecRequest,
+    };
+
+    #[test]
+    fn should_be_retryable_when_request_failed_at_transport_level() {
+        let error = State::FailedRequestExecution(FailedRequestExecution::new(
+            "Execute SEC Request",
+            FailedSecRequest::new(FailedSecRequestReason::FailedRequestExecution {
+                details: "connection reset".to_string(),
+            }),
+        ));
+
+        let expected_result = true;
+
+        let result = error.is_retryable();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_be_retryable_when_client_exhausted_its_retries() {
+        let error = State::FailedRequestExecution(FailedRequestExecution::new(
+            "Execute SEC Request",
+            FailedSecRequest::new(FailedSecRequestReason::FailedRequestExecution {
+                details: "connection reset".to_string(),
+            })
+            .with_retries_exhausted(),
+        ));
+
+        let expected_result = false;
+
+        let result = error.is_retryable();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_be_retryable_when_computation_timed_out() {
+        let error = State::StateTimedOut(StateTimedOut::new(
+            "Execute SEC Request",
+            std::time::Duration::from_secs(30),
+        ));
+
+        let expected_result = true;
+
+        let result = error.is_retryable();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_be_retryable_when_input_is_invalid() {
+        let error = State::InvalidInput;
+
+        let expected_result = false;
+
+        let result = error.is_retryable();
+
+        assert_eq!(result, expected_result);
+    }
+
     const fn implements_auto_traits<T: Sized + Send + Sync + Unpin>() {}
     #[test]
     const fn should_be_able_to_rely_auto_trait_implementation_when_using_state() {
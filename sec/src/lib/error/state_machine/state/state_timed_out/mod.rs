@@ -0,0 +1,110 @@
+//! # State Timed Out Error
+//!
+//! Provides the [`StateTimedOut`] error: a state's output computation did not finish within the
+//! timeout configured on its context.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use sec::error::state_machine::state::state_timed_out::StateTimedOut;
+//!
+//! let expected_result = Duration::from_secs(30);
+//!
+//! let result = StateTimedOut::new("Execute SEC Request", Duration::from_secs(30)).timeout();
+//!
+//! assert_eq!(result, expected_result);
+//! ```
+
+use std::time::Duration;
+
+use thiserror::Error;
+
+use super::State as StateError;
+
+/// Error indicating that a state's output computation exceeded its timeout.
+///
+/// Raised by the stream driver when a single computation attempt runs longer than the
+/// [`Context::timeout`](crate::traits::state_machine::state::Context::timeout) of the state. The
+/// attempt is abandoned; the computation itself is left unchanged, so a retry starts afresh.
+/// Carries the failing state's name and the timeout that was exceeded.
+#[derive(Error, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error(
+    "[StateTimedOut] Output computation did not finish in time in State: '{state_name}', Reason: 'Exceeded timeout of {timeout:?}'"
+)]
+pub struct StateTimedOut {
+    state_name: String,
+    timeout: Duration,
+}
+
+impl StateTimedOut {
+    /// Creates a new [`StateTimedOut`] error.
+    #[must_use]
+    pub fn new(state_name: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            state_name: state_name.into(),
+            timeout,
+        }
+    }
+
+    /// Returns the name of the state where the error occurred.
+    #[must_use]
+    pub fn state_name(&self) -> &str {
+        &self.state_name
+    }
+
+    /// Returns the timeout the computation exceeded.
+    #[must_use]
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl From<StateTimedOut> for StateError {
+    /// Wraps the error in the [`StateError::StateTimedOut`] variant.
+    fn from(error: StateTimedOut) -> Self {
+        Self::StateTimedOut(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_create_error_with_state_name_and_timeout_when_new_is_called() {
+        let expected_result = StateTimedOut {
+            state_name: "Execute SEC Request".to_string(),
+            timeout: Duration::from_secs(5),
+        };
+
+        let result = StateTimedOut::new("Execute SEC Request", Duration::from_secs(5));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_state_name_when_accessed() {
+        let error = StateTimedOut::new("Execute SEC Request", Duration::from_secs(5));
+
+        let expected_result = "Execute SEC Request";
+
+        let result = error.state_name();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_convert_to_state_error_when_into_is_called() {
+        let error = StateTimedOut::new("TestState", Duration::from_millis(250));
+
+        let expected_result = StateError::StateTimedOut(error.clone());
+
+        let result: StateError = error.into();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -8,7 +8,7 @@
 //!
 //! ## See Also
 //!
-//! - [`output`](super::output): The SEC response produced from this input.
+//! - [`output`](super::output): The company-facts document produced from this input.
 //! - [`crate::shared::request`]: The SEC request type carried here.
 
 use std::fmt;
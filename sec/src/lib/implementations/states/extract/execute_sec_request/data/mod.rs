@@ -3,13 +3,13 @@
 //! Groups the input and output data types for the
 //! [`ExecuteSecRequest`](super::ExecuteSecRequest) state.
 //!
-//! The state consumes a prepared client and request and produces the SEC response.
+//! The state consumes a prepared client and request and produces the company-facts document.
 //! Input and output live in separate child modules to mirror that transformation.
 //!
 //! ## Modules
 //!
 //! - [`input`]: The [`ExecuteSecRequestInput`] holding the prepared client and request.
-//! - [`output`]: The [`ExecuteSecRequestOutput`] holding the received SEC response.
+//! - [`output`]: The [`ExecuteSecRequestOutput`] holding the received company-facts document.
 //!
 //! ## See Also
 //!
@@ -4,49 +4,61 @@
 //! [`ExecuteSecRequest`](crate::implementations::states::extract::execute_sec_request::ExecuteSecRequest)
 //! state, along with its updater and builder.
 //!
-//! It wraps the [`SecResponse`] returned by the SEC API, which the transform phase consumes.
-//! The dispatched client and request live in [`input`](super::input).
+//! It wraps the [`CompanyFactsDocument`] deserialized from the SEC API's response, which the
+//! transform phase consumes. The dispatched client and request live in [`input`](super::input).
 //!
 //! ## See Also
 //!
-//! - [`input`](super::input): The client and request this response was produced from.
-//! - [`crate::shared::response`]: The SEC response type carried here.
+//! - [`input`](super::input): The client and request this document was produced from.
+//! - [`crate::shared::company_facts`]: The company-facts document carried here.
 
 use std::fmt;
 
-use serde::Serialize;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 use state_maschine::prelude::StateData as SMStateData;
 
 use crate::error::State as StateError;
-use crate::shared::response::implementations::sec_response::SecResponse;
+use crate::shared::company_facts::CompanyFactsDocument;
 use crate::traits::state_machine::state::StateData;
 
 /// Output data of the [`ExecuteSecRequest`](super::super::ExecuteSecRequest) state.
 ///
-/// Wraps the [`SecResponse`] returned by the SEC API.
-#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
+/// Wraps the [`CompanyFactsDocument`] deserialized from the SEC API's response. It serializes
+/// into the document's CIK and entity name only, keeping logged state events small; checkpoints
+/// keep the whole document.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 pub struct ExecuteSecRequestOutput {
-    /// The SEC response received from the API endpoint.
-    pub response: SecResponse,
+    /// The company facts received from the API endpoint.
+    pub document: CompanyFactsDocument,
 }
 
 impl ExecuteSecRequestOutput {
-    /// Creates a new [`ExecuteSecRequestOutput`] from an SEC response.
+    /// Creates a new [`ExecuteSecRequestOutput`] from a company-facts document.
     #[must_use]
-    pub const fn new(response: SecResponse) -> Self {
-        Self { response }
+    pub const fn new(document: CompanyFactsDocument) -> Self {
+        Self { document }
     }
 
-    /// Returns a reference to the SEC response.
+    /// Returns a reference to the company-facts document.
     #[must_use]
-    pub const fn response(&self) -> &SecResponse {
-        &self.response
+    pub const fn document(&self) -> &CompanyFactsDocument {
+        &self.document
+    }
+}
+impl Serialize for ExecuteSecRequestOutput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ExecuteSecRequestOutput", 2)?;
+        state.serialize_field("cik", self.document.cik())?;
+        state.serialize_field("entity_name", self.document.entity_name())?;
+        state.end()
     }
 }
+
 impl StateData for ExecuteSecRequestOutput {
     fn update_state(&mut self, updates: Self::UpdateType) -> Result<(), StateError> {
-        if let Some(response) = updates.response {
-            self.response = response;
+        if let Some(document) = updates.document {
+            self.document = document;
         }
         Ok(())
     }
@@ -70,7 +82,12 @@ impl SMStateData for ExecuteSecRequestOutput {
 
 impl fmt::Display for ExecuteSecRequestOutput {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "\t{}", self.response)
+        write!(
+            f,
+            "\tCIK: {}\n\tEntity Name: {}",
+            self.document.cik(),
+            self.document.entity_name()
+        )
     }
 }
 
@@ -79,8 +96,8 @@ impl fmt::Display for ExecuteSecRequestOutput {
 /// Fields set to `None` are left unchanged when the updater is applied.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 pub struct ExecuteSecRequestOutputUpdater {
-    /// Optional new value for the SEC response.
-    pub response: Option<SecResponse>,
+    /// Optional new value for the company-facts document.
+    pub document: Option<CompanyFactsDocument>,
 }
 
 impl ExecuteSecRequestOutputUpdater {
@@ -93,20 +110,20 @@ impl ExecuteSecRequestOutputUpdater {
 
 /// Fluent builder for an [`ExecuteSecRequestOutputUpdater`].
 pub struct ExecuteSecRequestOutputUpdaterBuilder {
-    response: Option<SecResponse>,
+    document: Option<CompanyFactsDocument>,
 }
 
 impl ExecuteSecRequestOutputUpdaterBuilder {
     /// Creates a new [`ExecuteSecRequestOutputUpdaterBuilder`] with all fields initialized to `None`.
     #[must_use]
     pub const fn new() -> Self {
-        Self { response: None }
+        Self { document: None }
     }
 
-    /// Sets the SEC response field.
+    /// Sets the company-facts document field.
     #[must_use]
-    pub fn response(mut self, response: SecResponse) -> Self {
-        self.response = Some(response);
+    pub fn document(mut self, document: CompanyFactsDocument) -> Self {
+        self.document = Some(document);
         self
     }
 
@@ -114,7 +131,7 @@ impl ExecuteSecRequestOutputUpdaterBuilder {
     #[must_use]
     pub fn build(self) -> ExecuteSecRequestOutputUpdater {
         ExecuteSecRequestOutputUpdater {
-            response: self.response,
+            document: self.document,
         }
     }
 }
@@ -127,66 +144,51 @@ impl Default for ExecuteSecRequestOutputUpdaterBuilder {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
     use std::{fmt::Debug, hash::Hash};
 
     use pretty_assertions::assert_eq;
 
     use super::*;
-    use crate::shared::content_type::ContentType;
-    use crate::shared::headers::Headers;
-    use crate::shared::response::implementations::sec_response::SecResponse;
-    use crate::shared::status_code::StatusCode;
-    use crate::shared::url::Url;
-
-    /// Creates a known-good baseline `SecResponse` for use in tests.
-    fn create_baseline_response() -> SecResponse {
-        let url: Url = "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json"
-            .parse()
-            .expect("Hardcoded URL should always parse successfully");
-
-        let mut raw_headers = HashMap::new();
-        raw_headers.insert("content-type".to_string(), "application/json".to_string());
-        let headers = Headers::new(raw_headers);
-
-        SecResponse::from_parts(
-            url,
-            headers,
-            ContentType::Json,
-            StatusCode::Ok,
-            serde_json::json!({}),
-        )
+
+    /// Creates a known-good baseline `CompanyFactsDocument` for use in tests.
+    fn create_baseline_document() -> CompanyFactsDocument {
+        CompanyFactsDocument::from_value(&serde_json::json!({
+            "cik": 1_067_983,
+            "entityName": "BERKSHIRE HATHAWAY INC",
+            "facts": {}
+        }))
+        .expect("A hardcoded company-facts body should always deserialize")
     }
 
     #[test]
-    fn should_create_new_output_data_with_provided_response() {
-        let response = create_baseline_response();
-        let expected_response = response.clone();
+    fn should_create_new_output_data_with_provided_document() {
+        let document = create_baseline_document();
+        let expected_document = document.clone();
 
-        let result = ExecuteSecRequestOutput::new(response);
+        let result = ExecuteSecRequestOutput::new(document);
 
-        assert_eq!(result.response(), &expected_response);
+        assert_eq!(result.document(), &expected_document);
     }
 
     #[test]
-    fn should_return_response_reference_when_accessing_response() {
-        let response = create_baseline_response();
-        let output_data = ExecuteSecRequestOutput::new(response.clone());
+    fn should_return_document_reference_when_accessing_document() {
+        let document = create_baseline_document();
+        let output_data = ExecuteSecRequestOutput::new(document.clone());
 
-        let expected_result = &response;
-        let result = output_data.response();
+        let expected_result = &document;
+        let result = output_data.document();
 
         assert_eq!(result, expected_result);
     }
 
     #[test]
-    fn should_update_response_when_updater_contains_response() {
-        let original_response = create_baseline_response();
-        let new_response = create_baseline_response();
-        let mut output_data = ExecuteSecRequestOutput::new(original_response);
+    fn should_update_document_when_updater_contains_document() {
+        let original_document = create_baseline_document();
+        let new_document = create_baseline_document();
+        let mut output_data = ExecuteSecRequestOutput::new(original_document);
 
         let updater = ExecuteSecRequestOutputUpdater::builder()
-            .response(new_response.clone())
+            .document(new_document.clone())
             .build();
 
         let expected_result = Ok(());
@@ -196,13 +198,13 @@ mod tests {
     }
 
     #[test]
-    fn should_display_response_information_when_formatted() {
-        let response = create_baseline_response();
-        let output_data = ExecuteSecRequestOutput::new(response);
+    fn should_display_document_information_when_formatted() {
+        let document = create_baseline_document();
+        let output_data = ExecuteSecRequestOutput::new(document);
 
-        let expected_result = false;
+        let expected_result = true;
 
-        let result = format!("{output_data}").is_empty();
+        let result = format!("{output_data}").contains("BERKSHIRE HATHAWAY INC");
 
         assert_eq!(result, expected_result);
     }
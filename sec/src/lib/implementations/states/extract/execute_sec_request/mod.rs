@@ -1,18 +1,20 @@
 //! # Execute SEC Request State
 //!
 //! Provides the [`ExecuteSecRequest`] state, which sends the prepared request to the SEC API
-//! and captures the response.
+//! and captures the company facts it replies with.
 //!
 //! This is the one extract state that touches the network: it consumes the prepared client
-//! and request, performs the HTTP call, and produces an
-//! [`SecResponse`](crate::shared::response::implementations::sec_response::SecResponse) for the
-//! transform phase. Isolating the network call here keeps the earlier states pure and testable.
+//! and request, performs the HTTP call, and deserializes the body into a
+//! [`CompanyFactsDocument`](crate::shared::company_facts::CompanyFactsDocument) for the
+//! transform phase while it downloads. Isolating the network call here keeps the earlier states
+//! pure and testable.
 //!
 //! ## Modules
 //!
 //! - [`constants`]: State metadata such as [`STATE_NAME`].
 //! - [`context`]: The [`ExecuteSecRequestContext`] carried alongside the state.
 //! - [`data`]: The [`ExecuteSecRequestInput`] and [`ExecuteSecRequestOutput`] data types.
+//! - [`snapshot`]: The [`ExecuteSecRequestSnapshot`] used to checkpoint and resume the state.
 //!
 //! ## Usage
 //!
@@ -36,46 +38,50 @@
 //!     .await
 //!     .expect("The live SEC request should succeed");
 //!
-//! let _response = state
+//! let _document = state
 //!     .output_data()
 //!     .expect("Output is present after a successful computation")
-//!     .response();
+//!     .document();
 //! # }
 //! ```
 //!
 //! ## See Also
 //!
 //! - [`crate::implementations::states::extract`]: Parent module describing the extraction flow.
-//! - [`crate::shared::response::implementations::sec_response::SecResponse`]: The response type produced by this state.
+//! - [`crate::shared::company_facts::CompanyFactsDocument`]: The document produced by this state.
 //! - [`crate::traits::state_machine::state::State`]: The trait implemented by [`ExecuteSecRequest`].
 
 use std::fmt;
 
-use async_trait::async_trait;
 use serde::Serialize;
-use state_maschine::prelude::State as SMState;
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
 use crate::error::state_machine::state::failed_request_execution::FailedRequestExecution;
-use crate::shared::http_client::SecClient as SecClientTrait;
+use crate::shared::executor::TokioExecutor;
 use crate::traits::state_machine::state::State;
 
 pub mod constants;
 pub mod context;
 pub mod data;
+pub mod snapshot;
 
 pub use constants::STATE_NAME;
 pub use context::ExecuteSecRequestContext;
 pub use data::ExecuteSecRequestInput;
 pub use data::ExecuteSecRequestOutput;
+pub use snapshot::ExecuteSecRequestSnapshot;
 
-/// Sends the prepared request to the SEC API and captures the response.
+/// Sends the prepared request to the SEC API and captures the company facts it replies with.
 ///
 /// Consumes the prepared [`SecClient`](crate::shared::http_client::implementations::sec_client::SecClient)
 /// and [`SecRequest`](crate::shared::request::implementations::sec_request::SecRequest), performs the
-/// HTTP call, and stores the resulting
-/// [`SecResponse`](crate::shared::response::implementations::sec_response::SecResponse). It is the only
-/// extract state that performs I/O, which is why network failures surface here.
+/// HTTP call through
+/// [`execute_company_facts_request`](crate::shared::http_client::implementations::sec_client::SecClient::execute_company_facts_request),
+/// and stores the resulting
+/// [`CompanyFactsDocument`](crate::shared::company_facts::CompanyFactsDocument). It is the only
+/// extract state that performs I/O, which is why network failures and malformed bodies surface
+/// here.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
 pub struct ExecuteSecRequest {
     input: ExecuteSecRequestInput,
@@ -129,34 +135,35 @@ impl ExecuteSecRequest {
     }
 }
 
-#[async_trait]
-impl State for ExecuteSecRequest {
-    /// Executes the prepared SEC request and stores the response as output.
+impl SMAsyncState for ExecuteSecRequest {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
+    /// Executes the prepared SEC request and stores the company-facts document as output.
     ///
     /// # Errors
     ///
     /// Returns [`StateError::FailedRequestExecution`] when the HTTP request fails — for
-    /// example on a network error, timeout, or non-success SEC API response.
+    /// example on a network error, timeout, non-success SEC API response, or a body that is not
+    /// a company-facts body.
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
         let client = &self.input.sec_client;
         let request = &self.input.sec_request;
 
-        let result = client.execute_sec_request(request.clone()).await;
+        let result = client.execute_company_facts_request(request.clone()).await;
 
         match result {
-            Ok(response) => {
-                self.output = Some(ExecuteSecRequestOutput::new(response));
+            Ok(document) => {
+                self.output = Some(ExecuteSecRequestOutput::new(document));
                 Ok(())
             }
-            Err(e) => {
-                let e: StateError =
-                    FailedRequestExecution::new(self.state_name().to_string(), e).into();
-                return Err(e);
-            }
+            Err(e) => Err(FailedRequestExecution::new(self.state_name().to_string(), e).into()),
         }
     }
 }
 
+impl State for ExecuteSecRequest {}
+
 impl SMState for ExecuteSecRequest {
     type InputData = ExecuteSecRequestInput;
     type OutputData = ExecuteSecRequestOutput;
@@ -167,29 +174,13 @@ impl SMState for ExecuteSecRequest {
         STATE_NAME
     }
 
-    /// Blocking wrapper around [`compute_output_data_async`](crate::traits::state_machine::state::State::compute_output_data_async).
-    ///
-    /// Detects whether a tokio runtime is available and runs the async computation
-    /// synchronously. This allows SEC states to be used as regular `SMState` implementations.
+    /// Blocking wrapper around [`compute_output_data_async`](state_maschine::prelude::AsyncState::compute_output_data_async),
+    /// see [`compute_output_data_blocking`](state_maschine::prelude::AsyncState::compute_output_data_blocking).
     ///
     /// # Panics
-    /// - Panics if the async computation returns an error.
-    /// - Panics if called inside a `current_thread` runtime (`block_in_place`
-    ///   requires a multi-thread runtime). All binaries and tests in this project
-    ///   use `flavor = "multi_thread"`.
+    /// Panics if the async computation returns an error.
     fn compute_output_data(&mut self) {
-        let result = if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| handle.block_on(self.compute_output_data_async()))
-        } else {
-            tokio::runtime::Runtime::new()
-                .expect("Failed to create tokio runtime for blocking compute")
-                .block_on(self.compute_output_data_async())
-        };
-
-        if let Err(e) = result {
-            let state_err: StateError = e;
-            panic!("compute_output_data failed: {state_err}")
-        }
+        self.compute_output_data_blocking();
     }
 
     fn context_data(&self) -> &Self::Context {
@@ -230,11 +221,13 @@ mod tests {
     use std::{fmt::Debug, hash::Hash};
 
     use pretty_assertions::assert_eq;
+    use proptest::prelude::Strategy;
 
     use super::*;
     use crate::shared::cik::Cik;
     use crate::shared::http_client::implementations::sec_client::SecClient;
     use crate::shared::request::implementations::sec_request::SecRequest;
+    use crate::tests::conformance::{arb_cik, state_conformance};
 
     const TEST_CIK: &str = "0001067983";
 
@@ -541,4 +534,22 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    fn arb_execute_sec_request() -> impl Strategy<Value = ExecuteSecRequest> {
+        let client = SecClient::default();
+        (arb_cik(), arb_cik()).prop_map(move |(request_cik, context_cik)| {
+            let request = SecRequest::builder()
+                .all_company_facts()
+                .cik(request_cik)
+                .build();
+            ExecuteSecRequest::new(
+                ExecuteSecRequestInput::new(client.clone(), request),
+                ExecuteSecRequestContext::new(context_cik),
+            )
+        })
+    }
+
+    state_conformance! {
+        state: ExecuteSecRequest = arb_execute_sec_request(),
+    }
 }
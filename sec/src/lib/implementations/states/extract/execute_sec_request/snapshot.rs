@@ -0,0 +1,148 @@
+//! # Execute SEC Request Snapshot
+//!
+//! Provides the [`ExecuteSecRequestSnapshot`] capturing an [`ExecuteSecRequest`] state for
+//! checkpoints.
+//!
+//! This is the checkpoint that matters most: it keeps the company-facts document, so a resumed
+//! pipeline continues into the transform phase without going back to the network.
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::cik::Cik;
+use crate::shared::company_facts::CompanyFactsDocument;
+use crate::shared::http_client::implementations::sec_client::SecClient;
+use crate::shared::request::implementations::sec_request::SecRequest;
+use crate::traits::state_machine::checkpoint::Checkpointable;
+
+use super::{
+    ExecuteSecRequest, ExecuteSecRequestContext, ExecuteSecRequestInput, ExecuteSecRequestOutput,
+};
+
+/// A lossless, client-free snapshot of an [`ExecuteSecRequest`] state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecuteSecRequestSnapshot {
+    /// The request to execute.
+    pub request: SecRequest,
+    /// The CIK carried by the context.
+    pub cik: Cik,
+    /// The context's retry budget.
+    pub max_retries: u32,
+    /// The company-facts document, if the state has completed.
+    pub document: Option<CompanyFactsDocument>,
+}
+
+impl Checkpointable for ExecuteSecRequest {
+    type Snapshot = ExecuteSecRequestSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        ExecuteSecRequestSnapshot {
+            request: self.input.sec_request.clone(),
+            cik: self.context.cik.clone(),
+            max_retries: self.context.max_retries,
+            document: self.output.as_ref().map(|output| output.document.clone()),
+        }
+    }
+}
+
+impl ExecuteSecRequest {
+    /// Restores an [`ExecuteSecRequest`] state from `snapshot`, handing it `sec_client`.
+    ///
+    /// A completed snapshot restores its document as output, so the request is not sent again.
+    #[must_use]
+    pub fn from_snapshot(snapshot: ExecuteSecRequestSnapshot, sec_client: SecClient) -> Self {
+        let mut context = ExecuteSecRequestContext::new(snapshot.cik);
+        context.max_retries = snapshot.max_retries;
+
+        Self {
+            input: ExecuteSecRequestInput::new(sec_client, snapshot.request),
+            context,
+            output: snapshot.document.map(ExecuteSecRequestOutput::new),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use state_maschine::prelude::State as SMState;
+
+    use super::*;
+
+    fn completed_state() -> ExecuteSecRequest {
+        let cik = Cik::new("1067983").expect("A hardcoded valid CIK should always parse");
+        let request = SecRequest::builder()
+            .all_company_facts()
+            .cik(cik.clone())
+            .build();
+        let document = CompanyFactsDocument::from_value(&serde_json::json!({
+            "cik": 1_067_983,
+            "entityName": "BERKSHIRE HATHAWAY INC",
+            "facts": {
+                "us-gaap": {
+                    "Assets": {
+                        "label": "Assets",
+                        "units": {
+                            "USD": [{
+                                "end": "2023-12-31",
+                                "val": 1_069_978_000_000_i64,
+                                "accn": "0000950170-24-021296",
+                                "fy": 2023,
+                                "fp": "FY",
+                                "form": "10-K",
+                                "filed": "2024-02-26",
+                                "frame": "CY2023Q4I"
+                            }]
+                        }
+                    }
+                }
+            }
+        }))
+        .expect("A hardcoded company-facts body should always deserialize");
+        let mut state = ExecuteSecRequest::new(
+            ExecuteSecRequestInput::new(SecClient::default(), request),
+            ExecuteSecRequestContext::new(cik),
+        );
+        state.output = Some(ExecuteSecRequestOutput::new(document));
+        state
+    }
+
+    #[test]
+    fn should_restore_equal_state_when_round_tripping_completed_state_through_json() {
+        let state = completed_state();
+        let serialized = serde_json::to_string(&state.snapshot())
+            .expect("A state snapshot should always serialize");
+
+        let expected_result = state;
+
+        let result = ExecuteSecRequest::from_snapshot(
+            serde_json::from_str(&serialized).expect("A serialized snapshot should deserialize"),
+            SecClient::default(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_restore_document_when_round_tripping_completed_state_through_json() {
+        let state = completed_state();
+        let serialized = serde_json::to_string(&state.snapshot())
+            .expect("A state snapshot should always serialize");
+
+        let expected_result = state
+            .output_data()
+            .expect("The completed fixture state should always have output")
+            .document()
+            .clone();
+
+        let result = ExecuteSecRequest::from_snapshot(
+            serde_json::from_str(&serialized).expect("A serialized snapshot should deserialize"),
+            SecClient::default(),
+        )
+        .output_data()
+        .expect("A restored completed state should always have output")
+        .document()
+        .clone();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -2,15 +2,16 @@
 //!
 //! Provides the states of the extract phase and the [`ExtractSuperState`] that drives them.
 //!
-//! Extraction turns a raw CIK string into a fetched SEC response through three ordered
-//! states. The super-state owns the shared HTTP client and the type-safe transitions
-//! between them, so callers advance the pipeline without managing each state by hand.
+//! Extraction turns a raw CIK string into fetched SEC company facts through three ordered
+//! states. The super-state owns the run's [`PipelineEnvironment`] and the type-safe
+//! transitions between them, so callers advance the pipeline without managing each state by
+//! hand.
 //!
 //! ## Modules
 //!
 //! - [`validate_cik_format`]: Validates and normalizes the raw CIK.
 //! - [`prepare_sec_request`]: Builds the request targeting the SEC API endpoint.
-//! - [`execute_sec_request`]: Sends the request and captures the response.
+//! - [`execute_sec_request`]: Sends the request and captures the company facts it returns.
 //!
 //! ## State Flow
 //!
@@ -23,12 +24,12 @@
 //! use sec::implementations::states::extract::*;
 //! use sec::implementations::states::extract::validate_cik_format::ValidateCikFormat;
 //! use sec::prelude::*;
-//! use sec::shared::http_client::implementations::sec_client::SecClient;
+//! use sec::shared::environment::PipelineEnvironment;
 //!
 //! # #[tokio::main]
 //! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let mut extract_state =
-//!     ExtractSuperState::<ValidateCikFormat>::new("1234567890", SecClient::default());
+//!     ExtractSuperState::<ValidateCikFormat>::new("1234567890", PipelineEnvironment::default());
 //! extract_state.compute_output_data_async().await?;
 //! let _next_state = extract_state.transition_to_next_state_sec()?;
 //! # Ok(())
@@ -39,9 +40,10 @@ pub mod execute_sec_request;
 pub mod prepare_sec_request;
 pub mod validate_cik_format;
 
+use std::cmp::Ordering;
 use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
 
-use async_trait::async_trait;
 use serde::Serialize;
 use state_maschine::prelude::{StateMachine as SMStateMachine, Transition as SMTransition};
 
@@ -62,9 +64,12 @@ use crate::implementations::states::transform::TransformSuperState;
 use crate::implementations::states::transform::parse_company_facts::ParseCompanyFacts;
 use crate::implementations::states::transform::parse_company_facts::constants::STATE_NAME as PARSE_COMPANY_FACTS;
 use crate::prelude::*;
+use crate::shared::checkpoint::{Checkpoint, CheckpointError};
 use crate::shared::cik::Cik;
-use crate::shared::http_client::implementations::sec_client::SecClient;
+use crate::shared::environment::{NoRepository, PipelineEnvironment, RepositorySlot};
+use crate::shared::executor::TokioExecutor;
 use crate::shared::request::implementations::sec_request::SecRequest;
+use crate::traits::state_machine::transition::TryFromState;
 
 /// Input and output data for the [`ExtractSuperState`].
 ///
@@ -89,22 +94,69 @@ impl StateData for ExtractSuperStateData {
 
 /// Context for the [`ExtractSuperState`].
 ///
-/// Holds the shared [`SecClient`] handed down to each inner state, so the client is
-/// constructed once and reused across the whole extract phase.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
-pub struct ExtractSuperStateContext {
-    pub(crate) sec_client: SecClient,
+/// Holds the run's [`PipelineEnvironment`], handed to every transition between the inner
+/// states and on to the transform phase, so its resources are constructed once and reused across
+/// the whole run.
+///
+/// # Type Parameters
+///
+/// - `R`: The repository of the environment, or [`NoRepository`] if the run persists nothing.
+#[derive(Debug, Serialize)]
+#[serde(bound = "")]
+pub struct ExtractSuperStateContext<R = NoRepository> {
+    pub(crate) environment: PipelineEnvironment<R>,
 }
 
-impl ExtractSuperStateContext {
-    /// Creates a new [`ExtractSuperStateContext`] wrapping the shared HTTP client.
+impl<R> ExtractSuperStateContext<R> {
+    /// Creates a new [`ExtractSuperStateContext`] holding the run's environment.
+    #[must_use]
+    pub const fn new(environment: PipelineEnvironment<R>) -> Self {
+        Self { environment }
+    }
+
+    /// Returns the run's environment.
     #[must_use]
-    pub const fn new(sec_client: SecClient) -> Self {
-        Self { sec_client }
+    pub const fn environment(&self) -> &PipelineEnvironment<R> {
+        &self.environment
+    }
+}
+
+// Implemented by hand rather than derived, so the repository itself need not be cloneable,
+// comparable, or hashable: like its environment, the context is identified by the execution ID.
+
+impl<R> Clone for ExtractSuperStateContext<R> {
+    fn clone(&self) -> Self {
+        Self::new(self.environment.clone())
+    }
+}
+
+impl<R> PartialEq for ExtractSuperStateContext<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.environment == other.environment
     }
 }
 
-impl SMContext for ExtractSuperStateContext {
+impl<R> Eq for ExtractSuperStateContext<R> {}
+
+impl<R> Hash for ExtractSuperStateContext<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.environment.hash(state);
+    }
+}
+
+impl<R> PartialOrd for ExtractSuperStateContext<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R> Ord for ExtractSuperStateContext<R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.environment.cmp(&other.environment)
+    }
+}
+
+impl<R: RepositorySlot> SMContext for ExtractSuperStateContext<R> {
     type UpdateType = ();
     fn context(&self) -> &Self {
         self
@@ -112,7 +164,7 @@ impl SMContext for ExtractSuperStateContext {
     fn update_context(&mut self, (): Self::UpdateType) {}
 }
 
-impl Context for ExtractSuperStateContext {
+impl<R: RepositorySlot> Context for ExtractSuperStateContext<R> {
     fn max_retries(&self) -> u32 {
         0
     }
@@ -120,20 +172,86 @@ impl Context for ExtractSuperStateContext {
 
 /// Hierarchical super-state that orchestrates the extract phase.
 ///
-/// Wraps the currently active extract state in its `S` type parameter and owns the shared
-/// HTTP client, exposing the inner state through a unified state-machine interface and
+/// Wraps the currently active extract state in its `S` type parameter and owns the run's
+/// environment, exposing the inner state through a unified state-machine interface and
 /// advancing the pipeline via type-safe transitions: [`ValidateCikFormat`] →
 /// [`PrepareSecRequest`] → [`ExecuteSecRequest`]. Encoding the active state in the type
 /// makes invalid transitions a compile error rather than a runtime check.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
-pub struct ExtractSuperState<S: State> {
+///
+/// # Type Parameters
+///
+/// - `S`: The active extract state.
+/// - `R`: The repository of the run's environment, or [`NoRepository`] if the run persists nothing.
+#[derive(Debug, Serialize)]
+#[serde(bound(serialize = "S: Serialize"))]
+pub struct ExtractSuperState<S: State, R = NoRepository> {
     current_state: S,
     input: ExtractSuperStateData,
     output: Option<ExtractSuperStateData>,
-    context: ExtractSuperStateContext,
+    context: ExtractSuperStateContext<R>,
 }
 
-impl<S: State> Display for ExtractSuperState<S> {
+impl<S: State, R> ExtractSuperState<S, R> {
+    /// Returns every field, so the hand-written comparisons below cover the same data a derive
+    /// would.
+    const fn fields(
+        &self,
+    ) -> (
+        &S,
+        &ExtractSuperStateData,
+        &Option<ExtractSuperStateData>,
+        &ExtractSuperStateContext<R>,
+    ) {
+        (
+            &self.current_state,
+            &self.input,
+            &self.output,
+            &self.context,
+        )
+    }
+}
+
+// Implemented by hand rather than derived, so the repository of the environment need not
+// implement these traits itself; see the context.
+
+impl<S: State, R> Clone for ExtractSuperState<S, R> {
+    fn clone(&self) -> Self {
+        Self {
+            current_state: self.current_state.clone(),
+            input: self.input.clone(),
+            output: self.output.clone(),
+            context: self.context.clone(),
+        }
+    }
+}
+
+impl<S: State, R> PartialEq for ExtractSuperState<S, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields() == other.fields()
+    }
+}
+
+impl<S: State, R> Eq for ExtractSuperState<S, R> {}
+
+impl<S: State, R> Hash for ExtractSuperState<S, R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fields().hash(state);
+    }
+}
+
+impl<S: State, R> PartialOrd for ExtractSuperState<S, R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: State, R> Ord for ExtractSuperState<S, R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.fields().cmp(&other.fields())
+    }
+}
+
+impl<S: State, R: RepositorySlot> Display for ExtractSuperState<S, R> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
@@ -143,10 +261,10 @@ impl<S: State> Display for ExtractSuperState<S> {
     }
 }
 
-impl<S: State> SMState for ExtractSuperState<S> {
+impl<S: State, R: RepositorySlot> SMState for ExtractSuperState<S, R> {
     type InputData = ExtractSuperStateData;
     type OutputData = ExtractSuperStateData;
-    type Context = ExtractSuperStateContext;
+    type Context = ExtractSuperStateContext<R>;
 
     fn state_name(&self) -> impl ToString {
         format!(
@@ -157,32 +275,13 @@ impl<S: State> SMState for ExtractSuperState<S> {
     fn input_data(&self) -> &Self::InputData {
         &self.input
     }
-    /// Blocking wrapper around [`compute_output_data_async`](crate::traits::state_machine::state::State::compute_output_data_async).
-    ///
-    /// Detects whether a tokio runtime is available and runs the async computation
-    /// synchronously. This allows SEC states to be used as regular `SMState` implementations.
+    /// Blocking wrapper around [`compute_output_data_async`](state_maschine::prelude::AsyncState::compute_output_data_async),
+    /// see [`compute_output_data_blocking`](state_maschine::prelude::AsyncState::compute_output_data_blocking).
     ///
     /// # Panics
-    /// - Panics if the async computation returns an error.
-    /// - Panics if called inside a `current_thread` runtime (`block_in_place`
-    ///   requires a multi-thread runtime). All binaries and tests in this project
-    ///   use `flavor = "multi_thread"`.
+    /// Panics if the async computation returns an error.
     fn compute_output_data(&mut self) {
-        let result = if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| {
-                handle.block_on(self.current_state.compute_output_data_async())
-            })
-        } else {
-            tokio::runtime::Runtime::new()
-                .expect("Failed to create tokio runtime for blocking compute")
-                .block_on(self.current_state.compute_output_data_async())
-        };
-
-        if let Err(e) = result {
-            #[allow(clippy::useless_conversion)]
-            let state_err: crate::error::State = e.into();
-            panic!("compute_output_data failed: {state_err}")
-        }
+        self.compute_output_data_blocking();
     }
     fn output_data(&self) -> Option<&Self::OutputData> {
         self.output.as_ref()
@@ -192,137 +291,199 @@ impl<S: State> SMState for ExtractSuperState<S> {
     }
 }
 
-#[async_trait]
-impl<S: State> State for ExtractSuperState<S> {
+impl<S: State, R: RepositorySlot> SMAsyncState for ExtractSuperState<S, R> {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
-        self.current_state
-            .compute_output_data_async()
-            .await
-            .map_err(std::convert::Into::into)
+        self.current_state.compute_output_data_async().await
     }
 }
 
-impl<S: State> SMStateMachine<S> for ExtractSuperState<S> {
+impl<S: State, R: RepositorySlot> State for ExtractSuperState<S, R> {}
+
+impl<S: State, R: RepositorySlot> SMStateMachine<S> for ExtractSuperState<S, R> {
     fn current_state(&self) -> &S {
         &self.current_state
     }
     fn current_state_mut(&mut self) -> &mut S {
         &mut self.current_state
     }
+    /// Blocking counterpart of
+    /// [`run_async`](state_maschine::prelude::AsyncStateMachine::run_async).
     fn run(&mut self) {
-        unimplemented!(
-            "SEC state machines are async-only. \
-             Use into_stream or drive states manually"
-        )
+        if !self.current_state.has_output_data_been_computed() {
+            self.advance_state();
+        }
     }
+    /// Blocking counterpart of
+    /// [`advance_state_async`](state_maschine::prelude::AsyncStateMachine::advance_state_async).
     fn advance_state(&mut self) {
-        unimplemented!(
-            "SEC state machines are async-only. \
-             Use into_stream or drive states manually"
-        )
+        self.current_state.compute_output_data();
     }
 }
 
-impl<S: State> StateMachine<S> for ExtractSuperState<S> {}
+impl<S: State, R: RepositorySlot> StateMachine<S> for ExtractSuperState<S, R> {}
+
+impl<S: State, R: RepositorySlot> SMAsyncStateMachine<S> for ExtractSuperState<S, R> {}
+
+impl<S: State, R: RepositorySlot> SMSuperState<S> for ExtractSuperState<S, R> {}
+
+impl<S: State, R: RepositorySlot> SMAsyncSuperState<S> for ExtractSuperState<S, R> {}
 
-impl<S: State> SMSuperState<S> for ExtractSuperState<S> {}
+impl<S: State, R: RepositorySlot> SuperState<S> for ExtractSuperState<S, R> {
+    fn super_state_name(&self) -> &'static str {
+        "Extract SuperState"
+    }
 
-impl<S: State> SuperState<S> for ExtractSuperState<S> {}
+    fn execution_id(&self) -> Option<uuid::Uuid> {
+        Some(self.context.environment.execution_id())
+    }
+}
 
-impl ExtractSuperState<ValidateCikFormat> {
-    /// Creates the super-state at the pipeline entry point from a raw CIK and shared client.
+impl<R: RepositorySlot> ExtractSuperState<ValidateCikFormat, R> {
+    /// Creates the super-state at the pipeline entry point from a raw CIK and the run's
+    /// environment.
     #[must_use]
-    pub fn new(input: impl Into<String>, sec_client: SecClient) -> Self {
+    pub fn new(input: impl Into<String>, environment: PipelineEnvironment<R>) -> Self {
         let input: String = input.into();
         let input_data = ValidateCikFormatInput::new(input.clone());
-        let state_context = ValidateCikFormatContext::new(input, sec_client.clone());
+        let state_context = ValidateCikFormatContext::new(input);
 
         Self {
             current_state: ValidateCikFormat::new(input_data, state_context),
             input: ExtractSuperStateData,
             output: None,
-            context: ExtractSuperStateContext::new(sec_client),
+            context: ExtractSuperStateContext::new(environment),
         }
     }
+
+    /// Resumes the super-state at the CIK-validation state from a checkpoint, within
+    /// `environment`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the checkpoint does not hold a [`ValidateCikFormat`] state.
+    pub fn resume_from(
+        checkpoint: &Checkpoint,
+        environment: PipelineEnvironment<R>,
+    ) -> Result<Self, CheckpointError> {
+        let current_state =
+            checkpoint.restore(|snapshot| Ok(ValidateCikFormat::from_snapshot(snapshot)))?;
+
+        Ok(Self {
+            current_state,
+            input: ExtractSuperStateData,
+            output: None,
+            context: ExtractSuperStateContext::new(environment),
+        })
+    }
 }
 
-impl ExtractSuperState<PrepareSecRequest> {
+impl<R: RepositorySlot> ExtractSuperState<PrepareSecRequest, R> {
     /// Creates the super-state positioned at the request-preparation state.
     #[must_use]
-    pub fn new(validated_cik: Cik, sec_client: SecClient) -> Self {
-        let input_data = PrepareSecRequestInput::new(validated_cik.clone(), sec_client.clone());
+    pub fn new(validated_cik: Cik, environment: PipelineEnvironment<R>) -> Self {
+        let input_data =
+            PrepareSecRequestInput::new(validated_cik.clone(), environment.sec_client().clone());
         let context = PrepareSecRequestContext::new(validated_cik);
 
         Self {
             current_state: PrepareSecRequest::new(input_data, context),
             input: ExtractSuperStateData,
             output: None,
-            context: ExtractSuperStateContext::new(sec_client),
+            context: ExtractSuperStateContext::new(environment),
         }
     }
+
+    /// Resumes the super-state at the request-preparation state from a checkpoint, handing the
+    /// restored state the client of `environment`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the checkpoint does not hold a [`PrepareSecRequest`] state.
+    pub fn resume_from(
+        checkpoint: &Checkpoint,
+        environment: PipelineEnvironment<R>,
+    ) -> Result<Self, CheckpointError> {
+        let current_state = checkpoint.restore(|snapshot| {
+            Ok(PrepareSecRequest::from_snapshot(
+                snapshot,
+                environment.sec_client().clone(),
+            ))
+        })?;
+
+        Ok(Self {
+            current_state,
+            input: ExtractSuperStateData,
+            output: None,
+            context: ExtractSuperStateContext::new(environment),
+        })
+    }
 }
 
-impl ExtractSuperState<ExecuteSecRequest> {
-    /// Creates the super-state positioned at the request-execution state.
+impl<R: RepositorySlot> ExtractSuperState<ExecuteSecRequest, R> {
+    /// Creates the super-state positioned at the request-execution state, retrying the request
+    /// as often as the configuration of `environment` allows.
     #[must_use]
-    pub fn new(client: SecClient, request: SecRequest, cik: Cik) -> Self {
-        let esr_input = ExecuteSecRequestInput::new(client.clone(), request);
-        let esr_context = ExecuteSecRequestContext::new(cik);
+    pub fn new(request: SecRequest, cik: Cik, environment: PipelineEnvironment<R>) -> Self {
+        let esr_input = ExecuteSecRequestInput::new(environment.sec_client().clone(), request);
+        let mut esr_context = ExecuteSecRequestContext::new(cik);
+        esr_context.max_retries = environment.config().max_state_retries();
 
         Self {
             current_state: ExecuteSecRequest::new(esr_input, esr_context),
             input: ExtractSuperStateData,
             output: None,
-            context: ExtractSuperStateContext::new(client),
+            context: ExtractSuperStateContext::new(environment),
         }
     }
-}
-
-impl<S: State> ExtractSuperState<S> {
-    /// Consumes the `SuperState` and returns the inner state.
-    #[must_use]
-    pub fn into_current_state(self) -> S {
-        self.current_state
-    }
-}
-
-// --- Streaming ---
-
-impl NonTerminal for ExtractSuperState<ValidateCikFormat> {
-    type Current = ValidateCikFormat;
-    type Next = PrepareSecRequest;
-}
-
-impl NonTerminal for ExtractSuperState<PrepareSecRequest> {
-    type Current = PrepareSecRequest;
-    type Next = ExecuteSecRequest;
-}
-
-/// `ExecuteSecRequest` is no longer terminal -- it transitions into the Transform `SuperState`.
-impl NonTerminal for ExtractSuperState<ExecuteSecRequest> {
-    type Current = ExecuteSecRequest;
-    type Next = ParseCompanyFacts;
-}
 
-impl Transition<ValidateCikFormat, PrepareSecRequest> for ExtractSuperState<ValidateCikFormat> {
-    fn transition_to_next_state_sec(self) -> Result<Self::NewStateMachine, TransitionError> {
-        let next_state = PrepareSecRequest::try_from(self.current_state)?;
+    /// Resumes the super-state at the request-execution state from a checkpoint, handing the
+    /// restored state the client of `environment`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the checkpoint does not hold a [`ExecuteSecRequest`] state.
+    pub fn resume_from(
+        checkpoint: &Checkpoint,
+        environment: PipelineEnvironment<R>,
+    ) -> Result<Self, CheckpointError> {
+        let current_state = checkpoint.restore(|snapshot| {
+            Ok(ExecuteSecRequest::from_snapshot(
+                snapshot,
+                environment.sec_client().clone(),
+            ))
+        })?;
 
-        Ok(ExtractSuperState::<PrepareSecRequest> {
-            current_state: next_state,
+        Ok(Self {
+            current_state,
             input: ExtractSuperStateData,
             output: None,
-            context: self.context,
+            context: ExtractSuperStateContext::new(environment),
         })
     }
 }
 
-impl Transition<PrepareSecRequest, ExecuteSecRequest> for ExtractSuperState<PrepareSecRequest> {
-    fn transition_to_next_state_sec(self) -> Result<Self::NewStateMachine, TransitionError> {
-        let next_state = ExecuteSecRequest::try_from(self.current_state)?;
+impl<S: State, R: RepositorySlot> ExtractSuperState<S, R> {
+    /// Consumes the `SuperState` and returns the inner state.
+    #[must_use]
+    pub fn into_current_state(self) -> S {
+        self.current_state
+    }
 
-        Ok(ExtractSuperState::<ExecuteSecRequest> {
-            current_state: next_state,
+    /// Moves the `SuperState` on to the state converted from its current one within the run's
+    /// environment, keeping the shared context.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransitionError`] if the current state cannot be converted into `N`.
+    fn try_advance<N>(self) -> Result<ExtractSuperState<N, R>, TransitionError>
+    where
+        N: State + TryFromState<S, R>,
+    {
+        Ok(ExtractSuperState {
+            current_state: N::try_from_state(self.current_state, &self.context.environment)?,
             input: ExtractSuperStateData,
             output: None,
             context: self.context,
@@ -330,51 +491,41 @@ impl Transition<PrepareSecRequest, ExecuteSecRequest> for ExtractSuperState<Prep
     }
 }
 
-impl SMTransition<PrepareSecRequest, ExecuteSecRequest> for ExtractSuperState<PrepareSecRequest> {
-    type NewStateMachine = ExtractSuperState<ExecuteSecRequest>;
-
-    fn transition_to_next_state(self) -> Result<Self::NewStateMachine, &'static str> {
-        // Placeholder implementation - use transition_to_next_state_sec() for actual functionality
-        Err(
-            "Use transition_to_next_state_sec() for SEC-specific transitions with rich error handling",
-        )
-    }
-}
+// --- Streaming ---
 
-impl SMTransition<ValidateCikFormat, PrepareSecRequest> for ExtractSuperState<ValidateCikFormat> {
-    type NewStateMachine = ExtractSuperState<PrepareSecRequest>;
+crate::state_sequence!(ExtractSuperState = "Extract": ValidateCikFormat => PrepareSecRequest => ExecuteSecRequest);
 
-    fn transition_to_next_state(self) -> Result<Self::NewStateMachine, &'static str> {
-        // Placeholder implementation - use transition_to_next_state_sec() for actual functionality
-        Err(
-            "Use transition_to_next_state_sec() for SEC-specific transitions with rich error handling",
-        )
-    }
+/// `ExecuteSecRequest` is no longer terminal -- it transitions into the Transform `SuperState`.
+impl<R: RepositorySlot> NonTerminal for ExtractSuperState<ExecuteSecRequest, R> {
+    type Current = ExecuteSecRequest;
+    type Next = ParseCompanyFacts;
 }
 
 // --- Cross-SuperState transition: Extract → Transform ---
 
-impl Transition<ExecuteSecRequest, ParseCompanyFacts> for ExtractSuperState<ExecuteSecRequest> {
+impl<R: RepositorySlot> Transition<ExecuteSecRequest, ParseCompanyFacts>
+    for ExtractSuperState<ExecuteSecRequest, R>
+{
     fn transition_to_next_state_sec(self) -> Result<Self::NewStateMachine, TransitionError> {
-        let inner_state = self.into_current_state();
-        let (_input, output, context) = inner_state.into_parts();
+        let environment = self.context.environment;
+        let (_input, output, context) = self.current_state.into_parts();
 
         let output_data = output.ok_or_else(|| {
             transition::MissingOutput::new(EXECUTE_SEC_REQUEST, PARSE_COMPANY_FACTS)
         })?;
 
-        let sec_response = output_data.response;
-        let cik = context.cik;
-
-        Ok(TransformSuperState::<ParseCompanyFacts>::new(
-            &sec_response,
-            cik,
+        Ok(TransformSuperState::<ParseCompanyFacts, R>::new(
+            output_data.document,
+            context.cik,
+            environment,
         ))
     }
 }
 
-impl SMTransition<ExecuteSecRequest, ParseCompanyFacts> for ExtractSuperState<ExecuteSecRequest> {
-    type NewStateMachine = TransformSuperState<ParseCompanyFacts>;
+impl<R: RepositorySlot> SMTransition<ExecuteSecRequest, ParseCompanyFacts>
+    for ExtractSuperState<ExecuteSecRequest, R>
+{
+    type NewStateMachine = TransformSuperState<ParseCompanyFacts, R>;
 
     fn transition_to_next_state(self) -> Result<Self::NewStateMachine, &'static str> {
         Err(
@@ -388,16 +539,17 @@ mod tests {
     use std::{fmt::Debug, hash::Hash};
 
     use pretty_assertions::assert_eq;
+    use storage::Repository;
 
     use super::*;
     use crate::shared::cik::Cik;
-    use crate::shared::http_client::implementations::sec_client::SecClient;
+    use crate::tests::fixtures::sample_repository::SampleRepository;
 
     #[test]
     fn should_return_super_state_name_with_current_state_when_in_validate_cik_format_state() {
         let input_cik = "1234567890";
-        let sec_client = SecClient::default();
-        let super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
 
         let expected_result = "Extract SuperState (Current: Validate CIK Format)";
 
@@ -409,8 +561,8 @@ mod tests {
     #[test]
     fn should_return_super_state_name_with_current_state_when_in_prepare_sec_request_state() {
         let cik = Cik::new("1234567890").expect("Hardcoded CIK should be valid");
-        let sec_client = SecClient::default();
-        let super_state = ExtractSuperState::<PrepareSecRequest>::new(cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let super_state = ExtractSuperState::<PrepareSecRequest>::new(cik, environment);
 
         let expected_result = "Extract SuperState (Current: Prepare SEC Request)";
 
@@ -422,8 +574,8 @@ mod tests {
     #[test]
     fn should_access_current_validate_cik_format_state_from_super_state() {
         let input_cik = "1234567890";
-        let sec_client = SecClient::default();
-        let super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
 
         let expected_result = "Validate CIK Format";
 
@@ -435,8 +587,8 @@ mod tests {
     #[test]
     fn should_access_current_prepare_sec_request_state_from_super_state() {
         let cik = Cik::new("1234567890").expect("Hardcoded CIK should be valid");
-        let sec_client = SecClient::default();
-        let super_state = ExtractSuperState::<PrepareSecRequest>::new(cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let super_state = ExtractSuperState::<PrepareSecRequest>::new(cik, environment);
 
         let expected_result = "Prepare SEC Request";
 
@@ -448,8 +600,8 @@ mod tests {
     #[tokio::test]
     async fn should_transition_from_prepare_sec_request_to_execute_sec_request_state() {
         let input_cik = "1234567890";
-        let sec_client = SecClient::default();
-        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
 
         super_state
             .compute_output_data_async()
@@ -476,11 +628,43 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[tokio::test]
+    async fn should_keep_repository_reachable_when_transitioning_within_repository_environment() {
+        let environment =
+            PipelineEnvironment::default().with_repository(SampleRepository::default());
+        let mut super_state =
+            ExtractSuperState::<ValidateCikFormat, _>::new("1234567890", environment);
+        super_state
+            .compute_output_data_async()
+            .await
+            .expect("Should compute output data");
+        let super_state = super_state
+            .transition_to_next_state_sec()
+            .expect("Should transition to PrepareSecRequest");
+        super_state
+            .context_data()
+            .environment()
+            .repository()
+            .persist("1234567890".to_string())
+            .await
+            .expect("The sample repository should always persist");
+
+        let expected_result = vec!["1234567890".to_string()];
+
+        let result = super_state
+            .context_data()
+            .environment()
+            .repository()
+            .persisted();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[tokio::test]
     async fn should_fail_transition_from_prepare_sec_request_when_output_data_not_yet_computed() {
         let cik = Cik::new("1234567890").expect("Hardcoded CIK should be valid");
-        let sec_client = SecClient::default();
-        let super_state = ExtractSuperState::<PrepareSecRequest>::new(cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let super_state = ExtractSuperState::<PrepareSecRequest>::new(cik, environment);
 
         let expected_result = true;
         let result = super_state.transition_to_next_state_sec().is_err();
@@ -491,8 +675,8 @@ mod tests {
     #[tokio::test]
     async fn should_delegate_computation_to_current_state_when_computing_output_data() {
         let input_cik = "1234567890";
-        let sec_client = SecClient::default();
-        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
 
         let expected_result = Ok(());
 
@@ -501,11 +685,58 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[tokio::test]
+    async fn should_compute_inner_state_output_when_running_super_state_async() {
+        let input_cik = "1234567890";
+        let environment = PipelineEnvironment::default();
+        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
+
+        let expected_result = true;
+
+        super_state
+            .run_async()
+            .await
+            .expect("Hardcoded valid CIK should always compute successfully");
+        let result = super_state.current_state().has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_invalid_cik_format_error_when_advancing_super_state_async_with_invalid_cik()
+     {
+        let environment = PipelineEnvironment::default();
+        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new("not-a-cik", environment);
+
+        let expected_result = true;
+
+        let result = matches!(
+            super_state.advance_state_async().await,
+            Err(StateError::InvalidCikFormat(_))
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_compute_inner_state_output_when_running_super_state_blocking() {
+        let input_cik = "1234567890";
+        let environment = PipelineEnvironment::default();
+        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
+
+        let expected_result = true;
+
+        super_state.run();
+        let result = super_state.current_state().has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[tokio::test]
     async fn should_transition_from_validate_cik_format_to_prepare_sec_request_state() {
         let input_cik = "1234567890";
-        let sec_client = SecClient::default();
-        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let mut super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
 
         super_state
             .compute_output_data_async()
@@ -523,12 +754,51 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[tokio::test]
+    async fn should_keep_run_environment_when_transitioning_between_inner_states() {
+        let environment = PipelineEnvironment::default();
+        let mut super_state =
+            ExtractSuperState::<ValidateCikFormat>::new("1234567890", environment.clone());
+        super_state
+            .compute_output_data_async()
+            .await
+            .expect("Hardcoded valid CIK should always compute successfully");
+
+        let expected_result = environment.execution_id();
+
+        let result = super_state
+            .transition_to_next_state_sec()
+            .expect("Should transition to PrepareSecRequest")
+            .context_data()
+            .environment()
+            .execution_id();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_stream_under_environment_execution_id_when_opened_with_other_id() {
+        let environment = PipelineEnvironment::default();
+        let super_state = ExtractSuperState::<ValidateCikFormat>::new("abc", environment.clone());
+        let stream = super_state.into_stream(uuid::Uuid::new_v4());
+
+        let expected_result = Some(environment.execution_id());
+
+        let result = futures_util::StreamExt::collect::<Vec<_>>(stream)
+            .await
+            .into_iter()
+            .find_map(Result::err)
+            .map(|error| error.execution_id);
+
+        assert_eq!(result, expected_result);
+    }
+
     #[tokio::test]
     #[should_panic]
     async fn should_fail_transition_when_output_data_not_yet_computed() {
         let input_cik = "1234567890";
-        let sec_client = SecClient::default();
-        let super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, sec_client);
+        let environment = PipelineEnvironment::default();
+        let super_state = ExtractSuperState::<ValidateCikFormat>::new(input_cik, environment);
 
         let _result = super_state
             .transition_to_next_state_sec()
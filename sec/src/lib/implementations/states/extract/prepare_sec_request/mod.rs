@@ -13,6 +13,7 @@
 //! - [`constants`]: State metadata such as [`STATE_NAME`].
 //! - [`context`]: The [`PrepareSecRequestContext`] carried alongside the state.
 //! - [`data`]: The [`PrepareSecRequestInput`] and [`PrepareSecRequestOutput`] data types.
+//! - [`snapshot`]: The [`PrepareSecRequestSnapshot`] used to checkpoint and resume the state.
 //!
 //! ## Usage
 //!
@@ -49,22 +50,24 @@
 
 use std::fmt;
 
-use async_trait::async_trait;
 use serde::Serialize;
-use state_maschine::prelude::State as SMState;
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
+use crate::shared::executor::TokioExecutor;
 use crate::shared::request::implementations::sec_request::SecRequest;
 use crate::traits::state_machine::state::State;
 
 pub mod constants;
 pub mod context;
 pub mod data;
+pub mod snapshot;
 
 pub use constants::STATE_NAME;
 pub use context::PrepareSecRequestContext;
 pub use data::PrepareSecRequestInput;
 pub use data::PrepareSecRequestOutput;
+pub use snapshot::PrepareSecRequestSnapshot;
 
 /// Builds the [`SecRequest`] for a validated CIK.
 ///
@@ -122,9 +125,12 @@ impl PrepareSecRequest {
     }
 }
 
-#[async_trait]
-impl State for PrepareSecRequest {
-    /// Builds the company-facts [`SecRequest`] for the input CIK and stores it as output.
+impl SMAsyncState for PrepareSecRequest {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
+    /// Builds the company-facts [`SecRequest`] for the input CIK, under the company-facts URL
+    /// prefix of the input client, and stores it as output.
     ///
     /// # Errors
     ///
@@ -133,6 +139,7 @@ impl State for PrepareSecRequest {
         let sec_client = self.input.sec_client.clone();
         let sec_request = SecRequest::builder()
             .all_company_facts()
+            .url_prefix(sec_client.company_facts_url_prefix().clone())
             .cik(self.input.validated_cik.clone())
             .build();
 
@@ -142,6 +149,8 @@ impl State for PrepareSecRequest {
     }
 }
 
+impl State for PrepareSecRequest {}
+
 impl SMState for PrepareSecRequest {
     type InputData = PrepareSecRequestInput;
     type OutputData = PrepareSecRequestOutput;
@@ -152,29 +161,13 @@ impl SMState for PrepareSecRequest {
         STATE_NAME
     }
 
-    /// Blocking wrapper around [`compute_output_data_async`](crate::traits::state_machine::state::State::compute_output_data_async).
-    ///
-    /// Detects whether a tokio runtime is available and runs the async computation
-    /// synchronously. This allows SEC states to be used as regular `SMState` implementations.
+    /// Blocking wrapper around [`compute_output_data_async`](state_maschine::prelude::AsyncState::compute_output_data_async),
+    /// see [`compute_output_data_blocking`](state_maschine::prelude::AsyncState::compute_output_data_blocking).
     ///
     /// # Panics
-    /// - Panics if the async computation returns an error.
-    /// - Panics if called inside a `current_thread` runtime (`block_in_place`
-    ///   requires a multi-thread runtime). All binaries and tests in this project
-    ///   use `flavor = "multi_thread"`.
+    /// Panics if the async computation returns an error.
     fn compute_output_data(&mut self) {
-        let result = if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| handle.block_on(self.compute_output_data_async()))
-        } else {
-            tokio::runtime::Runtime::new()
-                .expect("Failed to create tokio runtime for blocking compute")
-                .block_on(self.compute_output_data_async())
-        };
-
-        if let Err(e) = result {
-            let state_err: StateError = e;
-            panic!("compute_output_data failed: {state_err}")
-        }
+        self.compute_output_data_blocking();
     }
 
     fn context_data(&self) -> &Self::Context {
@@ -215,13 +208,15 @@ mod tests {
     use std::{fmt::Debug, hash::Hash};
 
     use pretty_assertions::assert_eq;
+    use proptest::prelude::Strategy;
     use tokio;
 
     use super::*;
+    use crate::implementations::states::extract::execute_sec_request::ExecuteSecRequest;
     use crate::shared::cik::Cik;
     use crate::shared::cik::constants::BERKSHIRE_HATHAWAY_CIK_RAW;
     use crate::shared::http_client::implementations::sec_client::SecClient;
-    use crate::traits::state_machine::state::State;
+    use crate::tests::conformance::{arb_cik, state_conformance};
 
     fn create_test_cik() -> Cik {
         Cik::new(BERKSHIRE_HATHAWAY_CIK_RAW).expect("Hardcoded CIK should always be valid")
@@ -503,7 +498,7 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
-    #[tokio::test(flavor = "multi_thread")]
+    #[tokio::test]
     async fn should_produce_output_when_calling_sync_compute_inside_tokio_runtime() {
         let mut state = create_test_state();
 
@@ -514,4 +509,19 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    fn arb_prepare_sec_request() -> impl Strategy<Value = PrepareSecRequest> {
+        let client = SecClient::default();
+        (arb_cik(), arb_cik()).prop_map(move |(validated_cik, context_cik)| {
+            PrepareSecRequest::new(
+                PrepareSecRequestInput::new(validated_cik, client.clone()),
+                PrepareSecRequestContext::new(context_cik),
+            )
+        })
+    }
+
+    state_conformance! {
+        state: PrepareSecRequest = arb_prepare_sec_request(),
+        transitions: [ExecuteSecRequest],
+    }
 }
@@ -0,0 +1,90 @@
+//! # Prepare SEC Request Snapshot
+//!
+//! Provides the [`PrepareSecRequestSnapshot`] capturing a [`PrepareSecRequest`] state for
+//! checkpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::cik::Cik;
+use crate::shared::http_client::implementations::sec_client::SecClient;
+use crate::shared::request::implementations::sec_request::SecRequest;
+use crate::traits::state_machine::checkpoint::Checkpointable;
+
+use super::{
+    PrepareSecRequest, PrepareSecRequestContext, PrepareSecRequestInput, PrepareSecRequestOutput,
+};
+
+/// A lossless, client-free snapshot of a [`PrepareSecRequest`] state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrepareSecRequestSnapshot {
+    /// The validated CIK from the input.
+    pub validated_cik: Cik,
+    /// The CIK carried by the context.
+    pub cik: Cik,
+    /// The context's retry budget.
+    pub max_retries: u32,
+    /// The prepared request, if the state has completed.
+    pub request: Option<SecRequest>,
+}
+
+impl Checkpointable for PrepareSecRequest {
+    type Snapshot = PrepareSecRequestSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        PrepareSecRequestSnapshot {
+            validated_cik: self.input.validated_cik.clone(),
+            cik: self.context.cik.clone(),
+            max_retries: self.context.max_retries,
+            request: self.output.as_ref().map(|output| output.request.clone()),
+        }
+    }
+}
+
+impl PrepareSecRequest {
+    /// Restores a [`PrepareSecRequest`] state from `snapshot`, handing it `sec_client`.
+    #[must_use]
+    pub fn from_snapshot(snapshot: PrepareSecRequestSnapshot, sec_client: SecClient) -> Self {
+        let mut context = PrepareSecRequestContext::new(snapshot.cik);
+        context.max_retries = snapshot.max_retries;
+
+        Self {
+            input: PrepareSecRequestInput::new(snapshot.validated_cik, sec_client.clone()),
+            context,
+            output: snapshot
+                .request
+                .map(|request| PrepareSecRequestOutput::new(sec_client, request)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::prelude::SMAsyncState;
+
+    #[tokio::test]
+    async fn should_restore_equal_state_when_round_tripping_completed_state_through_json() {
+        let cik = Cik::new("1067983").expect("A hardcoded valid CIK should always parse");
+        let mut state = PrepareSecRequest::new(
+            PrepareSecRequestInput::new(cik.clone(), SecClient::default()),
+            PrepareSecRequestContext::new(cik),
+        );
+        state
+            .compute_output_data_async()
+            .await
+            .expect("Preparing a request for a valid CIK should always succeed");
+        let serialized = serde_json::to_string(&state.snapshot())
+            .expect("A state snapshot should always serialize");
+
+        let expected_result = state;
+
+        let result = PrepareSecRequest::from_snapshot(
+            serde_json::from_str(&serialized).expect("A serialized snapshot should deserialize"),
+            SecClient::default(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -5,18 +5,18 @@
 //! state, together with its updater and builder.
 //!
 //! The context holds information that outlives any single input/output pair —
-//! the shared [`SecClient`] and the retry budget — so the state can be re-run and
-//! transitioned without reconstructing those resources. Updates are applied through the
+//! the raw CIK and the retry budget — so the state can be re-run without reconstructing it.
+//! Run-wide resources such as the HTTP client live in the
+//! [`PipelineEnvironment`](crate::shared::environment::PipelineEnvironment) instead. Updates are applied through the
 //! [`Context`] trait via a builder-constructed updater, keeping mutation explicit and partial.
 //!
 //! ## Usage
 //!
 //! ```rust
 //! use sec::implementations::states::extract::validate_cik_format::context::*;
-//! use sec::shared::http_client::implementations::sec_client::SecClient;
 //! use state_maschine::prelude::*;
 //!
-//! let mut context = ValidateCikFormatContext::new("1067983", SecClient::default());
+//! let mut context = ValidateCikFormatContext::new("1067983");
 //! let update = ValidateCikFormatContextUpdater::builder()
 //!     .cik("0000000001")
 //!     .build();
@@ -39,30 +39,25 @@ use std::fmt;
 use serde::Serialize;
 use state_maschine::prelude::Context as SMContext;
 
-use crate::shared::http_client::implementations::sec_client::SecClient;
 use crate::traits::state_machine::state::Context;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
 /// Context for the [`ValidateCikFormat`](super::ValidateCikFormat) state.
 ///
-/// Bundles the resources and configuration that persist across the state's lifetime —
-/// the raw CIK under validation, the shared [`SecClient`], and the retry budget — so they
-/// survive updates and transitions.
+/// Bundles the configuration that persists across the state's lifetime — the raw CIK under
+/// validation and the retry budget — so it survives updates.
 pub struct ValidateCikFormatContext {
     /// The unvalidated CIK string provided for validation.
     pub raw_cik: String,
-    /// The shared HTTP client for SEC API requests.
-    pub sec_client: SecClient,
     /// Maximum number of times the state may be retried on failure.
     pub max_retries: u32,
 }
 
 impl ValidateCikFormatContext {
-    /// Creates a new [`ValidateCikFormatContext`] from a raw CIK and a shared HTTP client.
-    pub fn new(cik: impl Into<String>, sec_client: SecClient) -> Self {
+    /// Creates a new [`ValidateCikFormatContext`] from a raw CIK.
+    pub fn new(cik: impl Into<String>) -> Self {
         Self {
             raw_cik: cik.into(),
-            sec_client,
             max_retries: 0,
         }
     }
@@ -72,12 +67,6 @@ impl ValidateCikFormatContext {
     pub const fn cik(&self) -> &String {
         &self.raw_cik
     }
-
-    /// Returns a reference to the HTTP client.
-    #[must_use]
-    pub const fn sec_client(&self) -> &SecClient {
-        &self.sec_client
-    }
 }
 
 impl Context for ValidateCikFormatContext {
@@ -102,9 +91,6 @@ impl SMContext for ValidateCikFormatContext {
         if let Some(cik) = updates.raw_cik {
             self.raw_cik = cik;
         }
-        if let Some(sec_client) = updates.sec_client {
-            self.sec_client = sec_client;
-        }
         if let Some(max_retries) = updates.max_retries {
             self.max_retries = max_retries;
         }
@@ -124,8 +110,6 @@ impl fmt::Display for ValidateCikFormatContext {
 pub struct ValidateCikFormatContextUpdater {
     /// Optional new raw CIK string value.
     pub raw_cik: Option<String>,
-    /// Optional new HTTP client value.
-    pub sec_client: Option<SecClient>,
     /// Optional new maximum retries value.
     pub max_retries: Option<u32>,
 }
@@ -141,7 +125,6 @@ impl ValidateCikFormatContextUpdater {
 /// Fluent builder for a [`ValidateCikFormatContextUpdater`].
 pub struct ValidateCikFormatContextUpdaterBuilder {
     raw_cik: Option<String>,
-    sec_client: Option<SecClient>,
     max_retries: Option<u32>,
 }
 impl ValidateCikFormatContextUpdaterBuilder {
@@ -150,7 +133,6 @@ impl ValidateCikFormatContextUpdaterBuilder {
     pub const fn new() -> Self {
         Self {
             raw_cik: None,
-            sec_client: None,
             max_retries: None,
         }
     }
@@ -163,13 +145,6 @@ impl ValidateCikFormatContextUpdaterBuilder {
         self
     }
 
-    /// Sets the SEC client field.
-    #[must_use]
-    pub fn sec_client(mut self, sec_client: SecClient) -> Self {
-        self.sec_client = Some(sec_client);
-        self
-    }
-
     /// Sets the `max_retries` field.
     #[must_use]
     pub const fn max_retries(mut self, max_retries: u32) -> Self {
@@ -182,7 +157,6 @@ impl ValidateCikFormatContextUpdaterBuilder {
     pub fn build(self) -> ValidateCikFormatContextUpdater {
         ValidateCikFormatContextUpdater {
             raw_cik: self.raw_cik,
-            sec_client: self.sec_client,
             max_retries: self.max_retries,
         }
     }
@@ -207,11 +181,9 @@ mod tests {
         ValidateCikFormatContextUpdaterBuilder,
     };
     use crate::shared::cik::constants::BERKSHIRE_HATHAWAY_CIK_RAW;
-    use crate::shared::http_client::implementations::sec_client::SecClient;
 
     fn test_context() -> ValidateCikFormatContext {
-        let sec_client = SecClient::default();
-        ValidateCikFormatContext::new(BERKSHIRE_HATHAWAY_CIK_RAW, sec_client)
+        ValidateCikFormatContext::new(BERKSHIRE_HATHAWAY_CIK_RAW)
     }
 
     #[test]
@@ -227,8 +199,7 @@ mod tests {
 
     #[test]
     fn should_create_different_context_with_custom_data_when_using_new_as_constructor() {
-        let sec_client = SecClient::default();
-        let validation_context = &ValidateCikFormatContext::new("0000000000", sec_client);
+        let validation_context = &ValidateCikFormatContext::new("0000000000");
 
         let expected_result = &test_context();
 
@@ -244,8 +215,7 @@ mod tests {
             .cik("Updated CIK!")
             .build();
 
-        let sec_client = SecClient::default();
-        let expected_result = &ValidateCikFormatContext::new("Updated CIK!", sec_client);
+        let expected_result = &ValidateCikFormatContext::new("Updated CIK!");
 
         context.update_context(update);
         let result = context.context();
@@ -261,8 +231,7 @@ mod tests {
             .cik("Latest CIK Update!")
             .build();
 
-        let sec_client = SecClient::default();
-        let expected_result = &ValidateCikFormatContext::new("Latest CIK Update!", sec_client);
+        let expected_result = &ValidateCikFormatContext::new("Latest CIK Update!");
 
         context.update_context(update);
         let result = context.context();
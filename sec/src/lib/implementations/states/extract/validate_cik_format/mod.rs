@@ -15,18 +15,18 @@
 //! - [`constants`]: State metadata such as [`STATE_NAME`].
 //! - [`context`]: The [`ValidateCikFormatContext`] carried alongside the state.
 //! - [`data`]: The [`ValidateCikFormatInput`] and [`ValidateCikFormatOutput`] data types.
+//! - [`snapshot`]: The [`ValidateCikFormatSnapshot`] used to checkpoint and resume the state.
 //!
 //! ## Usage
 //!
 //! ```rust
 //! use sec::implementations::states::extract::validate_cik_format::*;
 //! use sec::prelude::*;
-//! use sec::shared::http_client::implementations::sec_client::SecClient;
 //!
 //! # #[tokio::main]
 //! # async fn main() {
 //! let input = ValidateCikFormatInput::new("1234");
-//! let context = ValidateCikFormatContext::new("1234", SecClient::default());
+//! let context = ValidateCikFormatContext::new("1234");
 //! let mut state = ValidateCikFormat::new(input, context);
 //! state
 //!     .compute_output_data_async()
@@ -53,23 +53,25 @@
 
 use std::fmt;
 
-use async_trait::async_trait;
 use serde::Serialize;
-use state_maschine::prelude::State as SMState;
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
 use crate::error::state_machine::state::InvalidCikFormat;
+use crate::shared::executor::TokioExecutor;
 use crate::traits::error::FromDomainError;
 use crate::traits::state_machine::state::State;
 
 pub mod constants;
 pub mod context;
 pub mod data;
+pub mod snapshot;
 
 pub use constants::STATE_NAME;
 pub use context::ValidateCikFormatContext;
 pub use data::ValidateCikFormatInput;
 pub use data::ValidateCikFormatOutput;
+pub use snapshot::ValidateCikFormatSnapshot;
 
 use crate::shared::cik::Cik;
 
@@ -96,11 +98,10 @@ impl ValidateCikFormat {
     ///
     /// ```
     /// use sec::implementations::states::extract::validate_cik_format::*;
-    /// use sec::shared::http_client::implementations::sec_client::SecClient;
     /// use state_maschine::prelude::State as SMState;
     ///
     /// let input = ValidateCikFormatInput::new("1234");
-    /// let context = ValidateCikFormatContext::new("1234", SecClient::default());
+    /// let context = ValidateCikFormatContext::new("1234");
     /// let state = ValidateCikFormat::new(input, context);
     ///
     /// let expected_result = false;
@@ -131,8 +132,10 @@ impl ValidateCikFormat {
     }
 }
 
-#[async_trait]
-impl State for ValidateCikFormat {
+impl SMAsyncState for ValidateCikFormat {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     /// Validates the raw CIK and, on success, stores the normalized [`Cik`] as output.
     ///
     /// # Errors
@@ -160,6 +163,8 @@ impl State for ValidateCikFormat {
     }
 }
 
+impl State for ValidateCikFormat {}
+
 impl SMState for ValidateCikFormat {
     type InputData = ValidateCikFormatInput;
     type OutputData = ValidateCikFormatOutput;
@@ -169,29 +174,13 @@ impl SMState for ValidateCikFormat {
         STATE_NAME
     }
 
-    /// Blocking wrapper around [`compute_output_data_async`](crate::traits::state_machine::state::State::compute_output_data_async).
-    ///
-    /// Detects whether a tokio runtime is available and runs the async computation
-    /// synchronously. This allows SEC states to be used as regular `SMState` implementations.
+    /// Blocking wrapper around [`compute_output_data_async`](state_maschine::prelude::AsyncState::compute_output_data_async),
+    /// see [`compute_output_data_blocking`](state_maschine::prelude::AsyncState::compute_output_data_blocking).
     ///
     /// # Panics
-    /// - Panics if the async computation returns an error.
-    /// - Panics if called inside a `current_thread` runtime (`block_in_place`
-    ///   requires a multi-thread runtime). All binaries and tests in this project
-    ///   use `flavor = "multi_thread"`.
+    /// Panics if the async computation returns an error.
     fn compute_output_data(&mut self) {
-        let result = if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| handle.block_on(self.compute_output_data_async()))
-        } else {
-            tokio::runtime::Runtime::new()
-                .expect("Failed to create tokio runtime for blocking compute")
-                .block_on(self.compute_output_data_async())
-        };
-
-        if let Err(e) = result {
-            let state_err: StateError = e;
-            panic!("compute_output_data failed: {state_err}")
-        }
+        self.compute_output_data_blocking();
     }
 
     fn context_data(&self) -> &Self::Context {
@@ -232,15 +221,16 @@ mod tests {
     use std::{fmt::Debug, hash::Hash};
 
     use pretty_assertions::assert_eq;
+    use proptest::prelude::{Strategy, any};
     use tokio;
 
     use super::*;
+    use crate::implementations::states::extract::prepare_sec_request::PrepareSecRequest;
     use crate::shared::cik::constants::BERKSHIRE_HATHAWAY_CIK_RAW;
-    use crate::shared::http_client::implementations::sec_client::SecClient;
+    use crate::tests::conformance::state_conformance;
 
     fn test_context() -> ValidateCikFormatContext {
-        let sec_client = SecClient::default();
-        ValidateCikFormatContext::new(BERKSHIRE_HATHAWAY_CIK_RAW, sec_client)
+        ValidateCikFormatContext::new(BERKSHIRE_HATHAWAY_CIK_RAW)
     }
 
     fn test_input() -> ValidateCikFormatInput {
@@ -489,7 +479,7 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
-    #[tokio::test(flavor = "multi_thread")]
+    #[tokio::test]
     async fn should_produce_output_when_calling_sync_compute_inside_tokio_runtime() {
         let mut state = test_state();
 
@@ -512,4 +502,37 @@ mod tests {
 
         state.compute_output_data();
     }
+
+    #[tokio::test]
+    async fn should_return_invalid_cik_format_error_when_calling_fallible_sync_compute_with_invalid_input()
+     {
+        let input = ValidateCikFormatInput {
+            raw_cik: "INVALID".to_string(),
+        };
+        let context = test_context();
+        let mut state = ValidateCikFormat::new(input, context);
+
+        let expected_result = true;
+
+        let result = matches!(
+            state.try_compute_output_data(),
+            Err(StateError::InvalidCikFormat(_))
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    fn arb_validate_cik_format() -> impl Strategy<Value = ValidateCikFormat> {
+        (any::<String>(), any::<String>()).prop_map(|(raw_cik, context_raw_cik)| {
+            ValidateCikFormat::new(
+                ValidateCikFormatInput::new(raw_cik),
+                ValidateCikFormatContext::new(context_raw_cik),
+            )
+        })
+    }
+
+    state_conformance! {
+        state: ValidateCikFormat = arb_validate_cik_format(),
+        transitions: [PrepareSecRequest],
+    }
 }
@@ -0,0 +1,89 @@
+//! # Validate CIK Format Snapshot
+//!
+//! Provides the [`ValidateCikFormatSnapshot`] capturing a [`ValidateCikFormat`] state for
+//! checkpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::cik::Cik;
+use crate::traits::state_machine::checkpoint::Checkpointable;
+
+use super::{
+    ValidateCikFormat, ValidateCikFormatContext, ValidateCikFormatInput, ValidateCikFormatOutput,
+};
+
+/// A lossless snapshot of a [`ValidateCikFormat`] state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidateCikFormatSnapshot {
+    /// The unvalidated CIK string from the input.
+    pub raw_cik: String,
+    /// The raw CIK string carried by the context.
+    pub context_raw_cik: String,
+    /// The context's retry budget.
+    pub max_retries: u32,
+    /// The validated CIK, if the state has completed.
+    pub validated_cik: Option<Cik>,
+}
+
+impl Checkpointable for ValidateCikFormat {
+    type Snapshot = ValidateCikFormatSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        ValidateCikFormatSnapshot {
+            raw_cik: self.input.raw_cik.clone(),
+            context_raw_cik: self.context.raw_cik.clone(),
+            max_retries: self.context.max_retries,
+            validated_cik: self
+                .output
+                .as_ref()
+                .map(|output| output.validated_cik.clone()),
+        }
+    }
+}
+
+impl ValidateCikFormat {
+    /// Restores a [`ValidateCikFormat`] state from `snapshot`.
+    #[must_use]
+    pub fn from_snapshot(snapshot: ValidateCikFormatSnapshot) -> Self {
+        let mut context = ValidateCikFormatContext::new(snapshot.context_raw_cik);
+        context.max_retries = snapshot.max_retries;
+
+        Self {
+            input: ValidateCikFormatInput::new(snapshot.raw_cik),
+            context,
+            output: snapshot
+                .validated_cik
+                .map(|validated_cik| ValidateCikFormatOutput { validated_cik }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::prelude::SMAsyncState;
+
+    #[tokio::test]
+    async fn should_restore_equal_state_when_round_tripping_completed_state_through_json() {
+        let mut state = ValidateCikFormat::new(
+            ValidateCikFormatInput::new("1067983"),
+            ValidateCikFormatContext::new("1067983"),
+        );
+        state
+            .compute_output_data_async()
+            .await
+            .expect("A hardcoded valid CIK should always validate");
+        let serialized = serde_json::to_string(&state.snapshot())
+            .expect("A state snapshot should always serialize");
+
+        let expected_result = state;
+
+        let result = ValidateCikFormat::from_snapshot(
+            serde_json::from_str(&serialized).expect("A serialized snapshot should deserialize"),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+}
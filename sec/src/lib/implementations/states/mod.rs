@@ -8,9 +8,100 @@
 //! - [`transform`]: Contains states that transform extracted data into normalized or enriched forms for downstream processing.
 //! - [`load`]: Contains states that handle loading or persisting processed data into target systems or storage.
 //!
+//! The [`pipeline_topology`] function assembles the phases' [`Topology`] into the graph of the
+//! whole pipeline, from which the state diagrams in the documentation are generated.
+//!
 //! These modules are designed to be composed within state machines, supporting robust, testable, and extensible workflows.
 //!
 //! See the documentation for each submodule for details on their specific states and responsibilities.
 pub mod extract;
 pub mod load;
 pub mod transform;
+
+use crate::implementations::states::extract::ExtractSuperState;
+use crate::implementations::states::extract::validate_cik_format::ValidateCikFormat;
+use crate::implementations::states::transform::TransformSuperState;
+use crate::implementations::states::transform::parse_company_facts::ParseCompanyFacts;
+use crate::prelude::{StateGraph, Topology};
+
+/// Returns the topology of the SEC pipeline: its phases as nested super-states, in the order the
+/// pipeline runs them.
+///
+/// Each phase contributes the [`Topology`] generated from its state sequence; only the edges
+/// between phases are declared here, mirroring the hand-written cross-phase transitions.
+#[must_use]
+pub fn pipeline_topology() -> StateGraph {
+    StateGraph::new("SEC Data Processing Pipeline: State Machine Overview")
+        .with_super_state(ExtractSuperState::<ValidateCikFormat>::topology())
+        .with_super_state(TransformSuperState::<ParseCompanyFacts>::topology())
+        .with_transition("Extract", "Transform")
+        .with_initial("Extract")
+        .with_terminal("Transform")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const README: &str = include_str!("../../../../../README.md");
+    const STATE_DIAGRAM_DOC: &str =
+        include_str!("../../../../design/state_diagram/sec_state_machine.md");
+
+    /// Returns the body of the first fenced code block in `document` tagged with `language`.
+    fn fenced_block<'a>(document: &'a str, language: &str) -> &'a str {
+        let opening = format!("```{language}\n");
+        let start = document
+            .find(&opening)
+            .map(|index| index + opening.len())
+            .expect("The document should contain a fenced block in the requested language");
+        let length = document[start..]
+            .find("\n```")
+            .expect("The fenced block should be closed");
+        &document[start..start + length]
+    }
+
+    #[test]
+    fn should_match_readme_state_diagram_when_rendering_pipeline_topology_to_mermaid() {
+        let expected_result = fenced_block(README, "mermaid");
+
+        let result = pipeline_topology().to_mermaid();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_match_design_state_diagram_when_rendering_pipeline_topology_to_mermaid() {
+        let expected_result = fenced_block(STATE_DIAGRAM_DOC, "mermaid");
+
+        let result = pipeline_topology().to_mermaid();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_match_design_dot_diagram_when_rendering_pipeline_topology_to_dot() {
+        let expected_result = fenced_block(STATE_DIAGRAM_DOC, "dot");
+
+        let result = pipeline_topology().to_dot();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_describe_state_sequence_when_introspecting_extract_super_state() {
+        let expected_result = StateGraph::new("Extract")
+            .with_state("ValidateCikFormat")
+            .with_state("PrepareSecRequest")
+            .with_state("ExecuteSecRequest")
+            .with_transition("ValidateCikFormat", "PrepareSecRequest")
+            .with_transition("PrepareSecRequest", "ExecuteSecRequest")
+            .with_initial("ValidateCikFormat")
+            .with_terminal("ExecuteSecRequest");
+
+        let result = ExtractSuperState::<ValidateCikFormat>::topology();
+
+        assert_eq!(result, expected_result);
+    }
+}
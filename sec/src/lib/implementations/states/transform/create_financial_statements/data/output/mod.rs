@@ -14,13 +14,13 @@
 
 use std::fmt;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use state_maschine::prelude::StateData as SMStateData;
 
 use crate::error::State as StateError;
 use crate::traits::state_machine::state::StateData;
 
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
 /// Output data of the [`CreateFinancialStatements`](super::super::CreateFinancialStatements) state.
 ///
 /// A placeholder unit struct for the scaffold state; concrete financial-statement fields will
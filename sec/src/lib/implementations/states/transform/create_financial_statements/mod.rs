@@ -12,6 +12,7 @@
 //! - [`constants`]: State metadata such as [`STATE_NAME`].
 //! - [`context`]: The [`CreateFinancialStatementsContext`] carried alongside the state.
 //! - [`data`]: The [`CreateFinancialStatementsInput`] and [`CreateFinancialStatementsOutput`] data types.
+//! - [`snapshot`]: The [`CreateFinancialStatementsSnapshot`] used to checkpoint and resume the state.
 //!
 //! ## See Also
 //!
@@ -22,20 +23,22 @@
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
-use async_trait::async_trait;
-use state_maschine::prelude::State as SMState;
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
+use crate::shared::executor::TokioExecutor;
 use crate::traits::state_machine::state::State;
 
 pub mod constants;
 pub mod context;
 pub mod data;
+pub mod snapshot;
 
 pub use constants::STATE_NAME;
 pub use context::CreateFinancialStatementsContext;
 pub use data::CreateFinancialStatementsInput;
 pub use data::CreateFinancialStatementsOutput;
+pub use snapshot::CreateFinancialStatementsSnapshot;
 
 use serde::Serialize;
 
@@ -106,8 +109,10 @@ impl CreateFinancialStatements {
     }
 }
 
-#[async_trait]
-impl State for CreateFinancialStatements {
+impl SMAsyncState for CreateFinancialStatements {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     /// Produces the placeholder output for this scaffold state.
     ///
     /// # Errors
@@ -121,6 +126,8 @@ impl State for CreateFinancialStatements {
     }
 }
 
+impl State for CreateFinancialStatements {}
+
 impl SMState for CreateFinancialStatements {
     type InputData = CreateFinancialStatementsInput;
     type OutputData = CreateFinancialStatementsOutput;
@@ -130,29 +137,13 @@ impl SMState for CreateFinancialStatements {
         STATE_NAME
     }
 
-    /// Blocking wrapper around [`compute_output_data_async`](crate::traits::state_machine::state::State::compute_output_data_async).
-    ///
-    /// Detects whether a tokio runtime is available and runs the async computation
-    /// synchronously. This allows SEC states to be used as regular `SMState` implementations.
+    /// Blocking wrapper around [`compute_output_data_async`](state_maschine::prelude::AsyncState::compute_output_data_async),
+    /// see [`compute_output_data_blocking`](state_maschine::prelude::AsyncState::compute_output_data_blocking).
     ///
     /// # Panics
-    /// - Panics if the async computation returns an error.
-    /// - Panics if called inside a `current_thread` runtime (`block_in_place`
-    ///   requires a multi-thread runtime). All binaries and tests in this project
-    ///   use `flavor = "multi_thread"`.
+    /// Panics if the async computation returns an error.
     fn compute_output_data(&mut self) {
-        let result = if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| handle.block_on(self.compute_output_data_async()))
-        } else {
-            tokio::runtime::Runtime::new()
-                .expect("Failed to create tokio runtime for blocking compute")
-                .block_on(self.compute_output_data_async())
-        };
-
-        if let Err(e) = result {
-            let state_err: StateError = e;
-            panic!("compute_output_data failed: {state_err}")
-        }
+        self.compute_output_data_blocking();
     }
 
     fn context_data(&self) -> &Self::Context {
@@ -226,6 +217,7 @@ mod tests {
     use std::{fmt::Debug, hash::Hash};
 
     use pretty_assertions::assert_eq;
+    use proptest::prelude::{Strategy, any};
     use tokio;
 
     use super::*;
@@ -233,6 +225,7 @@ mod tests {
     use crate::shared::cik::constants::BERKSHIRE_HATHAWAY_CIK_RAW;
     use crate::shared::financial::company_data::CompanyData;
     use crate::shared::financial::entity_name::EntityName;
+    use crate::tests::conformance::{arb_cik, state_conformance};
 
     fn test_context() -> CreateFinancialStatementsContext {
         CreateFinancialStatementsContext::new(Cik::new(BERKSHIRE_HATHAWAY_CIK_RAW).expect(
@@ -478,7 +471,7 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
-    #[tokio::test(flavor = "multi_thread")]
+    #[tokio::test]
     async fn should_produce_output_when_calling_sync_compute_inside_tokio_runtime() {
         let mut state = create_baseline_state();
 
@@ -489,4 +482,21 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    fn arb_create_financial_statements() -> impl Strategy<Value = CreateFinancialStatements> {
+        (arb_cik(), any::<String>(), arb_cik()).prop_map(|(cik, entity_name, context_cik)| {
+            CreateFinancialStatements::new(
+                CreateFinancialStatementsInput::new(CompanyData::new(
+                    cik,
+                    EntityName::new(entity_name),
+                    HashMap::new(),
+                )),
+                CreateFinancialStatementsContext::new(context_cik),
+            )
+        })
+    }
+
+    state_conformance! {
+        state: CreateFinancialStatements = arb_create_financial_statements(),
+    }
 }
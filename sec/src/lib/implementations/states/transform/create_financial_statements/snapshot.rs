@@ -0,0 +1,109 @@
+//! # Create Financial Statements Snapshot
+//!
+//! Provides the [`CreateFinancialStatementsSnapshot`] capturing a [`CreateFinancialStatements`]
+//! state for checkpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::checkpoint::CheckpointError;
+use crate::shared::cik::Cik;
+use crate::shared::financial::company_data::{CompanyData, CompanyDataSnapshot};
+use crate::traits::state_machine::checkpoint::Checkpointable;
+
+use super::{
+    CreateFinancialStatements, CreateFinancialStatementsContext, CreateFinancialStatementsInput,
+    CreateFinancialStatementsOutput,
+};
+
+/// A lossless snapshot of a [`CreateFinancialStatements`] state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateFinancialStatementsSnapshot {
+    /// The parsed company data to build statements from.
+    pub company_data: CompanyDataSnapshot,
+    /// The CIK carried by the context.
+    pub cik: Cik,
+    /// The context's retry budget.
+    pub max_retries: u32,
+    /// Whether the state has produced its output.
+    ///
+    /// A flag rather than an `Option`: the output is currently a unit struct, which serializes to
+    /// `null` and so could not be told apart from a missing output.
+    pub completed: bool,
+}
+
+impl Checkpointable for CreateFinancialStatements {
+    type Snapshot = CreateFinancialStatementsSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        CreateFinancialStatementsSnapshot {
+            company_data: CompanyDataSnapshot::from(&self.input.company_data),
+            cik: self.context.cik.clone(),
+            max_retries: self.context.max_retries,
+            completed: self.output.is_some(),
+        }
+    }
+}
+
+impl CreateFinancialStatements {
+    /// Restores a [`CreateFinancialStatements`] state from `snapshot`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the snapshot's company data references a concept that is
+    /// no longer predefined.
+    pub fn from_snapshot(
+        snapshot: CreateFinancialStatementsSnapshot,
+    ) -> Result<Self, CheckpointError> {
+        let mut context = CreateFinancialStatementsContext::new(snapshot.cik);
+        context.max_retries = snapshot.max_retries;
+
+        Ok(Self {
+            input: CreateFinancialStatementsInput::new(CompanyData::try_from(
+                snapshot.company_data,
+            )?),
+            context,
+            output: snapshot
+                .completed
+                .then_some(CreateFinancialStatementsOutput),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::prelude::SMAsyncState;
+    use crate::shared::financial::entity_name::EntityName;
+
+    #[tokio::test]
+    async fn should_restore_equal_state_when_round_tripping_completed_state_through_json() {
+        let cik = Cik::new("1067983").expect("A hardcoded valid CIK should always parse");
+        let mut state = CreateFinancialStatements::new(
+            CreateFinancialStatementsInput::new(CompanyData::new(
+                cik.clone(),
+                EntityName::new("BERKSHIRE HATHAWAY INC"),
+                HashMap::new(),
+            )),
+            CreateFinancialStatementsContext::new(cik),
+        );
+        state
+            .compute_output_data_async()
+            .await
+            .expect("Creating statements from empty company data should always succeed");
+        let serialized = serde_json::to_string(&state.snapshot())
+            .expect("A state snapshot should always serialize");
+
+        let expected_result = state;
+
+        let result = CreateFinancialStatements::from_snapshot(
+            serde_json::from_str(&serialized).expect("A serialized snapshot should deserialize"),
+        )
+        .expect("A snapshot of empty company data should always restore");
+
+        assert_eq!(result, expected_result);
+    }
+}
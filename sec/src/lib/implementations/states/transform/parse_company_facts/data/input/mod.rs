@@ -4,8 +4,8 @@
 //! [`ParseCompanyFacts`](crate::implementations::states::transform::parse_company_facts::ParseCompanyFacts)
 //! state, along with its updater and builder.
 //!
-//! It carries the raw Company Facts JSON response, plus a precomputed
-//! [`BodyDigest`] so the (un-`Hash`able) JSON can still participate in equality and ordering.
+//! It carries the [`CompanyFactsDocument`] the extract phase deserialized from the SEC's
+//! company-facts response.
 //!
 //! ## See Also
 //!
@@ -13,106 +13,61 @@
 
 use std::fmt;
 
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 use state_maschine::prelude::StateData as SMStateData;
 
 use crate::error::State as StateError;
-use crate::shared::response::implementations::sec_response::body_digest::BodyDigest;
+use crate::shared::company_facts::CompanyFactsDocument;
 use crate::traits::state_machine::state::StateData;
 
 /// Input data for the [`ParseCompanyFacts`](super::super::ParseCompanyFacts) state.
 ///
-/// Holds the raw Company Facts JSON to be parsed, alongside a precomputed [`BodyDigest`]
-/// that backs the equality, ordering, and hashing impls (since [`serde_json::Value`]
-/// implements none of `Hash`/`Ord`).
-#[derive(Debug, Clone)]
+/// Holds the company-facts document to resolve into company data. It serializes into the
+/// document's CIK and entity name only, keeping logged state events small.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ParseCompanyFactsInput {
-    /// The raw SEC Company Facts JSON response to be parsed.
-    pub response: serde_json::Value,
-    /// Precomputed digest of the response body for efficient hashing and ordering.
-    body_digest: BodyDigest,
+    /// The company-facts document to be parsed.
+    pub document: CompanyFactsDocument,
 }
 
 impl ParseCompanyFactsInput {
-    /// Creates a new [`ParseCompanyFactsInput`] from a raw JSON response and its precomputed digest.
+    /// Creates a new [`ParseCompanyFactsInput`] from a company-facts document.
     ///
     /// # Examples
     ///
     /// ```
     /// use sec::implementations::states::transform::parse_company_facts::data::input::ParseCompanyFactsInput;
-    /// use sec::shared::response::implementations::sec_response::body_digest::BodyDigest;
+    /// use sec::shared::company_facts::CompanyFactsDocument;
     ///
-    /// let json = serde_json::json!({"cik": 320193, "entityName": "Apple Inc.", "facts": {}});
-    /// let digest = BodyDigest::from_body_text(&json.to_string());
-    /// let input = ParseCompanyFactsInput::new(json.clone(), digest);
+    /// let body = r#"{"cik": 320193, "entityName": "Apple Inc.", "facts": {}}"#;
+    /// let document = CompanyFactsDocument::from_reader(body.as_bytes())
+    ///     .expect("A well-formed company-facts body should always deserialize");
+    /// let input = ParseCompanyFactsInput::new(document.clone());
     ///
-    /// let expected_result = &json;
+    /// let expected_result = &document;
     ///
-    /// let result = input.response();
+    /// let result = input.document();
     ///
     /// assert_eq!(result, expected_result);
     /// ```
     #[must_use]
-    pub const fn new(response: serde_json::Value, body_digest: BodyDigest) -> Self {
-        Self {
-            response,
-            body_digest,
-        }
-    }
-
-    /// Returns a reference to the raw JSON response.
-    #[must_use]
-    pub const fn response(&self) -> &serde_json::Value {
-        &self.response
+    pub const fn new(document: CompanyFactsDocument) -> Self {
+        Self { document }
     }
 
-    /// Returns the precomputed body digest.
+    /// Returns a reference to the company-facts document.
     #[must_use]
-    pub const fn body_digest(&self) -> BodyDigest {
-        self.body_digest
-    }
-}
-
-impl PartialEq for ParseCompanyFactsInput {
-    fn eq(&self, other: &Self) -> bool {
-        self.response == other.response
-    }
-}
-
-impl Eq for ParseCompanyFactsInput {}
-
-// `serde_json::Value` does not implement `Hash`.
-// Uses the precomputed `BodyDigest` instead of re-serializing.
-impl std::hash::Hash for ParseCompanyFactsInput {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.body_digest.hash(state);
-    }
-}
-
-impl PartialOrd for ParseCompanyFactsInput {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+    pub const fn document(&self) -> &CompanyFactsDocument {
+        &self.document
     }
 }
 
-// `serde_json::Value` does not implement `Ord`.
-// Uses the precomputed `BodyDigest` instead of re-serializing.
-impl Ord for ParseCompanyFactsInput {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.body_digest.cmp(&other.body_digest)
-    }
-}
-
-impl serde::Serialize for ParseCompanyFactsInput {
-    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("ParseCompanyFactsInput", 1)?;
-        state.serialize_field(
-            "response_keys",
-            &self
-                .response
-                .as_object()
-                .map(|o| o.keys().collect::<Vec<_>>()),
-        )?;
+impl Serialize for ParseCompanyFactsInput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ParseCompanyFactsInput", 2)?;
+        state.serialize_field("cik", self.document.cik())?;
+        state.serialize_field("entity_name", self.document.entity_name())?;
         state.end()
     }
 }
@@ -120,10 +75,11 @@ impl serde::Serialize for ParseCompanyFactsInput {
 impl StateData for ParseCompanyFactsInput {
     /// Updates the state data using the provided updater.
     ///
-    /// If `response` is `Some`, updates the JSON response; otherwise, leaves it unchanged.
+    /// If `document` is `Some`, updates the company-facts document; otherwise, leaves it
+    /// unchanged.
     fn update_state(&mut self, updates: Self::UpdateType) -> Result<(), StateError> {
-        if let Some(response) = updates.response {
-            self.response = response;
+        if let Some(document) = updates.document {
+            self.document = document;
         }
         Ok(())
     }
@@ -152,11 +108,9 @@ impl fmt::Display for ParseCompanyFactsInput {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "\tResponse Keys: {:?}",
-            self.response
-                .as_object()
-                .map(|o| o.keys().collect::<Vec<_>>())
-                .unwrap_or_default()
+            "\tCIK: {}\n\tEntity Name: {}",
+            self.document.cik(),
+            self.document.entity_name()
         )
     }
 }
@@ -166,8 +120,8 @@ impl fmt::Display for ParseCompanyFactsInput {
 ///
 /// Fields set to `None` are left unchanged when the updater is applied.
 pub struct ParseCompanyFactsInputUpdater {
-    /// Optional new value for the JSON response.
-    pub response: Option<serde_json::Value>,
+    /// Optional new value for the company-facts document.
+    pub document: Option<CompanyFactsDocument>,
 }
 
 impl ParseCompanyFactsInputUpdater {
@@ -180,21 +134,21 @@ impl ParseCompanyFactsInputUpdater {
 
 /// Fluent builder for a [`ParseCompanyFactsInputUpdater`].
 pub struct ParseCompanyFactsInputUpdaterBuilder {
-    response: Option<serde_json::Value>,
+    document: Option<CompanyFactsDocument>,
 }
 
 impl ParseCompanyFactsInputUpdaterBuilder {
     /// Creates a new [`ParseCompanyFactsInputUpdaterBuilder`] with all fields initialized to `None`.
     #[must_use]
     pub const fn new() -> Self {
-        Self { response: None }
+        Self { document: None }
     }
 
-    /// Sets the response field.
+    /// Sets the document field.
     #[must_use]
     #[allow(clippy::missing_const_for_fn)]
-    pub fn response(mut self, response: serde_json::Value) -> Self {
-        self.response = Some(response);
+    pub fn document(mut self, document: CompanyFactsDocument) -> Self {
+        self.document = Some(document);
         self
     }
 
@@ -202,7 +156,7 @@ impl ParseCompanyFactsInputUpdaterBuilder {
     #[must_use]
     pub fn build(self) -> ParseCompanyFactsInputUpdater {
         ParseCompanyFactsInputUpdater {
-            response: self.response,
+            document: self.document,
         }
     }
 }
@@ -222,13 +176,20 @@ mod tests {
     use state_maschine::prelude::StateData as SMStateData;
 
     use super::{ParseCompanyFactsInput, ParseCompanyFactsInputUpdaterBuilder};
-    use crate::shared::response::implementations::sec_response::body_digest::BodyDigest;
+    use crate::shared::company_facts::CompanyFactsDocument;
     use crate::traits::state_machine::state::StateData;
 
+    fn test_document(cik: u64) -> CompanyFactsDocument {
+        CompanyFactsDocument::from_value(&serde_json::json!({
+            "cik": cik,
+            "entityName": "Apple Inc.",
+            "facts": {}
+        }))
+        .expect("A hardcoded company-facts body should always deserialize")
+    }
+
     fn test_input() -> ParseCompanyFactsInput {
-        let json = serde_json::json!({});
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        ParseCompanyFactsInput::new(json, digest)
+        ParseCompanyFactsInput::new(test_document(320_193))
     }
 
     #[test]
@@ -243,10 +204,8 @@ mod tests {
     }
 
     #[test]
-    fn should_create_different_input_data_with_custom_json_when_using_new_as_constructor() {
-        let json = serde_json::json!({"cik": 320_193});
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input_data = &ParseCompanyFactsInput::new(json, digest);
+    fn should_create_different_input_data_with_custom_document_when_using_new_as_constructor() {
+        let input_data = &ParseCompanyFactsInput::new(test_document(12345));
 
         let default_input_data = &test_input();
 
@@ -256,15 +215,14 @@ mod tests {
     }
 
     #[test]
-    fn should_update_state_data_when_update_contains_new_response() {
+    fn should_update_state_data_when_update_contains_new_document() {
         let mut state_data = test_input();
-        let new_json = serde_json::json!({"cik": 12345});
+        let new_document = test_document(12345);
         let update = ParseCompanyFactsInputUpdaterBuilder::default()
-            .response(new_json.clone())
+            .document(new_document.clone())
             .build();
 
-        let digest = BodyDigest::from_body_text(&new_json.to_string());
-        let expected_result = &ParseCompanyFactsInput::new(new_json, digest);
+        let expected_result = &ParseCompanyFactsInput::new(new_document);
 
         StateData::update_state(&mut state_data, update)
             .expect("Update with valid 'update' value should always succeed");
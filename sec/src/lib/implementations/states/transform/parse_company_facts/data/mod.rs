@@ -3,13 +3,13 @@
 //! Groups the input and output data types for the
 //! [`ParseCompanyFacts`](super::ParseCompanyFacts) state.
 //!
-//! The state consumes the raw Company Facts JSON and produces structured
+//! The state consumes the company-facts document and produces structured
 //! [`CompanyData`](crate::shared::financial::company_data::CompanyData). Input and output live
 //! in separate child modules to mirror that transformation.
 //!
 //! ## Modules
 //!
-//! - [`input`]: The [`ParseCompanyFactsInput`] holding the raw JSON response.
+//! - [`input`]: The [`ParseCompanyFactsInput`] holding the company-facts document.
 //! - [`output`]: The [`ParseCompanyFactsOutput`] holding the parsed company data.
 //!
 //! ## See Also
@@ -8,7 +8,7 @@
 //!
 //! ## See Also
 //!
-//! - [`input`](super::input): The raw JSON this output is parsed from.
+//! - [`input`](super::input): The company-facts document this output is resolved from.
 //! - [`crate::shared::financial::company_data::CompanyData`]: The resolved company data type carried here.
 
 use std::fmt;
@@ -21,7 +21,7 @@ use crate::traits::state_machine::state::StateData;
 
 /// Output data of the [`ParseCompanyFacts`](super::super::ParseCompanyFacts) state.
 ///
-/// Wraps the [`CompanyData`] resolved from the Company Facts JSON, ready for financial
+/// Wraps the [`CompanyData`] resolved from the company-facts document, ready for financial
 /// statement construction.
 #[derive(Debug, Clone)]
 pub struct ParseCompanyFactsOutput {
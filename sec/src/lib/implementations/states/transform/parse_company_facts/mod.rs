@@ -1,71 +1,55 @@
 //! # Parse Company Facts State
 //!
-//! Provides the [`ParseCompanyFacts`] state, which resolves raw SEC Company Facts JSON into
+//! Provides the [`ParseCompanyFacts`] state, which resolves an SEC company-facts document into
 //! strongly-typed [`CompanyData`].
 //!
-//! The SEC returns facts as deeply-nested XBRL JSON keyed by namespace and concept alias.
-//! This state walks that structure, resolving each required and optional concept into typed
-//! observations, and fails if any required concept is absent. Turning loosely-typed JSON into
-//! [`CompanyData`] here means the financial-statements state can work with validated domain types.
+//! The SEC returns facts as deeply-nested XBRL JSON keyed by namespace and concept alias, which
+//! the extract phase deserializes into a [`CompanyFactsDocument`] while it downloads. This state
+//! resolves each required and optional concept of that document into typed observations, and
+//! fails if any required concept is absent. Producing [`CompanyData`] here means the
+//! financial-statements state can work with validated domain types.
 //!
 //! ## Modules
 //!
 //! - [`constants`]: State metadata such as [`STATE_NAME`].
 //! - [`context`]: The [`ParseCompanyFactsContext`] carried alongside the state.
 //! - [`data`]: The [`ParseCompanyFactsInput`] and [`ParseCompanyFactsOutput`] data types.
+//! - [`snapshot`]: The [`ParseCompanyFactsSnapshot`] used to checkpoint and resume the state.
 //!
 //! ## See Also
 //!
 //! - [`crate::implementations::states::transform`]: Parent module describing the transform flow.
 //! - [`crate::shared::financial`]: The financial domain types this state produces.
 //! - [`crate::traits::state_machine::state::State`]: The trait implemented by [`ParseCompanyFacts`].
+//!
+//! [`CompanyData`]: crate::shared::financial::company_data::CompanyData
+//! [`CompanyFactsDocument`]: crate::shared::company_facts::CompanyFactsDocument
 
-use std::collections::HashMap;
 use std::fmt;
 
-use async_trait::async_trait;
-use chrono::NaiveDate;
 use serde::Serialize;
-use state_maschine::prelude::State as SMState;
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
-use crate::error::state_machine::state::incomplete_company_facts::{
-    IncompleteCompanyFacts, MissingFields,
-};
-use crate::shared::cik::Cik;
-use crate::shared::financial::accession_number::AccessionNumber;
-use crate::shared::financial::company_data::CompanyData;
-use crate::shared::financial::company_fact::CompanyFact;
-use crate::shared::financial::concept_definition::ConceptDefinition;
-use crate::shared::financial::concept_definition::constants::{
-    COMPANY_INFO_NAMESPACE, OPTIONAL_CONCEPTS, REQUIRED_CONCEPTS, REQUIRED_FACTS_NAMESPACE,
-    SHARES_OUTSTANDING,
-};
-use crate::shared::financial::entity_name::EntityName;
-use crate::shared::financial::filing_source::FilingSource;
-use crate::shared::financial::fiscal_period::FiscalPeriod;
-use crate::shared::financial::fiscal_year::FiscalYear;
-use crate::shared::financial::form::Form;
-use crate::shared::financial::frame::Frame;
-use crate::shared::financial::observation::Observation;
-use crate::shared::financial::period::Period;
-use crate::shared::financial::unit::Unit;
+use crate::error::state_machine::state::incomplete_company_facts::IncompleteCompanyFacts;
+use crate::shared::executor::TokioExecutor;
 use crate::traits::state_machine::state::State;
 
 pub mod constants;
 pub mod context;
 pub mod data;
+pub mod snapshot;
 
 pub use constants::STATE_NAME;
 pub use context::ParseCompanyFactsContext;
 pub use data::ParseCompanyFactsInput;
 pub use data::ParseCompanyFactsOutput;
+pub use snapshot::ParseCompanyFactsSnapshot;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
-/// Resolves Company Facts JSON into [`CompanyData`].
+/// Resolves a company-facts document into [`CompanyData`](crate::shared::financial::company_data::CompanyData).
 ///
-/// Reads the CIK, entity name, and facts from the SEC JSON, then resolves each required and
-/// optional XBRL concept by trying its key aliases across the relevant namespaces and
+/// Resolves each required and optional XBRL concept of the document by trying its key aliases across the relevant namespaces and
 /// collecting the matching observations. The computation fails if any *required* concept is
 /// absent, so a successful output is guaranteed to carry the full required fact set.
 pub struct ParseCompanyFacts {
@@ -82,13 +66,14 @@ impl ParseCompanyFacts {
     /// ```
     /// use sec::implementations::states::transform::parse_company_facts::*;
     /// use sec::shared::cik::Cik;
-    /// use sec::shared::response::implementations::sec_response::body_digest::BodyDigest;
+    /// use sec::shared::company_facts::CompanyFactsDocument;
     /// use state_maschine::prelude::State as SMState;
     ///
-    /// let json = serde_json::json!({});
-    /// let digest = BodyDigest::from_body_text(&json.to_string());
+    /// let body = r#"{"cik": 1067983, "entityName": "BERKSHIRE HATHAWAY INC", "facts": {}}"#;
+    /// let document = CompanyFactsDocument::from_reader(body.as_bytes())
+    ///     .expect("A well-formed company-facts body should always deserialize");
     /// let cik = Cik::new("0001067983").expect("A hardcoded valid CIK should always parse");
-    /// let input = ParseCompanyFactsInput::new(json, digest);
+    /// let input = ParseCompanyFactsInput::new(document);
     /// let context = ParseCompanyFactsContext::new(cik);
     /// let state = ParseCompanyFacts::new(input, context);
     ///
@@ -120,181 +105,32 @@ impl ParseCompanyFacts {
     }
 }
 
-/// Attempts to parse a date string in `YYYY-MM-DD` format into a [`NaiveDate`].
-fn parse_date(s: &str) -> Option<NaiveDate> {
-    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
-}
-
-/// Builds the [`Period`] for an observation entry from the SEC JSON.
-///
-/// SEC data uses `"end"` for all entries and optionally `"start"` for duration measurements.
-/// If `"start"` is present, it is a duration; otherwise, it is an instant.
-fn build_period(entry: &serde_json::Value) -> Option<Period> {
-    let end = parse_date(entry.get("end")?.as_str()?)?;
-    if let Some(start_val) = entry.get("start") {
-        let start = parse_date(start_val.as_str()?)?;
-        Some(Period::Duration { start, end })
-    } else {
-        Some(Period::Instant { date: end })
-    }
-}
-
-/// Builds a [`FilingSource`] from an SEC observation JSON entry.
-fn build_filing_source(entry: &serde_json::Value) -> Option<FilingSource> {
-    let accn = AccessionNumber::new(entry.get("accn")?.as_str()?);
-    let form = Form::from_sec_str(entry.get("form")?.as_str()?)?;
-    let fy = FiscalYear::try_from(entry.get("fy")?.as_u64()?).ok()?;
-    let fp = FiscalPeriod::from_sec_str(entry.get("fp")?.as_str()?)?;
-    let filed = parse_date(entry.get("filed")?.as_str()?)?;
-    let end = parse_date(entry.get("end")?.as_str()?)?;
-
-    Some(FilingSource::new(accn, form, fy, fp, filed, end))
-}
-
-/// Parses a single observation entry from the SEC JSON unit array.
-fn parse_observation(entry: &serde_json::Value, unit: Unit) -> Option<Observation> {
-    let value = entry.get("val")?.as_i64()?;
-    let period = build_period(entry)?;
-    let frame = entry
-        .get("frame")
-        .and_then(serde_json::Value::as_str)
-        .and_then(Frame::parse);
-    let filing = build_filing_source(entry)?;
-
-    Some(Observation::new(value, unit, period, frame, filing))
-}
-
-/// Determines the JSON namespace key for a given concept definition.
-///
-/// Returns the namespaces to search for a concept, in priority order.
-///
-/// Most concepts live in `us-gaap`. The `SHARES_OUTSTANDING` concept is searched
-/// in `dei` first (where `EntityCommonStockSharesOutstanding` lives), then falls
-/// back to `us-gaap` (where `CommonStockSharesOutstanding` lives for some companies).
-fn namespaces_for_concept(concept: &ConceptDefinition) -> &'static [&'static str] {
-    if concept.canonical_name() == SHARES_OUTSTANDING {
-        &[COMPANY_INFO_NAMESPACE, REQUIRED_FACTS_NAMESPACE]
-    } else {
-        &[REQUIRED_FACTS_NAMESPACE]
-    }
-}
+impl SMAsyncState for ParseCompanyFacts {
+    type Error = StateError;
+    type Executor = TokioExecutor;
 
-/// Attempts to resolve a [`ConceptDefinition`] from the SEC facts JSON.
-///
-/// Searches across all applicable namespaces and XBRL key aliases.
-/// Returns `Some(CompanyFact)` if the concept was found,
-/// or `None` if none of the aliases matched in any namespace.
-fn resolve_concept(
-    facts: &serde_json::Value,
-    concept: &'static ConceptDefinition,
-) -> Option<CompanyFact> {
-    for namespace in namespaces_for_concept(concept) {
-        let Some(namespace_data) = facts.get(namespace) else {
-            continue;
-        };
-
-        for &xbrl_key in concept.xbrl_keys() {
-            if let Some(concept_data) = namespace_data.get(xbrl_key) {
-                let company_label = concept_data
-                    .get("label")
-                    .and_then(serde_json::Value::as_str)
-                    .unwrap_or_else(|| concept.canonical_name())
-                    .to_string();
-
-                let unit_key = concept.expected_unit().to_string();
-                let units_data = concept_data.get("units")?;
-                let unit_array = units_data.get(&unit_key)?.as_array()?;
-
-                let observations: Vec<Observation> = unit_array
-                    .iter()
-                    .filter_map(|entry| parse_observation(entry, concept.expected_unit()))
-                    .collect();
-
-                return Some(CompanyFact::new(
-                    company_label,
-                    xbrl_key.to_string(),
-                    observations,
-                ));
-            }
-        }
-    }
-
-    None
-}
-
-#[async_trait]
-impl State for ParseCompanyFacts {
-    /// Resolves the Company Facts JSON into [`CompanyData`] and stores it as output.
+    /// Resolves the company-facts document into
+    /// [`CompanyData`](crate::shared::financial::company_data::CompanyData) and stores it as output.
     ///
     /// # Errors
     ///
-    /// Returns [`StateError::IncompleteCompanyFacts`] when a required concept is missing, or
-    /// [`StateError::FailedOutputComputation`] when the JSON lacks the expected top-level
-    /// structure (`cik`, `entityName`, or `facts`).
+    /// Returns [`StateError::IncompleteCompanyFacts`] when a required concept is missing.
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
-        let response = &self.input.response;
-
-        // Validate top-level structure
-        let obj = response
-            .as_object()
-            .ok_or(StateError::FailedOutputComputation)?;
-
-        let cik_value = obj.get("cik").ok_or(StateError::FailedOutputComputation)?;
-
-        let cik_str = if let Some(n) = cik_value.as_u64() {
-            n.to_string()
-        } else if let Some(s) = cik_value.as_str() {
-            s.to_string()
-        } else {
-            return Err(StateError::FailedOutputComputation);
-        };
-
-        let cik = Cik::new(&cik_str).map_err(|_| StateError::FailedOutputComputation)?;
-
-        let entity_name_str = obj
-            .get("entityName")
-            .and_then(serde_json::Value::as_str)
-            .ok_or(StateError::FailedOutputComputation)?;
-
-        let entity_name = EntityName::new(entity_name_str);
-
-        let facts = obj
-            .get("facts")
-            .ok_or(StateError::FailedOutputComputation)?;
-
-        // Resolve required and optional concepts
-        let mut resolved_facts: HashMap<&'static ConceptDefinition, CompanyFact> = HashMap::new();
-        let mut missing_fields: Vec<String> = Vec::new();
-
-        for concept in REQUIRED_CONCEPTS {
-            if let Some(fact) = resolve_concept(facts, concept) {
-                resolved_facts.insert(concept, fact);
-            } else {
-                missing_fields.push(concept.canonical_name().to_string());
-            }
-        }
-
-        if !missing_fields.is_empty() {
-            let error = IncompleteCompanyFacts::new(
-                self.state_name().to_string(),
-                MissingFields::new(missing_fields),
-            );
-            return Err(error.into());
-        }
-
-        for concept in OPTIONAL_CONCEPTS {
-            if let Some(fact) = resolve_concept(facts, concept) {
-                resolved_facts.insert(concept, fact);
-            }
-        }
-
-        let company_data = CompanyData::new(cik, entity_name, resolved_facts);
+        let company_data = self
+            .input
+            .document
+            .to_company_data()
+            .map_err(|missing_fields| {
+                IncompleteCompanyFacts::new(self.state_name().to_string(), missing_fields)
+            })?;
         self.output = Some(ParseCompanyFactsOutput::new(company_data));
 
         Ok(())
     }
 }
 
+impl State for ParseCompanyFacts {}
+
 impl SMState for ParseCompanyFacts {
     type InputData = ParseCompanyFactsInput;
     type OutputData = ParseCompanyFactsOutput;
@@ -304,29 +140,13 @@ impl SMState for ParseCompanyFacts {
         STATE_NAME
     }
 
-    /// Blocking wrapper around [`compute_output_data_async`](crate::traits::state_machine::state::State::compute_output_data_async).
-    ///
-    /// Detects whether a tokio runtime is available and runs the async computation
-    /// synchronously. This allows SEC states to be used as regular `SMState` implementations.
+    /// Blocking wrapper around [`compute_output_data_async`](state_maschine::prelude::AsyncState::compute_output_data_async),
+    /// see [`compute_output_data_blocking`](state_maschine::prelude::AsyncState::compute_output_data_blocking).
     ///
     /// # Panics
-    /// - Panics if the async computation returns an error.
-    /// - Panics if called inside a `current_thread` runtime (`block_in_place`
-    ///   requires a multi-thread runtime). All binaries and tests in this project
-    ///   use `flavor = "multi_thread"`.
+    /// Panics if the async computation returns an error.
     fn compute_output_data(&mut self) {
-        let result = if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| handle.block_on(self.compute_output_data_async()))
-        } else {
-            tokio::runtime::Runtime::new()
-                .expect("Failed to create tokio runtime for blocking compute")
-                .block_on(self.compute_output_data_async())
-        };
-
-        if let Err(e) = result {
-            let state_err: StateError = e;
-            panic!("compute_output_data failed: {state_err}")
-        }
+        self.compute_output_data_blocking();
     }
 
     fn context_data(&self) -> &Self::Context {
@@ -367,17 +187,30 @@ mod tests {
     use std::{fmt::Debug, hash::Hash};
 
     use pretty_assertions::assert_eq;
+    use proptest::prelude::{Strategy, any};
     use tokio;
 
     use super::*;
+    use crate::implementations::states::transform::create_financial_statements::CreateFinancialStatements;
     use crate::shared::cik::Cik;
     use crate::shared::cik::constants::BERKSHIRE_HATHAWAY_CIK_RAW;
-    use crate::shared::response::implementations::sec_response::body_digest::BodyDigest;
+    use crate::shared::company_facts::CompanyFactsDocument;
+    use crate::shared::financial::concept_definition::constants::REQUIRED_CONCEPTS;
+    use crate::tests::conformance::{arb_cik, state_conformance};
+
+    fn input_from(json: &serde_json::Value) -> ParseCompanyFactsInput {
+        ParseCompanyFactsInput::new(
+            CompanyFactsDocument::from_value(json)
+                .expect("A hardcoded company-facts body should always deserialize"),
+        )
+    }
 
     fn test_input() -> ParseCompanyFactsInput {
-        let json = serde_json::json!({});
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        ParseCompanyFactsInput::new(json, digest)
+        input_from(&serde_json::json!({
+            "cik": 1_067_983,
+            "entityName": "BERKSHIRE HATHAWAY INC",
+            "facts": {}
+        }))
     }
 
     fn test_context() -> ParseCompanyFactsContext {
@@ -670,8 +503,7 @@ mod tests {
     #[tokio::test]
     async fn should_produce_output_with_correct_entity_name_when_computing_valid_json() {
         let json = get_baseline_company_facts_json();
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
+        let input = input_from(&json);
         let context = test_context();
         let mut parse_state = ParseCompanyFacts::new(input, context);
 
@@ -694,8 +526,7 @@ mod tests {
     #[tokio::test]
     async fn should_resolve_all_required_concepts_when_computing_valid_json() {
         let json = get_baseline_company_facts_json();
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
+        let input = input_from(&json);
         let context = test_context();
         let mut parse_state = ParseCompanyFacts::new(input, context);
 
@@ -725,8 +556,7 @@ mod tests {
                 "dei": {}
             }
         });
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
+        let input = input_from(&json);
         let context = test_context();
         let mut parse_state = ParseCompanyFacts::new(input, context);
 
@@ -740,8 +570,7 @@ mod tests {
     #[tokio::test]
     async fn should_not_change_input_data_when_computing_output_data() {
         let json = get_baseline_company_facts_json();
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
+        let input = input_from(&json);
         let context = test_context();
         let mut parse_state = ParseCompanyFacts::new(input, context);
 
@@ -756,21 +585,6 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
-    #[tokio::test]
-    async fn should_return_error_when_response_is_not_an_object() {
-        let json = serde_json::json!("not an object");
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
-        let context = test_context();
-        let mut parse_state = ParseCompanyFacts::new(input, context);
-
-        let expected_result = true;
-
-        let result = parse_state.compute_output_data_async().await.is_err();
-
-        assert_eq!(result, expected_result);
-    }
-
     const fn implements_auto_traits<T: Sized + Send + Sync + Unpin>() {}
     #[test]
     const fn should_still_implement_auto_traits_when_implementing_state_trait() {
@@ -911,8 +725,7 @@ mod tests {
     #[test]
     fn should_produce_output_when_calling_sync_compute_outside_tokio_runtime() {
         let json = get_baseline_company_facts_json();
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
+        let input = input_from(&json);
         let context = test_context();
         let mut state = ParseCompanyFacts::new(input, context);
 
@@ -924,11 +737,10 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
-    #[tokio::test(flavor = "multi_thread")]
+    #[tokio::test]
     async fn should_produce_output_when_calling_sync_compute_inside_tokio_runtime() {
         let json = get_baseline_company_facts_json();
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
+        let input = input_from(&json);
         let context = test_context();
         let mut state = ParseCompanyFacts::new(input, context);
 
@@ -947,4 +759,20 @@ mod tests {
 
         state.compute_output_data();
     }
+
+    fn arb_parse_company_facts() -> impl Strategy<Value = ParseCompanyFacts> {
+        (any::<String>(), arb_cik()).prop_map(|(entity_name, cik)| {
+            let json = serde_json::json!({
+                "cik": cik.to_string(),
+                "entityName": entity_name,
+                "facts": {}
+            });
+            ParseCompanyFacts::new(input_from(&json), ParseCompanyFactsContext::new(cik))
+        })
+    }
+
+    state_conformance! {
+        state: ParseCompanyFacts = arb_parse_company_facts(),
+        transitions: [CreateFinancialStatements],
+    }
 }
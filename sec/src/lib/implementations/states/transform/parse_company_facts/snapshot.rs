@@ -0,0 +1,126 @@
+//! # Parse Company Facts Snapshot
+//!
+//! Provides the [`ParseCompanyFactsSnapshot`] capturing a [`ParseCompanyFacts`] state for
+//! checkpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::checkpoint::CheckpointError;
+use crate::shared::cik::Cik;
+use crate::shared::company_facts::CompanyFactsDocument;
+use crate::shared::financial::company_data::{CompanyData, CompanyDataSnapshot};
+use crate::traits::state_machine::checkpoint::Checkpointable;
+
+use super::{
+    ParseCompanyFacts, ParseCompanyFactsContext, ParseCompanyFactsInput, ParseCompanyFactsOutput,
+};
+
+/// A lossless snapshot of a [`ParseCompanyFacts`] state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseCompanyFactsSnapshot {
+    /// The company-facts document to parse.
+    pub document: CompanyFactsDocument,
+    /// The CIK carried by the context.
+    pub cik: Cik,
+    /// The context's retry budget.
+    pub max_retries: u32,
+    /// The parsed company data, if the state has completed.
+    pub company_data: Option<CompanyDataSnapshot>,
+}
+
+impl Checkpointable for ParseCompanyFacts {
+    type Snapshot = ParseCompanyFactsSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        ParseCompanyFactsSnapshot {
+            document: self.input.document().clone(),
+            cik: self.context.cik.clone(),
+            max_retries: self.context.max_retries,
+            company_data: self
+                .output
+                .as_ref()
+                .map(|output| CompanyDataSnapshot::from(&output.company_data)),
+        }
+    }
+}
+
+impl ParseCompanyFacts {
+    /// Restores a [`ParseCompanyFacts`] state from `snapshot`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the snapshot's company data references a concept that is
+    /// no longer predefined.
+    pub fn from_snapshot(snapshot: ParseCompanyFactsSnapshot) -> Result<Self, CheckpointError> {
+        let mut context = ParseCompanyFactsContext::new(snapshot.cik);
+        context.max_retries = snapshot.max_retries;
+        let output = snapshot
+            .company_data
+            .map(CompanyData::try_from)
+            .transpose()?
+            .map(ParseCompanyFactsOutput::new);
+
+        Ok(Self {
+            input: ParseCompanyFactsInput::new(snapshot.document),
+            context,
+            output,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn sample_state() -> ParseCompanyFacts {
+        let document = CompanyFactsDocument::from_value(&serde_json::json!({
+            "cik": 1_067_983,
+            "entityName": "BERKSHIRE HATHAWAY INC",
+            "facts": {},
+        }))
+        .expect("A hardcoded company-facts body should always deserialize");
+        ParseCompanyFacts::new(
+            ParseCompanyFactsInput::new(document),
+            ParseCompanyFactsContext::new(
+                Cik::new("1067983").expect("A hardcoded valid CIK should always parse"),
+            ),
+        )
+    }
+
+    #[test]
+    fn should_restore_equal_state_when_round_tripping_pending_state_through_json() {
+        let state = sample_state();
+        let serialized = serde_json::to_string(&state.snapshot())
+            .expect("A state snapshot should always serialize");
+
+        let expected_result = state;
+
+        let result = ParseCompanyFacts::from_snapshot(
+            serde_json::from_str(&serialized).expect("A serialized snapshot should deserialize"),
+        )
+        .expect("A snapshot without company data should always restore");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_document_when_round_tripping_through_json() {
+        let state = sample_state();
+        let serialized = serde_json::to_string(&state.snapshot())
+            .expect("A state snapshot should always serialize");
+
+        let expected_result = state.input.document().clone();
+
+        let result = ParseCompanyFacts::from_snapshot(
+            serde_json::from_str(&serialized).expect("A serialized snapshot should deserialize"),
+        )
+        .expect("A snapshot without company data should always restore")
+        .input
+        .document()
+        .clone();
+
+        assert_eq!(result, expected_result);
+    }
+}
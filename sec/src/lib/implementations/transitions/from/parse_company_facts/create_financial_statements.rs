@@ -1,20 +1,19 @@
 //! # Transition: `ParseCompanyFacts` → `CreateFinancialStatements`
 //!
-//! Implements the [`TryFrom`] conversion producing a [`CreateFinancialStatements`] from a
-//! [`ParseCompanyFacts`], the transform-phase transition.
+//! Implements, through [`state_transition!`](crate::state_transition), the
+//! [`TryFromState`](crate::traits::state_machine::transition::TryFromState) conversion producing a
+//! [`CreateFinancialStatements`] from a [`ParseCompanyFacts`], the transform-phase transition.
 //!
 //! It moves the parsed [`CompanyData`](crate::shared::financial::company_data::CompanyData) from the
 //! source state's output into the next state's input, and carries the CIK across via context. The
-//! supporting `From` impls perform the field-level conversions; the `TryFrom` is fallible because
+//! supporting `From` impls perform the field-level conversions; the conversion is fallible because
 //! the source output may be absent.
 //!
 //! # Errors
 //!
-//! Returns [`TransitionError`] (a [`MissingOutput`](crate::error::state_machine::transition::MissingOutput))
-//! when the source state has no computed output to carry forward.
+//! Returns a [`MissingOutput`](crate::error::state_machine::transition::MissingOutput) transition
+//! error when the source state has no computed output to carry forward.
 
-use crate::error::state_machine::transition;
-use crate::error::state_machine::transition::Transition as TransitionError;
 use crate::implementations::states::transform::create_financial_statements::constants::STATE_NAME as CREATE_FINANCIAL_STATEMENTS;
 use crate::implementations::states::transform::create_financial_statements::{
     CreateFinancialStatements, CreateFinancialStatementsContext, CreateFinancialStatementsInput,
@@ -36,20 +35,13 @@ impl From<ParseCompanyFactsContext> for CreateFinancialStatementsContext {
     }
 }
 
-impl TryFrom<ParseCompanyFacts> for CreateFinancialStatements {
-    type Error = TransitionError;
-
-    fn try_from(state: ParseCompanyFacts) -> Result<Self, TransitionError> {
-        let (_input, output, context) = state.into_parts();
-        let output_data = output.ok_or_else(|| {
-            transition::MissingOutput::new(PARSE_COMPANY_FACTS, CREATE_FINANCIAL_STATEMENTS)
-        })?;
-
-        let new_input: CreateFinancialStatementsInput = output_data.into();
-        let new_context: CreateFinancialStatementsContext = context.into();
-
-        Ok(Self::new(new_input, new_context))
-    }
+crate::state_transition! {
+    from: ParseCompanyFacts = PARSE_COMPANY_FACTS,
+    to: CreateFinancialStatements = CREATE_FINANCIAL_STATEMENTS,
+    |output, context| (
+        CreateFinancialStatementsInput::from(output),
+        CreateFinancialStatementsContext::from(context),
+    )
 }
 
 #[cfg(test)]
@@ -61,14 +53,20 @@ mod tests {
     use super::*;
     use crate::implementations::states::transform::parse_company_facts::ParseCompanyFactsInput;
     use crate::shared::cik::Cik;
+    use crate::shared::company_facts::CompanyFactsDocument;
+    use crate::shared::environment::PipelineEnvironment;
     use crate::shared::financial::company_data::CompanyData;
     use crate::shared::financial::entity_name::EntityName;
-    use crate::shared::response::implementations::sec_response::body_digest::BodyDigest;
+    use crate::traits::state_machine::transition::TryFromState;
 
     fn get_baseline_parse_state_without_output() -> ParseCompanyFacts {
-        let json: serde_json::Value = serde_json::json!({});
-        let digest = BodyDigest::from_body_text(&json.to_string());
-        let input = ParseCompanyFactsInput::new(json, digest);
+        let document = CompanyFactsDocument::from_value(&serde_json::json!({
+            "cik": 1_067_983,
+            "entityName": "BERKSHIRE HATHAWAY INC",
+            "facts": {}
+        }))
+        .expect("A hardcoded company-facts body should always deserialize");
+        let input = ParseCompanyFactsInput::new(document);
         let cik = Cik::new("0001067983").expect("Hardcoded CIK should always be valid");
         let context = ParseCompanyFactsContext::new(cik);
         ParseCompanyFacts::new(input, context)
@@ -112,7 +110,9 @@ mod tests {
 
         let expected_result = true;
 
-        let result = CreateFinancialStatements::try_from(state).is_err();
+        let result =
+            CreateFinancialStatements::try_from_state(state, &PipelineEnvironment::default())
+                .is_err();
 
         assert_eq!(result, expected_result);
     }
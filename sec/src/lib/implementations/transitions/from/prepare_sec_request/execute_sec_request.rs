@@ -1,19 +1,19 @@
 //! # Transition: `PrepareSecRequest` → `ExecuteSecRequest`
 //!
-//! Implements the [`TryFrom`] conversion producing an [`ExecuteSecRequest`] from a
-//! [`PrepareSecRequest`].
+//! Implements, through [`state_transition!`](crate::state_transition), the
+//! [`TryFromState`](crate::traits::state_machine::transition::TryFromState) conversion producing an
+//! [`ExecuteSecRequest`] from a [`PrepareSecRequest`].
 //!
 //! It moves the prepared client and request from the source state's output into the next state's
-//! input, and carries the CIK across via context. The supporting `From` impls perform the
-//! field-level conversions; the `TryFrom` is fallible because the source output may be absent.
+//! input, and carries the CIK across via context, seeding the retries of the request from the
+//! run's configuration. The supporting `From` impls perform the
+//! field-level conversions; the conversion is fallible because the source output may be absent.
 //!
 //! # Errors
 //!
-//! Returns [`TransitionError`] (a [`MissingOutput`](crate::error::state_machine::transition::MissingOutput))
-//! when the source state has no computed output to carry forward.
+//! Returns a [`MissingOutput`](crate::error::state_machine::transition::MissingOutput) transition
+//! error when the source state has no computed output to carry forward.
 
-use crate::error::state_machine::transition;
-use crate::error::state_machine::transition::Transition as TransitionError;
 use crate::implementations::states::extract::execute_sec_request::constants::STATE_NAME as EXECUTE_SEC_REQUEST;
 use crate::implementations::states::extract::execute_sec_request::{
     ExecuteSecRequest, ExecuteSecRequestContext, ExecuteSecRequestInput,
@@ -35,20 +35,16 @@ impl From<PrepareSecRequestOutput> for ExecuteSecRequestInput {
     }
 }
 
-impl TryFrom<PrepareSecRequest> for ExecuteSecRequest {
-    type Error = TransitionError;
-
-    fn try_from(state: PrepareSecRequest) -> Result<Self, TransitionError> {
-        let (_input, output, context) = state.into_parts();
-        let output_data = output.ok_or_else(|| {
-            transition::MissingOutput::new(PREPARE_SEC_REQUEST, EXECUTE_SEC_REQUEST)
-        })?;
-
-        let new_context: ExecuteSecRequestContext = context.into();
-        let new_input: ExecuteSecRequestInput = output_data.into();
-
-        Ok(Self::new(new_input, new_context))
-    }
+crate::state_transition! {
+    from: PrepareSecRequest = PREPARE_SEC_REQUEST,
+    to: ExecuteSecRequest = EXECUTE_SEC_REQUEST,
+    |output, context, environment| (
+        ExecuteSecRequestInput::from(output),
+        ExecuteSecRequestContext {
+            max_retries: environment.config().max_state_retries(),
+            ..ExecuteSecRequestContext::from(context)
+        },
+    )
 }
 
 #[cfg(test)]
@@ -57,10 +53,13 @@ mod tests {
 
     use super::*;
     use crate::implementations::states::extract::prepare_sec_request::PrepareSecRequestInput;
+    use crate::prelude::SMAsyncState;
     use crate::shared::cik::Cik;
+    use crate::shared::config::{ArkadConfig, ConfigLayer};
+    use crate::shared::environment::PipelineEnvironment;
     use crate::shared::http_client::implementations::sec_client::SecClient;
     use crate::shared::request::implementations::sec_request::SecRequest;
-    use crate::traits::state_machine::state::State;
+    use crate::traits::state_machine::transition::TryFromState;
 
     #[test]
     fn should_convert_context_when_valid_context() {
@@ -108,7 +107,34 @@ mod tests {
 
         let expected_result = true;
 
-        let result = ExecuteSecRequest::try_from(state).is_ok();
+        let result =
+            ExecuteSecRequest::try_from_state(state, &PipelineEnvironment::default()).is_ok();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_take_max_state_retries_from_config_when_transitioning() {
+        let cik = Cik::new("0001234567")
+            .expect("Hardcoded valid CIK string should always parse successfully");
+        let input = PrepareSecRequestInput::new(cik.clone(), SecClient::default());
+        let context = PrepareSecRequestContext::new(cik);
+        let mut state = PrepareSecRequest::new(input, context);
+        state
+            .compute_output_data_async()
+            .await
+            .expect("Valid state should always compute output data");
+        let config = ArkadConfig::resolve([ConfigLayer::new().with_max_state_retries(3)])
+            .expect("Valid settings should always resolve");
+        let environment = PipelineEnvironment::default().with_config(config);
+
+        let expected_result = 3;
+
+        let result = ExecuteSecRequest::try_from_state(state, &environment)
+            .expect("A state with output should always transition")
+            .into_parts()
+            .2
+            .max_retries;
 
         assert_eq!(result, expected_result);
     }
@@ -124,7 +150,8 @@ mod tests {
 
         let expected_result = true;
 
-        let result = ExecuteSecRequest::try_from(state).is_err();
+        let result =
+            ExecuteSecRequest::try_from_state(state, &PipelineEnvironment::default()).is_err();
 
         assert_eq!(result, expected_result);
     }
@@ -1,19 +1,19 @@
 //! # Transition: `ValidateCikFormat` → `PrepareSecRequest`
 //!
-//! Implements the [`TryFrom`] conversion producing a [`PrepareSecRequest`] from a
-//! [`ValidateCikFormat`].
+//! Implements, through [`state_transition!`](crate::state_transition), the
+//! [`TryFromState`](crate::traits::state_machine::transition::TryFromState) conversion producing a
+//! [`PrepareSecRequest`] from a [`ValidateCikFormat`].
 //!
 //! It takes the validated [`Cik`](crate::shared::cik::Cik) from the source state's output and the
-//! shared client from its context, and seeds the successor's input and context with them. The
+//! shared client from the run's environment, and seeds the successor's input and context with
+//! them. The
 //! conversion is fallible because the source state may not have computed its output yet.
 //!
 //! # Errors
 //!
-//! Returns [`TransitionError`] (a [`MissingOutput`](crate::error::state_machine::transition::MissingOutput))
-//! when the source state has no computed output to carry forward.
+//! Returns a [`MissingOutput`](crate::error::state_machine::transition::MissingOutput) transition
+//! error when the source state has no computed output to carry forward.
 
-use crate::error::state_machine::transition;
-use crate::error::state_machine::transition::Transition as TransitionError;
 use crate::implementations::states::extract::prepare_sec_request::constants::STATE_NAME as PREPARE_SEC_REQUEST;
 use crate::implementations::states::extract::prepare_sec_request::{
     PrepareSecRequest, PrepareSecRequestContext, PrepareSecRequestInput,
@@ -21,20 +21,16 @@ use crate::implementations::states::extract::prepare_sec_request::{
 use crate::implementations::states::extract::validate_cik_format::ValidateCikFormat;
 use crate::implementations::states::extract::validate_cik_format::constants::STATE_NAME as VALIDATE_CIK_FORMAT;
 
-impl TryFrom<ValidateCikFormat> for PrepareSecRequest {
-    type Error = TransitionError;
-
-    fn try_from(state: ValidateCikFormat) -> Result<Self, TransitionError> {
-        let (_input, output, context) = state.into_parts();
-        let output_data = output.ok_or_else(|| {
-            transition::MissingOutput::new(VALIDATE_CIK_FORMAT, PREPARE_SEC_REQUEST)
-        })?;
-
-        let new_context = PrepareSecRequestContext::new(output_data.validated_cik.clone());
-        let new_input = PrepareSecRequestInput::new(output_data.validated_cik, context.sec_client);
-
-        Ok(Self::new(new_input, new_context))
-    }
+crate::state_transition! {
+    from: ValidateCikFormat = VALIDATE_CIK_FORMAT,
+    to: PrepareSecRequest = PREPARE_SEC_REQUEST,
+    |output, _context, environment| (
+        PrepareSecRequestInput::new(
+            output.validated_cik.clone(),
+            environment.sec_client().clone(),
+        ),
+        PrepareSecRequestContext::new(output.validated_cik),
+    )
 }
 
 #[cfg(test)]
@@ -45,19 +41,20 @@ mod tests {
     use crate::implementations::states::extract::validate_cik_format::{
         ValidateCikFormat, ValidateCikFormatContext, ValidateCikFormatInput,
     };
+    use crate::prelude::SMAsyncState;
     use crate::shared::cik::Cik;
     use crate::shared::cik::constants::BERKSHIRE_HATHAWAY_CIK_RAW;
-    use crate::shared::http_client::implementations::sec_client::SecClient;
-    use crate::traits::state_machine::state::State;
+    use crate::shared::environment::PipelineEnvironment;
+    use crate::traits::state_machine::transition::TryFromState;
 
     #[tokio::test]
     async fn should_transition_to_prepare_sec_request_when_validate_cik_format_has_output() {
         let cik_string = "0001234567";
-        let sec_client = SecClient::default();
+        let environment = PipelineEnvironment::default();
         let input = ValidateCikFormatInput {
             raw_cik: cik_string.into(),
         };
-        let context = ValidateCikFormatContext::new(cik_string, sec_client.clone());
+        let context = ValidateCikFormatContext::new(cik_string);
         let mut state = ValidateCikFormat::new(input, context);
         state
             .compute_output_data_async()
@@ -67,10 +64,11 @@ mod tests {
         let expected_cik = Cik::new(cik_string)
             .expect("Hardcoded valid CIK string should always parse successfully");
         let expected_context = PrepareSecRequestContext::new(expected_cik.clone());
-        let expected_input = PrepareSecRequestInput::new(expected_cik, sec_client);
+        let expected_input =
+            PrepareSecRequestInput::new(expected_cik, environment.sec_client().clone());
         let expected_result = PrepareSecRequest::new(expected_input, expected_context);
 
-        let result = PrepareSecRequest::try_from(state)
+        let result = PrepareSecRequest::try_from_state(state, &environment)
             .expect("State with computed output should always transition successfully");
 
         assert_eq!(result, expected_result);
@@ -78,14 +76,14 @@ mod tests {
 
     #[test]
     fn should_return_error_when_validate_cik_format_has_no_output() {
-        let sec_client = SecClient::default();
         let input = ValidateCikFormatInput::new(BERKSHIRE_HATHAWAY_CIK_RAW);
-        let context = ValidateCikFormatContext::new(BERKSHIRE_HATHAWAY_CIK_RAW, sec_client);
+        let context = ValidateCikFormatContext::new(BERKSHIRE_HATHAWAY_CIK_RAW);
         let state = ValidateCikFormat::new(input, context);
 
         let expected_result = true;
 
-        let result = PrepareSecRequest::try_from(state).is_err();
+        let result =
+            PrepareSecRequest::try_from_state(state, &PipelineEnvironment::default()).is_err();
 
         assert_eq!(result, expected_result);
     }
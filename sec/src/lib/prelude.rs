@@ -11,6 +11,9 @@
 //! - [`Context`]: Trait for context management.
 //! - [`SuperState`]: Trait for hierarchical state machines.
 //! - [`Transition`]: Trait for defining state transitions.
+//! - [`Checkpointable`]: Trait for capturing a state into a resumable checkpoint.
+//! - [`StreamMiddleware`]: Trait for hooking into the lifecycle of every streamed state.
+//! - [`Topology`]: Trait for introspecting a state machine's static structure as a [`StateGraph`].
 //!
 //! ## Usage
 //!
@@ -28,21 +31,32 @@
 //! ```
 
 pub use crate::traits::state_machine::StateMachine;
+pub use crate::traits::state_machine::checkpoint::Checkpointable;
+pub use crate::traits::state_machine::middleware::StreamMiddleware;
+pub use crate::traits::state_machine::payload::StatePayload;
 pub use crate::traits::state_machine::state::Context;
 pub use crate::traits::state_machine::state::State;
 pub use crate::traits::state_machine::state::StateData;
+pub use crate::traits::state_machine::stream::Branching;
 pub use crate::traits::state_machine::stream::IntoStateMachineStream;
 pub use crate::traits::state_machine::stream::NonTerminal;
 pub use crate::traits::state_machine::stream::StateMachineStream;
 pub use crate::traits::state_machine::stream::StreamError;
 pub use crate::traits::state_machine::stream::StreamEvent;
 pub use crate::traits::state_machine::stream::StreamItem;
+pub use crate::traits::state_machine::stream::StreamOptions;
+pub use crate::traits::state_machine::stream::Terminal;
 pub use crate::traits::state_machine::super_state::SuperState;
 pub use crate::traits::state_machine::transition::Transition;
 
+pub use state_maschine::prelude::AsyncState as SMAsyncState;
+pub use state_maschine::prelude::AsyncStateMachine as SMAsyncStateMachine;
+pub use state_maschine::prelude::AsyncSuperState as SMAsyncSuperState;
 pub use state_maschine::prelude::Context as SMContext;
 pub use state_maschine::prelude::State as SMState;
 pub use state_maschine::prelude::StateData as SMStateData;
+pub use state_maschine::prelude::StateGraph;
 pub use state_maschine::prelude::StateMachine as SMStateMachine;
 pub use state_maschine::prelude::SuperState as SMSuperState;
+pub use state_maschine::prelude::Topology;
 pub use state_maschine::prelude::Transition as SMTransition;
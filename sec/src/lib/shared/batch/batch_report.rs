@@ -0,0 +1,444 @@
+//! # Batch Report
+//!
+//! Provides the [`BatchReport`] summarizing a finished batch: which pipelines succeeded, failed,
+//! were cancelled, or were skipped, how long each took, and how long each state took across all
+//! pipelines.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::time::Duration;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::constants::CAUSED_BY;
+use crate::error::State as StateError;
+use crate::error::StateMachine as StateMachineError;
+use crate::error::state_machine::transition::Transition as TransitionError;
+use crate::traits::state_machine::stream::{StreamError, StreamEvent, StreamItem};
+
+/// How a pipeline of a batch ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PipelineStatus {
+    /// The pipeline reached its final state.
+    Succeeded,
+    /// The pipeline ended with an error.
+    Failed(PipelineFailure),
+    /// The pipeline was cancelled before its final state.
+    Cancelled,
+    /// The batch was shut down before the pipeline was launched.
+    Skipped,
+}
+
+impl PipelineStatus {
+    /// Returns the snake-case name of the status, e.g. `succeeded`, as it is serialized.
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Failed(_) => "failed",
+            Self::Cancelled => "cancelled",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+/// The error a failed pipeline ended with, in reportable form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PipelineFailure {
+    /// What was happening when the error occurred.
+    pub event: StreamEvent,
+    /// The name of the state where the error occurred.
+    pub state_name: String,
+    /// The name of the error variant, e.g. `InvalidCikFormat`; failures are grouped by it.
+    pub kind: &'static str,
+    /// The message of the underlying state machine error.
+    pub message: String,
+    /// The messages of the error and each of its sources, outermost first.
+    pub error_chain: Vec<String>,
+    /// The XBRL concepts the company facts lacked, if the pipeline failed on
+    /// [`IncompleteCompanyFacts`](StateError::IncompleteCompanyFacts).
+    pub missing_concepts: Vec<String>,
+}
+
+impl From<&StreamError> for PipelineFailure {
+    fn from(error: &StreamError) -> Self {
+        Self {
+            event: error.event.clone(),
+            state_name: error.state_name.clone(),
+            kind: failure_kind(&error.source),
+            message: error.source.to_string(),
+            error_chain: error_chain(&error.source),
+            missing_concepts: missing_concepts(&error.source),
+        }
+    }
+}
+
+/// The outcome of one pipeline of a batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PipelineOutcome {
+    /// The CIK the pipeline processed.
+    pub cik: String,
+    /// The execution ID of the pipeline run, or `None` if it was never launched.
+    pub execution_id: Option<Uuid>,
+    /// How long the pipeline ran, from launch to its last event.
+    pub duration: Duration,
+    /// How the pipeline ended.
+    #[serde(flatten)]
+    pub status: PipelineStatus,
+}
+
+/// The median and 95th percentile of a state's compute durations across a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct StateTiming {
+    /// The number of times the state completed.
+    pub count: usize,
+    /// The median duration.
+    pub p50: Duration,
+    /// The 95th percentile duration.
+    pub p95: Duration,
+}
+
+/// The summary of a batch, built up while its events are consumed.
+///
+/// Pipelines are listed in the order they finished. State timings are taken from the
+/// [`StateCompleted`](StreamEvent::StateCompleted) events of every pipeline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchReport {
+    outcomes: Vec<PipelineOutcome>,
+    state_durations: BTreeMap<String, Vec<Duration>>,
+    elapsed: Duration,
+}
+
+impl BatchReport {
+    /// Creates an empty [`BatchReport`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            outcomes: Vec::new(),
+            state_durations: BTreeMap::new(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Records the timing of `item` if it completes a state.
+    pub fn observe(&mut self, item: &StreamItem) {
+        if item.event == StreamEvent::StateCompleted {
+            self.state_durations
+                .entry(item.state_name.clone())
+                .or_default()
+                .push(item.event_duration);
+        }
+    }
+
+    /// Records the outcome of a finished pipeline.
+    pub fn record(&mut self, outcome: PipelineOutcome) {
+        self.outcomes.push(outcome);
+    }
+
+    /// Sets how long the whole batch ran.
+    pub const fn set_elapsed(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed;
+    }
+
+    /// Returns the outcome of every finished pipeline, in the order they finished.
+    #[must_use]
+    pub fn outcomes(&self) -> &[PipelineOutcome] {
+        &self.outcomes
+    }
+
+    /// Returns the pipelines that reached their final state.
+    pub fn successes(&self) -> impl Iterator<Item = &PipelineOutcome> {
+        self.with_status(|status| matches!(status, PipelineStatus::Succeeded))
+    }
+
+    /// Returns the pipelines that were cancelled before their final state.
+    pub fn cancellations(&self) -> impl Iterator<Item = &PipelineOutcome> {
+        self.with_status(|status| matches!(status, PipelineStatus::Cancelled))
+    }
+
+    /// Returns the pipelines that were never launched because the batch was shut down.
+    pub fn skipped(&self) -> impl Iterator<Item = &PipelineOutcome> {
+        self.with_status(|status| matches!(status, PipelineStatus::Skipped))
+    }
+
+    /// Returns the failed pipelines grouped by the [`kind`](PipelineFailure::kind) of their error.
+    #[must_use]
+    pub fn failures_by_kind(&self) -> BTreeMap<&'static str, Vec<&PipelineOutcome>> {
+        let mut failures: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for outcome in &self.outcomes {
+            if let PipelineStatus::Failed(failure) = &outcome.status {
+                failures.entry(failure.kind).or_default().push(outcome);
+            }
+        }
+        failures
+    }
+
+    /// Returns the median and 95th percentile compute duration of every state that completed at
+    /// least once, keyed by state name.
+    #[must_use]
+    pub fn state_timings(&self) -> BTreeMap<&str, StateTiming> {
+        self.state_durations
+            .iter()
+            .map(|(state_name, durations)| {
+                let mut sorted = durations.clone();
+                sorted.sort_unstable();
+                let timing = StateTiming {
+                    count: sorted.len(),
+                    p50: percentile(&sorted, 50),
+                    p95: percentile(&sorted, 95),
+                };
+                (state_name.as_str(), timing)
+            })
+            .collect()
+    }
+
+    /// Returns how long the whole batch ran.
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    fn with_status(
+        &self,
+        predicate: impl Fn(&PipelineStatus) -> bool,
+    ) -> impl Iterator<Item = &PipelineOutcome> {
+        self.outcomes
+            .iter()
+            .filter(move |outcome| predicate(&outcome.status))
+    }
+}
+
+/// Returns the nearest-rank `percentile` of `sorted`, or zero if it is empty.
+fn percentile(sorted: &[Duration], percentile: usize) -> Duration {
+    let rank = (percentile * sorted.len()).div_ceil(100);
+    sorted
+        .get(rank.saturating_sub(1))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Returns the messages of `error` and of every error in its source chain, outermost first.
+///
+/// Each entry is that error's own message: its source is recorded as the next entry, so it is cut
+/// from the message rather than repeated at every level.
+fn error_chain(error: &StateMachineError) -> Vec<String> {
+    let mut next: Option<&dyn Error> = match error {
+        StateMachineError::State(state) => Some(state),
+        StateMachineError::Transition(transition) => Some(transition),
+        _ => None,
+    };
+    let mut chain = vec![own_message(error, next)];
+    while let Some(current) = next {
+        next = current.source();
+        chain.push(own_message(current, next));
+    }
+    chain
+}
+
+/// Returns the message of `error` without the message of its `source` that it embeds, e.g.
+/// `[StateError] A state level error occurred` for
+/// `[StateError] A state level error occurred, Caused by: [InvalidCikFormat] …`.
+fn own_message(error: &dyn Display, source: Option<&dyn Error>) -> String {
+    let message = error.to_string();
+    let Some(source) = source else {
+        return message;
+    };
+    message
+        .rfind(&source.to_string())
+        .map_or(message.as_str(), |start| {
+            let is_separator = |c: char| matches!(c, ',' | ':' | ' ');
+            let quoted = &message[..start];
+            let own = quoted
+                .strip_suffix('\'')
+                .unwrap_or(quoted)
+                .trim_end_matches(is_separator);
+            own.strip_suffix(CAUSED_BY)
+                .unwrap_or(own)
+                .trim_end_matches(is_separator)
+        })
+        .to_string()
+}
+
+/// Returns the concepts missing from the company facts if `error` reports incomplete ones.
+fn missing_concepts(error: &StateMachineError) -> Vec<String> {
+    match error {
+        StateMachineError::State(StateError::IncompleteCompanyFacts(incomplete)) => {
+            incomplete.missing_fields().as_slice().to_vec()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the name of the innermost variant of `error`, e.g. `InvalidCikFormat`.
+const fn failure_kind(error: &StateMachineError) -> &'static str {
+    match error {
+        StateMachineError::InvalidConfiguration => "InvalidConfiguration",
+        StateMachineError::State(state) => match state {
+            StateError::InvalidCikFormat(_) => "InvalidCikFormat",
+            StateError::FailedRequestExecution(_) => "FailedRequestExecution",
+            StateError::IncompleteCompanyFacts(_) => "IncompleteCompanyFacts",
+            StateError::StateTimedOut(_) => "StateTimedOut",
+            StateError::InvalidInput => "InvalidInput",
+            StateError::InvalidContext => "InvalidContext",
+            StateError::FailedOutputComputation => "FailedOutputComputation",
+            StateError::StateDataUpdateFailed => "StateDataUpdateFailed",
+            StateError::ContextUpdateFailed => "ContextUpdateFailed",
+        },
+        StateMachineError::Transition(transition) => match transition {
+            TransitionError::MissingOutput(_) => "MissingOutput",
+            TransitionError::FailedOutputConversion(_) => "FailedOutputConversion",
+            TransitionError::FailedContextConversion(_) => "FailedContextConversion",
+        },
+        StateMachineError::Cancelled => "Cancelled",
+        StateMachineError::Replayed(_) => "Replayed",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::error::state_machine::state::InvalidCikFormat;
+    use crate::error::state_machine::state::incomplete_company_facts::{
+        IncompleteCompanyFacts, MissingFields,
+    };
+    use crate::shared::cik::cik_error::{CikError, InvalidCikReason};
+    use crate::traits::state_machine::payload::StatePayload;
+    use crate::traits::state_machine::state_path::StatePath;
+
+    fn completed(state_name: &str, millis: u64) -> StreamItem {
+        StreamItem {
+            event: StreamEvent::StateCompleted,
+            state_name: state_name.to_string(),
+            path: StatePath::default(),
+            sequence: 0,
+            data: StatePayload::default(),
+            event_duration: Duration::from_millis(millis),
+        }
+    }
+
+    fn outcome(cik: &str, status: PipelineStatus) -> PipelineOutcome {
+        PipelineOutcome {
+            cik: cik.to_string(),
+            execution_id: Some(Uuid::new_v4()),
+            duration: Duration::from_millis(1),
+            status,
+        }
+    }
+
+    fn failed_with(source: StateError) -> StreamError {
+        StreamError {
+            event: StreamEvent::StateFailed,
+            execution_id: Uuid::new_v4(),
+            state_name: "Validate CIK Format".to_string(),
+            path: StatePath::default(),
+            sequence: 1,
+            data: StatePayload::default(),
+            source: StateMachineError::State(source),
+        }
+    }
+
+    #[test]
+    fn should_report_nearest_rank_percentiles_when_state_completed_repeatedly() {
+        let mut report = BatchReport::new();
+        for millis in 1..=20 {
+            report.observe(&completed("Validate CIK Format", millis));
+        }
+
+        let expected_result = StateTiming {
+            count: 20,
+            p50: Duration::from_millis(10),
+            p95: Duration::from_millis(19),
+        };
+
+        let result = report.state_timings()["Validate CIK Format"];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_ignore_events_other_than_completions_when_observing_items() {
+        let mut report = BatchReport::new();
+        report.observe(&StreamItem {
+            event: StreamEvent::StateStarted,
+            ..completed("Validate CIK Format", 5)
+        });
+
+        let expected_result = 0;
+
+        let result = report.state_timings().len();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_group_failures_by_error_variant_when_pipelines_failed() {
+        let failure = PipelineFailure::from(&failed_with(StateError::InvalidCikFormat(
+            InvalidCikFormat::new(
+                "Validate CIK Format",
+                CikError::new(InvalidCikReason::ContainsNonNumericCharacters, "ABC"),
+            ),
+        )));
+        let mut report = BatchReport::new();
+        report.record(outcome("ABC", PipelineStatus::Failed(failure.clone())));
+        report.record(outcome("XYZ", PipelineStatus::Failed(failure)));
+        report.record(outcome("320193", PipelineStatus::Succeeded));
+
+        let expected_result = vec![("InvalidCikFormat", vec!["ABC", "XYZ"])];
+
+        let result = report
+            .failures_by_kind()
+            .into_iter()
+            .map(|(kind, outcomes)| {
+                (
+                    kind,
+                    outcomes
+                        .into_iter()
+                        .map(|outcome| outcome.cik.as_str())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_list_missing_concepts_when_company_facts_were_incomplete() {
+        let failure = PipelineFailure::from(&failed_with(StateError::IncompleteCompanyFacts(
+            IncompleteCompanyFacts::new(
+                "Parse Company Facts",
+                MissingFields::new(vec!["Revenue".to_string(), "Total Assets".to_string()]),
+            ),
+        )));
+
+        let expected_result = vec!["Revenue".to_string(), "Total Assets".to_string()];
+
+        let result = failure.missing_concepts;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_list_every_source_when_building_error_chain() {
+        let cik_error = CikError::new(InvalidCikReason::ContainsNonNumericCharacters, "ABC");
+        let invalid_cik_format = InvalidCikFormat::new("Validate CIK Format", cik_error.clone());
+        let state_error = StateError::InvalidCikFormat(invalid_cik_format.clone());
+        let failure = PipelineFailure::from(&failed_with(state_error.clone()));
+
+        let expected_result = vec![
+            "Problem occurred during internal state operations".to_string(),
+            "[StateError] A state level error occurred".to_string(),
+            "[InvalidCikFormat] Failure in State: 'Validate CIK Format'".to_string(),
+            cik_error.to_string(),
+        ];
+
+        let result = failure.error_chain;
+
+        assert_eq!(result, expected_result);
+    }
+}
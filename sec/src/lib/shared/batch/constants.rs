@@ -0,0 +1,26 @@
+//! # Batch Constants
+//!
+//! Defaults of the [`BatchRunner`](super::BatchRunner) and the file names of exported
+//! [`RunReport`](super::RunReport)s.
+
+use std::num::NonZeroUsize;
+
+/// The number of pipelines a [`BatchRunner`](super::BatchRunner) keeps in flight unless told
+/// otherwise.
+///
+/// Requests are paced by the SEC client's rate limiter regardless; the cap bounds the memory and
+/// open connections held by pipelines waiting on it.
+pub const DEFAULT_MAX_IN_FLIGHT: NonZeroUsize = NonZeroUsize::new(16).unwrap();
+
+/// The file name a [`RunReport`](super::RunReport) is exported to as JSON.
+pub const RUN_REPORT_JSON_FILE: &str = "run_report.json";
+
+/// The file name a [`RunReport`](super::RunReport) is exported to as CSV.
+pub const RUN_REPORT_CSV_FILE: &str = "run_report.csv";
+
+/// The file name a [`RunReport`](super::RunReport) is exported to as an HTML summary page.
+pub const RUN_REPORT_HTML_FILE: &str = "run_report.html";
+
+/// The phrase an error's message introduces the message of its source with, cut from each entry
+/// of a [`PipelineFailure`](super::PipelineFailure)'s error chain.
+pub const CAUSED_BY: &str = "Caused by";
@@ -0,0 +1,371 @@
+//! # Batch
+//!
+//! Provides the [`BatchRunner`], which runs the pipelines of many CIKs concurrently and merges
+//! their events into a single [`BatchRun`] stream.
+//!
+//! The runner caps how many pipelines are in flight at once; the next CIK is only launched once
+//! a running pipeline has finished. Every event of the merged stream is a [`BatchItem`] carrying
+//! the CIK and execution ID of the pipeline it came from. While the stream is consumed, the run
+//! builds up a [`BatchReport`] of every pipeline's outcome and the per-state timings.
+//!
+//! Once the batch has finished, a [`RunReport`] turns its report into a machine-readable record,
+//! exported as JSON, CSV, and a static HTML summary page.
+//!
+//! Cancelling the runner's token shuts the batch down gracefully: running pipelines stop before
+//! their next state, and pipelines that have not been launched yet are reported as skipped.
+//!
+//! ## Modules
+//!
+//! - [`batch_report`]: The [`BatchReport`] summarizing the outcomes and timings of a batch.
+//! - [`constants`]: The [`DEFAULT_MAX_IN_FLIGHT`] pipelines of a batch and the run report file
+//!   names.
+//! - [`pipeline_launcher`]: The [`PipelineLauncher`] opening each pipeline, and the
+//!   [`EtlPipelineLauncher`].
+//! - [`run_report`]: The [`RunReport`] exporting a finished batch as JSON, CSV, and HTML.
+
+pub mod batch_report;
+pub mod constants;
+pub mod pipeline_launcher;
+pub mod run_report;
+
+use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+pub use batch_report::{
+    BatchReport, PipelineFailure, PipelineOutcome, PipelineStatus, StateTiming,
+};
+pub use constants::{
+    DEFAULT_MAX_IN_FLIGHT, RUN_REPORT_CSV_FILE, RUN_REPORT_HTML_FILE, RUN_REPORT_JSON_FILE,
+};
+pub use pipeline_launcher::{EtlPipelineLauncher, PipelineLauncher};
+pub use run_report::{RunReport, RunReportError};
+
+use crate::traits::state_machine::stream::{StreamError, StreamEvent, StreamItem, StreamOptions};
+
+/// One event of a batch: a pipeline's stream result together with the pipeline it came from.
+#[derive(Debug)]
+pub struct BatchItem {
+    /// The CIK the pipeline processes.
+    pub cik: String,
+    /// The execution ID of the pipeline run.
+    pub execution_id: Uuid,
+    /// The event the pipeline's stream yielded.
+    pub result: Result<StreamItem, StreamError>,
+}
+
+/// What a pipeline's wrapped stream yields: its events, then its outcome.
+enum PipelineEmission {
+    Item(BatchItem),
+    Finished(PipelineOutcome),
+}
+
+/// Runs the pipelines of many CIKs with bounded concurrency.
+///
+/// # Examples
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use sec::shared::batch::{BatchRunner, EtlPipelineLauncher};
+/// use sec::shared::http_client::implementations::sec_client::SecClient;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let runner = BatchRunner::new(EtlPipelineLauncher::new(SecClient::default()));
+/// let mut run = runner.run(["320193", "1067983"]);
+/// while let Some(item) = run.next().await {
+///     println!("{}: {:?}", item.cik, item.result.map(|item| item.event));
+/// }
+///
+/// let report = run.into_report();
+/// println!("{} succeeded", report.successes().count());
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BatchRunner<L> {
+    launcher: Arc<L>,
+    max_in_flight: NonZeroUsize,
+    cancellation_token: CancellationToken,
+}
+
+impl<L: PipelineLauncher + 'static> BatchRunner<L> {
+    /// Creates a new [`BatchRunner`] opening its pipelines through `launcher`, with at most
+    /// [`DEFAULT_MAX_IN_FLIGHT`] pipelines in flight and a fresh [`CancellationToken`].
+    #[must_use]
+    pub fn new(launcher: L) -> Self {
+        Self {
+            launcher: Arc::new(launcher),
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            cancellation_token: CancellationToken::new(),
+        }
+    }
+
+    /// Caps the number of pipelines in flight at once.
+    #[must_use]
+    pub const fn with_max_in_flight(mut self, max_in_flight: NonZeroUsize) -> Self {
+        self.max_in_flight = max_in_flight;
+        self
+    }
+
+    /// Shuts the batch down gracefully once `cancellation_token` is cancelled.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = cancellation_token;
+        self
+    }
+
+    /// Returns the token that shuts the batch down once cancelled.
+    #[must_use]
+    pub const fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
+    /// Starts the pipelines of `ciks`, in order, and returns the stream of their merged events.
+    ///
+    /// Pipelines are only launched while the returned [`BatchRun`] is polled.
+    pub fn run<I>(&self, ciks: I) -> BatchRun
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        I::IntoIter: Send + 'static,
+    {
+        let launcher = Arc::clone(&self.launcher);
+        let cancellation_token = self.cancellation_token.clone();
+        let emissions = futures_util::stream::iter(ciks)
+            .map(move |cik| {
+                pipeline_emissions(
+                    Arc::clone(&launcher),
+                    cik.into(),
+                    cancellation_token.clone(),
+                )
+            })
+            .flatten_unordered(self.max_in_flight.get());
+
+        BatchRun {
+            emissions: Box::pin(emissions),
+            report: BatchReport::new(),
+            start: Instant::now(),
+        }
+    }
+}
+
+/// Launches the pipeline of `cik` and yields its events, followed by its outcome.
+fn pipeline_emissions<L: PipelineLauncher + 'static>(
+    launcher: Arc<L>,
+    cik: String,
+    cancellation_token: CancellationToken,
+) -> Pin<Box<dyn Stream<Item = PipelineEmission> + Send>> {
+    Box::pin(async_stream::stream! {
+        if cancellation_token.is_cancelled() {
+            yield PipelineEmission::Finished(PipelineOutcome {
+                cik,
+                execution_id: None,
+                duration: Duration::ZERO,
+                status: PipelineStatus::Skipped,
+            });
+            return;
+        }
+
+        let start = Instant::now();
+        let options = StreamOptions::new().with_cancellation_token(cancellation_token);
+        let (execution_id, mut stream) = launcher.launch(&cik, options).await;
+
+        // The pipeline ended with an error if its last event was one; a failed child of a
+        // parallel super-state does not end the stream, so it does not count.
+        let mut status = PipelineStatus::Succeeded;
+        while let Some(result) = stream.next().await {
+            match &result {
+                Ok(_) => status = PipelineStatus::Succeeded,
+                Err(e) if e.event == StreamEvent::ChildFailed => {}
+                Err(e) if e.event == StreamEvent::PipelineCancelled => {
+                    status = PipelineStatus::Cancelled;
+                }
+                Err(e) => status = PipelineStatus::Failed(PipelineFailure::from(e)),
+            }
+            yield PipelineEmission::Item(BatchItem {
+                cik: cik.clone(),
+                execution_id,
+                result,
+            });
+        }
+
+        let outcome = PipelineOutcome {
+            cik,
+            execution_id: Some(execution_id),
+            duration: start.elapsed(),
+            status,
+        };
+        launcher.finish(&outcome).await;
+        yield PipelineEmission::Finished(outcome);
+    })
+}
+
+/// The merged event stream of a running batch, building up its [`BatchReport`] as it is
+/// consumed.
+///
+/// Yields the events of all pipelines as they happen; events of one pipeline keep their order.
+/// The stream ends once every pipeline has finished.
+pub struct BatchRun {
+    emissions: Pin<Box<dyn Stream<Item = PipelineEmission> + Send>>,
+    report: BatchReport,
+    start: Instant,
+}
+
+impl BatchRun {
+    /// Returns the report of the pipelines finished so far.
+    #[must_use]
+    pub const fn report(&self) -> &BatchReport {
+        &self.report
+    }
+
+    /// Consumes the run and returns its report.
+    #[must_use]
+    pub fn into_report(self) -> BatchReport {
+        self.report
+    }
+
+    /// Drives the remaining pipelines to completion, discarding their events, and returns the
+    /// report.
+    pub async fn collect_report(mut self) -> BatchReport {
+        while self.next().await.is_some() {}
+        self.report
+    }
+}
+
+impl Stream for BatchRun {
+    type Item = BatchItem;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.emissions.poll_next_unpin(cx) {
+                Poll::Ready(Some(PipelineEmission::Item(item))) => {
+                    if let Ok(stream_item) = &item.result {
+                        self.report.observe(stream_item);
+                    }
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(Some(PipelineEmission::Finished(outcome))) => {
+                    self.report.record(outcome);
+                }
+                Poll::Ready(None) => {
+                    let elapsed = self.start.elapsed();
+                    self.report.set_elapsed(elapsed);
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for BatchRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchRun")
+            .field("report", &self.report)
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::tests::fixtures::sample_pipeline_launcher::SamplePipelineLauncher;
+
+    const CIKS: [&str; 6] = ["1", "2", "3", "4", "5", "6"];
+
+    #[tokio::test]
+    async fn should_never_exceed_limit_when_running_more_pipelines_than_allowed_in_flight() {
+        let launcher = SamplePipelineLauncher::default();
+        let in_flight = launcher.in_flight();
+        let runner = BatchRunner::new(launcher)
+            .with_max_in_flight(NonZeroUsize::new(2).expect("Two should always be non-zero"));
+        runner.run(CIKS).collect_report().await;
+
+        let expected_result = 2;
+
+        let result = in_flight.max();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_report_every_pipeline_when_batch_completes() {
+        let launcher = SamplePipelineLauncher::default().failing(["3", "5"]);
+        let report = BatchRunner::new(launcher).run(CIKS).collect_report().await;
+
+        let expected_result = (4, 2);
+
+        let result = (
+            report.successes().count(),
+            report.failures_by_kind().values().map(Vec::len).sum(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_attach_pipeline_execution_id_when_yielding_events() {
+        let mut run = BatchRunner::new(SamplePipelineLauncher::default()).run(CIKS);
+        let mut seen = BTreeMap::new();
+        while let Some(item) = run.next().await {
+            seen.insert(item.cik, item.execution_id);
+        }
+
+        let expected_result = run
+            .into_report()
+            .outcomes()
+            .iter()
+            .map(|outcome| (outcome.cik.clone(), outcome.execution_id))
+            .collect::<BTreeMap<_, _>>();
+
+        let result = seen
+            .into_iter()
+            .map(|(cik, execution_id)| (cik, Some(execution_id)))
+            .collect::<BTreeMap<_, _>>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_skip_unlaunched_pipelines_when_batch_was_shut_down() {
+        let runner = BatchRunner::new(SamplePipelineLauncher::default());
+        runner.cancellation_token().cancel();
+        let report = runner.run(CIKS).collect_report().await;
+
+        let expected_result = CIKS.len();
+
+        let result = report.skipped().count();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_collect_state_timings_when_pipelines_complete_states() {
+        let report = BatchRunner::new(SamplePipelineLauncher::default())
+            .run(CIKS)
+            .collect_report()
+            .await;
+
+        let expected_result = Some(CIKS.len());
+
+        let result = report
+            .state_timings()
+            .values()
+            .next()
+            .map(|timing| timing.count);
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,76 @@
+//! # Pipeline Launcher
+//!
+//! Provides the [`PipelineLauncher`] a [`BatchRunner`](super::BatchRunner) opens each pipeline
+//! of a batch through, and the [`EtlPipelineLauncher`] running the full Extract → Transform
+//! pipeline from scratch.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::batch_report::PipelineOutcome;
+use crate::implementations::states::extract::ExtractSuperState;
+use crate::implementations::states::extract::validate_cik_format::ValidateCikFormat;
+use crate::shared::config::ArkadConfig;
+use crate::shared::environment::PipelineEnvironment;
+use crate::shared::http_client::implementations::sec_client::SecClient;
+use crate::traits::state_machine::stream::{
+    IntoStateMachineStream, StateMachineStream, StreamOptions,
+};
+
+/// Opens the pipeline of one batch input and is told when it has finished.
+///
+/// Abstracts over how a pipeline starts, so a batch can e.g. resume pipelines from checkpoints
+/// or run only part of the pipeline, while the [`BatchRunner`](super::BatchRunner) owns
+/// concurrency, shutdown, and reporting.
+#[async_trait]
+pub trait PipelineLauncher: Send + Sync + Debug {
+    /// Opens the stream of the pipeline processing `cik`, returning it with its execution ID.
+    ///
+    /// The stream must be driven with `options`, which carry the batch's cancellation token.
+    async fn launch(&self, cik: &str, options: StreamOptions) -> (Uuid, StateMachineStream);
+
+    /// Called once the pipeline's stream has ended, e.g. to clean up after a successful run.
+    ///
+    /// Does nothing by default.
+    async fn finish(&self, _outcome: &PipelineOutcome) {}
+}
+
+/// The [`PipelineLauncher`] running the full Extract → Transform pipeline of every CIK from its
+/// first state, each under a fresh execution ID.
+#[derive(Debug, Clone)]
+pub struct EtlPipelineLauncher {
+    sec_client: SecClient,
+    config: ArkadConfig,
+}
+
+impl EtlPipelineLauncher {
+    /// Creates a new [`EtlPipelineLauncher`] whose pipelines share `sec_client` and the default
+    /// [`ArkadConfig`].
+    #[must_use]
+    pub fn new(sec_client: SecClient) -> Self {
+        Self {
+            sec_client,
+            config: ArkadConfig::default(),
+        }
+    }
+
+    /// Sets the configuration every pipeline's environment carries.
+    #[must_use]
+    pub fn with_config(self, config: ArkadConfig) -> Self {
+        Self { config, ..self }
+    }
+}
+
+#[async_trait]
+impl PipelineLauncher for EtlPipelineLauncher {
+    async fn launch(&self, cik: &str, options: StreamOptions) -> (Uuid, StateMachineStream) {
+        let execution_id = Uuid::new_v4();
+        let environment = PipelineEnvironment::new(self.sec_client.clone())
+            .with_config(self.config.clone())
+            .with_execution_id(execution_id);
+        let state = ExtractSuperState::<ValidateCikFormat>::new(cik, environment);
+        (execution_id, state.into_stream_with(execution_id, options))
+    }
+}
@@ -0,0 +1,492 @@
+//! # Run Report
+//!
+//! Provides the [`RunReport`], the machine-readable record of a finished batch, and its exports
+//! as JSON, CSV, and a static HTML summary page.
+//!
+//! A run report lists every pipeline of the batch, sorted by CIK, with its outcome, the state it
+//! failed in, the chain of error messages it failed with, the concepts its company facts lacked,
+//! and how long it ran, next to the per-state timings of the whole batch. Sorting by CIK keeps
+//! reports of the same CIK list line-by-line comparable across releases, so coverage regressions
+//! show up as plain diffs.
+//!
+//! ## Modules
+//!
+//! - [`run_report_error`]: The [`RunReportError`] returned when a report cannot be exported.
+
+pub mod run_report_error;
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub use run_report_error::RunReportError;
+
+use super::batch_report::{BatchReport, PipelineOutcome, PipelineStatus};
+use super::constants::{RUN_REPORT_CSV_FILE, RUN_REPORT_HTML_FILE, RUN_REPORT_JSON_FILE};
+
+/// The header row of a run report's CSV export.
+const CSV_HEADER: [&str; 8] = [
+    "cik",
+    "execution_id",
+    "status",
+    "duration_ms",
+    "failing_state",
+    "error_kind",
+    "missing_concepts",
+    "error_chain",
+];
+
+/// The separator joining the items of list-valued CSV and HTML cells.
+const LIST_SEPARATOR: &str = "; ";
+
+/// The machine-readable record of a finished batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunReport {
+    /// When the report was generated.
+    pub generated_at: DateTime<Utc>,
+    /// How long the whole batch ran, in milliseconds.
+    pub elapsed_ms: u64,
+    /// The number of pipelines per outcome.
+    pub summary: RunSummary,
+    /// Every pipeline of the batch, sorted by CIK.
+    pub pipelines: Vec<PipelineRecord>,
+    /// The compute durations of every state that completed at least once, sorted by state name.
+    pub state_timings: Vec<StateTimingRecord>,
+}
+
+/// The number of pipelines of a batch per outcome.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// The number of pipelines in the batch.
+    pub total: usize,
+    /// The number of pipelines that reached their final state.
+    pub succeeded: usize,
+    /// The number of pipelines that ended with an error.
+    pub failed: usize,
+    /// The number of pipelines cancelled before their final state.
+    pub cancelled: usize,
+    /// The number of pipelines never launched because the batch was shut down.
+    pub skipped: usize,
+    /// The number of failed pipelines per error variant, e.g. `IncompleteCompanyFacts`.
+    pub failures_by_kind: BTreeMap<String, usize>,
+}
+
+/// The outcome of one pipeline of a batch, in reportable form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PipelineRecord {
+    /// The CIK the pipeline processed.
+    pub cik: String,
+    /// The execution ID of the pipeline run, or `None` if it was never launched.
+    pub execution_id: Option<Uuid>,
+    /// How the pipeline ended: `succeeded`, `failed`, `cancelled`, or `skipped`.
+    pub status: String,
+    /// How long the pipeline ran, in milliseconds.
+    pub duration_ms: u64,
+    /// The name of the state the pipeline failed in, if it failed.
+    pub failing_state: Option<String>,
+    /// The name of the error variant the pipeline failed with, if it failed.
+    pub error_kind: Option<String>,
+    /// The messages of the error the pipeline failed with and of its sources, outermost first.
+    pub error_chain: Vec<String>,
+    /// The XBRL concepts the pipeline's company facts lacked.
+    pub missing_concepts: Vec<String>,
+}
+
+impl From<&PipelineOutcome> for PipelineRecord {
+    fn from(outcome: &PipelineOutcome) -> Self {
+        let failure = match &outcome.status {
+            PipelineStatus::Failed(failure) => Some(failure),
+            _ => None,
+        };
+        Self {
+            cik: outcome.cik.clone(),
+            execution_id: outcome.execution_id,
+            status: outcome.status.label().to_string(),
+            duration_ms: millis(outcome.duration),
+            failing_state: failure.map(|failure| failure.state_name.clone()),
+            error_kind: failure.map(|failure| failure.kind.to_string()),
+            error_chain: failure
+                .map(|failure| failure.error_chain.clone())
+                .unwrap_or_default(),
+            missing_concepts: failure
+                .map(|failure| failure.missing_concepts.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The compute durations of one state across a batch, in reportable form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateTimingRecord {
+    /// The name of the state.
+    pub state_name: String,
+    /// The number of times the state completed.
+    pub count: usize,
+    /// The median duration, in milliseconds.
+    pub p50_ms: u64,
+    /// The 95th percentile duration, in milliseconds.
+    pub p95_ms: u64,
+}
+
+impl RunReport {
+    /// Creates the [`RunReport`] of the batch summarized by `report`, generated at
+    /// `generated_at`.
+    #[must_use]
+    pub fn new(report: &BatchReport, generated_at: DateTime<Utc>) -> Self {
+        let mut pipelines: Vec<_> = report.outcomes().iter().map(PipelineRecord::from).collect();
+        pipelines.sort_by(|a, b| a.cik.cmp(&b.cik));
+
+        let summary = RunSummary {
+            total: pipelines.len(),
+            succeeded: report.successes().count(),
+            failed: report.failures_by_kind().values().map(Vec::len).sum(),
+            cancelled: report.cancellations().count(),
+            skipped: report.skipped().count(),
+            failures_by_kind: report
+                .failures_by_kind()
+                .into_iter()
+                .map(|(kind, outcomes)| (kind.to_string(), outcomes.len()))
+                .collect(),
+        };
+
+        let state_timings = report
+            .state_timings()
+            .into_iter()
+            .map(|(state_name, timing)| StateTimingRecord {
+                state_name: state_name.to_string(),
+                count: timing.count,
+                p50_ms: millis(timing.p50),
+                p95_ms: millis(timing.p95),
+            })
+            .collect();
+
+        Self {
+            generated_at,
+            elapsed_ms: millis(report.elapsed()),
+            summary,
+            pipelines,
+            state_timings,
+        }
+    }
+
+    /// Renders the report as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RunReportError`] if the report cannot be serialized.
+    pub fn to_json(&self) -> Result<String, RunReportError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Renders the pipelines of the report as CSV, one row per pipeline below a header row.
+    ///
+    /// List-valued columns join their items with `; `.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = CSV_HEADER.join(",");
+        csv.push('\n');
+        for pipeline in &self.pipelines {
+            let row = [
+                pipeline.cik.clone(),
+                pipeline
+                    .execution_id
+                    .map(|execution_id| execution_id.to_string())
+                    .unwrap_or_default(),
+                pipeline.status.clone(),
+                pipeline.duration_ms.to_string(),
+                pipeline.failing_state.clone().unwrap_or_default(),
+                pipeline.error_kind.clone().unwrap_or_default(),
+                pipeline.missing_concepts.join(LIST_SEPARATOR),
+                pipeline.error_chain.join(LIST_SEPARATOR),
+            ];
+            let row: Vec<_> = row.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Renders the report as a self-contained, static HTML summary page.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let summary = &self.summary;
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Pipeline Run Report</title>\n<style>\n\
+             body { font-family: sans-serif; margin: 2rem; }\n\
+             table { border-collapse: collapse; margin-bottom: 2rem; }\n\
+             th, td { border: 1px solid #ccc; padding: 0.25rem 0.5rem; text-align: left; vertical-align: top; }\n\
+             .succeeded { color: #1a7f37; }\n.failed { color: #cf222e; }\n\
+             .cancelled, .skipped { color: #9a6700; }\n\
+             </style>\n</head>\n<body>\n<h1>Pipeline Run Report</h1>\n",
+        );
+
+        let _ = writeln!(
+            html,
+            "<p>Generated at {} in {} ms.</p>",
+            escape_html(&self.generated_at.to_rfc3339()),
+            self.elapsed_ms
+        );
+
+        html.push_str("<h2>Summary</h2>\n<table>\n");
+        for (label, count) in [
+            ("Total", summary.total),
+            ("Succeeded", summary.succeeded),
+            ("Failed", summary.failed),
+            ("Cancelled", summary.cancelled),
+            ("Skipped", summary.skipped),
+        ] {
+            let _ = writeln!(html, "<tr><th>{label}</th><td>{count}</td></tr>");
+        }
+        html.push_str("</table>\n");
+
+        if !summary.failures_by_kind.is_empty() {
+            html.push_str(
+                "<h2>Failures by Error</h2>\n<table>\n<tr><th>Error</th><th>Pipelines</th></tr>\n",
+            );
+            for (kind, count) in &summary.failures_by_kind {
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{count}</td></tr>",
+                    escape_html(kind)
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        if !self.state_timings.is_empty() {
+            html.push_str(
+                "<h2>State Timings</h2>\n<table>\n\
+                 <tr><th>State</th><th>Completions</th><th>p50 (ms)</th><th>p95 (ms)</th></tr>\n",
+            );
+            for timing in &self.state_timings {
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    escape_html(&timing.state_name),
+                    timing.count,
+                    timing.p50_ms,
+                    timing.p95_ms
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        html.push_str(
+            "<h2>Pipelines</h2>\n<table>\n<tr><th>CIK</th><th>Status</th><th>Duration (ms)</th>\
+             <th>Failing State</th><th>Error</th><th>Missing Concepts</th></tr>\n",
+        );
+        for pipeline in &self.pipelines {
+            let status = escape_html(&pipeline.status);
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td class=\"{status}\">{status}</td><td>{}</td><td>{}</td>\
+                 <td>{}</td><td>{}</td></tr>",
+                escape_html(&pipeline.cik),
+                pipeline.duration_ms,
+                escape_html(pipeline.failing_state.as_deref().unwrap_or_default()),
+                escape_html(pipeline.error_chain.first().map_or("", String::as_str)),
+                escape_html(&pipeline.missing_concepts.join(LIST_SEPARATOR)),
+            );
+        }
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+
+    /// Writes the report to `directory` as [`RUN_REPORT_JSON_FILE`], [`RUN_REPORT_CSV_FILE`],
+    /// and [`RUN_REPORT_HTML_FILE`], creating the directory if needed and replacing earlier
+    /// reports.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RunReportError`] if the report cannot be serialized or a file cannot be
+    /// written.
+    pub async fn write_to(&self, directory: impl AsRef<Path>) -> Result<(), RunReportError> {
+        let directory = directory.as_ref();
+        let json = self.to_json()?;
+
+        tokio::fs::create_dir_all(directory).await?;
+        tokio::fs::write(directory.join(RUN_REPORT_JSON_FILE), json).await?;
+        tokio::fs::write(directory.join(RUN_REPORT_CSV_FILE), self.to_csv()).await?;
+        tokio::fs::write(directory.join(RUN_REPORT_HTML_FILE), self.to_html()).await?;
+        Ok(())
+    }
+}
+
+/// Returns `duration` in whole milliseconds, saturating at [`u64::MAX`].
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Quotes `field` for CSV if it contains a separator, quote, or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Escapes the characters of `text` that are significant in HTML.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::shared::batch::batch_report::PipelineFailure;
+    use crate::traits::state_machine::stream::StreamEvent;
+
+    fn generated_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5)
+            .single()
+            .expect("Hardcoded timestamp should always be valid")
+    }
+
+    fn incomplete_failure() -> PipelineFailure {
+        PipelineFailure {
+            event: StreamEvent::StateFailed,
+            state_name: "Parse Company Facts".to_string(),
+            kind: "IncompleteCompanyFacts",
+            message: "Missing fields".to_string(),
+            error_chain: vec![
+                "Missing fields".to_string(),
+                "Missing, \"fields\"".to_string(),
+            ],
+            missing_concepts: vec!["Revenue".to_string(), "Total Assets".to_string()],
+        }
+    }
+
+    fn sample_report() -> BatchReport {
+        let mut report = BatchReport::new();
+        report.record(PipelineOutcome {
+            cik: "320193".to_string(),
+            execution_id: None,
+            duration: Duration::from_millis(12),
+            status: PipelineStatus::Succeeded,
+        });
+        report.record(PipelineOutcome {
+            cik: "19617".to_string(),
+            execution_id: None,
+            duration: Duration::from_millis(7),
+            status: PipelineStatus::Failed(incomplete_failure()),
+        });
+        report
+    }
+
+    #[test]
+    fn should_sort_pipelines_by_cik_when_creating_run_report() {
+        let run_report = RunReport::new(&sample_report(), generated_at());
+
+        let expected_result = vec!["19617", "320193"];
+
+        let result = run_report
+            .pipelines
+            .iter()
+            .map(|pipeline| pipeline.cik.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_count_failures_by_kind_when_creating_run_report() {
+        let run_report = RunReport::new(&sample_report(), generated_at());
+
+        let expected_result = RunSummary {
+            total: 2,
+            succeeded: 1,
+            failed: 1,
+            cancelled: 0,
+            skipped: 0,
+            failures_by_kind: BTreeMap::from([("IncompleteCompanyFacts".to_string(), 1)]),
+        };
+
+        let result = run_report.summary;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_round_trip_when_exporting_as_json() {
+        let run_report = RunReport::new(&sample_report(), generated_at());
+        let json = run_report
+            .to_json()
+            .expect("Serializing a run report should always succeed");
+
+        let expected_result = run_report;
+
+        let result: RunReport =
+            serde_json::from_str(&json).expect("An exported run report should always parse");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_quote_fields_with_separators_when_exporting_as_csv() {
+        let run_report = RunReport::new(&sample_report(), generated_at());
+
+        let expected_result = "cik,execution_id,status,duration_ms,failing_state,error_kind,missing_concepts,error_chain\n\
+             19617,,failed,7,Parse Company Facts,IncompleteCompanyFacts,Revenue; Total Assets,\"Missing fields; Missing, \"\"fields\"\"\"\n\
+             320193,,succeeded,12,,,,\n";
+
+        let result = run_report.to_csv();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_escape_markup_when_exporting_as_html() {
+        let mut run_report = RunReport::new(&sample_report(), generated_at());
+        run_report.pipelines[0].cik = "<script>".to_string();
+
+        let expected_result = (true, false);
+
+        let result = run_report.to_html();
+        let result = (
+            result.contains("<td>&lt;script&gt;</td>"),
+            result.contains("<script>"),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_write_every_format_when_writing_to_directory() {
+        let directory = std::env::temp_dir().join(format!("sec-run-report-{}", Uuid::new_v4()));
+        let run_report = RunReport::new(&sample_report(), generated_at());
+        run_report
+            .write_to(&directory)
+            .await
+            .expect("Writing to a fresh temporary directory should always succeed");
+
+        let expected_result = run_report.to_csv();
+
+        let result = tokio::fs::read_to_string(directory.join(RUN_REPORT_CSV_FILE))
+            .await
+            .expect("The CSV export should have been written");
+
+        assert_eq!(result, expected_result);
+        let _ = tokio::fs::remove_dir_all(&directory).await;
+    }
+}
@@ -0,0 +1,96 @@
+//! # Run Report Errors
+//!
+//! Provides the [`RunReportError`] returned when a run report cannot be exported, and the
+//! [`ErrorReason`] describing why.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Error indicating that exporting a run report failed.
+///
+/// Wraps the [`ErrorReason`] distinguishing a report that could not be serialized from one that
+/// could not be written.
+#[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error("[RunReportError] Run report export failed, Caused by: {reason}")]
+pub struct RunReportError {
+    /// The reason why the export failed.
+    pub reason: ErrorReason,
+}
+
+impl RunReportError {
+    /// Creates a new [`RunReportError`].
+    #[must_use]
+    pub const fn new(reason: ErrorReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Enum representing the reason why exporting a run report failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorReason {
+    /// The report could not be serialized.
+    Serialization {
+        /// A human-readable description of the serialization failure.
+        details: String,
+    },
+    /// A report file could not be written.
+    Storage {
+        /// A human-readable description of the storage failure.
+        details: String,
+    },
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Serialization { details } => write!(
+                f,
+                "[Serialization] Run report serialization failed, Reason: '{details}'"
+            ),
+            Self::Storage { details } => {
+                write!(
+                    f,
+                    "[Storage] Run report storage failed, Reason: '{details}'"
+                )
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for RunReportError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::new(ErrorReason::Serialization {
+            details: e.to_string(),
+        })
+    }
+}
+
+impl From<std::io::Error> for RunReportError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorReason::Storage {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_map_to_storage_reason_when_converting_from_io_error() {
+        let io_error = std::io::Error::other("disk full");
+
+        let expected_result = ErrorReason::Storage {
+            details: "disk full".to_string(),
+        };
+
+        let result = RunReportError::from(io_error).reason;
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,168 @@
+//! # Checkpoint Errors
+//!
+//! Provides the [`CheckpointError`] returned when a checkpoint cannot be captured, stored, or
+//! restored, and the [`ErrorReason`] describing why.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Error indicating that a checkpoint operation failed.
+///
+/// Wraps the [`ErrorReason`] distinguishing an incompatible checkpoint from a snapshot that could
+/// not be (de)serialized or a sink that could not be read or written.
+#[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error("[CheckpointError] Checkpoint operation failed, Caused by: {reason}")]
+pub struct CheckpointError {
+    /// The reason why the checkpoint operation failed.
+    pub reason: ErrorReason,
+}
+
+impl CheckpointError {
+    /// Creates a new [`CheckpointError`].
+    #[must_use]
+    pub const fn new(reason: ErrorReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Enum representing the reason why a checkpoint operation failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorReason {
+    /// The checkpoint was written by a build using a different envelope version.
+    UnsupportedVersion {
+        /// The version recorded in the checkpoint.
+        found: u32,
+        /// The version this build reads and writes.
+        expected: u32,
+    },
+    /// The checkpoint holds a snapshot of a different state than the one being restored.
+    StateMismatch {
+        /// The name of the state being restored.
+        expected: String,
+        /// The name of the state recorded in the checkpoint.
+        found: String,
+    },
+    /// The checkpoint holds a state the resuming pipeline does not know how to restore.
+    UnknownState {
+        /// The name of the state recorded in the checkpoint.
+        state_name: String,
+    },
+    /// The snapshot or envelope could not be serialized or deserialized.
+    Serialization {
+        /// A human-readable description of the serialization failure.
+        details: String,
+    },
+    /// The snapshot references a concept that is not defined in this build.
+    UnknownConcept {
+        /// The canonical name of the unknown concept.
+        canonical_name: String,
+    },
+    /// The checkpoint sink failed to read, write, or remove a checkpoint.
+    Storage {
+        /// A human-readable description of the storage failure.
+        details: String,
+    },
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion { found, expected } => write!(
+                f,
+                "[UnsupportedVersion] Checkpoint version '{found}' is not supported, Expected: '{expected}'"
+            ),
+            Self::StateMismatch { expected, found } => write!(
+                f,
+                "[StateMismatch] Checkpoint holds state '{found}', Expected: '{expected}'"
+            ),
+            Self::UnknownState { state_name } => write!(
+                f,
+                "[UnknownState] Checkpoint holds state '{state_name}', which cannot be resumed"
+            ),
+            Self::Serialization { details } => {
+                write!(
+                    f,
+                    "[Serialization] Checkpoint (de)serialization failed, Reason: '{details}'"
+                )
+            }
+            Self::UnknownConcept { canonical_name } => write!(
+                f,
+                "[UnknownConcept] Checkpoint references unknown concept '{canonical_name}'"
+            ),
+            Self::Storage { details } => {
+                write!(
+                    f,
+                    "[Storage] Checkpoint storage failed, Reason: '{details}'"
+                )
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for CheckpointError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::new(ErrorReason::Serialization {
+            details: e.to_string(),
+        })
+    }
+}
+
+impl From<std::io::Error> for CheckpointError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorReason::Storage {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_format_display_as_expected_when_version_is_unsupported() {
+        let reason = ErrorReason::UnsupportedVersion {
+            found: 2,
+            expected: 1,
+        };
+        let checkpoint_error = CheckpointError::new(reason.clone());
+
+        let expected_result =
+            format!("[CheckpointError] Checkpoint operation failed, Caused by: {reason}");
+
+        let result = format!("{checkpoint_error}");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_map_to_serialization_reason_when_converting_from_serde_json_error() {
+        let serde_error = serde_json::from_str::<u32>("not json")
+            .expect_err("Non-JSON text should always fail to deserialize");
+
+        let expected_result = ErrorReason::Serialization {
+            details: serde_error.to_string(),
+        };
+
+        let result = CheckpointError::from(serde_error).reason;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_map_to_storage_reason_when_converting_from_io_error() {
+        let io_error = std::io::Error::other("disk full");
+
+        let expected_result = ErrorReason::Storage {
+            details: "disk full".to_string(),
+        };
+
+        let result = CheckpointError::from(io_error).reason;
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,74 @@
+//! # Checkpoint Writer
+//!
+//! Provides the [`CheckpointWriter`], which binds a [`CheckpointSink`] to the key a single
+//! pipeline run writes its checkpoints under.
+
+use std::fmt;
+use std::sync::Arc;
+
+use super::traits::CheckpointSink;
+use super::{Checkpoint, CheckpointError};
+
+/// Writes a run's checkpoints to a [`CheckpointSink`] under a fixed key.
+///
+/// Each write replaces the previous checkpoint for the key, so the sink always holds the latest
+/// completed state of the run. The key should be stable across restarts (e.g. the raw CIK), so a
+/// restarted run can find the checkpoint its predecessor left behind.
+///
+/// Cloning is cheap: the sink is shared behind an `Arc`.
+#[derive(Clone)]
+pub struct CheckpointWriter {
+    sink: Arc<dyn CheckpointSink>,
+    key: String,
+}
+
+impl CheckpointWriter {
+    /// Creates a new [`CheckpointWriter`] writing to `sink` under `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use sec::shared::checkpoint::{CheckpointWriter, FileSystemCheckpointSink};
+    ///
+    /// let sink = Arc::new(FileSystemCheckpointSink::new(std::env::temp_dir()));
+    /// let writer = CheckpointWriter::new(sink, "1067983");
+    ///
+    /// let expected_result = "1067983";
+    ///
+    /// let result = writer.key();
+    ///
+    /// assert_eq!(result, expected_result);
+    /// ```
+    pub fn new(sink: Arc<dyn CheckpointSink>, key: impl Into<String>) -> Self {
+        Self {
+            sink,
+            key: key.into(),
+        }
+    }
+
+    /// Returns the key the checkpoints are written under.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Writes `checkpoint`, replacing any checkpoint previously written under the key.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the sink fails to store the checkpoint.
+    pub async fn write(&self, checkpoint: &Checkpoint) -> Result<(), CheckpointError> {
+        self.sink.save(&self.key, checkpoint).await
+    }
+}
+
+impl fmt::Debug for CheckpointWriter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CheckpointWriter")
+            .field("sink", &self.sink)
+            .field("key", &self.key)
+            .finish()
+    }
+}
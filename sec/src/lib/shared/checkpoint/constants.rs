@@ -0,0 +1,9 @@
+//! # Checkpoint Constants
+//!
+//! Constants describing the on-disk [`Checkpoint`](super::Checkpoint) format.
+
+/// The version of the checkpoint envelope written by this build.
+///
+/// Bump this whenever the envelope or any state snapshot changes shape, so checkpoints written by
+/// an incompatible build are rejected instead of being misread.
+pub const CHECKPOINT_VERSION: u32 = 1;
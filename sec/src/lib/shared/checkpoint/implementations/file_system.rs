@@ -0,0 +1,190 @@
+//! # File System Checkpoint Sink
+//!
+//! Provides the [`FileSystemCheckpointSink`], a [`CheckpointSink`] that stores each checkpoint as
+//! a pretty-printed JSON file in a directory.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::shared::checkpoint::traits::CheckpointSink;
+use crate::shared::checkpoint::{Checkpoint, CheckpointError};
+
+/// A [`CheckpointSink`] storing one `<key>.json` file per key in a directory.
+///
+/// The directory is created on first save. Writes go to a temporary file that is then renamed over
+/// the previous checkpoint, so a crash mid-write never leaves a truncated checkpoint behind.
+///
+/// Characters other than ASCII alphanumerics, `-`, and `_` are replaced with `_` in file names,
+/// so arbitrary keys cannot escape the directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystemCheckpointSink {
+    directory: PathBuf,
+}
+
+impl FileSystemCheckpointSink {
+    /// Creates a new [`FileSystemCheckpointSink`] storing checkpoints in `directory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec::shared::checkpoint::FileSystemCheckpointSink;
+    ///
+    /// let sink = FileSystemCheckpointSink::new("checkpoints");
+    ///
+    /// let expected_result = std::path::Path::new("checkpoints");
+    ///
+    /// let result = sink.directory();
+    ///
+    /// assert_eq!(result, expected_result);
+    /// ```
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Returns the directory the checkpoints are stored in.
+    #[must_use]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the path of the checkpoint file for `key`.
+    #[must_use]
+    pub fn path_for(&self, key: &str) -> PathBuf {
+        let file_stem: String = key
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        self.directory.join(format!("{file_stem}.json"))
+    }
+}
+
+#[async_trait]
+impl CheckpointSink for FileSystemCheckpointSink {
+    async fn save(&self, key: &str, checkpoint: &Checkpoint) -> Result<(), CheckpointError> {
+        let path = self.path_for(key);
+        let temporary_path = path.with_extension("json.tmp");
+        let contents = serde_json::to_vec_pretty(checkpoint)?;
+
+        tokio::fs::create_dir_all(&self.directory).await?;
+        tokio::fs::write(&temporary_path, contents).await?;
+        tokio::fs::rename(&temporary_path, &path).await?;
+        Ok(())
+    }
+
+    async fn load(&self, key: &str) -> Result<Option<Checkpoint>, CheckpointError> {
+        match tokio::fs::read(self.path_for(key)).await {
+            Ok(contents) => Ok(Some(serde_json::from_slice(&contents)?)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn remove(&self, key: &str) -> Result<(), CheckpointError> {
+        match tokio::fs::remove_file(self.path_for(key)).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::tests::fixtures::sample_streaming_super_state::SampleStateA;
+
+    fn temporary_sink() -> FileSystemCheckpointSink {
+        FileSystemCheckpointSink::new(
+            std::env::temp_dir().join(format!("sec-checkpoints-{}", Uuid::new_v4())),
+        )
+    }
+
+    #[test]
+    fn should_replace_dots_and_path_separators_when_building_path_for_key() {
+        let sink = FileSystemCheckpointSink::new("checkpoints");
+
+        let expected_result = PathBuf::from("checkpoints").join("______etc.json");
+
+        let result = sink.path_for("../../etc");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_load_saved_checkpoint_when_key_was_saved() {
+        let sink = temporary_sink();
+        let checkpoint = Checkpoint::capture(Uuid::new_v4(), &SampleStateA::default())
+            .expect("Capturing a fixture state should always succeed");
+
+        let expected_result = Some(checkpoint.clone());
+
+        sink.save("0001067983", &checkpoint)
+            .await
+            .expect("Saving to a temporary directory should always succeed");
+        let result = sink
+            .load("0001067983")
+            .await
+            .expect("Loading a saved checkpoint should always succeed");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_none_when_loading_unknown_key() {
+        let sink = temporary_sink();
+
+        let expected_result = None;
+
+        let result = sink
+            .load("0001067983")
+            .await
+            .expect("Loading a missing key should not be an error");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_none_when_loading_removed_key() {
+        let sink = temporary_sink();
+        let checkpoint = Checkpoint::capture(Uuid::new_v4(), &SampleStateA::default())
+            .expect("Capturing a fixture state should always succeed");
+        sink.save("0001067983", &checkpoint)
+            .await
+            .expect("Saving to a temporary directory should always succeed");
+
+        let expected_result = None;
+
+        sink.remove("0001067983")
+            .await
+            .expect("Removing a saved checkpoint should always succeed");
+        let result = sink
+            .load("0001067983")
+            .await
+            .expect("Loading a removed key should not be an error");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_succeed_when_removing_unknown_key() {
+        let sink = temporary_sink();
+
+        let expected_result = true;
+
+        let result = sink.remove("0001067983").await.is_ok();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,11 @@
+//! # Checkpoint Sink Implementations
+//!
+//! Concrete sinks implementing the [`traits`](super::traits) contract.
+//!
+//! ## Modules
+//!
+//! - [`file_system`]: The [`FileSystemCheckpointSink`], storing one JSON file per key.
+
+pub mod file_system;
+
+pub use file_system::FileSystemCheckpointSink;
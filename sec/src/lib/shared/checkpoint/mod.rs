@@ -0,0 +1,291 @@
+//! # Checkpoint
+//!
+//! Provides the [`Checkpoint`] envelope used to persist a pipeline's progress after each completed
+//! state, so an interrupted run can resume without redoing finished work (e.g. without re-fetching
+//! a response that [`ExecuteSecRequest`] already received).
+//!
+//! A checkpoint wraps a state's lossless [`Snapshot`](Checkpointable::Snapshot) together with a
+//! format version, the execution ID, the state name, and a timestamp. Checkpoints are stored
+//! through a [`CheckpointSink`], so the storage backend is pluggable; a
+//! [`FileSystemCheckpointSink`] is included.
+//!
+//! ## Modules
+//!
+//! - [`checkpoint_error`]: The [`CheckpointError`] returned when a checkpoint operation fails.
+//! - [`checkpoint_writer`]: The [`CheckpointWriter`] binding a sink to the key a run writes to.
+//! - [`constants`]: The [`CHECKPOINT_VERSION`] of the envelope format.
+//! - [`traits`]: The [`CheckpointSink`] contract.
+//! - [`implementations`]: The [`FileSystemCheckpointSink`].
+//!
+//! [`ExecuteSecRequest`]: crate::implementations::states::extract::execute_sec_request::ExecuteSecRequest
+
+pub mod checkpoint_error;
+pub mod checkpoint_writer;
+pub mod constants;
+pub mod implementations;
+pub mod traits;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub use checkpoint_error::CheckpointError;
+pub use checkpoint_writer::CheckpointWriter;
+pub use constants::CHECKPOINT_VERSION;
+pub use implementations::FileSystemCheckpointSink;
+pub use traits::CheckpointSink;
+
+use crate::traits::state_machine::checkpoint::Checkpointable;
+
+use self::checkpoint_error::ErrorReason;
+
+/// A versioned snapshot of one completed (or pending) state of a pipeline run.
+///
+/// The snapshot is held in its serialized form, so checkpoints of different states share one type
+/// and can be stored and loaded without knowing which state they contain. The concrete state is
+/// only reconstructed on [`restore`](Checkpoint::restore).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    version: u32,
+    execution_id: Uuid,
+    state_name: String,
+    created_at: DateTime<Utc>,
+    snapshot: serde_json::Value,
+}
+
+impl Checkpoint {
+    /// Captures `state` into a new checkpoint for the run identified by `execution_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the state's snapshot cannot be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec::implementations::states::extract::validate_cik_format::*;
+    /// use sec::shared::checkpoint::Checkpoint;
+    ///
+    /// let input = ValidateCikFormatInput::new("1067983");
+    /// let context = ValidateCikFormatContext::new("1067983");
+    /// let state = ValidateCikFormat::new(input, context);
+    ///
+    /// let checkpoint = Checkpoint::capture(uuid::Uuid::new_v4(), &state)
+    ///     .expect("A freshly created state should always be capturable");
+    ///
+    /// let expected_result = "Validate CIK Format";
+    ///
+    /// let result = checkpoint.state_name();
+    ///
+    /// assert_eq!(result, expected_result);
+    /// ```
+    pub fn capture<S: Checkpointable>(
+        execution_id: Uuid,
+        state: &S,
+    ) -> Result<Self, CheckpointError> {
+        Ok(Self {
+            version: CHECKPOINT_VERSION,
+            execution_id,
+            state_name: state.state_name().to_string(),
+            created_at: Utc::now(),
+            snapshot: serde_json::to_value(state.snapshot())?,
+        })
+    }
+
+    /// Restores a state of type `S` from this checkpoint.
+    ///
+    /// Checks the envelope version, deserializes the snapshot, and hands it to `restore` to rebuild
+    /// the state together with any live resources. The rebuilt state must carry the state name
+    /// recorded in the checkpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the checkpoint was written with a different
+    /// [`CHECKPOINT_VERSION`], its snapshot does not deserialize into `S::Snapshot`, `restore`
+    /// fails, or the rebuilt state is not the one recorded in the checkpoint.
+    pub fn restore<S, F>(&self, restore: F) -> Result<S, CheckpointError>
+    where
+        S: Checkpointable,
+        F: FnOnce(S::Snapshot) -> Result<S, CheckpointError>,
+    {
+        if self.version != CHECKPOINT_VERSION {
+            return Err(CheckpointError::new(ErrorReason::UnsupportedVersion {
+                found: self.version,
+                expected: CHECKPOINT_VERSION,
+            }));
+        }
+
+        let snapshot = S::Snapshot::deserialize(&self.snapshot)?;
+        let state = restore(snapshot)?;
+
+        let restored_state_name = state.state_name().to_string();
+        if restored_state_name != self.state_name {
+            return Err(CheckpointError::new(ErrorReason::StateMismatch {
+                expected: restored_state_name,
+                found: self.state_name.clone(),
+            }));
+        }
+
+        Ok(state)
+    }
+
+    /// Returns the envelope version the checkpoint was written with.
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the ID of the run that wrote the checkpoint.
+    #[must_use]
+    pub const fn execution_id(&self) -> Uuid {
+        self.execution_id
+    }
+
+    /// Returns the name of the checkpointed state.
+    #[must_use]
+    pub fn state_name(&self) -> &str {
+        &self.state_name
+    }
+
+    /// Returns when the checkpoint was captured.
+    #[must_use]
+    pub const fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// Returns the serialized snapshot of the checkpointed state.
+    #[must_use]
+    pub const fn snapshot(&self) -> &serde_json::Value {
+        &self.snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use state_maschine::prelude::State as SMState;
+
+    use super::*;
+    use crate::implementations::states::transform::parse_company_facts::{
+        ParseCompanyFacts, ParseCompanyFactsContext, ParseCompanyFactsInput,
+    };
+    use crate::prelude::SMAsyncState;
+    use crate::shared::cik::Cik;
+    use crate::shared::company_facts::CompanyFactsDocument;
+    use crate::tests::fixtures::sample_streaming_super_state::{SampleStateA, SampleStateB};
+
+    #[test]
+    fn should_record_current_version_when_capturing_state() {
+        let state = SampleStateA::default();
+
+        let expected_result = CHECKPOINT_VERSION;
+
+        let result = Checkpoint::capture(Uuid::new_v4(), &state)
+            .expect("Capturing a fixture state should always succeed")
+            .version();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_restore_equal_state_when_restoring_captured_checkpoint() {
+        let mut state = SampleStateA::default();
+        state
+            .compute_output_data_async()
+            .await
+            .expect("Hardcoded fixture state should always compute output successfully");
+        let checkpoint = Checkpoint::capture(Uuid::new_v4(), &state)
+            .expect("Capturing a fixture state should always succeed");
+
+        let expected_result = state;
+
+        let result = checkpoint
+            .restore::<SampleStateA, _>(Ok)
+            .expect("A checkpoint should always restore the state it captured");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_survive_json_round_trip_when_serializing_envelope() {
+        let checkpoint = Checkpoint::capture(Uuid::new_v4(), &SampleStateA::default())
+            .expect("Capturing a fixture state should always succeed");
+
+        let expected_result = checkpoint.clone();
+
+        let result: Checkpoint = serde_json::from_str(
+            &serde_json::to_string(&checkpoint).expect("A checkpoint should always serialize"),
+        )
+        .expect("A serialized checkpoint should always deserialize");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_with_unsupported_version_when_restoring_checkpoint_from_other_version() {
+        let mut checkpoint = Checkpoint::capture(Uuid::new_v4(), &SampleStateA::default())
+            .expect("Capturing a fixture state should always succeed");
+        checkpoint.version = CHECKPOINT_VERSION + 1;
+
+        let expected_result = ErrorReason::UnsupportedVersion {
+            found: CHECKPOINT_VERSION + 1,
+            expected: CHECKPOINT_VERSION,
+        };
+
+        let result = checkpoint
+            .restore::<SampleStateA, _>(Ok)
+            .expect_err("A checkpoint from another version should never restore")
+            .reason;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_with_state_mismatch_when_restoring_checkpoint_of_other_state() {
+        let checkpoint = Checkpoint::capture(Uuid::new_v4(), &SampleStateA::default())
+            .expect("Capturing a fixture state should always succeed");
+
+        let expected_result = ErrorReason::StateMismatch {
+            expected: SampleStateB::default().state_name().to_string(),
+            found: SampleStateA::default().state_name().to_string(),
+        };
+
+        let result = checkpoint
+            .restore::<SampleStateB, _>(|_| Ok(SampleStateB::default()))
+            .expect_err("A checkpoint of another state should never restore")
+            .reason;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_with_serialization_error_when_restoring_checkpoint_with_malformed_cik() {
+        let document = CompanyFactsDocument::from_value(&serde_json::json!({
+            "cik": 1_067_983,
+            "entityName": "BERKSHIRE HATHAWAY INC",
+            "facts": {}
+        }))
+        .expect("A hardcoded company-facts body should always deserialize");
+        let state = ParseCompanyFacts::new(
+            ParseCompanyFactsInput::new(document),
+            ParseCompanyFactsContext::new(
+                Cik::new("1067983").expect("A hardcoded valid CIK should always parse"),
+            ),
+        );
+        let mut checkpoint = Checkpoint::capture(Uuid::new_v4(), &state)
+            .expect("Capturing a parse state should always succeed");
+        checkpoint.snapshot["cik"] = serde_json::json!("abc");
+
+        let expected_result = true;
+
+        let result = matches!(
+            checkpoint
+                .restore::<ParseCompanyFacts, _>(ParseCompanyFacts::from_snapshot)
+                .expect_err("A checkpoint with a malformed CIK should never restore")
+                .reason,
+            ErrorReason::Serialization { .. }
+        );
+
+        assert_eq!(result, expected_result);
+    }
+}
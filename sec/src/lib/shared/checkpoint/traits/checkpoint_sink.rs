@@ -0,0 +1,34 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::shared::checkpoint::{Checkpoint, CheckpointError};
+
+/// A checkpoint store: persists the latest [`Checkpoint`] per key.
+///
+/// Abstracts over concrete storage backends so pipelines are not bound to any one of them, and so
+/// an in-memory store can be substituted in tests. A key identifies one logical run across
+/// restarts (e.g. a CIK); saving under an existing key replaces its checkpoint.
+#[async_trait]
+pub trait CheckpointSink: Send + Sync + Debug {
+    /// Stores `checkpoint` under `key`, replacing any checkpoint already stored there.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the checkpoint cannot be serialized or stored.
+    async fn save(&self, key: &str, checkpoint: &Checkpoint) -> Result<(), CheckpointError>;
+
+    /// Loads the checkpoint stored under `key`, or `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if a stored checkpoint cannot be read or deserialized.
+    async fn load(&self, key: &str) -> Result<Option<Checkpoint>, CheckpointError>;
+
+    /// Removes the checkpoint stored under `key`; removing a missing key is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if an existing checkpoint cannot be removed.
+    async fn remove(&self, key: &str) -> Result<(), CheckpointError>;
+}
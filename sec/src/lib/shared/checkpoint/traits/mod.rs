@@ -0,0 +1,11 @@
+//! # Checkpoint Traits
+//!
+//! The storage contract abstracting where checkpoints are persisted.
+//!
+//! ## Modules
+//!
+//! - [`checkpoint_sink`]: The [`CheckpointSink`] trait abstracting a concrete storage backend.
+
+pub mod checkpoint_sink;
+
+pub use checkpoint_sink::CheckpointSink;
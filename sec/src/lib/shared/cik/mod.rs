@@ -23,14 +23,16 @@ pub use constants::CIK_LENGTH;
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 /// A validated SEC Central Index Key: exactly ten zero-padded digits.
 ///
 /// Wrapping the CIK in a newtype turns "this string is a well-formed CIK" into a type-level
 /// guarantee, so code holding a `Cik` never has to re-validate the format. The inner value is
-/// private; values are only produced through [`Cik::new`] (or the [`TryFrom`] impls).
+/// private; values are only produced through [`Cik::new`] (or the [`TryFrom`] impls), and
+/// deserializing goes through the same validation, so a checkpoint cannot restore an invalid CIK.
 pub struct Cik {
     value: String,
 }
@@ -134,6 +136,12 @@ impl TryFrom<String> for Cik {
     }
 }
 
+impl From<Cik> for String {
+    fn from(cik: Cik) -> Self {
+        cik.value
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::{assert_eq, assert_ne};
@@ -308,4 +316,24 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_serialize_as_plain_string_when_serialized_to_json() {
+        let cik = Cik::new("1067983").expect("A hardcoded valid CIK should always parse");
+
+        let expected_result = "\"0001067983\"";
+
+        let result = serde_json::to_string(&cik).expect("A CIK should always serialize");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_when_cik_contains_letters() {
+        let expected_result = true;
+
+        let result = serde_json::from_str::<Cik>("\"abc\"").is_err();
+
+        assert_eq!(result, expected_result);
+    }
 }
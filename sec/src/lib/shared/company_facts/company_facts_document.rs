@@ -0,0 +1,462 @@
+//! # Company Facts Document
+//!
+//! Provides the [`CompanyFactsDocument`], the parts of a company-facts body that
+//! [`CompanyData`] is resolved from.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Read};
+use std::sync::LazyLock;
+
+use futures_util::TryStreamExt;
+use serde::de::{self, DeserializeSeed};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tokio_util::io::{StreamReader, SyncIoBridge};
+
+use crate::error::state_machine::state::incomplete_company_facts::MissingFields;
+use crate::shared::cik::Cik;
+use crate::shared::content_type::ContentType;
+use crate::shared::financial::company_data::CompanyData;
+use crate::shared::financial::company_fact::CompanyFact;
+use crate::shared::financial::concept_definition::ConceptDefinition;
+use crate::shared::financial::concept_definition::constants::{
+    COMPANY_INFO_NAMESPACE, OPTIONAL_CONCEPTS, REQUIRED_CONCEPTS, REQUIRED_FACTS_NAMESPACE,
+    SHARES_OUTSTANDING,
+};
+use crate::shared::financial::entity_name::EntityName;
+use crate::shared::financial::observation::Observation;
+use crate::shared::financial::unit::Unit;
+use crate::shared::response::implementations::sec_response::error::{
+    ErrorReason, InvalidSecResponse,
+};
+use crate::shared::response::implementations::sec_response::validate_head;
+use crate::shared::status_code::StatusCode;
+
+use super::deserialize::{DocumentSeed, WantedConcepts};
+use super::limited_reader::LimitedReader;
+
+/// The namespace and XBRL key a concept is reported under.
+pub(super) type ConceptKey = (&'static str, &'static str);
+
+/// The kept parts of one reported concept.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub(super) struct ConceptEntry {
+    /// The company's label of the concept, if any.
+    pub label: Option<String>,
+    /// The observations per wanted unit, or `None` if the concept has no `units` object.
+    pub units: Option<BTreeMap<Unit, Vec<Observation>>>,
+}
+
+/// The namespace, key, and unit of every alias of every required and optional concept.
+static WANTED_CONCEPTS: LazyLock<WantedConcepts> = LazyLock::new(|| {
+    let mut wanted = WantedConcepts::new();
+    for concept in REQUIRED_CONCEPTS.iter().chain(OPTIONAL_CONCEPTS) {
+        for &namespace in namespaces_for_concept(concept) {
+            for &xbrl_key in concept.xbrl_keys() {
+                let units: &mut Vec<Unit> = wanted
+                    .entry(namespace)
+                    .or_default()
+                    .entry(xbrl_key)
+                    .or_default();
+                if !units.contains(&concept.expected_unit()) {
+                    units.push(concept.expected_unit());
+                }
+            }
+        }
+    }
+    wanted
+});
+
+/// Returns the namespaces to search for a concept, in priority order.
+///
+/// Most concepts live in `us-gaap`. The `SHARES_OUTSTANDING` concept is searched
+/// in `dei` first (where `EntityCommonStockSharesOutstanding` lives), then falls
+/// back to `us-gaap` (where `CommonStockSharesOutstanding` lives for some companies).
+fn namespaces_for_concept(concept: &ConceptDefinition) -> &'static [&'static str] {
+    if concept.canonical_name() == SHARES_OUTSTANDING {
+        &[COMPANY_INFO_NAMESPACE, REQUIRED_FACTS_NAMESPACE]
+    } else {
+        &[REQUIRED_FACTS_NAMESPACE]
+    }
+}
+
+/// The company, and the observations of every concept it may be resolved from, read from an SEC
+/// company-facts body.
+///
+/// Deserializing keeps only what some [`ConceptDefinition`] asks for: the namespaces, XBRL
+/// keys, and units of the required and optional concepts. Everything else is skipped while it is
+/// read, and each observation entry is parsed as soon as it is complete, so neither the raw body
+/// nor a `serde_json::Value` tree of it is ever held whole.
+///
+/// A document serializes into the kept concepts only, so it is also what checkpoints store of a
+/// company-facts response.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::company_facts::CompanyFactsDocument;
+///
+/// let body = r#"{"cik": 320193, "entityName": "Apple Inc.", "facts": {"us-gaap": {}}}"#;
+///
+/// let expected_result = "Apple Inc.";
+///
+/// let document = CompanyFactsDocument::from_reader(body.as_bytes())
+///     .expect("A well-formed company-facts body should always deserialize");
+/// let result = document.entity_name().to_string();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct CompanyFactsDocument {
+    cik: Cik,
+    entity_name: EntityName,
+    #[serde(
+        serialize_with = "serialize_concepts",
+        deserialize_with = "deserialize_concepts"
+    )]
+    concepts: BTreeMap<ConceptKey, ConceptEntry>,
+}
+
+/// Serializes the kept concepts as `[namespace, key, entry]` triples.
+fn serialize_concepts<S: Serializer>(
+    concepts: &BTreeMap<ConceptKey, ConceptEntry>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(
+        concepts
+            .iter()
+            .map(|(&(namespace, key), entry)| (namespace, key, entry)),
+    )
+}
+
+/// Deserializes `[namespace, key, entry]` triples, rejecting a concept no
+/// [`ConceptDefinition`] asks for.
+fn deserialize_concepts<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<ConceptKey, ConceptEntry>, D::Error> {
+    Vec::<(String, String, ConceptEntry)>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(namespace, key, entry)| {
+            let concept_key = WANTED_CONCEPTS
+                .get_key_value(namespace.as_str())
+                .and_then(|(&namespace, keys)| {
+                    keys.get_key_value(key.as_str())
+                        .map(|(&key, _)| (namespace, key))
+                })
+                .ok_or_else(|| {
+                    de::Error::custom(format!("unwanted concept '{namespace}:{key}'"))
+                })?;
+            Ok((concept_key, entry))
+        })
+        .collect()
+}
+
+impl CompanyFactsDocument {
+    pub(super) const fn new(
+        cik: Cik,
+        entity_name: EntityName,
+        concepts: BTreeMap<ConceptKey, ConceptEntry>,
+    ) -> Self {
+        Self {
+            cik,
+            entity_name,
+            concepts,
+        }
+    }
+
+    /// Deserializes a company-facts body from `reader` as it is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] if reading fails, the body is not JSON, lacks a `cik`,
+    /// `entityName`, or `facts`, or holds a kept value of an unexpected type.
+    pub fn from_reader(reader: impl Read) -> Result<Self, serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let document = DocumentSeed {
+            wanted: &WANTED_CONCEPTS,
+        }
+        .deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(document)
+    }
+
+    /// Deserializes a company-facts body already parsed into `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] if `value` is not an object, lacks a `cik`, `entityName`,
+    /// or `facts`, or holds a kept value of an unexpected type.
+    pub fn from_value(value: &serde_json::Value) -> Result<Self, serde_json::Error> {
+        DocumentSeed {
+            wanted: &WANTED_CONCEPTS,
+        }
+        .deserialize(value)
+    }
+
+    /// Validates `inner` like an SEC response and deserializes its body while it downloads,
+    /// rejecting a body larger than `max_body_size` bytes once decompressed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSecResponse`] if the status is not a success, the content type is not
+    /// JSON, the body cannot be read, is larger than `max_body_size`
+    /// ([`ErrorReason::BodyTooLarge`]), or is not a company-facts body.
+    pub async fn from_inner(
+        inner: reqwest::Response,
+        max_body_size: u64,
+    ) -> Result<Self, InvalidSecResponse> {
+        let status_code = StatusCode::from(inner.status());
+        let content_type = inner
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map_or(ContentType::Unknown, ContentType::from_content_type);
+        validate_head(status_code, &content_type)?;
+
+        let body = StreamReader::new(inner.bytes_stream().map_err(io::Error::other));
+        let bridge = SyncIoBridge::new(body);
+        tokio::task::spawn_blocking(move || {
+            let mut reader = LimitedReader::new(bridge, max_body_size);
+            Self::from_reader(&mut reader).map_err(|e| {
+                let reason = if reader.exceeded() {
+                    ErrorReason::BodyTooLarge {
+                        limit: max_body_size,
+                    }
+                } else if e.is_io() {
+                    ErrorReason::FailedBodyRead {
+                        details: e.to_string(),
+                    }
+                } else {
+                    ErrorReason::InvalidBody {
+                        details: e.to_string(),
+                    }
+                };
+                InvalidSecResponse::new(reason)
+            })
+        })
+        .await
+        .map_err(|e| {
+            InvalidSecResponse::new(ErrorReason::FailedBodyRead {
+                details: e.to_string(),
+            })
+        })?
+    }
+
+    /// Returns the CIK the body reports facts of.
+    #[must_use]
+    pub const fn cik(&self) -> &Cik {
+        &self.cik
+    }
+
+    /// Returns the name of the reporting entity.
+    #[must_use]
+    pub const fn entity_name(&self) -> &EntityName {
+        &self.entity_name
+    }
+
+    /// Resolves every required and optional concept into [`CompanyData`].
+    ///
+    /// Each concept is looked up by its XBRL key aliases across its namespaces, in priority
+    /// order; the first alias reported is used, even if it holds no observations in the
+    /// expected unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`MissingFields`] naming every required concept that could not be resolved.
+    pub fn to_company_data(&self) -> Result<CompanyData, MissingFields> {
+        let mut resolved_facts: HashMap<&'static ConceptDefinition, CompanyFact> = HashMap::new();
+        let mut missing_fields: Vec<String> = Vec::new();
+
+        for concept in REQUIRED_CONCEPTS {
+            if let Some(fact) = self.resolve_concept(concept) {
+                resolved_facts.insert(concept, fact);
+            } else {
+                missing_fields.push(concept.canonical_name().to_string());
+            }
+        }
+
+        if !missing_fields.is_empty() {
+            return Err(MissingFields::new(missing_fields));
+        }
+
+        for concept in OPTIONAL_CONCEPTS {
+            if let Some(fact) = self.resolve_concept(concept) {
+                resolved_facts.insert(concept, fact);
+            }
+        }
+
+        Ok(CompanyData::new(
+            self.cik.clone(),
+            self.entity_name.clone(),
+            resolved_facts,
+        ))
+    }
+
+    /// Resolves `concept` from the first of its aliases reported, or `None` if none is, or the
+    /// first one lacks observations in the expected unit.
+    fn resolve_concept(&self, concept: &'static ConceptDefinition) -> Option<CompanyFact> {
+        for &namespace in namespaces_for_concept(concept) {
+            for &xbrl_key in concept.xbrl_keys() {
+                if let Some(entry) = self.concepts.get(&(namespace, xbrl_key)) {
+                    let company_label = entry
+                        .label
+                        .clone()
+                        .unwrap_or_else(|| concept.canonical_name().to_string());
+                    let observations = entry.units.as_ref()?.get(&concept.expected_unit())?.clone();
+
+                    return Some(CompanyFact::new(
+                        company_label,
+                        xbrl_key.to_string(),
+                        observations,
+                    ));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::shared::company_facts::observation_parser::parse_observation;
+
+    fn assets_entry() -> serde_json::Value {
+        serde_json::json!({
+            "end": "2023-12-31",
+            "val": 1_069_978_000_000_i64,
+            "accn": "0000950170-24-021296",
+            "fy": 2023,
+            "fp": "FY",
+            "form": "10-K",
+            "filed": "2024-02-26",
+            "frame": "CY2023Q4I"
+        })
+    }
+
+    fn company_facts_json() -> serde_json::Value {
+        serde_json::json!({
+            "cik": 1_067_983,
+            "entityName": "BERKSHIRE HATHAWAY INC",
+            "facts": {
+                "srt": {
+                    "Assets": { "units": { "USD": [assets_entry()] } }
+                },
+                "us-gaap": {
+                    "Assets": {
+                        "label": "Assets",
+                        "description": "Sum of the carrying amounts of all assets.",
+                        "units": {
+                            "USD": [assets_entry(), { "end": "not a date" }],
+                            "shares": [assets_entry()]
+                        }
+                    },
+                    "Goodwill": { "units": { "USD": [assets_entry()] } }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn should_keep_only_wanted_concepts_and_units_when_deserialized_from_reader() {
+        let body = company_facts_json().to_string();
+        let observation = parse_observation(&assets_entry(), Unit::Usd)
+            .expect("A complete observation entry should always parse");
+
+        let expected_result = BTreeMap::from([(
+            (REQUIRED_FACTS_NAMESPACE, "Assets"),
+            ConceptEntry {
+                label: Some("Assets".to_string()),
+                units: Some(BTreeMap::from([(Unit::Usd, vec![observation])])),
+            },
+        )]);
+
+        let result = CompanyFactsDocument::from_reader(body.as_bytes())
+            .expect("A well-formed company-facts body should always deserialize")
+            .concepts;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_equal_streamed_document_when_deserialized_from_value() {
+        let json = company_facts_json();
+
+        let expected_result = CompanyFactsDocument::from_reader(json.to_string().as_bytes())
+            .expect("A well-formed company-facts body should always deserialize");
+
+        let result = CompanyFactsDocument::from_value(&json)
+            .expect("A well-formed company-facts value should always deserialize");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_restore_equal_document_when_round_tripping_through_json() {
+        let document = CompanyFactsDocument::from_value(&company_facts_json())
+            .expect("A well-formed company-facts value should always deserialize");
+        let serialized =
+            serde_json::to_string(&document).expect("A document should always serialize");
+
+        let expected_result = document;
+
+        let result: CompanyFactsDocument =
+            serde_json::from_str(&serialized).expect("A serialized document should deserialize");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_serialized_document_holds_unwanted_concept() {
+        let document = CompanyFactsDocument::from_value(&company_facts_json())
+            .expect("A well-formed company-facts value should always deserialize");
+        let mut serialized =
+            serde_json::to_value(&document).expect("A document should always serialize");
+        serialized["concepts"] = serde_json::json!([["us-gaap", "Unwanted", { "label": null }]]);
+
+        let expected_result = true;
+
+        let result = serde_json::from_value::<CompanyFactsDocument>(serialized)
+            .expect_err("A document with an unwanted concept should always fail to deserialize")
+            .to_string()
+            .contains("us-gaap:Unwanted");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_entity_name_is_missing() {
+        let body = r#"{"cik": 1067983, "facts": {}}"#;
+
+        let expected_result = true;
+
+        let result = CompanyFactsDocument::from_reader(body.as_bytes())
+            .expect_err("A body without an entity name should always fail")
+            .to_string()
+            .contains("entityName");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_name_every_missing_required_concept_when_resolving_company_data() {
+        let document = CompanyFactsDocument::from_value(&company_facts_json())
+            .expect("A well-formed company-facts value should always deserialize");
+
+        let expected_result = MissingFields::new(
+            REQUIRED_CONCEPTS
+                .iter()
+                .filter(|concept| concept.xbrl_keys() != ["Assets"])
+                .map(|concept| concept.canonical_name().to_string())
+                .collect(),
+        );
+
+        let result = document
+            .to_company_data()
+            .expect_err("A document lacking required concepts should always fail to resolve");
+
+        assert_eq!(result, expected_result);
+    }
+}
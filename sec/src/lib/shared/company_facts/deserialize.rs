@@ -0,0 +1,274 @@
+//! # Company Facts Deserialization
+//!
+//! The [`DeserializeSeed`]s walking a company-facts body in a single pass. Only the namespaces,
+//! concepts, and units some [`ConceptDefinition`] asks for are kept; everything else is skipped
+//! with [`IgnoredAny`] as it streams by, and each kept observation entry is handed to
+//! [`parse_observation`] on its own.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use serde::Deserialize;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+use crate::shared::cik::Cik;
+use crate::shared::financial::entity_name::EntityName;
+use crate::shared::financial::observation::Observation;
+use crate::shared::financial::unit::Unit;
+
+use super::company_facts_document::{CompanyFactsDocument, ConceptEntry, ConceptKey};
+use super::observation_parser::parse_observation;
+
+/// The units wanted per XBRL key, per namespace.
+pub type WantedConcepts = HashMap<&'static str, HashMap<&'static str, Vec<Unit>>>;
+
+/// The CIK of a company-facts body, which the SEC writes as a number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawCik {
+    Number(u64),
+    Text(String),
+}
+
+/// Deserializes a whole company-facts body into a [`CompanyFactsDocument`].
+pub struct DocumentSeed<'w> {
+    pub wanted: &'w WantedConcepts,
+}
+
+impl<'de> DeserializeSeed<'de> for DocumentSeed<'_> {
+    type Value = CompanyFactsDocument;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DocumentSeed<'_> {
+    type Value = CompanyFactsDocument;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a company-facts object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut cik = None;
+        let mut entity_name = None;
+        let mut concepts = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "cik" => {
+                    let raw = match map.next_value::<RawCik>()? {
+                        RawCik::Number(n) => n.to_string(),
+                        RawCik::Text(s) => s,
+                    };
+                    cik = Some(Cik::new(&raw).map_err(de::Error::custom)?);
+                }
+                "entityName" => entity_name = Some(EntityName::new(&map.next_value::<String>()?)),
+                "facts" => {
+                    concepts = Some(map.next_value_seed(FactsSeed {
+                        wanted: self.wanted,
+                    })?);
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(CompanyFactsDocument::new(
+            cik.ok_or_else(|| de::Error::missing_field("cik"))?,
+            entity_name.ok_or_else(|| de::Error::missing_field("entityName"))?,
+            concepts.ok_or_else(|| de::Error::missing_field("facts"))?,
+        ))
+    }
+}
+
+/// Deserializes the `facts` object, keyed by namespace.
+struct FactsSeed<'w> {
+    wanted: &'w WantedConcepts,
+}
+
+impl<'de> DeserializeSeed<'de> for FactsSeed<'_> {
+    type Value = BTreeMap<ConceptKey, ConceptEntry>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FactsSeed<'_> {
+    type Value = BTreeMap<ConceptKey, ConceptEntry>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an object of namespaces")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut concepts = BTreeMap::new();
+        while let Some(namespace) = map.next_key::<String>()? {
+            match self.wanted.get_key_value(namespace.as_str()) {
+                Some((&namespace, keys)) => {
+                    map.next_value_seed(NamespaceSeed {
+                        namespace,
+                        keys,
+                        concepts: &mut concepts,
+                    })?;
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(concepts)
+    }
+}
+
+/// Deserializes the concepts of one namespace into `concepts`, keeping the wanted `keys`.
+struct NamespaceSeed<'w> {
+    namespace: &'static str,
+    keys: &'w HashMap<&'static str, Vec<Unit>>,
+    concepts: &'w mut BTreeMap<ConceptKey, ConceptEntry>,
+}
+
+impl<'de> DeserializeSeed<'de> for NamespaceSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for NamespaceSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an object of concepts")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            match self.keys.get_key_value(key.as_str()) {
+                Some((&key, units)) => {
+                    let entry = map.next_value_seed(ConceptSeed { units })?;
+                    self.concepts.insert((self.namespace, key), entry);
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes one concept, keeping its label and the observations in the wanted `units`.
+struct ConceptSeed<'w> {
+    units: &'w [Unit],
+}
+
+impl<'de> DeserializeSeed<'de> for ConceptSeed<'_> {
+    type Value = ConceptEntry;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ConceptSeed<'_> {
+    type Value = ConceptEntry;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a concept object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entry = ConceptEntry::default();
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "label" => {
+                    // A label that is not a string falls back to the canonical name, as if absent.
+                    entry.label = map
+                        .next_value::<serde_json::Value>()?
+                        .as_str()
+                        .map(str::to_string);
+                }
+                "units" => {
+                    entry.units = Some(map.next_value_seed(UnitsSeed { units: self.units })?);
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(entry)
+    }
+}
+
+/// Deserializes the `units` object of a concept, keeping the wanted `units`.
+struct UnitsSeed<'w> {
+    units: &'w [Unit],
+}
+
+impl<'de> DeserializeSeed<'de> for UnitsSeed<'_> {
+    type Value = BTreeMap<Unit, Vec<Observation>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for UnitsSeed<'_> {
+    type Value = BTreeMap<Unit, Vec<Observation>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an object of units")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut observations = BTreeMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            match self.units.iter().find(|unit| unit.to_string() == key) {
+                Some(&unit) => {
+                    observations.insert(unit, map.next_value_seed(ObservationsSeed { unit })?);
+                }
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(observations)
+    }
+}
+
+/// Deserializes a unit array, parsing every entry into an [`Observation`] as soon as it is read.
+struct ObservationsSeed {
+    unit: Unit,
+}
+
+impl<'de> DeserializeSeed<'de> for ObservationsSeed {
+    type Value = Vec<Observation>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ObservationsSeed {
+    type Value = Vec<Observation>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of observations")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut observations = Vec::new();
+        // Only one entry — a handful of scalars — is held as a `Value` at a time.
+        while let Some(entry) = seq.next_element::<serde_json::Value>()? {
+            if let Some(observation) = parse_observation(&entry, self.unit) {
+                observations.push(observation);
+            }
+        }
+        Ok(observations)
+    }
+}
@@ -0,0 +1,95 @@
+//! # Limited Reader
+//!
+//! Provides the [`LimitedReader`], a [`Read`] adapter failing once more than a set number of bytes
+//! has been read.
+
+use std::io::{self, Read};
+
+/// A reader passing through at most `limit` bytes of its inner reader.
+///
+/// Unlike [`Read::take`], which silently ends the input at the limit, reading past the limit fails
+/// and marks the reader as [`exceeded`](Self::exceeded), so an oversized body is told apart from a
+/// truncated one.
+#[derive(Debug)]
+pub struct LimitedReader<R> {
+    inner: R,
+    limit: u64,
+    read: u64,
+    exceeded: bool,
+}
+
+impl<R> LimitedReader<R> {
+    /// Creates a [`LimitedReader`] passing through at most `limit` bytes of `inner`.
+    #[must_use]
+    pub const fn new(inner: R, limit: u64) -> Self {
+        Self {
+            inner,
+            limit,
+            read: 0,
+            exceeded: false,
+        }
+    }
+
+    /// Returns `true` once the inner reader has yielded more than the limit.
+    #[must_use]
+    pub const fn exceeded(&self) -> bool {
+        self.exceeded
+    }
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.exceeded {
+            return Err(too_large());
+        }
+        let read = self.inner.read(buf)?;
+        self.read = self
+            .read
+            .saturating_add(u64::try_from(read).unwrap_or(u64::MAX));
+        if self.read > self.limit {
+            self.exceeded = true;
+            return Err(too_large());
+        }
+        Ok(read)
+    }
+}
+
+fn too_large() -> io::Error {
+    io::Error::other("input exceeds the size limit")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_pass_input_through_when_within_limit() {
+        let mut reader = LimitedReader::new(b"12345".as_slice(), 5);
+        let mut output = String::new();
+
+        let expected_result = ("12345".to_string(), false);
+
+        reader
+            .read_to_string(&mut output)
+            .expect("Input within the limit should always be read");
+        let result = (output, reader.exceeded());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_and_mark_exceeded_when_input_passes_limit() {
+        let mut reader = LimitedReader::new(b"123456".as_slice(), 5);
+
+        let expected_result = (true, true);
+
+        let result = (
+            reader.read_to_end(&mut Vec::new()).is_err(),
+            reader.exceeded(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,25 @@
+//! # Company Facts
+//!
+//! Provides the [`CompanyFactsDocument`] an SEC company-facts body is deserialized into in a
+//! single streaming pass. Only the concepts and units some [`ConceptDefinition`] asks for are
+//! kept, and each observation entry is handed to the observation parser as soon as it is read,
+//! so the multi-megabyte body is never held as a whole `serde_json::Value`.
+//!
+//! ## Modules
+//!
+//! - [`company_facts_document`]: The [`CompanyFactsDocument`] and its resolution into
+//!   [`CompanyData`].
+//! - [`limited_reader`]: The [`LimitedReader`] refusing to read past a maximum body size.
+//! - [`observation_parser`]: Parses a single observation entry into an [`Observation`].
+//!
+//! [`ConceptDefinition`]: crate::shared::financial::concept_definition::ConceptDefinition
+//! [`CompanyData`]: crate::shared::financial::company_data::CompanyData
+//! [`Observation`]: crate::shared::financial::observation::Observation
+
+pub mod company_facts_document;
+mod deserialize;
+pub mod limited_reader;
+pub mod observation_parser;
+
+pub use company_facts_document::CompanyFactsDocument;
+pub use limited_reader::LimitedReader;
@@ -0,0 +1,102 @@
+//! # Observation Parser
+//!
+//! Parses one entry of a company-facts unit array into a typed [`Observation`].
+
+use chrono::NaiveDate;
+
+use crate::shared::financial::accession_number::AccessionNumber;
+use crate::shared::financial::filing_source::FilingSource;
+use crate::shared::financial::fiscal_period::FiscalPeriod;
+use crate::shared::financial::fiscal_year::FiscalYear;
+use crate::shared::financial::form::Form;
+use crate::shared::financial::frame::Frame;
+use crate::shared::financial::observation::Observation;
+use crate::shared::financial::period::Period;
+use crate::shared::financial::unit::Unit;
+
+/// Attempts to parse a date string in `YYYY-MM-DD` format into a [`NaiveDate`].
+fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+}
+
+/// Builds the [`Period`] for an observation entry from the SEC JSON.
+///
+/// SEC data uses `"end"` for all entries and optionally `"start"` for duration measurements.
+/// If `"start"` is present, it is a duration; otherwise, it is an instant.
+fn build_period(entry: &serde_json::Value) -> Option<Period> {
+    let end = parse_date(entry.get("end")?.as_str()?)?;
+    if let Some(start_val) = entry.get("start") {
+        let start = parse_date(start_val.as_str()?)?;
+        Some(Period::Duration { start, end })
+    } else {
+        Some(Period::Instant { date: end })
+    }
+}
+
+/// Builds a [`FilingSource`] from an SEC observation JSON entry.
+fn build_filing_source(entry: &serde_json::Value) -> Option<FilingSource> {
+    let accn = AccessionNumber::new(entry.get("accn")?.as_str()?);
+    let form = Form::from_sec_str(entry.get("form")?.as_str()?)?;
+    let fy = FiscalYear::try_from(entry.get("fy")?.as_u64()?).ok()?;
+    let fp = FiscalPeriod::from_sec_str(entry.get("fp")?.as_str()?)?;
+    let filed = parse_date(entry.get("filed")?.as_str()?)?;
+    let end = parse_date(entry.get("end")?.as_str()?)?;
+
+    Some(FilingSource::new(accn, form, fy, fp, filed, end))
+}
+
+/// Parses a single observation entry from the SEC JSON unit array, measured in `unit`.
+///
+/// Returns `None` for an entry lacking a field or holding one that does not parse, e.g. a
+/// non-integer value or an unknown form, so one malformed entry never fails a whole concept.
+#[must_use]
+pub fn parse_observation(entry: &serde_json::Value, unit: Unit) -> Option<Observation> {
+    let value = entry.get("val")?.as_i64()?;
+    let period = build_period(entry)?;
+    let frame = entry
+        .get("frame")
+        .and_then(serde_json::Value::as_str)
+        .and_then(Frame::parse);
+    let filing = build_filing_source(entry)?;
+
+    Some(Observation::new(value, unit, period, frame, filing))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_parse_duration_when_entry_has_start() {
+        let entry = serde_json::json!({
+            "start": "2024-01-01", "end": "2024-12-31", "val": 100,
+            "accn": "0000320193-25-000008", "fy": 2024, "fp": "FY", "form": "10-K",
+            "filed": "2025-02-01"
+        });
+
+        let expected_result = Some(Period::Duration {
+            start: NaiveDate::from_ymd_opt(2024, 1, 1).expect("Hardcoded date should be valid"),
+            end: NaiveDate::from_ymd_opt(2024, 12, 31).expect("Hardcoded date should be valid"),
+        });
+
+        let result = parse_observation(&entry, Unit::Usd).map(|o| *o.period());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_skip_entry_when_value_is_not_an_integer() {
+        let entry = serde_json::json!({
+            "end": "2024-12-31", "val": 1.5, "accn": "0000320193-25-000008", "fy": 2024,
+            "fp": "FY", "form": "10-K", "filed": "2025-02-01"
+        });
+
+        let expected_result = None;
+
+        let result = parse_observation(&entry, Unit::Usd);
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,108 @@
+//! # Configuration Errors
+//!
+//! Provides the [`ConfigError`] returned when the configuration cannot be read or fails
+//! validation, and the [`ErrorReason`] describing why.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Error indicating that the configuration could not be loaded.
+///
+/// Wraps the [`ErrorReason`] distinguishing an unreadable file, a file that is not valid TOML,
+/// an unknown setting, and a setting with an invalid value.
+#[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error("[ConfigError] Loading the configuration failed, Caused by: {reason}")]
+pub struct ConfigError {
+    /// The reason why loading the configuration failed.
+    pub reason: ErrorReason,
+}
+
+impl ConfigError {
+    /// Creates a new [`ConfigError`].
+    #[must_use]
+    pub const fn new(reason: ErrorReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Enum representing the reason why loading the configuration failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorReason {
+    /// The configuration file could not be read.
+    Storage {
+        /// A human-readable description of the storage failure.
+        details: String,
+    },
+    /// The configuration file is not valid TOML.
+    MalformedDocument {
+        /// A human-readable description of what is wrong with the document.
+        details: String,
+    },
+    /// The configuration file names a setting that does not exist.
+    UnknownKey {
+        /// The dotted path of the unknown setting, e.g. `sec.user_agnet`.
+        key: String,
+    },
+    /// A setting has a value of the wrong type or one that fails validation.
+    InvalidValue {
+        /// The setting, as a dotted path in the file or an environment variable name.
+        key: String,
+        /// A human-readable description of what is wrong with the value.
+        details: String,
+    },
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Storage { details } => write!(
+                f,
+                "[Storage] Configuration file could not be read, Reason: '{details}'"
+            ),
+            Self::MalformedDocument { details } => write!(
+                f,
+                "[MalformedDocument] Configuration file is not valid TOML, Reason: '{details}'"
+            ),
+            Self::UnknownKey { key } => {
+                write!(f, "[UnknownKey] Setting '{key}' does not exist")
+            }
+            Self::InvalidValue { key, details } => write!(
+                f,
+                "[InvalidValue] Setting '{key}' is invalid, Reason: '{details}'"
+            ),
+        }
+    }
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorReason::Storage {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_format_display_as_expected_when_value_is_invalid() {
+        let reason = ErrorReason::InvalidValue {
+            key: "sec.user_agent".to_string(),
+            details: "missing email".to_string(),
+        };
+        let config_error = ConfigError::new(reason.clone());
+
+        let expected_result =
+            format!("[ConfigError] Loading the configuration failed, Caused by: {reason}");
+
+        let result = format!("{config_error}");
+
+        assert_eq!(result, expected_result);
+    }
+}
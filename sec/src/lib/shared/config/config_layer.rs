@@ -0,0 +1,327 @@
+//! # Configuration Layer
+//!
+//! Provides the [`ConfigLayer`], one source of configuration settings — a TOML file, the
+//! environment, or command-line overrides — where every setting is optional.
+
+use std::time::Duration;
+
+use toml_edit::{DocumentMut, Item};
+
+use super::config_error::{ConfigError, ErrorReason};
+use super::constants::{
+    COMPANY_FACTS_URL_PREFIX_ENV_VAR, MAX_BODY_BYTES_ENV_VAR, MAX_IN_FLIGHT_ENV_VAR,
+    MAX_STATE_RETRIES_ENV_VAR, MIN_REQUEST_INTERVAL_ENV_VAR, USER_AGENT_ENV_VAR,
+};
+
+/// The settings one configuration source provides; a `None` setting leaves the one of a lower
+/// layer in place.
+///
+/// Values are only checked for their type here; [`ArkadConfig::resolve`](super::ArkadConfig::resolve)
+/// validates them once all layers are merged.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::config::ConfigLayer;
+///
+/// let file = ConfigLayer::from_toml("[pipeline]\nmax_state_retries = 2\nmax_in_flight = 8\n")
+///     .expect("A well-formed configuration file should always parse");
+/// let cli = ConfigLayer::new().with_max_in_flight(4);
+///
+/// let expected_result = (Some(2), Some(4));
+///
+/// let merged = file.merge(cli);
+/// let result = (merged.max_state_retries, merged.max_in_flight);
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigLayer {
+    /// The SEC user agent, `sec.user_agent` in a file.
+    pub user_agent: Option<String>,
+    /// The minimum spacing between SEC requests, `sec.min_request_interval_ms` in a file.
+    pub min_request_interval: Option<Duration>,
+    /// The company-facts URL prefix, `sec.company_facts_url_prefix` in a file.
+    pub company_facts_url_prefix: Option<String>,
+    /// The largest SEC response body accepted, in bytes, `sec.max_body_bytes` in a file.
+    pub max_body_size: Option<u64>,
+    /// The number of times the pipeline recomputes a failed state, `pipeline.max_state_retries` in
+    /// a file.
+    pub max_state_retries: Option<u32>,
+    /// The number of pipelines in flight at once, `pipeline.max_in_flight` in a file.
+    pub max_in_flight: Option<usize>,
+}
+
+impl ConfigLayer {
+    /// Creates a [`ConfigLayer`] providing no settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the SEC user agent.
+    #[must_use]
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the minimum spacing between SEC requests.
+    #[must_use]
+    pub const fn with_min_request_interval(mut self, min_request_interval: Duration) -> Self {
+        self.min_request_interval = Some(min_request_interval);
+        self
+    }
+
+    /// Sets the company-facts URL prefix.
+    #[must_use]
+    pub fn with_company_facts_url_prefix(mut self, url_prefix: impl Into<String>) -> Self {
+        self.company_facts_url_prefix = Some(url_prefix.into());
+        self
+    }
+
+    /// Sets the largest SEC response body accepted, in bytes.
+    #[must_use]
+    pub const fn with_max_body_size(mut self, max_body_size: u64) -> Self {
+        self.max_body_size = Some(max_body_size);
+        self
+    }
+
+    /// Sets the number of times the pipeline recomputes a failed state.
+    #[must_use]
+    pub const fn with_max_state_retries(mut self, max_state_retries: u32) -> Self {
+        self.max_state_retries = Some(max_state_retries);
+        self
+    }
+
+    /// Sets the number of pipelines in flight at once.
+    #[must_use]
+    pub const fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        self.max_in_flight = Some(max_in_flight);
+        self
+    }
+
+    /// Returns this layer with every setting `higher` provides replaced by that of `higher`.
+    #[must_use]
+    pub fn merge(self, higher: Self) -> Self {
+        Self {
+            user_agent: higher.user_agent.or(self.user_agent),
+            min_request_interval: higher.min_request_interval.or(self.min_request_interval),
+            company_facts_url_prefix: higher
+                .company_facts_url_prefix
+                .or(self.company_facts_url_prefix),
+            max_body_size: higher.max_body_size.or(self.max_body_size),
+            max_state_retries: higher.max_state_retries.or(self.max_state_retries),
+            max_in_flight: higher.max_in_flight.or(self.max_in_flight),
+        }
+    }
+
+    /// Parses the TOML configuration file `contents`.
+    ///
+    /// Settings live in a `[sec]` table (`user_agent`, `min_request_interval_ms`,
+    /// `company_facts_url_prefix`, `max_body_bytes`) and a `[pipeline]` table
+    /// (`max_state_retries`, `max_in_flight`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if `contents` is not valid TOML
+    /// ([`ErrorReason::MalformedDocument`]), names a setting that does not exist
+    /// ([`ErrorReason::UnknownKey`]), or gives a setting a value of the wrong type
+    /// ([`ErrorReason::InvalidValue`]).
+    pub fn from_toml(contents: &str) -> Result<Self, ConfigError> {
+        let document = contents.parse::<DocumentMut>().map_err(|e| {
+            ConfigError::new(ErrorReason::MalformedDocument {
+                details: e.to_string(),
+            })
+        })?;
+
+        let mut layer = Self::new();
+        for (section, item) in document.iter() {
+            let table = item.as_table_like().ok_or_else(|| unknown_key(section))?;
+            for (key, value) in table.iter() {
+                let key = format!("{section}.{key}");
+                match key.as_str() {
+                    "sec.user_agent" => layer.user_agent = Some(toml_string(&key, value)?),
+                    "sec.min_request_interval_ms" => {
+                        layer.min_request_interval =
+                            Some(Duration::from_millis(toml_integer(&key, value)?));
+                    }
+                    "sec.company_facts_url_prefix" => {
+                        layer.company_facts_url_prefix = Some(toml_string(&key, value)?);
+                    }
+                    "sec.max_body_bytes" => {
+                        layer.max_body_size = Some(toml_integer(&key, value)?);
+                    }
+                    "pipeline.max_state_retries" => {
+                        layer.max_state_retries = Some(toml_integer(&key, value)?);
+                    }
+                    "pipeline.max_in_flight" => {
+                        layer.max_in_flight = Some(toml_integer(&key, value)?);
+                    }
+                    _ => return Err(unknown_key(&key)),
+                }
+            }
+        }
+        Ok(layer)
+    }
+
+    /// Reads the settings from the environment variables `vars`, e.g. [`std::env::vars`].
+    ///
+    /// Recognizes `ARKAD_USER_AGENT`, `ARKAD_MIN_REQUEST_INTERVAL_MS`,
+    /// `ARKAD_COMPANY_FACTS_URL_PREFIX`, `ARKAD_MAX_BODY_BYTES`, `ARKAD_MAX_STATE_RETRIES`, and
+    /// `ARKAD_MAX_IN_FLIGHT`, ignoring
+    /// every other variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] ([`ErrorReason::InvalidValue`]) if a numeric setting is not a
+    /// non-negative integer.
+    pub fn from_env(vars: impl IntoIterator<Item = (String, String)>) -> Result<Self, ConfigError> {
+        let mut layer = Self::new();
+        for (name, value) in vars {
+            match name.as_str() {
+                USER_AGENT_ENV_VAR => layer.user_agent = Some(value),
+                MIN_REQUEST_INTERVAL_ENV_VAR => {
+                    layer.min_request_interval =
+                        Some(Duration::from_millis(env_integer(&name, &value)?));
+                }
+                COMPANY_FACTS_URL_PREFIX_ENV_VAR => layer.company_facts_url_prefix = Some(value),
+                MAX_BODY_BYTES_ENV_VAR => layer.max_body_size = Some(env_integer(&name, &value)?),
+                MAX_STATE_RETRIES_ENV_VAR => {
+                    layer.max_state_retries = Some(env_integer(&name, &value)?);
+                }
+                MAX_IN_FLIGHT_ENV_VAR => layer.max_in_flight = Some(env_integer(&name, &value)?),
+                _ => {}
+            }
+        }
+        Ok(layer)
+    }
+}
+
+fn toml_string(key: &str, value: &Item) -> Result<String, ConfigError> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| invalid_value(key, "expected a string"))
+}
+
+fn toml_integer<T: TryFrom<i64>>(key: &str, value: &Item) -> Result<T, ConfigError> {
+    value
+        .as_integer()
+        .and_then(|integer| T::try_from(integer).ok())
+        .ok_or_else(|| invalid_value(key, "expected a non-negative integer in range"))
+}
+
+fn env_integer<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, ConfigError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| invalid_value(name, "expected a non-negative integer in range"))
+}
+
+fn unknown_key(key: &str) -> ConfigError {
+    ConfigError::new(ErrorReason::UnknownKey {
+        key: key.to_string(),
+    })
+}
+
+fn invalid_value(key: &str, details: &str) -> ConfigError {
+    ConfigError::new(ErrorReason::InvalidValue {
+        key: key.to_string(),
+        details: details.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_read_every_setting_when_parsing_complete_file() {
+        let contents = r#"
+            [sec]
+            user_agent = "Sample Company contact@example.com"
+            min_request_interval_ms = 250
+            company_facts_url_prefix = "http://localhost:8080/companyfacts/CIK"
+            max_body_bytes = 1048576
+
+            [pipeline]
+            max_state_retries = 3
+            max_in_flight = 4
+        "#;
+
+        let expected_result = ConfigLayer::new()
+            .with_user_agent("Sample Company contact@example.com")
+            .with_min_request_interval(Duration::from_millis(250))
+            .with_company_facts_url_prefix("http://localhost:8080/companyfacts/CIK")
+            .with_max_body_size(1_048_576)
+            .with_max_state_retries(3)
+            .with_max_in_flight(4);
+
+        let result = ConfigLayer::from_toml(contents)
+            .expect("A well-formed configuration file should always parse");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_file_names_unknown_setting() {
+        let expected_result = Err(ErrorReason::UnknownKey {
+            key: "sec.user_agnet".to_string(),
+        });
+
+        let result = ConfigLayer::from_toml("[sec]\nuser_agnet = \"typo\"\n").map_err(|e| e.reason);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_setting_in_file_is_negative() {
+        let expected_result = Err(ErrorReason::InvalidValue {
+            key: "pipeline.max_state_retries".to_string(),
+            details: "expected a non-negative integer in range".to_string(),
+        });
+
+        let result =
+            ConfigLayer::from_toml("[pipeline]\nmax_state_retries = -1\n").map_err(|e| e.reason);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_ignore_unrelated_variables_when_reading_environment() {
+        let vars = [
+            ("PATH", "/usr/bin"),
+            (MAX_STATE_RETRIES_ENV_VAR, "2"),
+            (USER_AGENT_ENV_VAR, "Sample Company contact@example.com"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+
+        let expected_result = ConfigLayer::new()
+            .with_max_state_retries(2)
+            .with_user_agent("Sample Company contact@example.com");
+
+        let result = ConfigLayer::from_env(vars)
+            .expect("Well-formed environment variables should always parse");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_prefer_higher_layer_when_merging() {
+        let lower = ConfigLayer::new()
+            .with_max_state_retries(1)
+            .with_max_in_flight(8);
+        let higher = ConfigLayer::new().with_max_state_retries(5);
+
+        let expected_result = ConfigLayer::new()
+            .with_max_state_retries(5)
+            .with_max_in_flight(8);
+
+        let result = lower.merge(higher);
+
+        assert_eq!(result, expected_result);
+    }
+}
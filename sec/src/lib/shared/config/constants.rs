@@ -0,0 +1,33 @@
+//! # Configuration Constants
+//!
+//! Defaults of the settings without a home of their own, and the environment variables the
+//! configuration is read from.
+
+/// Number of times the pipeline recomputes a failed state unless configured otherwise.
+///
+/// These state retries come on top of the SEC client's own retries of each request
+/// ([`DEFAULT_REQUEST_RETRIES`]), which this setting does not change.
+///
+/// [`DEFAULT_REQUEST_RETRIES`]: crate::shared::http_client::implementations::sec_client::constants::DEFAULT_REQUEST_RETRIES
+pub const DEFAULT_MAX_STATE_RETRIES: u32 = 0;
+
+/// Environment variable naming the TOML file to read the configuration from.
+pub const CONFIG_FILE_ENV_VAR: &str = "ARKAD_CONFIG";
+
+/// Environment variable overriding the SEC user agent.
+pub const USER_AGENT_ENV_VAR: &str = "ARKAD_USER_AGENT";
+
+/// Environment variable overriding the minimum spacing between SEC requests, in milliseconds.
+pub const MIN_REQUEST_INTERVAL_ENV_VAR: &str = "ARKAD_MIN_REQUEST_INTERVAL_MS";
+
+/// Environment variable overriding the company-facts URL prefix.
+pub const COMPANY_FACTS_URL_PREFIX_ENV_VAR: &str = "ARKAD_COMPANY_FACTS_URL_PREFIX";
+
+/// Environment variable overriding the largest SEC response body accepted, in bytes.
+pub const MAX_BODY_BYTES_ENV_VAR: &str = "ARKAD_MAX_BODY_BYTES";
+
+/// Environment variable overriding the number of times the pipeline recomputes a failed state.
+pub const MAX_STATE_RETRIES_ENV_VAR: &str = "ARKAD_MAX_STATE_RETRIES";
+
+/// Environment variable overriding the number of pipelines in flight at once.
+pub const MAX_IN_FLIGHT_ENV_VAR: &str = "ARKAD_MAX_IN_FLIGHT";
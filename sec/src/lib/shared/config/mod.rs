@@ -0,0 +1,348 @@
+//! # Configuration
+//!
+//! Provides the [`ArkadConfig`], the validated settings of a run: the SEC user agent, the
+//! minimum spacing between SEC requests, the company-facts URL prefix, the largest response body
+//! accepted, the number of retries of a failed SEC request, and the number of pipelines in flight
+//! at once.
+//!
+//! The configuration is assembled from [`ConfigLayer`]s, each providing some of the settings,
+//! and validated once all of them are merged, so a misconfigured run fails at startup rather
+//! than at its first request. [`ArkadConfig::load`] merges, from lowest to highest precedence:
+//!
+//! 1. The defaults, i.e. the constants the settings replace.
+//! 2. The TOML file given explicitly or named by the `ARKAD_CONFIG` environment variable.
+//! 3. The `ARKAD_*` environment variables.
+//! 4. The overrides of the caller, e.g. command-line options.
+//!
+//! The validated configuration is injected into the [`SecClient`](crate::shared::http_client::implementations::sec_client::SecClient)
+//! through [`SecClient::from_config`](crate::shared::http_client::implementations::sec_client::SecClient::from_config)
+//! and into the super-states' contexts through
+//! [`PipelineEnvironment::with_config`](crate::shared::environment::PipelineEnvironment::with_config).
+//!
+//! ## Modules
+//!
+//! - [`config_error`]: The [`ConfigError`] returned when the configuration cannot be loaded.
+//! - [`config_layer`]: The [`ConfigLayer`] one configuration source provides.
+//! - [`constants`]: The default number of retries and the environment variable names.
+
+pub mod config_error;
+pub mod config_layer;
+pub mod constants;
+
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::time::Duration;
+
+use reqwest::Url;
+
+pub use config_error::ConfigError;
+pub use config_layer::ConfigLayer;
+
+use self::config_error::ErrorReason;
+use self::constants::{CONFIG_FILE_ENV_VAR, DEFAULT_MAX_STATE_RETRIES};
+use crate::shared::batch::DEFAULT_MAX_IN_FLIGHT;
+use crate::shared::rate_limiter::implementations::sec_rate_limiter::constants::MIN_REQUEST_INTERVAL;
+use crate::shared::request::implementations::sec_request::constants::SEC_COMPANY_FACTS_URL_PREFIX;
+use crate::shared::response::implementations::sec_response::constants::DEFAULT_MAX_BODY_SIZE;
+use crate::shared::user_agent::UserAgent;
+use crate::shared::user_agent::constants::DEFAULT_SEC_USER_AGENT;
+
+/// The validated settings of a run.
+///
+/// Every field has passed validation, so holding an [`ArkadConfig`] guarantees a compliant user
+/// agent, a non-zero request interval, a usable URL prefix, a non-zero body size limit, and a
+/// non-zero pipeline budget.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::config::{ArkadConfig, ConfigLayer};
+///
+/// let file = ConfigLayer::from_toml("[pipeline]\nmax_state_retries = 2\n")
+///     .expect("A well-formed configuration file should always parse");
+/// let cli = ConfigLayer::new().with_user_agent("Sample Company contact@example.com");
+/// let config = ArkadConfig::resolve([file, cli]).expect("Valid settings should always resolve");
+///
+/// let expected_result = 2;
+///
+/// let result = config.max_state_retries();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArkadConfig {
+    user_agent: UserAgent,
+    min_request_interval: Duration,
+    company_facts_url_prefix: Url,
+    max_body_size: u64,
+    max_state_retries: u32,
+    max_in_flight: NonZeroUsize,
+}
+
+impl ArkadConfig {
+    /// Merges `layers`, each overriding the ones before it, on top of the defaults and validates
+    /// the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] ([`ErrorReason::InvalidValue`]) if the user agent is not
+    /// SEC-compliant, the request interval, body size limit, or pipeline budget is zero, or the URL
+    /// prefix is not
+    /// an absolute `http` or `https` URL.
+    pub fn resolve(layers: impl IntoIterator<Item = ConfigLayer>) -> Result<Self, ConfigError> {
+        let layer = layers
+            .into_iter()
+            .fold(ConfigLayer::new(), ConfigLayer::merge);
+        let mut config = Self::default();
+
+        if let Some(user_agent) = layer.user_agent {
+            config.user_agent = UserAgent::new(&user_agent)
+                .map_err(|e| invalid_value("sec.user_agent", e.to_string()))?;
+        }
+        if let Some(min_request_interval) = layer.min_request_interval {
+            if min_request_interval.is_zero() {
+                return Err(invalid_value(
+                    "sec.min_request_interval_ms",
+                    "expected a positive number of milliseconds",
+                ));
+            }
+            config.min_request_interval = min_request_interval;
+        }
+        if let Some(url_prefix) = layer.company_facts_url_prefix {
+            config.company_facts_url_prefix = parse_url_prefix(&url_prefix)?;
+        }
+        if let Some(max_body_size) = layer.max_body_size {
+            if max_body_size == 0 {
+                return Err(invalid_value(
+                    "sec.max_body_bytes",
+                    "expected a positive number of bytes",
+                ));
+            }
+            config.max_body_size = max_body_size;
+        }
+        if let Some(max_state_retries) = layer.max_state_retries {
+            config.max_state_retries = max_state_retries;
+        }
+        if let Some(max_in_flight) = layer.max_in_flight {
+            config.max_in_flight = NonZeroUsize::new(max_in_flight).ok_or_else(|| {
+                invalid_value("pipeline.max_in_flight", "expected a positive number")
+            })?;
+        }
+        Ok(config)
+    }
+
+    /// Loads the configuration of a run from `config_file`, or the file named by `ARKAD_CONFIG`
+    /// if none is given, the `ARKAD_*` environment variables, and `overrides`, in increasing
+    /// precedence.
+    ///
+    /// Without a file, only the environment and `overrides` are applied on top of the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if the file cannot be read or parsed, an environment variable
+    /// holds a malformed value, or the merged settings fail validation.
+    pub async fn load(
+        config_file: Option<&Path>,
+        overrides: ConfigLayer,
+    ) -> Result<Self, ConfigError> {
+        let config_file = config_file
+            .map(Path::to_path_buf)
+            .or_else(|| std::env::var_os(CONFIG_FILE_ENV_VAR).map(Into::into));
+        let file = match config_file {
+            Some(path) => ConfigLayer::from_toml(&tokio::fs::read_to_string(path).await?)?,
+            None => ConfigLayer::new(),
+        };
+        let environment = ConfigLayer::from_env(std::env::vars())?;
+        Self::resolve([file, environment, overrides])
+    }
+
+    /// Returns the user agent the SEC client identifies itself with.
+    #[must_use]
+    pub const fn user_agent(&self) -> &UserAgent {
+        &self.user_agent
+    }
+
+    /// Returns the minimum spacing between SEC requests.
+    #[must_use]
+    pub const fn min_request_interval(&self) -> Duration {
+        self.min_request_interval
+    }
+
+    /// Returns the prefix the zero-padded CIK is appended to when fetching company facts.
+    #[must_use]
+    pub const fn company_facts_url_prefix(&self) -> &Url {
+        &self.company_facts_url_prefix
+    }
+
+    /// Returns the largest SEC response body accepted, in bytes.
+    #[must_use]
+    pub const fn max_body_size(&self) -> u64 {
+        self.max_body_size
+    }
+
+    /// Returns the number of times the pipeline recomputes a failed state.
+    ///
+    /// Each recomputation of [`ExecuteSecRequest`] sends its request again, with the SEC client's
+    /// own retries; those are not affected by this setting.
+    ///
+    /// [`ExecuteSecRequest`]: crate::implementations::states::extract::execute_sec_request::ExecuteSecRequest
+    #[must_use]
+    pub const fn max_state_retries(&self) -> u32 {
+        self.max_state_retries
+    }
+
+    /// Returns the number of pipelines in flight at once.
+    #[must_use]
+    pub const fn max_in_flight(&self) -> NonZeroUsize {
+        self.max_in_flight
+    }
+}
+
+/// Creates the configuration every setting of which is its default.
+impl Default for ArkadConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: UserAgent::new(DEFAULT_SEC_USER_AGENT)
+                .expect("The default SEC user agent constant should always be valid"),
+            min_request_interval: MIN_REQUEST_INTERVAL,
+            company_facts_url_prefix: Url::parse(SEC_COMPANY_FACTS_URL_PREFIX)
+                .expect("Hardcoded URL should always be valid"),
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            max_state_retries: DEFAULT_MAX_STATE_RETRIES,
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+        }
+    }
+}
+
+/// Parses `url_prefix`, which must be an absolute `http` or `https` URL.
+fn parse_url_prefix(url_prefix: &str) -> Result<Url, ConfigError> {
+    const KEY: &str = "sec.company_facts_url_prefix";
+    let url = Url::parse(url_prefix).map_err(|e| invalid_value(KEY, e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid_value(KEY, "expected an http or https URL"));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(invalid_value(
+            KEY,
+            "expected no query or fragment, since the CIK is appended",
+        ));
+    }
+    Ok(url)
+}
+
+fn invalid_value(key: &str, details: impl Into<String>) -> ConfigError {
+    ConfigError::new(ErrorReason::InvalidValue {
+        key: key.to_string(),
+        details: details.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_use_defaults_when_no_layer_sets_anything() {
+        let expected_result = ArkadConfig::default();
+
+        let result = ArkadConfig::resolve([ConfigLayer::new(), ConfigLayer::new()])
+            .expect("The defaults should always resolve");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_prefer_later_layers_when_resolving() {
+        let file = ConfigLayer::new()
+            .with_max_state_retries(1)
+            .with_max_in_flight(8);
+        let environment = ConfigLayer::new().with_max_state_retries(2);
+        let cli = ConfigLayer::new().with_max_in_flight(4);
+
+        let expected_result = (2, NonZeroUsize::new(4));
+
+        let config = ArkadConfig::resolve([file, environment, cli])
+            .expect("Valid settings should always resolve");
+        let result = (config.max_state_retries(), Some(config.max_in_flight()));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_user_agent_is_not_sec_compliant() {
+        let layer = ConfigLayer::new().with_user_agent("nobody");
+
+        let expected_result = true;
+
+        let result = matches!(
+            ArkadConfig::resolve([layer]).map_err(|e| e.reason),
+            Err(ErrorReason::InvalidValue { key, .. }) if key == "sec.user_agent"
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_min_request_interval_is_zero() {
+        let layer = ConfigLayer::new().with_min_request_interval(Duration::ZERO);
+
+        let expected_result = Err(ErrorReason::InvalidValue {
+            key: "sec.min_request_interval_ms".to_string(),
+            details: "expected a positive number of milliseconds".to_string(),
+        });
+
+        let result = ArkadConfig::resolve([layer]).map_err(|e| e.reason);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_max_body_size_is_zero() {
+        let layer = ConfigLayer::new().with_max_body_size(0);
+
+        let expected_result = Err(ErrorReason::InvalidValue {
+            key: "sec.max_body_bytes".to_string(),
+            details: "expected a positive number of bytes".to_string(),
+        });
+
+        let result = ArkadConfig::resolve([layer]).map_err(|e| e.reason);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_url_prefix_is_not_http() {
+        let layer = ConfigLayer::new().with_company_facts_url_prefix("ftp://example.com/CIK");
+
+        let expected_result = Err(ErrorReason::InvalidValue {
+            key: "sec.company_facts_url_prefix".to_string(),
+            details: "expected an http or https URL".to_string(),
+        });
+
+        let result = ArkadConfig::resolve([layer]).map_err(|e| e.reason);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_read_file_when_loading_with_explicit_path() {
+        let path = std::env::temp_dir().join(format!("arkad-config-{}.toml", uuid::Uuid::new_v4()));
+        tokio::fs::write(&path, "[sec]\nmin_request_interval_ms = 500\n")
+            .await
+            .expect("Writing to the temp directory should always succeed");
+
+        let expected_result = Duration::from_millis(500);
+
+        let result = ArkadConfig::load(Some(&path), ConfigLayer::new()).await;
+        tokio::fs::remove_file(&path)
+            .await
+            .expect("Removing the written file should always succeed");
+        let result = result
+            .expect("A valid configuration file should always load")
+            .min_request_interval();
+
+        assert_eq!(result, expected_result);
+    }
+}
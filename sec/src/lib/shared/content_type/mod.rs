@@ -5,14 +5,14 @@
 use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// The MIME content type of an SEC API response.
 ///
 /// Models the content types relevant to SEC interactions as explicit variants so callers can
 /// match on them directly instead of comparing header strings. Anything recognized-but-unmodeled
 /// is captured by `Other`, and a missing or unreadable header by `Unknown`.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ContentType {
     /// `application/json`.
     Json,
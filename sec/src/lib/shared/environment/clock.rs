@@ -0,0 +1,68 @@
+//! # Clock
+//!
+//! Provides the [`Clock`] a [`PipelineEnvironment`](super::PipelineEnvironment) tells the time
+//! with, the [`SystemClock`] used in production, and the [`FixedClock`] that pins the time for
+//! deterministic tests.
+
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time.
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The [`Clock`] reading the system time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A [`Clock`] that always returns the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedClock {
+    now: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Creates a new [`FixedClock`] pinned to `now`.
+    #[must_use]
+    pub const fn new(now: DateTime<Utc>) -> Self {
+        Self { now }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_return_pinned_time_when_reading_fixed_clock() {
+        let now = Utc
+            .with_ymd_and_hms(2024, 3, 1, 12, 0, 0)
+            .single()
+            .expect("A hardcoded timestamp should always be valid");
+        let clock = FixedClock::new(now);
+
+        let expected_result = now;
+
+        let result = clock.now();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,307 @@
+//! # Pipeline Environment
+//!
+//! Provides the [`PipelineEnvironment`], the run-wide resources every state of one pipeline run
+//! shares: the [`SecClient`], the [`ArkadConfig`], the execution ID, the [`Clock`], and the
+//! repository the run persists through.
+//!
+//! The environment is owned by the super-states' contexts and handed to every transition through
+//! [`TryFromState`](crate::traits::state_machine::transition::TryFromState), so a state that needs
+//! one of its resources takes it from the environment instead of having it copied field by field
+//! through every state before it. Cloning is cheap: the resources are shared behind `Arc`s.
+//!
+//! The repository is a type parameter rather than a field that may be missing: an environment
+//! built without one holds [`NoRepository`], and [`repository`](PipelineEnvironment::repository)
+//! only exists on environments whose repository implements [`Repository`]. A state whose
+//! construction reads the repository therefore cannot be built from an environment lacking one —
+//! the mistake is a compile error, not a runtime `None`:
+//!
+//! ```compile_fail
+//! use sec::shared::environment::PipelineEnvironment;
+//!
+//! let environment = PipelineEnvironment::default();
+//! let _repository = environment.repository();
+//! ```
+//!
+//! ## Modules
+//!
+//! - [`clock`]: The [`Clock`] trait and its system and fixed implementations.
+
+pub mod clock;
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use storage::Repository;
+use uuid::Uuid;
+
+pub use clock::{Clock, FixedClock, SystemClock};
+
+use crate::shared::config::ArkadConfig;
+use crate::shared::http_client::implementations::sec_client::SecClient;
+
+/// The repository slot of a [`PipelineEnvironment`] built without a repository.
+///
+/// Does not implement [`Repository`], so no state requiring one can be built from such an
+/// environment.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoRepository;
+
+/// The types that can fill the repository slot of a [`PipelineEnvironment`] carried through a
+/// pipeline's states: [`NoRepository`] and every `'static` [`Repository`] implementing [`Debug`].
+///
+/// Implemented automatically; it only names the bounds the super-states place on their
+/// environment's repository.
+pub trait RepositorySlot: Debug + Send + Sync + 'static {}
+
+impl<R: Debug + Send + Sync + 'static> RepositorySlot for R {}
+
+/// The resources shared by every state of one pipeline run.
+///
+/// # Type Parameters
+///
+/// - `R`: The repository the run persists through, or [`NoRepository`] if it persists nothing.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::environment::PipelineEnvironment;
+/// use sec::shared::http_client::implementations::sec_client::SecClient;
+/// use uuid::Uuid;
+///
+/// let execution_id = Uuid::new_v4();
+/// let environment = PipelineEnvironment::new(SecClient::default()).with_execution_id(execution_id);
+///
+/// let expected_result = execution_id;
+///
+/// let result = environment.clone().execution_id();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug)]
+pub struct PipelineEnvironment<R = NoRepository> {
+    sec_client: SecClient,
+    config: Arc<ArkadConfig>,
+    execution_id: Uuid,
+    clock: Arc<dyn Clock>,
+    repository: Arc<R>,
+}
+
+impl PipelineEnvironment {
+    /// Creates a new [`PipelineEnvironment`] around `sec_client`, with the default
+    /// [`ArkadConfig`], a fresh execution ID, the [`SystemClock`], and no repository.
+    #[must_use]
+    pub fn new(sec_client: SecClient) -> Self {
+        Self {
+            sec_client,
+            config: Arc::new(ArkadConfig::default()),
+            execution_id: Uuid::new_v4(),
+            clock: Arc::new(SystemClock),
+            repository: Arc::new(NoRepository),
+        }
+    }
+}
+
+impl<R> PipelineEnvironment<R> {
+    /// Replaces the execution ID, e.g. to resume a run under its original ID.
+    #[must_use]
+    pub fn with_execution_id(self, execution_id: Uuid) -> Self {
+        Self {
+            execution_id,
+            ..self
+        }
+    }
+
+    /// Replaces the configuration the states of the run read their settings from.
+    ///
+    /// The client is not rebuilt; create it with
+    /// [`SecClient::from_config`] from the same configuration.
+    #[must_use]
+    pub fn with_config(self, config: ArkadConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            ..self
+        }
+    }
+
+    /// Replaces the clock, e.g. with a [`FixedClock`] for deterministic tests.
+    #[must_use]
+    pub fn with_clock(self, clock: impl Clock + 'static) -> Self {
+        Self {
+            clock: Arc::new(clock),
+            ..self
+        }
+    }
+
+    /// Returns an environment sharing the same resources that persists through `repository`.
+    #[must_use]
+    pub fn with_repository<Repo: Repository>(self, repository: Repo) -> PipelineEnvironment<Repo> {
+        PipelineEnvironment {
+            sec_client: self.sec_client,
+            config: self.config,
+            execution_id: self.execution_id,
+            clock: self.clock,
+            repository: Arc::new(repository),
+        }
+    }
+
+    /// Returns the shared HTTP client for SEC API requests.
+    #[must_use]
+    pub const fn sec_client(&self) -> &SecClient {
+        &self.sec_client
+    }
+
+    /// Returns the configuration of the run.
+    #[must_use]
+    pub fn config(&self) -> &ArkadConfig {
+        &self.config
+    }
+
+    /// Returns the ID identifying the run.
+    #[must_use]
+    pub const fn execution_id(&self) -> Uuid {
+        self.execution_id
+    }
+
+    /// Returns the current time according to the environment's clock.
+    #[must_use]
+    pub fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+}
+
+impl<R: Repository> PipelineEnvironment<R> {
+    /// Returns the repository the run persists through.
+    #[must_use]
+    pub fn repository(&self) -> &R {
+        &self.repository
+    }
+}
+
+impl Default for PipelineEnvironment {
+    /// Creates an environment around the default [`SecClient`].
+    fn default() -> Self {
+        Self::new(SecClient::default())
+    }
+}
+
+impl<R> Clone for PipelineEnvironment<R> {
+    fn clone(&self) -> Self {
+        Self {
+            sec_client: self.sec_client.clone(),
+            config: Arc::clone(&self.config),
+            execution_id: self.execution_id,
+            clock: Arc::clone(&self.clock),
+            repository: Arc::clone(&self.repository),
+        }
+    }
+}
+
+// Deviation: the client, clock, and repository expose no comparable or hashable state, and the
+// configuration is shared by every run, so an environment is identified by its execution ID
+// alone. This satisfies the `Eq + Ord + Hash + Serialize` bounds of the contexts holding it.
+
+impl<R> PartialEq for PipelineEnvironment<R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.execution_id == other.execution_id
+    }
+}
+
+impl<R> Eq for PipelineEnvironment<R> {}
+
+impl<R> Hash for PipelineEnvironment<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.execution_id.hash(state);
+    }
+}
+
+impl<R> PartialOrd for PipelineEnvironment<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R> Ord for PipelineEnvironment<R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.execution_id.cmp(&other.execution_id)
+    }
+}
+
+impl<R> Serialize for PipelineEnvironment<R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PipelineEnvironment", 1)?;
+        state.serialize_field("execution_id", &self.execution_id)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::tests::fixtures::sample_repository::SampleRepository;
+
+    #[test]
+    fn should_read_time_from_configured_clock_when_asked_for_now() {
+        let now = Utc
+            .with_ymd_and_hms(2024, 3, 1, 12, 0, 0)
+            .single()
+            .expect("A hardcoded timestamp should always be valid");
+        let environment = PipelineEnvironment::default().with_clock(FixedClock::new(now));
+
+        let expected_result = now;
+
+        let result = environment.now();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_execution_id_when_adding_repository() {
+        let environment = PipelineEnvironment::default();
+
+        let expected_result = environment.execution_id();
+
+        let result = environment
+            .with_repository(SampleRepository::default())
+            .execution_id();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_share_repository_between_clones_when_persisting_through_either() {
+        let environment =
+            PipelineEnvironment::default().with_repository(SampleRepository::default());
+        let clone = environment.clone();
+        clone
+            .repository()
+            .persist("0001067983".to_string())
+            .await
+            .expect("The sample repository should always persist");
+
+        let expected_result = vec!["0001067983".to_string()];
+
+        let result = environment.repository().persisted();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serialize_only_execution_id_when_serializing_environment() {
+        let environment = PipelineEnvironment::default();
+
+        let expected_result = serde_json::json!({ "execution_id": environment.execution_id() });
+
+        let result =
+            serde_json::to_value(&environment).expect("An environment should always serialize");
+
+        assert_eq!(result, expected_result);
+    }
+}
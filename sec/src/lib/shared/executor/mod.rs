@@ -0,0 +1,138 @@
+//! # Tokio Executor
+//!
+//! Provides the [`TokioExecutor`], the [`Executor`] every SEC state computes its output on when
+//! driven from synchronous code.
+//!
+//! SEC states do I/O through `reqwest` and pace it with timers, so their futures need a tokio
+//! runtime. They all run on one process-wide runtime, built on first use and never dropped: a
+//! shared client's pooled connections are driven by tasks of the runtime that opened them, so a
+//! runtime per call would leave the pool holding connections whose runtime is gone.
+//!
+//! Blocking on a runtime from a thread that already runs one panics, as does `block_in_place` on
+//! a `current_thread` runtime. Outside a runtime the executor blocks on the shared one directly;
+//! inside a multi-threaded runtime it does so within `block_in_place`; and inside a
+//! `current_thread` runtime it blocks a helper thread instead.
+
+use std::sync::LazyLock;
+
+use state_maschine::prelude::Executor;
+use tokio::runtime::{Builder, Handle, Runtime, RuntimeFlavor};
+
+/// The runtime every [`TokioExecutor`] drives its futures on.
+static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| {
+    Builder::new_multi_thread()
+        .thread_name("sec-executor")
+        .enable_all()
+        .build()
+        .expect("The operating system should always provide the resources for a tokio runtime")
+});
+
+/// An [`Executor`] that drives futures on a shared, process-wide tokio runtime.
+///
+/// Works from plain synchronous code and from within any tokio runtime, including a
+/// `current_thread` one. Called from within a runtime, it blocks one of that runtime's threads
+/// for the duration of the computation, like any other blocking call.
+///
+/// # Panics
+///
+/// [`block_on`](Executor::block_on) panics if the operating system refuses the resources for the
+/// shared runtime or a helper thread, and propagates a panic of the future itself.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::executor::TokioExecutor;
+/// use state_maschine::prelude::Executor;
+///
+/// let expected_result = 42;
+///
+/// let result = TokioExecutor.block_on(async {
+///     tokio::task::yield_now().await;
+///     42
+/// });
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TokioExecutor;
+
+impl Executor for TokioExecutor {
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        let Ok(handle) = Handle::try_current() else {
+            return RUNTIME.block_on(future);
+        };
+
+        if handle.runtime_flavor() != RuntimeFlavor::CurrentThread {
+            return tokio::task::block_in_place(|| RUNTIME.block_on(future));
+        }
+
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| RUNTIME.block_on(future))
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    async fn sleep_then_answer() -> u32 {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        42
+    }
+
+    #[test]
+    fn should_complete_timer_future_when_called_outside_runtime() {
+        let expected_result = 42;
+
+        let result = TokioExecutor.block_on(sleep_then_answer());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_complete_timer_future_when_called_inside_current_thread_runtime() {
+        let expected_result = 42;
+
+        let result = TokioExecutor.block_on(sleep_then_answer());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_tasks_spawned_by_earlier_call_running_when_called_again() {
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        TokioExecutor.block_on(async {
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                let _ = sender.send(42);
+            });
+        });
+
+        let expected_result = Ok(42);
+
+        let result = TokioExecutor.block_on(receiver);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_complete_timer_future_when_called_inside_multi_thread_runtime() {
+        let expected_result = 42;
+
+        let result = TokioExecutor.block_on(sleep_then_answer());
+
+        assert_eq!(result, expected_result);
+    }
+}
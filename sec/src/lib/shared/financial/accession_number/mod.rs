@@ -4,13 +4,13 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// An SEC filing accession number — the unique ID of one EDGAR submission.
 ///
 /// Stored verbatim in its typical `{CIK}-{YY}-{sequence}` shape (e.g. `"0000320193-23-000106"`);
 /// the newtype keeps it distinct from other identifier strings.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct AccessionNumber(String);
 
 impl AccessionNumber {
@@ -1,6 +1,10 @@
 //! # Company Data
 //!
 //! Provides the [`CompanyData`] struct: a company's complete set of resolved financial facts.
+//!
+//! ## Modules
+//!
+//! - [`snapshot`]: The [`CompanyDataSnapshot`] keeping every fact for checkpoints.
 
 use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
@@ -13,6 +17,10 @@ use crate::shared::financial::company_fact::CompanyFact;
 use crate::shared::financial::concept_definition::ConceptDefinition;
 use crate::shared::financial::entity_name::EntityName;
 
+pub mod snapshot;
+
+pub use snapshot::CompanyDataSnapshot;
+
 /// A company's complete set of resolved financial facts, keyed by concept.
 ///
 /// The output of the
@@ -0,0 +1,138 @@
+//! # Company Data Snapshot
+//!
+//! Provides the [`CompanyDataSnapshot`], a lossless serializable form of [`CompanyData`] used in
+//! checkpoints.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::checkpoint::CheckpointError;
+use crate::shared::checkpoint::checkpoint_error::ErrorReason;
+use crate::shared::cik::Cik;
+use crate::shared::financial::company_fact::CompanyFact;
+use crate::shared::financial::concept_definition::constants::{
+    OPTIONAL_CONCEPTS, REQUIRED_CONCEPTS,
+};
+use crate::shared::financial::entity_name::EntityName;
+
+use super::CompanyData;
+
+/// A lossless, serializable representation of [`CompanyData`].
+///
+/// [`CompanyData`]'s own [`Serialize`] impl only emits a fact count to keep stream logs small;
+/// this snapshot keeps every fact, keyed by its concept's canonical name. On restore, each name is
+/// resolved back to the predefined
+/// [`ConceptDefinition`](crate::shared::financial::concept_definition::ConceptDefinition).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompanyDataSnapshot {
+    /// The company's CIK.
+    pub cik: Cik,
+    /// The company's entity name.
+    pub entity_name: EntityName,
+    /// The resolved facts, keyed by concept canonical name.
+    pub facts: BTreeMap<String, CompanyFact>,
+}
+
+impl From<&CompanyData> for CompanyDataSnapshot {
+    fn from(company_data: &CompanyData) -> Self {
+        Self {
+            cik: company_data.cik.clone(),
+            entity_name: company_data.entity_name.clone(),
+            facts: company_data
+                .facts
+                .iter()
+                .map(|(concept, fact)| (concept.canonical_name().to_string(), fact.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<CompanyDataSnapshot> for CompanyData {
+    type Error = CheckpointError;
+
+    /// Restores the company data, resolving each canonical name to its predefined concept.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if a canonical name matches none of the predefined concepts.
+    fn try_from(snapshot: CompanyDataSnapshot) -> Result<Self, Self::Error> {
+        let facts = snapshot
+            .facts
+            .into_iter()
+            .map(|(canonical_name, fact)| {
+                REQUIRED_CONCEPTS
+                    .iter()
+                    .chain(OPTIONAL_CONCEPTS)
+                    .find(|concept| concept.canonical_name() == canonical_name)
+                    .map(|concept| (concept, fact))
+                    .ok_or_else(|| {
+                        CheckpointError::new(ErrorReason::UnknownConcept { canonical_name })
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::new(snapshot.cik, snapshot.entity_name, facts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::shared::financial::concept_definition::constants::REVENUE;
+
+    fn sample_company_data() -> CompanyData {
+        let revenue = REQUIRED_CONCEPTS
+            .iter()
+            .find(|concept| concept.canonical_name() == REVENUE)
+            .expect("Revenue should always be a required concept");
+        let fact = CompanyFact::new("Revenues".to_string(), "Revenues".to_string(), vec![]);
+        CompanyData::new(
+            Cik::new("1067983").expect("A hardcoded valid CIK should always parse"),
+            EntityName::new("BERKSHIRE HATHAWAY INC"),
+            HashMap::from([(revenue, fact)]),
+        )
+    }
+
+    #[test]
+    fn should_keep_every_fact_when_round_tripping_through_json() {
+        let company_data = sample_company_data();
+        let serialized = serde_json::to_string(&CompanyDataSnapshot::from(&company_data))
+            .expect("A company data snapshot should always serialize");
+
+        let expected_result = company_data.facts().clone();
+
+        let result = CompanyData::try_from(
+            serde_json::from_str::<CompanyDataSnapshot>(&serialized)
+                .expect("A serialized company data snapshot should always deserialize"),
+        )
+        .expect("A snapshot of predefined concepts should always restore")
+        .facts()
+        .clone();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_with_unknown_concept_when_canonical_name_is_not_predefined() {
+        let mut snapshot = CompanyDataSnapshot::from(&sample_company_data());
+        snapshot.facts.insert(
+            "Not A Concept".to_string(),
+            CompanyFact::new(String::new(), String::new(), vec![]),
+        );
+
+        let expected_result = ErrorReason::UnknownConcept {
+            canonical_name: "Not A Concept".to_string(),
+        };
+
+        let result = CompanyData::try_from(snapshot)
+            .expect_err("An unknown concept should never restore")
+            .reason;
+
+        assert_eq!(result, expected_result);
+    }
+}
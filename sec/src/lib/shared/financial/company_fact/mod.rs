@@ -4,7 +4,7 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::shared::financial::observation::Observation;
 
@@ -15,7 +15,7 @@ use crate::shared::financial::observation::Observation;
 /// company's SEC data. Keeps the company's own label and the matched XBRL key alongside the
 /// observation time series, so data can be queried by canonical concept while preserving the
 /// company's original terminology.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyFact {
     company_label: String,
     matched_xbrl_key: String,
@@ -4,13 +4,13 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// An SEC entity (company) name.
 ///
 /// Wraps the `entityName` field from a Company Facts response, stored verbatim without
 /// normalization, so a company name can't be mixed up with other strings in the domain.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct EntityName(String);
 
 impl EntityName {
@@ -4,7 +4,7 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use chrono::NaiveDate;
 
@@ -17,7 +17,7 @@ use crate::shared::financial::form::Form;
 ///
 /// Carried by every [`Observation`](crate::shared::financial::observation::Observation) so each
 /// value in a final statement can be traced back to its originating submission.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct FilingSource {
     accession_number: AccessionNumber,
     form: Form,
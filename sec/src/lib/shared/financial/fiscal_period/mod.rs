@@ -4,14 +4,14 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A company's fiscal reporting period — a full year or one of its fiscal quarters.
 ///
 /// Relative to the company's fiscal year, which need not align with the calendar (e.g. Apple's
 /// fiscal year ends in September, so its fiscal Q4 falls in calendar Q3).
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum FiscalPeriod {
     /// Full fiscal year.
     Fy,
@@ -4,12 +4,12 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A fiscal year identifier (e.g. 2023, 2024).
 ///
 /// Wraps the `fy` field from SEC XBRL data so a year can't be confused with other numeric fields.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct FiscalYear(u16);
 
 impl FiscalYear {
@@ -4,7 +4,7 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::shared::financial::quarter::Quarter;
 
@@ -13,7 +13,7 @@ use crate::shared::financial::quarter::Quarter;
 /// SEC frame strings like `"CY2023"` (annual duration), `"CY2023Q1"` (quarterly duration), or
 /// `"CY2023Q3I"` (quarterly instant) pack a calendar year, an optional quarter, and an
 /// instant/duration flag into one token; this struct holds those three parts in typed form.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Frame {
     year: u16,
     quarter: Option<Quarter>,
@@ -4,7 +4,7 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::shared::financial::filing_source::FilingSource;
 use crate::shared::financial::frame::Frame;
@@ -16,7 +16,7 @@ use crate::shared::financial::unit::Unit;
 /// One value in a concept's time series — e.g. "Revenue was $383B USD over Oct 2022–Sep 2023,
 /// reported in 10-K FY2023" — bundling the value with its [`Unit`], [`Period`], optional
 /// [`Frame`], and originating [`FilingSource`].
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Observation {
     value: i64,
     unit: Unit,
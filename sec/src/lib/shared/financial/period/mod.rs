@@ -4,7 +4,7 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use chrono::NaiveDate;
 
@@ -17,7 +17,7 @@ use chrono::NaiveDate;
 /// - `Instant`: a snapshot on one date (Balance Sheet items, e.g. total assets on Sep 30, 2023).
 /// - `Duration`: a measurement spanning a range (Income Statement / Cash Flow items).
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Period {
     /// A point-in-time snapshot (e.g., Balance Sheet items).
     Instant {
@@ -5,14 +5,14 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A calendar quarter (Q1–Q4), independent of any company's fiscal year.
 ///
 /// Distinct from fiscal quarters: a company's fiscal Q4 may fall in calendar Q3 (e.g. Apple's
 /// fiscal year ends in September).
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Quarter {
     /// First quarter (January - March).
     Q1,
@@ -13,3 +13,9 @@ pub const DATE_HEADER: &str = "date";
 
 /// The `Accept-Encoding` header name, lowercased.
 pub const ACCEPT_ENCODING_HEADER: &str = "accept-encoding";
+
+/// The `Last-Modified` header name, lowercased.
+///
+/// Not promoted to a typed field; [`Headers::last_modified`](super::Headers::last_modified) reads
+/// it from the overflow map.
+pub const LAST_MODIFIED_HEADER: &str = "last-modified";
@@ -13,11 +13,13 @@
 
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::shared::content_type::ContentType;
 
-use self::constants::{ACCEPT_ENCODING_HEADER, CONTENT_TYPE_HEADER, DATE_HEADER, ETAG_HEADER};
+use self::constants::{
+    ACCEPT_ENCODING_HEADER, CONTENT_TYPE_HEADER, DATE_HEADER, ETAG_HEADER, LAST_MODIFIED_HEADER,
+};
 
 pub mod constants;
 pub mod headers_error;
@@ -28,7 +30,7 @@ pub use headers_error::{HeadersError, InvalidHeadersReason};
 ///
 /// Recognized headers are promoted to typed fields with a [`ContentType`] parsed from
 /// `content-type`; any others are kept in an overflow map reachable via [`Headers::get`].
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Headers {
     /// The content type parsed from the `Content-Type` header.
     content_type: ContentType,
@@ -112,6 +114,12 @@ impl Headers {
         self.date.as_deref()
     }
 
+    /// Returns the `Last-Modified` header value, if present.
+    #[must_use]
+    pub fn last_modified(&self) -> Option<&str> {
+        self.get(LAST_MODIFIED_HEADER)
+    }
+
     /// Returns the `Accept-Encoding` header value, if present.
     #[must_use]
     pub fn accept_encoding(&self) -> Option<&str> {
@@ -218,6 +226,22 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_extract_last_modified_when_header_name_is_mixed_case() {
+        let mut raw = HashMap::new();
+        raw.insert(
+            "Last-Modified".to_string(),
+            "Wed, 31 Dec 2025 18:00:00 GMT".to_string(),
+        );
+        let headers = Headers::new(raw);
+
+        let expected_result = Some("Wed, 31 Dec 2025 18:00:00 GMT");
+
+        let result = headers.last_modified();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_extract_accept_encoding_when_header_is_present() {
         let mut raw = HashMap::new();
@@ -0,0 +1,7 @@
+use std::time::Duration;
+
+/// Retries of a request failing with a transient error, on top of the first attempt.
+pub const DEFAULT_REQUEST_RETRIES: u32 = 3;
+
+/// Upper bound on the time spent retrying one request, measured from its first attempt.
+pub const DEFAULT_MAX_RETRY_ELAPSED: Duration = Duration::from_mins(1);
@@ -2,30 +2,119 @@
 //!
 //! Provides the [`FailedSecRequest`] returned by
 //! [`SecClient::execute_sec_request`](crate::shared::http_client::SecClient::execute_sec_request),
-//! and its [`ErrorReason`].
+//! its [`ErrorReason`], and the [`RequestAttempt`]s that failed before it.
 
 use std::fmt;
+use std::time::Duration;
 
 use thiserror::Error;
 
 use crate::shared::response::implementations::sec_response::error::InvalidSecResponse;
+use crate::shared::response_fixtures::ResponseFixtureError;
 
 /// Error indicating that an SEC request could not be completed successfully.
 ///
 /// Wraps the [`ErrorReason`] distinguishing a transport-level failure from a response that arrived
-/// but failed SEC validation.
+/// but failed SEC validation. A request retried before failing for good also carries the
+/// [`history`](Self::history) of its earlier attempts, and one the client gave up on after spending
+/// its retry budget is marked [`retries_exhausted`](Self::retries_exhausted).
 #[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[error("[FailedSecRequest] SEC request failed, Caused by: {reason}")]
+#[error("[FailedSecRequest] SEC request failed{}, Caused by: {reason}", attempts_note(.history))]
 pub struct FailedSecRequest {
-    /// The reason why the SEC request failed.
+    /// The reason why the last attempt of the SEC request failed.
     pub reason: ErrorReason,
+    /// The attempts that failed before the last one, oldest first.
+    pub history: Vec<RequestAttempt>,
+    /// Whether the client already retried the request as often as its retry budget allows.
+    pub retries_exhausted: bool,
 }
 
 impl FailedSecRequest {
-    /// Creates a new [`FailedSecRequest`] error.
+    /// Creates a new [`FailedSecRequest`] error, failing on its first attempt.
     #[must_use]
     pub const fn new(reason: ErrorReason) -> Self {
-        Self { reason }
+        Self {
+            reason,
+            history: Vec::new(),
+            retries_exhausted: false,
+        }
+    }
+
+    /// Returns the same error, preceded by the failed attempts of `history`.
+    #[must_use]
+    pub fn with_history(self, history: Vec<RequestAttempt>) -> Self {
+        Self { history, ..self }
+    }
+
+    /// Returns the same error, marked as given up on after the client's retry budget was spent.
+    #[must_use]
+    pub fn with_retries_exhausted(self) -> Self {
+        Self {
+            retries_exhausted: true,
+            ..self
+        }
+    }
+
+    /// Returns how many times the request was sent, the last attempt included.
+    #[must_use]
+    pub const fn attempt_count(&self) -> usize {
+        self.history.len() + 1
+    }
+
+    /// Returns `true` if the same request may succeed when sent again.
+    ///
+    /// Transport-level failures are always transient; an invalid response is transient only if
+    /// [`InvalidSecResponse::is_transient`] says so; a fixture that cannot be replayed or
+    /// recorded never is.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        match &self.reason {
+            ErrorReason::FailedRequestExecution { .. } => true,
+            ErrorReason::InvalidResponse { source } => source.is_transient(),
+            ErrorReason::FixtureUnavailable { .. } => false,
+        }
+    }
+
+    /// Returns `true` if the caller may still send the request again.
+    ///
+    /// The failure must be [transient](Self::is_transient), and the client must not have retried
+    /// it already: retrying a request whose retries are exhausted would multiply the attempts
+    /// against the SEC's request budget.
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        self.is_transient() && !self.retries_exhausted
+    }
+}
+
+/// Describes the attempt count of a retried request, and nothing for a request sent once.
+fn attempts_note(history: &[RequestAttempt]) -> String {
+    if history.is_empty() {
+        String::new()
+    } else {
+        format!(" after {} attempts", history.len() + 1)
+    }
+}
+
+/// An attempt of an SEC request that failed and was retried.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestAttempt {
+    /// The reason why the attempt failed.
+    pub reason: ErrorReason,
+    /// How long the client waited before sending the next attempt.
+    pub delay: Duration,
+}
+
+impl RequestAttempt {
+    /// Creates a new [`RequestAttempt`], retried after `delay`.
+    #[must_use]
+    pub const fn new(reason: ErrorReason, delay: Duration) -> Self {
+        Self { reason, delay }
+    }
+}
+
+impl fmt::Display for RequestAttempt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, retried after {:?}", self.reason, self.delay)
     }
 }
 
@@ -43,6 +132,11 @@ pub enum ErrorReason {
         /// The underlying response-validation error.
         source: InvalidSecResponse,
     },
+    /// The response could not be replayed from, or recorded into, the client's fixtures.
+    FixtureUnavailable {
+        /// The underlying response-fixture error.
+        source: ResponseFixtureError,
+    },
 }
 
 impl fmt::Display for ErrorReason {
@@ -60,6 +154,12 @@ impl fmt::Display for ErrorReason {
                     "[InvalidResponse] Response validation failed, Caused by: {source}"
                 )
             }
+            Self::FixtureUnavailable { source } => {
+                write!(
+                    f,
+                    "[FixtureUnavailable] Response fixture unavailable, Caused by: {source}"
+                )
+            }
         }
     }
 }
@@ -72,6 +172,12 @@ impl From<reqwest::Error> for FailedSecRequest {
     }
 }
 
+impl From<ResponseFixtureError> for FailedSecRequest {
+    fn from(e: ResponseFixtureError) -> Self {
+        Self::new(ErrorReason::FixtureUnavailable { source: e })
+    }
+}
+
 impl From<InvalidSecResponse> for FailedSecRequest {
     fn from(e: InvalidSecResponse) -> Self {
         Self::new(ErrorReason::InvalidResponse { source: e })
@@ -121,6 +227,39 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_format_display_with_attempt_count_when_request_was_retried() {
+        let reason = ErrorReason::FailedRequestExecution {
+            details: "connection refused".to_string(),
+        };
+        let error = FailedSecRequest::new(reason.clone()).with_history(vec![
+            RequestAttempt::new(reason.clone(), Duration::from_millis(500)),
+            RequestAttempt::new(reason.clone(), Duration::from_secs(1)),
+        ]);
+
+        let expected_result =
+            format!("[FailedSecRequest] SEC request failed after 3 attempts, Caused by: {reason}");
+
+        let result = format!("{error}");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_count_last_attempt_when_counting_attempts() {
+        let reason = ErrorReason::FailedRequestExecution {
+            details: "connection reset".to_string(),
+        };
+        let error = FailedSecRequest::new(reason.clone())
+            .with_history(vec![RequestAttempt::new(reason, Duration::ZERO)]);
+
+        let expected_result = 2;
+
+        let result = error.attempt_count();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_convert_from_invalid_sec_response_when_using_from() {
         let sec_response_error =
@@ -136,4 +275,32 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_be_transient_when_reason_is_failed_request_execution() {
+        let error = FailedSecRequest::new(ErrorReason::FailedRequestExecution {
+            details: "connection reset".to_string(),
+        });
+
+        let expected_result = true;
+
+        let result = error.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_be_transient_when_response_has_unexpected_content_type() {
+        let error = FailedSecRequest::from(InvalidSecResponse::new(
+            SecResponseErrorReason::InvalidContentType {
+                content_type: ContentType::Html,
+            },
+        ));
+
+        let expected_result = false;
+
+        let result = error.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
 }
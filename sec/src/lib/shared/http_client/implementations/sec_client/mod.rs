@@ -3,32 +3,68 @@
 //! Provides the [`SecClient`], the concrete [`SecClient`](crate::shared::http_client::SecClient)
 //! used throughout the pipeline.
 //!
-//! Rate limiting is globally shared within this process: all [`SecClient`] instances — whether
-//! constructed independently or cloned — draw from a single budget, ensuring the SEC's request-rate
-//! ceiling is not exceeded by this process regardless of how many clients exist.
+//! Rate limiting is globally shared within this process by default: all [`SecClient`] instances —
+//! whether constructed independently or cloned — draw from a single budget, ensuring the SEC's
+//! request-rate ceiling is not exceeded by this process regardless of how many clients exist. A
+//! client given its own limiter through [`SecClient::with_rate_limiter`], or built from a
+//! configuration through [`SecClient::from_config`], paces independently.
+//!
+//! A client given a [`ResponseCache`] revalidates cached responses instead of downloading them
+//! again; see [`response_cache`](crate::shared::response_cache) for how.
+//!
+//! Requests failing with a transient error — a 429 or 5xx status, a connection failure, or a
+//! timeout — are retried with exponential backoff, honoring `Retry-After`, until the retries or the
+//! time allotted to them run out.
+//!
+//! A client given [`ResponseFixtures`] replays recorded responses instead of contacting the SEC,
+//! or records the responses it receives; see [`response_fixtures`](crate::shared::response_fixtures).
+//!
+//! Responses are requested and decompressed as gzip or deflate where the server supports it, and
+//! no decompressed body larger than the client's maximum body size is read.
 //!
 //! ## Modules
 //!
+//! - [`constants`]: The default retry budget.
 //! - [`error`]: The [`FailedSecRequest`] error returned when execution fails.
+//! - [`retry_after`]: The parser of the `Retry-After` header.
 
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::header::{HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, RETRY_AFTER};
+use reqwest::{Method, Request, Response, Url};
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
 
+use crate::shared::company_facts::CompanyFactsDocument;
+use crate::shared::config::ArkadConfig;
 use crate::shared::http_client::InnerClient;
 use crate::shared::http_client::SecClient as SecClientTrait;
 use crate::shared::rate_limiter::{RateLimiter, SecRateLimiter};
 use crate::shared::request::implementations::sec_request::SecRequest;
+use crate::shared::request::implementations::sec_request::constants::SEC_COMPANY_FACTS_URL_PREFIX;
 use crate::shared::response::SecResponse as SecResponseTrait;
 use crate::shared::response::implementations::sec_response::SecResponse;
+use crate::shared::response::implementations::sec_response::constants::DEFAULT_MAX_BODY_SIZE;
+use crate::shared::response::implementations::sec_response::error::{
+    ErrorReason as InvalidSecResponseReason, InvalidSecResponse,
+};
+use crate::shared::response_cache::{CacheStatus, CachedResponse, ResponseCache};
+use crate::shared::response_fixtures::{FixtureMode, ResponseFixtures};
+use crate::shared::retry_policy::RetryPolicy;
+use crate::shared::status_code::StatusCode;
 use crate::shared::user_agent::UserAgent;
 use crate::shared::user_agent::constants::DEFAULT_SEC_USER_AGENT;
 
-use self::error::FailedSecRequest;
+use self::constants::{DEFAULT_MAX_RETRY_ELAPSED, DEFAULT_REQUEST_RETRIES};
+use self::error::{FailedSecRequest, RequestAttempt};
+use self::retry_after::parse_retry_after;
 
+pub mod constants;
 pub mod error;
+pub mod retry_after;
 
 /// Shared rate limiter backing all [`SecClient`] instances.
 static GLOBAL_RATE_LIMITER: OnceLock<SecRateLimiter> = OnceLock::new();
@@ -42,7 +78,11 @@ static GLOBAL_RATE_LIMITER: OnceLock<SecRateLimiter> = OnceLock::new();
 ///
 /// Every request first awaits a permit from the shared [`SecRateLimiter`], pacing outgoing traffic
 /// under the SEC's request-rate ceiling. Multiple instances can maintain separate TLS connections
-/// while still collectively respecting the rate limit.
+/// while still collectively respecting the rate limit. The status of every response is reported
+/// back to the limiter, which slows down while the SEC throttles.
+///
+/// Tests and runs on behalf of separate tenants can give a client a budget of its own with
+/// [`SecClient::with_rate_limiter`].
 ///
 /// # Cloning
 ///
@@ -54,10 +94,56 @@ static GLOBAL_RATE_LIMITER: OnceLock<SecRateLimiter> = OnceLock::new();
 ///
 /// [`SecClient::default`] sets the `User-Agent` from a validated [`UserAgent`], guaranteeing every
 /// request carries the SEC-compliant header the API requires.
+///
+/// # Configuration
+///
+/// [`SecClient::from_config`] takes the user agent, request pacing, and company-facts URL prefix
+/// from a validated [`ArkadConfig`], pacing with a limiter of its own; every other constructor uses
+/// the defaults and the process-wide limiter.
+///
+/// # Response Cache
+///
+/// Without a [`ResponseCache`], every request downloads the full response. With one, set through
+/// [`SecClient::with_response_cache`], a cached response younger than
+/// [`with_cache_max_age`](SecClient::with_cache_max_age) is served without a request, and an older
+/// one is revalidated with `If-None-Match`/`If-Modified-Since`. A cache that fails to load or store
+/// is bypassed: the request is answered as if nothing were cached.
+///
+/// # Retries
+///
+/// A `GET` failing with a 429 or 5xx status, a connection failure, or a timeout is sent again,
+/// after the delay its `Retry-After` header asks for or else the backoff of the client's
+/// [`RetryPolicy`] (see [`with_retry_policy`](SecClient::with_retry_policy)). Each attempt awaits
+/// the rate limiter anew. Retrying stops once the policy's retries are spent or the next attempt
+/// would start past [`with_max_retry_elapsed`](SecClient::with_max_retry_elapsed); the
+/// [`FailedSecRequest`] then lists every earlier attempt in its history.
+///
+/// # Response Fixtures
+///
+/// With [`ResponseFixtures`] in [`FixtureMode::Replay`], set through
+/// [`SecClient::with_response_fixtures`], every request is answered from the response recorded
+/// for its URL, without awaiting the rate limiter or contacting the SEC; a request never recorded
+/// fails. In [`FixtureMode::Record`], requests are sent as usual and every response they are
+/// answered with is recorded, replacing any earlier recording.
+///
+/// # Body Size
+///
+/// A response body is refused with [`InvalidSecResponseReason::BodyTooLarge`] as soon as more
+/// than [`with_max_body_size`](SecClient::with_max_body_size) bytes of it have been decompressed.
+/// [`SecClient::execute_company_facts_request`] deserializes a company-facts body into a
+/// [`CompanyFactsDocument`] while it downloads, never holding the raw body or a
+/// `serde_json::Value` of it whole, unless a response cache or fixtures need the whole response.
 #[derive(Debug, Clone)]
 pub struct SecClient {
     inner: reqwest::Client,
     rate_limiter: SecRateLimiter,
+    company_facts_url_prefix: Url,
+    response_cache: Option<Arc<dyn ResponseCache>>,
+    cache_max_age: Duration,
+    retry_policy: RetryPolicy,
+    max_retry_elapsed: Duration,
+    max_body_size: u64,
+    response_fixtures: Option<ResponseFixtures>,
 }
 
 impl Serialize for SecClient {
@@ -69,13 +155,297 @@ impl Serialize for SecClient {
 
 impl SecClient {
     /// Creates a new [`SecClient`] with the given `reqwest::Client`, backed by the shared
-    /// [`SecRateLimiter`] that paces requests under the SEC's request-rate ceiling.
+    /// [`SecRateLimiter`] that paces requests under the SEC's request-rate ceiling, targeting the
+    /// SEC's own company-facts endpoint.
+    ///
+    /// # Panics
+    ///
+    /// Never in practice: the hardcoded company-facts URL prefix always parses.
     #[must_use]
     pub fn new(inner: reqwest::Client) -> Self {
         Self {
             inner,
             rate_limiter: GLOBAL_RATE_LIMITER.get_or_init(SecRateLimiter::new).clone(),
+            company_facts_url_prefix: Url::parse(SEC_COMPANY_FACTS_URL_PREFIX)
+                .expect("Hardcoded URL should always be valid"),
+            response_cache: None,
+            cache_max_age: Duration::ZERO,
+            retry_policy: RetryPolicy::new(DEFAULT_REQUEST_RETRIES),
+            max_retry_elapsed: DEFAULT_MAX_RETRY_ELAPSED,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            response_fixtures: None,
+        }
+    }
+
+    /// Creates a new [`SecClient`] identifying itself to the SEC with `user_agent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be initialized, e.g. because no TLS backend is available.
+    #[must_use]
+    pub fn with_user_agent(user_agent: &UserAgent) -> Self {
+        let http_client = reqwest::Client::builder()
+            .user_agent(user_agent.inner())
+            .build()
+            .expect("A validated UserAgent should always produce a valid HTTP client");
+        Self::new(http_client)
+    }
+
+    /// Creates a new [`SecClient`] with the user agent, request pacing, company-facts URL prefix,
+    /// and maximum body size of `config`.
+    ///
+    /// The client paces its requests, and those of its clones, with a limiter of its own built from
+    /// the request interval of `config` rather than the process-wide one, so the interval applies
+    /// however many clients were created before. Share one such client (by cloning it) across a
+    /// run to keep the whole run within a single budget.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be initialized, e.g. because no TLS backend is available.
+    #[must_use]
+    pub fn from_config(config: &ArkadConfig) -> Self {
+        Self {
+            company_facts_url_prefix: config.company_facts_url_prefix().clone(),
+            max_body_size: config.max_body_size(),
+            ..Self::with_user_agent(config.user_agent())
+        }
+        .with_rate_limiter(SecRateLimiter::with_min_interval(
+            config.min_request_interval(),
+        ))
+    }
+
+    /// Returns the prefix the zero-padded CIK is appended to when fetching company facts.
+    #[must_use]
+    pub const fn company_facts_url_prefix(&self) -> &Url {
+        &self.company_facts_url_prefix
+    }
+
+    /// Sets the limiter pacing this client and its clones instead of the process-wide one.
+    #[must_use]
+    pub fn with_rate_limiter(self, rate_limiter: SecRateLimiter) -> Self {
+        Self {
+            rate_limiter,
+            ..self
+        }
+    }
+
+    /// Sets the cache responses are revalidated against and stored in; clones share it.
+    #[must_use]
+    pub fn with_response_cache(self, response_cache: Arc<dyn ResponseCache>) -> Self {
+        Self {
+            response_cache: Some(response_cache),
+            ..self
+        }
+    }
+
+    /// Sets how long a cached response is served without asking the SEC whether it changed.
+    ///
+    /// Defaults to zero, so every cached response is revalidated before use.
+    #[must_use]
+    pub fn with_cache_max_age(self, cache_max_age: Duration) -> Self {
+        Self {
+            cache_max_age,
+            ..self
+        }
+    }
+
+    /// Sets how many times, and after what backoff, transiently failing requests are retried.
+    ///
+    /// Only the retry budget and backoff of `retry_policy` apply; its error classifier does not.
+    /// Defaults to three retries with the default backoff; [`RetryPolicy::none`] disables retries.
+    #[must_use]
+    pub const fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets how long after its first attempt a request may still be retried.
+    #[must_use]
+    pub const fn with_max_retry_elapsed(mut self, max_retry_elapsed: Duration) -> Self {
+        self.max_retry_elapsed = max_retry_elapsed;
+        self
+    }
+
+    /// Sets the most bytes a decompressed response body may have before it is refused.
+    #[must_use]
+    pub const fn with_max_body_size(mut self, max_body_size: u64) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Sets the fixtures responses are replayed from or recorded into; clones share them.
+    #[must_use]
+    pub fn with_response_fixtures(self, response_fixtures: ResponseFixtures) -> Self {
+        Self {
+            response_fixtures: Some(response_fixtures),
+            ..self
+        }
+    }
+
+    /// Executes a company-facts `request` and deserializes the reply into a
+    /// [`CompanyFactsDocument`] while it downloads, keeping only the concepts the pipeline
+    /// resolves.
+    ///
+    /// Requests are paced and retried like [`execute_sec_request`](SecClientTrait::execute_sec_request).
+    /// A client with a [`ResponseCache`] or [`ResponseFixtures`] keeps whole responses, so it
+    /// answers the request through [`execute_sec_request`](SecClientTrait::execute_sec_request)
+    /// instead, serving, revalidating, storing, replaying, or recording the whole response, and
+    /// deserializes the document from its body.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FailedSecRequest`] if the request fails, the reply is not a successful JSON
+    /// response, its body exceeds the maximum body size, or it is not a company-facts body.
+    pub async fn execute_company_facts_request(
+        &self,
+        request: SecRequest,
+    ) -> Result<CompanyFactsDocument, FailedSecRequest> {
+        if self.response_fixtures.is_some() || self.response_cache.is_some() {
+            let response = self.execute_sec_request(request).await?;
+            let document = CompanyFactsDocument::from_value(response.body()).map_err(|e| {
+                InvalidSecResponse::new(InvalidSecResponseReason::InvalidBody {
+                    details: e.to_string(),
+                })
+            })?;
+            return Ok(document);
+        }
+        let inner_response = self.send(&request.into_inner()).await?;
+        let document = CompanyFactsDocument::from_inner(inner_response, self.max_body_size).await?;
+        Ok(document)
+    }
+
+    /// Sends `inner_request` once the rate limiter allows, retrying it while it fails transiently.
+    ///
+    /// Returns the first response whose status is not worth retrying, which may still be an
+    /// error status for the caller to reject.
+    async fn send(&self, inner_request: &Request) -> Result<Response, FailedSecRequest> {
+        let started = Instant::now();
+        let max_retries = if inner_request.method() == Method::GET {
+            self.retry_policy.max_retries()
+        } else {
+            0
+        };
+        let mut history = Vec::new();
+
+        loop {
+            let request = inner_request
+                .try_clone()
+                .expect("A bodiless SEC request should always be cloneable");
+            self.rate_limiter().await_turn().await;
+
+            let (error, retry_after) = match self.inner.execute_request(request).await {
+                Ok(response) => {
+                    let status_code = StatusCode::from(response.status());
+                    self.rate_limiter().record_response(&status_code);
+                    if !status_code.is_transient() {
+                        return Ok(response);
+                    }
+                    let retry_after = response
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| parse_retry_after(v, Utc::now()));
+                    let error = FailedSecRequest::from(InvalidSecResponse::new(
+                        InvalidSecResponseReason::InvalidStatusCode { status_code },
+                    ));
+                    (error, retry_after)
+                }
+                Err(e) if e.is_connect() || e.is_timeout() => (FailedSecRequest::from(e), None),
+                Err(e) => return Err(FailedSecRequest::from(e).with_history(history)),
+            };
+
+            let retry = u32::try_from(history.len())
+                .unwrap_or(u32::MAX)
+                .saturating_add(1);
+            let delay = retry_after.unwrap_or_else(|| self.retry_policy.backoff(retry));
+            if retry > max_retries || started.elapsed() + delay > self.max_retry_elapsed {
+                let error = error.with_history(history);
+                return Err(if max_retries > 0 {
+                    error.with_retries_exhausted()
+                } else {
+                    error
+                });
+            }
+            history.push(RequestAttempt::new(error.reason, delay));
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Answers `inner_request` through the response cache if the client has one, and from the SEC
+    /// otherwise.
+    async fn resolve(&self, inner_request: Request) -> Result<SecResponse, FailedSecRequest> {
+        match &self.response_cache {
+            Some(response_cache) => {
+                self.fetch_through_cache(response_cache.as_ref(), inner_request)
+                    .await
+            }
+            None => self.fetch(inner_request).await,
+        }
+    }
+
+    /// Sends `inner_request`, retrying transient failures, and validates the reply.
+    async fn fetch(&self, inner_request: Request) -> Result<SecResponse, FailedSecRequest> {
+        let inner_response = self.send(&inner_request).await?;
+        let sec_response =
+            SecResponse::from_inner_with_limit(inner_response, self.max_body_size).await?;
+        Ok(sec_response)
+    }
+
+    /// Answers `inner_request` from `response_cache` where possible, revalidating the cached
+    /// response with a conditional request, and stores what the SEC replies.
+    async fn fetch_through_cache(
+        &self,
+        response_cache: &dyn ResponseCache,
+        mut inner_request: Request,
+    ) -> Result<SecResponse, FailedSecRequest> {
+        let url = inner_request.url().to_string();
+        let now = Utc::now();
+        let cached = response_cache.load(&url).await.ok().flatten();
+
+        if let Some(entry) = &cached {
+            if entry.is_fresh(self.cache_max_age, now) {
+                return Ok(entry.clone().into_response(CacheStatus::Hit));
+            }
+            let headers = inner_request.headers_mut();
+            if let Some(etag) = entry.etag().and_then(|v| HeaderValue::from_str(v).ok()) {
+                headers.insert(IF_NONE_MATCH, etag);
+            }
+            if let Some(date) = entry
+                .last_modified()
+                .and_then(|v| HeaderValue::from_str(v).ok())
+            {
+                headers.insert(IF_MODIFIED_SINCE, date);
+            }
         }
+
+        let inner_response = self.send(&inner_request).await?;
+
+        let (sec_response, entry) = match cached {
+            Some(entry) if inner_response.status() == reqwest::StatusCode::NOT_MODIFIED => {
+                let entry = entry.restamped(now);
+                (entry.clone().into_response(CacheStatus::Revalidated), entry)
+            }
+            cached => {
+                let sec_response =
+                    SecResponse::from_inner_with_limit(inner_response, self.max_body_size).await?;
+                // A server ignoring the validators may still resend the very same body.
+                let unchanged =
+                    cached.is_some_and(|entry| entry.body_digest() == sec_response.body_digest());
+                let entry = CachedResponse::new(&sec_response, now);
+                if unchanged {
+                    (
+                        sec_response.with_cache_status(CacheStatus::Revalidated),
+                        entry,
+                    )
+                } else {
+                    (sec_response, entry)
+                }
+            }
+        };
+
+        // Failing to store only costs a full download on the next request.
+        let _ = response_cache.store(&url, &entry).await;
+        Ok(sec_response)
     }
 }
 
@@ -84,17 +454,13 @@ impl Default for SecClient {
     fn default() -> Self {
         let user_agent = UserAgent::new(DEFAULT_SEC_USER_AGENT)
             .expect("The default SEC user agent constant should always be valid");
-        let http_client = reqwest::Client::builder()
-            .user_agent(user_agent.inner())
-            .build()
-            .expect("A validated UserAgent should always produce a valid HTTP client");
-        Self::new(http_client)
+        Self::with_user_agent(&user_agent)
     }
 }
 
 // Deviation: neither `reqwest::Client` nor the `SecRateLimiter` expose any comparable or
-// hashable state, so all `SecClient` instances are considered equal and the limiter field — like
-// `inner` — is excluded from these impls. This satisfies trait bounds that require
+// hashable state, so all `SecClient` instances are considered equal and the limiter, URL prefix,
+// and response cache — like `inner` — are excluded from these impls. This satisfies trait bounds that require
 // `Eq + Ord + Hash` (e.g. for use in collections or state machines).
 
 impl PartialEq for SecClient {
@@ -143,19 +509,586 @@ impl SecClientTrait for SecClient {
     ) -> Result<Self::Response, Self::Error> {
         let inner_request = request.into_inner();
 
-        self.rate_limiter().await_turn().await;
-
-        let inner_response = self.inner.execute_request(inner_request).await?;
-        let sec_response = SecResponse::from_inner(inner_response).await?;
-        Ok(sec_response)
+        match &self.response_fixtures {
+            Some(response_fixtures) => {
+                let url = inner_request.url().to_string();
+                match response_fixtures.mode() {
+                    FixtureMode::Replay => Ok(response_fixtures.load(&url).await?),
+                    FixtureMode::Record => {
+                        let response = self.resolve(inner_request).await?;
+                        response_fixtures.store(&url, &response).await?;
+                        Ok(response)
+                    }
+                }
+            }
+            None => self.resolve(inner_request).await,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+    use std::io::Write;
+
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+
     use pretty_assertions::assert_eq;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::shared::cik::Cik;
+    use crate::shared::config::ConfigLayer;
+    use crate::shared::content_type::ContentType;
+    use crate::shared::headers::Headers;
+    use crate::shared::response_cache::InMemoryResponseCache;
+    use crate::shared::response_fixtures::ResponseFixtureError;
+    use crate::shared::response_fixtures::response_fixture_error::ErrorReason as ResponseFixtureErrorReason;
+    use crate::shared::status_code::StatusCode;
+
+    /// Serves `reply` to the first connection on a local port, returning the URL prefix to
+    /// request and a handle yielding the request head the server received.
+    async fn serve_once(reply: impl AsRef<[u8]> + Send + 'static) -> (Url, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Binding a local port should always succeed");
+        let address = listener
+            .local_addr()
+            .expect("A bound listener should always have an address");
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener
+                .accept()
+                .await
+                .expect("The client should always connect");
+            let mut head = Vec::new();
+            let mut buffer = [0; 1024];
+            while !head.ends_with(b"\r\n\r\n") {
+                let read = stream
+                    .read(&mut buffer)
+                    .await
+                    .expect("Reading the request should always succeed");
+                head.extend_from_slice(&buffer[..read]);
+            }
+            stream
+                .write_all(reply.as_ref())
+                .await
+                .expect("Writing the reply should always succeed");
+            String::from_utf8_lossy(&head).to_ascii_lowercase()
+        });
+        let url_prefix = Url::parse(&format!("http://{address}/CIK"))
+            .expect("A local address should always form a valid URL");
+        (url_prefix, server)
+    }
+
+    /// Serves `replies` in turn, one per connection, on a local port, returning the URL prefix to
+    /// request and a handle yielding how many replies were sent.
+    async fn serve_in_turn(replies: &'static [&'static str]) -> (Url, JoinHandle<usize>) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Binding a local port should always succeed");
+        let address = listener
+            .local_addr()
+            .expect("A bound listener should always have an address");
+        let server = tokio::spawn(async move {
+            for reply in replies {
+                let (mut stream, _) = listener
+                    .accept()
+                    .await
+                    .expect("The client should always connect");
+                let mut buffer = [0; 1024];
+                let mut head = Vec::new();
+                while !head.ends_with(b"\r\n\r\n") {
+                    let read = stream
+                        .read(&mut buffer)
+                        .await
+                        .expect("Reading the request should always succeed");
+                    head.extend_from_slice(&buffer[..read]);
+                }
+                stream
+                    .write_all(reply.as_bytes())
+                    .await
+                    .expect("Writing the reply should always succeed");
+            }
+            replies.len()
+        });
+        let url_prefix = Url::parse(&format!("http://{address}/CIK"))
+            .expect("A local address should always form a valid URL");
+        (url_prefix, server)
+    }
+
+    fn retrying_client(max_retries: u32) -> SecClient {
+        let http_client = reqwest::Client::builder()
+            .no_proxy()
+            .build()
+            .expect("A plain HTTP client should always build");
+        SecClient::new(http_client).with_retry_policy(
+            RetryPolicy::new(max_retries)
+                .with_initial_backoff(Duration::from_millis(1))
+                .with_jitter(false),
+        )
+    }
+
+    fn cached_client(response_cache: Arc<dyn ResponseCache>) -> SecClient {
+        let http_client = reqwest::Client::builder()
+            .no_proxy()
+            .build()
+            .expect("A plain HTTP client should always build");
+        SecClient::new(http_client).with_response_cache(response_cache)
+    }
 
-    use super::SecClient;
+    fn request_to(url_prefix: Url) -> SecRequest {
+        let cik = Cik::new("1067983").expect("A hardcoded CIK should always be valid");
+        SecRequest::builder()
+            .all_company_facts()
+            .url_prefix(url_prefix)
+            .cik(cik)
+            .build()
+    }
+
+    fn entry_for(request: &SecRequest, etag: &str) -> CachedResponse {
+        let response = SecResponse::from_parts(
+            crate::shared::url::Url::from(request.inner.url().clone()),
+            Headers::new(HashMap::from([("etag".to_string(), etag.to_string())])),
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({ "cik": 1_067_983 }),
+        );
+        CachedResponse::new(&response, Utc::now())
+    }
+
+    #[tokio::test]
+    async fn should_serve_cached_response_without_request_when_entry_is_fresh() {
+        // Nothing listens on port 9, so any request would fail.
+        let request = request_to(
+            Url::parse("http://127.0.0.1:9/CIK").expect("Hardcoded URL should always be valid"),
+        );
+        let response_cache = Arc::new(InMemoryResponseCache::default());
+        response_cache
+            .store(
+                request.inner.url().as_str(),
+                &entry_for(&request, "\"abc\""),
+            )
+            .await
+            .expect("Storing in memory should always succeed");
+        let client = cached_client(response_cache).with_cache_max_age(Duration::from_hours(1));
+
+        let expected_result = CacheStatus::Hit;
+
+        let result = client
+            .execute_sec_request(request)
+            .await
+            .expect("A fresh cached response should always be served")
+            .cache_status();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_serve_company_facts_document_from_cache_when_entry_is_fresh() {
+        // Nothing listens on port 9, so any request would fail.
+        let request = request_to(
+            Url::parse("http://127.0.0.1:9/CIK").expect("Hardcoded URL should always be valid"),
+        );
+        let response = SecResponse::from_parts(
+            crate::shared::url::Url::from(request.inner.url().clone()),
+            Headers::new(HashMap::new()),
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({
+                "cik": 1_067_983,
+                "entityName": "BERKSHIRE HATHAWAY INC",
+                "facts": {}
+            }),
+        );
+        let response_cache = Arc::new(InMemoryResponseCache::default());
+        response_cache
+            .store(
+                request.inner.url().as_str(),
+                &CachedResponse::new(&response, Utc::now()),
+            )
+            .await
+            .expect("Storing in memory should always succeed");
+        let client = cached_client(response_cache).with_cache_max_age(Duration::from_hours(1));
+
+        let expected_result = "BERKSHIRE HATHAWAY INC".to_string();
+
+        let result = client
+            .execute_company_facts_request(request)
+            .await
+            .expect("A fresh cached company-facts response should always be served")
+            .entity_name()
+            .to_string();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_revalidate_with_etag_when_server_replies_not_modified() {
+        let (url_prefix, server) =
+            serve_once("HTTP/1.1 304 Not Modified\r\ncontent-length: 0\r\n\r\n").await;
+        let request = request_to(url_prefix);
+        let entry = entry_for(&request, "\"abc\"");
+        let response_cache = Arc::new(InMemoryResponseCache::default());
+        response_cache
+            .store(request.inner.url().as_str(), &entry)
+            .await
+            .expect("Storing in memory should always succeed");
+        let client = cached_client(response_cache);
+
+        let expected_result = (CacheStatus::Revalidated, entry.body_digest(), true);
+
+        let response = client
+            .execute_sec_request(request)
+            .await
+            .expect("A revalidated cached response should always be served");
+        let head = server.await.expect("The server task should never panic");
+        let result = (
+            response.cache_status(),
+            response.body_digest(),
+            head.contains("if-none-match: \"abc\""),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_store_response_when_nothing_was_cached() {
+        let (url_prefix, server) = serve_once(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\netag: \"def\"\r\ncontent-length: 2\r\n\r\n{}",
+        )
+        .await;
+        let request = request_to(url_prefix);
+        let url = request.inner.url().to_string();
+        let response_cache = Arc::new(InMemoryResponseCache::default());
+        let client = cached_client(response_cache.clone());
+
+        let expected_result = (CacheStatus::Miss, Some("\"def\"".to_string()));
+
+        let response = client
+            .execute_sec_request(request)
+            .await
+            .expect("A valid reply should always be accepted");
+        server.await.expect("The server task should never panic");
+        let stored = response_cache
+            .load(&url)
+            .await
+            .expect("Loading from memory should always succeed");
+        let result = (
+            response.cache_status(),
+            stored.and_then(|entry| entry.etag().map(str::to_string)),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\nretry-after: 0\r\nconnection: close\r\ncontent-length: 0\r\n\r\n";
+
+    #[tokio::test]
+    async fn should_retry_request_when_service_is_unavailable() {
+        let (url_prefix, server) = serve_in_turn(&[
+            UNAVAILABLE,
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\nconnection: close\r\ncontent-length: 2\r\n\r\n{}",
+        ])
+        .await;
+        let client = retrying_client(1);
+
+        let expected_result = (StatusCode::Ok, 2);
+
+        let response = client
+            .execute_sec_request(request_to(url_prefix))
+            .await
+            .expect("A request succeeding on retry should always be answered");
+        let replies = server.await.expect("The server task should never panic");
+        let result = (response.status_code(), replies);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_report_every_attempt_when_retries_run_out() {
+        let (url_prefix, server) = serve_in_turn(&[UNAVAILABLE, UNAVAILABLE, UNAVAILABLE]).await;
+        let client = retrying_client(2);
+        let reason = FailedSecRequest::from(InvalidSecResponse::new(
+            InvalidSecResponseReason::InvalidStatusCode {
+                status_code: StatusCode::ServiceUnavailable,
+            },
+        ))
+        .reason;
+
+        let expected_result = FailedSecRequest::new(reason.clone())
+            .with_history(vec![
+                RequestAttempt::new(reason.clone(), Duration::ZERO),
+                RequestAttempt::new(reason, Duration::ZERO),
+            ])
+            .with_retries_exhausted();
+
+        let result = client
+            .execute_sec_request(request_to(url_prefix))
+            .await
+            .expect_err("A request never succeeding should always fail");
+        server.await.expect("The server task should never panic");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_when_retry_after_exceeds_max_elapsed() {
+        let (url_prefix, server) = serve_in_turn(&[
+            "HTTP/1.1 429 Too Many Requests\r\nretry-after: 120\r\nconnection: close\r\ncontent-length: 0\r\n\r\n",
+        ])
+        .await;
+        let client = retrying_client(3).with_max_retry_elapsed(Duration::from_secs(1));
+
+        let expected_result = 1;
+
+        let result = client
+            .execute_sec_request(request_to(url_prefix))
+            .await
+            .expect_err("A request throttled past the retry budget should always fail")
+            .attempt_count();
+        server.await.expect("The server task should never panic");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_when_status_is_not_transient() {
+        let (url_prefix, server) = serve_in_turn(&[
+            "HTTP/1.1 404 Not Found\r\nconnection: close\r\ncontent-length: 0\r\n\r\n",
+        ])
+        .await;
+        let client = retrying_client(3);
+
+        let expected_result = 1;
+
+        let result = client
+            .execute_sec_request(request_to(url_prefix))
+            .await
+            .expect_err("A missing resource should always fail")
+            .attempt_count();
+        server.await.expect("The server task should never panic");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_report_throttling_to_own_rate_limiter_when_server_throttles() {
+        let (url_prefix, server) = serve_in_turn(&[
+            "HTTP/1.1 429 Too Many Requests\r\nconnection: close\r\ncontent-length: 0\r\n\r\n",
+        ])
+        .await;
+        let rate_limiter = SecRateLimiter::new();
+        let client = retrying_client(0).with_rate_limiter(rate_limiter.clone());
+
+        let expected_result = 1;
+
+        let _ = client.execute_sec_request(request_to(url_prefix)).await;
+        server.await.expect("The server task should never panic");
+        let result = rate_limiter.metrics().throttle_events;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_pace_with_configured_interval_when_created_from_config_after_another_client() {
+        let _earlier_client = SecClient::default();
+        let config = ArkadConfig::resolve([
+            ConfigLayer::new().with_min_request_interval(Duration::from_millis(500))
+        ])
+        .expect("A positive request interval should always resolve");
+
+        let expected_result = Duration::from_millis(500);
+
+        let result = SecClient::from_config(&config)
+            .rate_limiter()
+            .metrics()
+            .current_interval;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_decompress_body_when_server_replies_with_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(br#"{"cik":1067983}"#)
+            .expect("Compressing in memory should always succeed");
+        let body = encoder
+            .finish()
+            .expect("Compressing in memory should always succeed");
+        let mut reply = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-encoding: gzip\r\ncontent-length: {}\r\n\r\n",
+            body.len()
+        )
+        .into_bytes();
+        reply.extend_from_slice(&body);
+        let (url_prefix, server) = serve_once(reply).await;
+        let client = retrying_client(0);
+
+        let expected_result = (serde_json::json!({ "cik": 1_067_983 }), true);
+
+        let response = client
+            .execute_sec_request(request_to(url_prefix))
+            .await
+            .expect("A gzip-compressed reply should always be decompressed");
+        let head = server.await.expect("The server task should never panic");
+        let result = (
+            response.body().clone(),
+            head.contains("accept-encoding: gzip"),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_body_when_larger_than_max_body_size() {
+        let (url_prefix, server) = serve_once(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\n\r\n{}",
+        )
+        .await;
+        let client = retrying_client(0).with_max_body_size(1);
+
+        let expected_result = FailedSecRequest::from(InvalidSecResponse::new(
+            InvalidSecResponseReason::BodyTooLarge { limit: 1 },
+        ));
+
+        let result = client
+            .execute_sec_request(request_to(url_prefix))
+            .await
+            .expect_err("A body over the limit should always be refused");
+        server.await.expect("The server task should never panic");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_stream_company_facts_document_when_body_is_company_facts() {
+        let (url_prefix, server) = serve_once(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 69\r\n\r\n{\"cik\": 1067983, \"entityName\": \"BERKSHIRE HATHAWAY INC\", \"facts\": {}}",
+        )
+        .await;
+        let client = retrying_client(0);
+
+        let expected_result = (
+            Cik::new("1067983").expect("A hardcoded CIK should always be valid"),
+            "BERKSHIRE HATHAWAY INC".to_string(),
+        );
+
+        let document = client
+            .execute_company_facts_request(request_to(url_prefix))
+            .await
+            .expect("A company-facts reply should always deserialize");
+        server.await.expect("The server task should never panic");
+        let result = (document.cik().clone(), document.entity_name().to_string());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_company_facts_body_when_larger_than_max_body_size() {
+        let (url_prefix, server) = serve_once(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 69\r\n\r\n{\"cik\": 1067983, \"entityName\": \"BERKSHIRE HATHAWAY INC\", \"facts\": {}}",
+        )
+        .await;
+        let client = retrying_client(0).with_max_body_size(16);
+
+        let expected_result = FailedSecRequest::from(InvalidSecResponse::new(
+            InvalidSecResponseReason::BodyTooLarge { limit: 16 },
+        ));
+
+        let result = client
+            .execute_company_facts_request(request_to(url_prefix))
+            .await
+            .expect_err("A body over the limit should always be refused");
+        server.await.expect("The server task should never panic");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_fail_with_invalid_body_when_company_facts_body_is_not_an_object() {
+        let (url_prefix, server) = serve_once(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 15\r\n\r\n\"not an object\"",
+        )
+        .await;
+        let client = retrying_client(0);
+
+        let expected_result = true;
+
+        let error = client
+            .execute_company_facts_request(request_to(url_prefix))
+            .await
+            .expect_err("A body that is not an object should always be refused");
+        server.await.expect("The server task should never panic");
+        let result = matches!(
+            error.reason,
+            error::ErrorReason::InvalidResponse { source }
+                if matches!(source.reason, InvalidSecResponseReason::InvalidBody { .. })
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_replay_recorded_response_without_request_when_fixtures_replay() {
+        let (url_prefix, server) = serve_once(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 69\r\n\r\n{\"cik\": 1067983, \"entityName\": \"BERKSHIRE HATHAWAY INC\", \"facts\": {}}",
+        )
+        .await;
+        let response_fixtures = ResponseFixtures::record(
+            std::env::temp_dir().join(format!("sec-response-fixtures-{}", Uuid::new_v4())),
+        );
+        let recorded = retrying_client(0)
+            .with_response_fixtures(response_fixtures.clone())
+            .execute_sec_request(request_to(url_prefix.clone()))
+            .await
+            .expect("A valid reply should always be recorded");
+        server.await.expect("The server task should never panic");
+        // The server has shut down, so any request would fail.
+        let client = retrying_client(0)
+            .with_response_fixtures(ResponseFixtures::replay(response_fixtures.directory()));
+
+        let expected_result = (recorded, "BERKSHIRE HATHAWAY INC".to_string());
+
+        let replayed = client
+            .execute_sec_request(request_to(url_prefix.clone()))
+            .await
+            .expect("A recorded response should always be replayed");
+        let document = client
+            .execute_company_facts_request(request_to(url_prefix))
+            .await
+            .expect("A recorded company-facts response should always deserialize");
+        let result = (replayed, document.entity_name().to_string());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_fail_without_request_when_replayed_fixture_is_missing() {
+        // Nothing listens on port 9, so any request would fail.
+        let url_prefix =
+            Url::parse("http://127.0.0.1:9/CIK").expect("Hardcoded URL should always be valid");
+        let request = request_to(url_prefix);
+        let url = request.inner.url().to_string();
+        let client = retrying_client(0).with_response_fixtures(ResponseFixtures::replay(
+            std::env::temp_dir().join(format!("sec-response-fixtures-{}", Uuid::new_v4())),
+        ));
+
+        let expected_result = FailedSecRequest::from(ResponseFixtureError::new(
+            ResponseFixtureErrorReason::Missing { url },
+        ));
+
+        let result = client
+            .execute_sec_request(request)
+            .await
+            .expect_err("A request never recorded should always fail");
+
+        assert_eq!(result, expected_result);
+    }
 
     #[test]
     fn should_serialize_to_empty_struct_when_serialized_to_json() {
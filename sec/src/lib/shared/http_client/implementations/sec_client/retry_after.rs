@@ -0,0 +1,82 @@
+//! # Retry-After
+//!
+//! Parses the `Retry-After` header a throttled or unavailable SEC endpoint may send, which is
+//! either a number of seconds or an HTTP date.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// Returns how long `value`, a `Retry-After` header value, asks to wait from `now`.
+///
+/// A date already past asks for no wait at all; a value that is neither delay-seconds nor an
+/// HTTP date yields `None`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use chrono::Utc;
+/// use sec::shared::http_client::implementations::sec_client::retry_after::parse_retry_after;
+///
+/// let expected_result = Some(Duration::from_secs(120));
+///
+/// let result = parse_retry_after("120", Utc::now());
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[must_use]
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0)
+            .single()
+            .expect("Hardcoded date should always be valid")
+    }
+
+    #[test]
+    fn should_wait_until_date_when_value_is_http_date() {
+        let expected_result = Some(Duration::from_secs(90));
+
+        let result = parse_retry_after("Thu, 01 Jan 2026 00:01:30 GMT", now());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_wait_when_date_is_past() {
+        let expected_result = Some(Duration::ZERO);
+
+        let result = parse_retry_after("Wed, 31 Dec 2025 23:00:00 GMT", now());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_none_when_value_is_malformed() {
+        let expected_result = None;
+
+        let result = parse_retry_after("soon", now());
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,120 @@
+//! # Journal Errors
+//!
+//! Provides the [`JournalError`] returned when a journal cannot be written or read back, and the
+//! [`ErrorReason`] describing why.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Error indicating that a journal operation failed.
+///
+/// Wraps the [`ErrorReason`] distinguishing an entry that could not be (de)serialized from a
+/// journal that could not be written.
+#[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error("[JournalError] Journal operation failed, Caused by: {reason}")]
+pub struct JournalError {
+    /// The reason why the journal operation failed.
+    pub reason: ErrorReason,
+}
+
+impl JournalError {
+    /// Creates a new [`JournalError`].
+    #[must_use]
+    pub const fn new(reason: ErrorReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Enum representing the reason why a journal operation failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorReason {
+    /// An entry could not be serialized.
+    Serialization {
+        /// A human-readable description of the serialization failure.
+        details: String,
+    },
+    /// A line of a JSON-lines journal could not be deserialized into an entry.
+    MalformedLine {
+        /// The number of the offending line, counted from one.
+        line: usize,
+        /// A human-readable description of the deserialization failure.
+        details: String,
+    },
+    /// The underlying writer failed to write or flush an entry.
+    Storage {
+        /// A human-readable description of the storage failure.
+        details: String,
+    },
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Serialization { details } => write!(
+                f,
+                "[Serialization] Journal entry serialization failed, Reason: '{details}'"
+            ),
+            Self::MalformedLine { line, details } => write!(
+                f,
+                "[MalformedLine] Journal line {line} is not a valid entry, Reason: '{details}'"
+            ),
+            Self::Storage { details } => {
+                write!(f, "[Storage] Journal storage failed, Reason: '{details}'")
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for JournalError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::new(ErrorReason::Serialization {
+            details: e.to_string(),
+        })
+    }
+}
+
+impl From<std::io::Error> for JournalError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorReason::Storage {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_format_display_as_expected_when_line_is_malformed() {
+        let reason = ErrorReason::MalformedLine {
+            line: 3,
+            details: "expected value".to_string(),
+        };
+        let journal_error = JournalError::new(reason.clone());
+
+        let expected_result =
+            format!("[JournalError] Journal operation failed, Caused by: {reason}");
+
+        let result = format!("{journal_error}");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_map_to_storage_reason_when_converting_from_io_error() {
+        let io_error = std::io::Error::other("disk full");
+
+        let expected_result = ErrorReason::Storage {
+            details: "disk full".to_string(),
+        };
+
+        let result = JournalError::from(io_error).reason;
+
+        assert_eq!(result, expected_result);
+    }
+}
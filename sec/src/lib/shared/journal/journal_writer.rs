@@ -0,0 +1,104 @@
+//! # Journal Writer
+//!
+//! Provides the [`JournalWriter`], which appends the events of a running stream to a JSON-lines
+//! journal as they happen.
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::{JournalEntry, JournalError};
+use crate::traits::state_machine::stream::{StreamError, StreamItem};
+
+/// Appends stream events to a JSON-lines journal, one [`JournalEntry`] per line.
+///
+/// Every entry is flushed as soon as it is recorded, so a run that crashes midway still leaves a
+/// journal of everything up to the crash. The journal can be read back with
+/// [`Journal::from_json_lines`](super::Journal::from_json_lines).
+#[derive(Debug)]
+pub struct JournalWriter<W> {
+    writer: W,
+}
+
+impl<W: AsyncWrite + Unpin + Send> JournalWriter<W> {
+    /// Creates a new [`JournalWriter`] appending to `writer`, e.g. a [`tokio::fs::File`].
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Appends the entry recording `result` and flushes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JournalError`] if the entry cannot be serialized, written, or flushed.
+    pub async fn record(
+        &mut self,
+        result: &Result<StreamItem, StreamError>,
+    ) -> Result<(), JournalError> {
+        let mut line = serde_json::to_vec(&JournalEntry::from(result))?;
+        line.push(b'\n');
+        self.writer.write_all(&line).await?;
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Consumes the [`JournalWriter`] and returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::shared::journal::Journal;
+    use crate::tests::fixtures::sample_streaming_super_state::{
+        SampleStateA, SampleStreamingSuperState,
+    };
+    use crate::traits::state_machine::stream::IntoStateMachineStream;
+
+    #[tokio::test]
+    async fn should_write_one_line_per_event_when_recording_stream() {
+        let stream = SampleStreamingSuperState::<SampleStateA>::new().into_stream(Uuid::new_v4());
+        let journal = Journal::collect(stream).await;
+        let mut writer = JournalWriter::new(Vec::new());
+        for entry in journal.entries() {
+            writer
+                .record(&entry.clone().into())
+                .await
+                .expect("Writing to an in-memory buffer should always succeed");
+        }
+
+        let expected_result = journal.entries().len();
+
+        let result = String::from_utf8(writer.into_inner())
+            .expect("A journal should always be valid UTF-8")
+            .lines()
+            .count();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_read_back_recorded_journal_when_parsing_written_lines() {
+        let stream = SampleStreamingSuperState::<SampleStateA>::new().into_stream(Uuid::new_v4());
+        let journal = Journal::collect(stream).await;
+        let mut writer = JournalWriter::new(Vec::new());
+        for entry in journal.entries() {
+            writer
+                .record(&entry.clone().into())
+                .await
+                .expect("Writing to an in-memory buffer should always succeed");
+        }
+        let written =
+            String::from_utf8(writer.into_inner()).expect("A journal should always be valid UTF-8");
+
+        let expected_result = journal;
+
+        let result =
+            Journal::from_json_lines(&written).expect("A written journal should always parse back");
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,333 @@
+//! # Journal
+//!
+//! Provides the [`Journal`], a recording of every event a [`StateMachineStream`] yielded during
+//! one run, and the means to replay it.
+//!
+//! A journal holds one [`JournalEntry`] per [`StreamItem`] or [`StreamError`], including the
+//! serialized state data, and round-trips through a JSON-lines file: one entry per line, in the
+//! order the stream yielded them. [`Journal::replay`] re-emits the recorded events as a
+//! [`StateMachineStream`], so a production failure can be reproduced locally, two runs of the same
+//! CIK can be diffed entry by entry, and a pipeline's output can be golden-tested.
+//!
+//! Errors are recorded with their message in place of the typed
+//! [`StateMachine`](crate::error::StateMachine) error they wrap; a replayed error carries the
+//! message as a [`StateMachine::Replayed`](crate::error::StateMachine::Replayed) source, so it
+//! displays exactly like the original.
+//!
+//! ## Modules
+//!
+//! - [`journal_error`]: The [`JournalError`] returned when a journal cannot be written or read.
+//! - [`journal_writer`]: The [`JournalWriter`] appending a running stream's events to a file.
+
+pub mod journal_error;
+pub mod journal_writer;
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub use journal_error::JournalError;
+pub use journal_writer::JournalWriter;
+
+use self::journal_error::ErrorReason;
+use crate::error::StateMachine as StateMachineError;
+use crate::traits::state_machine::payload::StatePayload;
+use crate::traits::state_machine::state_path::StatePath;
+use crate::traits::state_machine::stream::{
+    StateMachineStream, StreamError, StreamEvent, StreamItem,
+};
+
+/// One recorded event of a stream: either an item it yielded or the error it ended with.
+///
+/// Serializes with an `outcome` tag of `"item"` or `"error"` next to the event's fields.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum JournalEntry {
+    /// A [`StreamItem`] the stream yielded.
+    Item(StreamItem),
+    /// A [`StreamError`] the stream yielded.
+    Error(RecordedError),
+}
+
+impl From<JournalEntry> for Result<StreamItem, StreamError> {
+    /// Converts the entry back into the stream result it was recorded from.
+    fn from(entry: JournalEntry) -> Self {
+        match entry {
+            JournalEntry::Item(item) => Ok(item),
+            JournalEntry::Error(error) => Err(error.into()),
+        }
+    }
+}
+
+impl From<&Result<StreamItem, StreamError>> for JournalEntry {
+    fn from(result: &Result<StreamItem, StreamError>) -> Self {
+        match result {
+            Ok(item) => Self::Item(item.clone()),
+            Err(error) => Self::Error(RecordedError::from(error)),
+        }
+    }
+}
+
+/// A [`StreamError`] in recordable form: its stream context plus the message of its source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedError {
+    /// What was happening when the error occurred.
+    pub event: StreamEvent,
+    /// The execution ID of the recorded run.
+    pub execution_id: Uuid,
+    /// The name of the state where the error occurred.
+    pub state_name: String,
+    /// The path of super-states leading to the state where the error occurred.
+    pub path: StatePath,
+    /// The position of the error among all events of the run, counted from zero.
+    pub sequence: u64,
+    /// The data attached to the error, e.g. the state where it occurred.
+    pub data: StatePayload,
+    /// The message of the underlying state machine error.
+    pub message: String,
+}
+
+impl From<&StreamError> for RecordedError {
+    fn from(error: &StreamError) -> Self {
+        Self {
+            event: error.event.clone(),
+            execution_id: error.execution_id,
+            state_name: error.state_name.clone(),
+            path: error.path.clone(),
+            sequence: error.sequence,
+            data: error.data.clone(),
+            message: error.source.to_string(),
+        }
+    }
+}
+
+impl From<RecordedError> for StreamError {
+    fn from(error: RecordedError) -> Self {
+        Self {
+            event: error.event,
+            execution_id: error.execution_id,
+            state_name: error.state_name,
+            path: error.path,
+            sequence: error.sequence,
+            data: error.data,
+            source: StateMachineError::Replayed(error.message),
+        }
+    }
+}
+
+/// The recorded events of one stream, in the order they were yielded.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::journal::Journal;
+///
+/// let journal = Journal::from_json_lines("")
+///     .expect("An empty journal should always parse");
+///
+/// let expected_result = 0;
+///
+/// let result = journal.entries().len();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Journal {
+    entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Creates an empty [`Journal`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Drives `stream` to completion and records every event it yields.
+    pub async fn collect(mut stream: StateMachineStream) -> Self {
+        let mut journal = Self::new();
+        while let Some(result) = stream.next().await {
+            journal.record(&result);
+        }
+        journal
+    }
+
+    /// Appends the entry recording `result`.
+    pub fn record(&mut self, result: &Result<StreamItem, StreamError>) {
+        self.entries.push(JournalEntry::from(result));
+    }
+
+    /// Returns the recorded entries, in the order they were recorded.
+    #[must_use]
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Serializes the journal as JSON lines, one entry per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JournalError`] if an entry cannot be serialized.
+    pub fn to_json_lines(&self) -> Result<String, JournalError> {
+        self.entries
+            .iter()
+            .try_fold(String::new(), |mut lines, entry| {
+                lines.push_str(&serde_json::to_string(entry)?);
+                lines.push('\n');
+                Ok(lines)
+            })
+    }
+
+    /// Parses a journal from JSON lines, as written by [`to_json_lines`](Journal::to_json_lines)
+    /// or a [`JournalWriter`]. Blank lines are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`JournalError`] naming the first line that is not a valid entry.
+    pub fn from_json_lines(input: &str) -> Result<Self, JournalError> {
+        let entries = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|e| {
+                    JournalError::new(ErrorReason::MalformedLine {
+                        line: index + 1,
+                        details: e.to_string(),
+                    })
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+
+    /// Re-emits the recorded events as a [`StateMachineStream`], in their recorded order.
+    ///
+    /// The replay is deterministic: it yields exactly the recorded items and errors, without
+    /// computing any state or waiting for any timing.
+    #[must_use]
+    pub fn replay(self) -> StateMachineStream {
+        Box::pin(futures_util::stream::iter(
+            self.entries.into_iter().map(Result::from),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use state_maschine::prelude::StateMachine as SMStateMachine;
+
+    use super::*;
+    use crate::tests::fixtures::sample_streaming_super_state::{
+        SampleStateA, SampleStreamingSuperState,
+    };
+    use crate::traits::state_machine::stream::IntoStateMachineStream;
+
+    async fn failing_run_journal() -> Journal {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().force_compute_error = true;
+        Journal::collect(sm.into_stream(Uuid::new_v4())).await
+    }
+
+    #[tokio::test]
+    async fn should_record_events_in_yielded_order_when_collecting_stream() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let journal = Journal::collect(sm.into_stream(Uuid::new_v4())).await;
+
+        let expected_result = (0..journal.entries().len() as u64).collect::<Vec<_>>();
+
+        let result = journal
+            .entries()
+            .iter()
+            .map(|entry| match entry {
+                JournalEntry::Item(item) => item.sequence,
+                JournalEntry::Error(error) => error.sequence,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_survive_json_lines_round_trip_when_run_ends_with_error() {
+        let journal = failing_run_journal().await;
+
+        let expected_result = journal.clone();
+
+        let result = Journal::from_json_lines(
+            &journal
+                .to_json_lines()
+                .expect("A recorded journal should always serialize"),
+        )
+        .expect("A serialized journal should always parse back");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_re_emit_recorded_journal_when_replaying() {
+        let journal = failing_run_journal().await;
+
+        let expected_result = journal.clone();
+
+        let result = Journal::collect(journal.replay()).await;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_display_replayed_error_like_original_when_replaying_failed_run() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().force_compute_error = true;
+        let mut stream = sm.into_stream(Uuid::new_v4());
+        let mut journal = Journal::new();
+        let mut original = None;
+        while let Some(result) = stream.next().await {
+            journal.record(&result);
+            if let Err(error) = result {
+                original = Some(error.to_string());
+            }
+        }
+
+        let expected_result = original;
+
+        let result = journal
+            .replay()
+            .filter_map(|result| std::future::ready(result.err().map(|error| error.to_string())))
+            .next()
+            .await;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_name_offending_line_when_parsing_malformed_journal() {
+        let input = "\n{not json}\n";
+
+        let expected_result = 2;
+
+        let result = match Journal::from_json_lines(input)
+            .expect_err("A malformed line should never parse")
+            .reason
+        {
+            ErrorReason::MalformedLine { line, .. } => line,
+            other => panic!("Expected a malformed line, got: {other}"),
+        };
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serialize_transition_event_like_its_display_when_serializing_stream_event() {
+        let event = StreamEvent::TransitionCompleted;
+
+        let expected_result = serde_json::json!(event.to_string());
+
+        let result = serde_json::to_value(&event).expect("A stream event should always serialize");
+
+        assert_eq!(result, expected_result);
+    }
+}
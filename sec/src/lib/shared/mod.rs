@@ -4,11 +4,22 @@
 //! These components provide reusable logic and strongly-typed representations for common SEC concepts, such as the Central Index Key (CIK).
 //!
 //! ## Modules
+//! - [`batch`]: Provides the batch runner executing many pipelines with bounded concurrency and reporting their outcomes.
+//! - [`checkpoint`]: Provides versioned checkpoints and pluggable sinks for resuming interrupted pipelines.
 //! - [`cik`]: Provides parsing, validation, and formatting utilities for SEC Central Index Keys (CIKs).
+//! - [`company_facts`]: Provides the company-facts document deserialized in one streaming pass, feeding observations to the parser as they are read.
+//! - [`config`]: Provides the layered run configuration loaded from a TOML file, the environment, and overrides.
+//! - [`environment`]: Provides the pipeline environment sharing a run's client, clock, and repository across states.
+//! - [`executor`]: Provides the tokio-backed executor driving SEC states from synchronous code.
+//! - [`journal`]: Provides the JSON-lines journal for recording and replaying state machine streams.
 //! - [`http_client`]: Provides utilities for creating and managing SEC API clients, including user agent handling.
 //! - [`rate_limiter`]: Provides a leaky-bucket rate limiter for pacing outgoing SEC API requests.
 //! - [`request`]: Provides utilities for constructing SEC API requests, ensuring proper URL formatting.
 //! - [`response`]: Provides utilities for handling HTTP responses from SEC endpoints.
+//! - [`response_cache`]: Provides the pluggable cache SEC responses are revalidated against with `ETag` and `Last-Modified`.
+//! - [`response_fixtures`]: Provides the directory of recorded SEC responses a client replays for offline, deterministic runs.
+//! - [`retry_policy`]: Provides the retry policy deciding whether and when failed states are recomputed.
+//! - [`universe`]: Provides the CIK universe a batch runs, read from a plain list, CSV, or SEC company tickers file.
 //! - [`user_agent`]: Provides utilities for creating and validating SEC-compliant user agent strings.
 //!
 //! ## Usage
@@ -19,14 +30,25 @@
 //! - [`crate::implementations`]: Concrete state and state machine implementations that use these shared utilities.
 //! - [`crate::error`]: Error types that may reference shared domain types for detailed error reporting.
 
+pub mod batch;
+pub mod checkpoint;
 pub mod cik;
+pub mod company_facts;
+pub mod config;
 pub mod content_type;
+pub mod environment;
+pub mod executor;
 pub mod financial;
 pub mod headers;
 pub mod http_client;
+pub mod journal;
 pub mod rate_limiter;
 pub mod request;
 pub mod response;
+pub mod response_cache;
+pub mod response_fixtures;
+pub mod retry_policy;
 pub mod status_code;
+pub mod universe;
 pub mod url;
 pub mod user_agent;
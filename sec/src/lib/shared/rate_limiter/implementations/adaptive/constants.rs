@@ -0,0 +1,20 @@
+//! # Adaptive Rate Limiter Constants
+//!
+//! Default tuning of how far and how fast the adaptive limiter slows down and recovers.
+
+use std::time::Duration;
+
+/// Longest spacing between requests the limiter slows down to, however often it is throttled.
+pub const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Factor the spacing between requests is multiplied by on a throttling response.
+pub const DEFAULT_BACKOFF_FACTOR: u32 = 2;
+
+/// Amount the spacing between requests shrinks by on every unthrottled response.
+pub const DEFAULT_RECOVERY_STEP: Duration = Duration::from_millis(10);
+
+/// Time after a slowdown during which further throttling responses do not slow down again.
+///
+/// Requests already in flight when the server starts throttling are all answered with a
+/// throttling status; counting them as one signal keeps a single burst from collapsing the rate.
+pub const DEFAULT_THROTTLE_COOLDOWN: Duration = Duration::from_secs(1);
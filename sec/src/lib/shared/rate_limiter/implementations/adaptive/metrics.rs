@@ -0,0 +1,43 @@
+//! # Rate Limiter Metrics
+//!
+//! Provides the [`RateLimiterMetrics`], a snapshot of an adaptive limiter's pacing.
+
+use std::time::Duration;
+
+/// A snapshot of the pacing of an [`AdaptiveRateLimiter`](super::AdaptiveRateLimiter).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimiterMetrics {
+    /// The current spacing between consecutive requests.
+    pub current_interval: Duration,
+    /// The throttling responses recorded so far, including those within a cooldown.
+    pub throttle_events: u64,
+}
+
+impl RateLimiterMetrics {
+    /// Returns the current rate, in requests per second.
+    #[must_use]
+    pub fn requests_per_second(&self) -> f64 {
+        1.0 / self.current_interval.as_secs_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_invert_interval_when_computing_requests_per_second() {
+        let metrics = RateLimiterMetrics {
+            current_interval: Duration::from_millis(250),
+            throttle_events: 0,
+        };
+
+        let expected_result = true;
+
+        let result = (metrics.requests_per_second() - 4.0).abs() < f64::EPSILON;
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,333 @@
+//! # Adaptive Rate Limiter
+//!
+//! Provides the [`AdaptiveRateLimiter`], a [`RateLimiter`] that paces callers like a leaky bucket
+//! but adjusts its pace to the throttling signals of the server it paces requests to.
+//!
+//! ## Modules
+//!
+//! - [`constants`]: The default slowdown and recovery tuning.
+//! - [`metrics`]: The [`RateLimiterMetrics`] snapshot of the current pacing.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use crate::shared::rate_limiter::traits::RateLimiter;
+use crate::shared::status_code::StatusCode;
+
+use self::constants::{
+    DEFAULT_BACKOFF_FACTOR, DEFAULT_MAX_INTERVAL, DEFAULT_RECOVERY_STEP, DEFAULT_THROTTLE_COOLDOWN,
+};
+
+pub mod constants;
+pub mod metrics;
+
+pub use metrics::RateLimiterMetrics;
+
+/// A leaky-bucket rate limiter that slows down when throttled and recovers gradually.
+///
+/// Paces callers to one every *current interval*, which starts at the fastest allowed pace and
+/// moves AIMD-style with every response [recorded](RateLimiter::record_response):
+///
+/// - A `429 Too Many Requests` or `403 Forbidden` — the SEC's throttling replies — multiplies the
+///   interval by the backoff factor, up to a maximum, and pushes the next permit back accordingly.
+///   Further throttling replies within a cooldown are counted but do not slow down again.
+/// - Any other successful or not-modified response shrinks the interval by the recovery step, down
+///   to the fastest pace.
+///
+/// # Sharing
+///
+/// The pacing state is held behind an `Arc`, so clones share one budget and one current pace.
+/// Independently constructed limiters pace independently.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use sec::shared::rate_limiter::{AdaptiveRateLimiter, RateLimiter};
+/// use sec::shared::status_code::StatusCode;
+///
+/// let limiter = AdaptiveRateLimiter::new(Duration::from_millis(100));
+/// limiter.record_response(&StatusCode::TooManyRequests);
+///
+/// let expected_result = (Duration::from_millis(200), 1);
+///
+/// let metrics = limiter.metrics();
+/// let result = (metrics.current_interval, metrics.throttle_events);
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdaptiveRateLimiter {
+    min_interval: Duration,
+    max_interval: Duration,
+    backoff_factor: u32,
+    recovery_step: Duration,
+    throttle_cooldown: Duration,
+    state: Arc<Mutex<PacingState>>,
+}
+
+/// The pacing shared by the clones of one [`AdaptiveRateLimiter`].
+#[derive(Debug)]
+struct PacingState {
+    interval: Duration,
+    next_permit: Option<Instant>,
+    last_slowdown: Option<Instant>,
+    throttle_events: u64,
+}
+
+impl AdaptiveRateLimiter {
+    /// Creates a new [`AdaptiveRateLimiter`] pacing callers to one every `min_interval` until
+    /// throttled, with the default slowdown and recovery tuning.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_interval` is zero, since a zero interval cannot pace anything.
+    #[must_use]
+    pub fn new(min_interval: Duration) -> Self {
+        assert!(
+            !min_interval.is_zero(),
+            "The pacing interval should be a non-zero interval"
+        );
+        Self {
+            min_interval,
+            max_interval: DEFAULT_MAX_INTERVAL.max(min_interval),
+            backoff_factor: DEFAULT_BACKOFF_FACTOR,
+            recovery_step: DEFAULT_RECOVERY_STEP,
+            throttle_cooldown: DEFAULT_THROTTLE_COOLDOWN,
+            state: Arc::new(Mutex::new(PacingState {
+                interval: min_interval,
+                next_permit: None,
+                last_slowdown: None,
+                throttle_events: 0,
+            })),
+        }
+    }
+
+    /// Sets the longest interval the limiter slows down to; never shorter than the fastest pace.
+    #[must_use]
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval.max(self.min_interval);
+        self
+    }
+
+    /// Sets the factor the interval is multiplied by on a throttling response; at least one.
+    #[must_use]
+    pub fn with_backoff_factor(mut self, backoff_factor: u32) -> Self {
+        self.backoff_factor = backoff_factor.max(1);
+        self
+    }
+
+    /// Sets the amount the interval shrinks by on every unthrottled response.
+    #[must_use]
+    pub const fn with_recovery_step(mut self, recovery_step: Duration) -> Self {
+        self.recovery_step = recovery_step;
+        self
+    }
+
+    /// Sets how long after a slowdown further throttling responses do not slow down again.
+    #[must_use]
+    pub const fn with_throttle_cooldown(mut self, throttle_cooldown: Duration) -> Self {
+        self.throttle_cooldown = throttle_cooldown;
+        self
+    }
+
+    /// Returns a snapshot of the current pace and the throttling responses recorded so far.
+    #[must_use]
+    pub fn metrics(&self) -> RateLimiterMetrics {
+        let state = self.lock();
+        RateLimiterMetrics {
+            current_interval: state.interval,
+            throttle_events: state.throttle_events,
+        }
+    }
+
+    /// Locks the pacing state; a poisoned lock still holds consistent pacing, so it is recovered.
+    fn lock(&self) -> MutexGuard<'_, PacingState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Slows down after a throttling response at `now`, unless still cooling down from the last.
+    fn slow_down(&self, now: Instant) {
+        let mut state = self.lock();
+        state.throttle_events += 1;
+        let cooling_down = state
+            .last_slowdown
+            .is_some_and(|last| now.duration_since(last) < self.throttle_cooldown);
+        if cooling_down {
+            return;
+        }
+        state.interval = state
+            .interval
+            .saturating_mul(self.backoff_factor)
+            .min(self.max_interval);
+        state.last_slowdown = Some(now);
+        let paused_until = now + state.interval;
+        state.next_permit = Some(
+            state
+                .next_permit
+                .map_or(paused_until, |next| next.max(paused_until)),
+        );
+    }
+
+    /// Speeds up by one recovery step after an unthrottled response.
+    fn recover(&self) {
+        let mut state = self.lock();
+        state.interval = state
+            .interval
+            .saturating_sub(self.recovery_step)
+            .max(self.min_interval);
+    }
+}
+
+#[async_trait]
+impl RateLimiter for AdaptiveRateLimiter {
+    async fn await_turn(&self) {
+        let permit = {
+            let mut state = self.lock();
+            let now = Instant::now();
+            let permit = state.next_permit.map_or(now, |next| next.max(now));
+            state.next_permit = Some(permit + state.interval);
+            permit
+        };
+        tokio::time::sleep_until(permit).await;
+    }
+
+    fn record_response(&self, status_code: &StatusCode) {
+        match status_code {
+            StatusCode::TooManyRequests | StatusCode::Forbidden => self.slow_down(Instant::now()),
+            StatusCode::NotModified => self.recover(),
+            status_code if status_code.is_success() => self.recover(),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn limiter() -> AdaptiveRateLimiter {
+        AdaptiveRateLimiter::new(Duration::from_millis(100))
+            .with_max_interval(Duration::from_millis(300))
+            .with_recovery_step(Duration::from_millis(50))
+    }
+
+    #[test]
+    fn should_slow_down_up_to_max_interval_when_throttled_repeatedly() {
+        let limiter = limiter().with_throttle_cooldown(Duration::ZERO);
+        for _ in 0..3 {
+            limiter.record_response(&StatusCode::TooManyRequests);
+        }
+
+        let expected_result = RateLimiterMetrics {
+            current_interval: Duration::from_millis(300),
+            throttle_events: 3,
+        };
+
+        let result = limiter.metrics();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_slow_down_once_when_throttled_within_cooldown() {
+        let limiter = limiter();
+        limiter.record_response(&StatusCode::Forbidden);
+        limiter.record_response(&StatusCode::TooManyRequests);
+
+        let expected_result = RateLimiterMetrics {
+            current_interval: Duration::from_millis(200),
+            throttle_events: 2,
+        };
+
+        let result = limiter.metrics();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_recover_gradually_to_min_interval_when_no_longer_throttled() {
+        let limiter = limiter();
+        limiter.record_response(&StatusCode::TooManyRequests);
+        limiter.record_response(&StatusCode::Ok);
+
+        let expected_result = (Duration::from_millis(150), Duration::from_millis(100));
+
+        let after_one = limiter.metrics().current_interval;
+        limiter.record_response(&StatusCode::NotModified);
+        limiter.record_response(&StatusCode::Ok);
+        let result = (after_one, limiter.metrics().current_interval);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_pace_when_response_is_neither_success_nor_throttling() {
+        let limiter = limiter();
+        limiter.record_response(&StatusCode::TooManyRequests);
+        limiter.record_response(&StatusCode::NotFound);
+
+        let expected_result = Duration::from_millis(200);
+
+        let result = limiter.metrics().current_interval;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_share_pace_with_clones_but_not_with_independent_limiters() {
+        let limiter = limiter();
+        let clone = limiter.clone();
+        let independent = self::limiter();
+        clone.record_response(&StatusCode::TooManyRequests);
+
+        let expected_result = (Duration::from_millis(200), Duration::from_millis(100));
+
+        let result = (
+            limiter.metrics().current_interval,
+            independent.metrics().current_interval,
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_space_permits_by_current_interval_when_awaiting_turns() {
+        let limiter = AdaptiveRateLimiter::new(Duration::from_millis(50));
+        let started = Instant::now();
+        for _ in 0..3 {
+            limiter.await_turn().await;
+        }
+
+        let expected_result = true;
+
+        let result = started.elapsed() >= Duration::from_millis(100);
+
+        assert_eq!(result, expected_result);
+    }
+
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn should_be_send() {
+        assert_send::<AdaptiveRateLimiter>();
+    }
+
+    #[test]
+    fn should_be_sync() {
+        assert_sync::<AdaptiveRateLimiter>();
+    }
+
+    #[test]
+    fn should_be_unpin() {
+        assert_unpin::<AdaptiveRateLimiter>();
+    }
+}
@@ -4,13 +4,17 @@
 //!
 //! ## Modules
 //!
+//! - [`adaptive`]: The [`AdaptiveRateLimiter`], a leaky-bucket limiter slowing down when throttled
+//!   and recovering gradually.
 //! - [`governor`]: The [`GovernorRateLimiter`], a generic leaky-bucket limiter backed by the
 //!   `governor` crate.
-//! - [`sec_rate_limiter`]: The [`SecRateLimiter`], the [`GovernorRateLimiter`] configured for the
+//! - [`sec_rate_limiter`]: The [`SecRateLimiter`], the [`AdaptiveRateLimiter`] configured for the
 //!   SEC EDGAR API's request-rate ceiling.
 
+pub mod adaptive;
 pub mod governor;
 pub mod sec_rate_limiter;
 
+pub use adaptive::{AdaptiveRateLimiter, RateLimiterMetrics};
 pub use governor::GovernorRateLimiter;
 pub use sec_rate_limiter::SecRateLimiter;
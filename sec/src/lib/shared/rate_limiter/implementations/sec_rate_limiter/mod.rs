@@ -6,10 +6,15 @@
 //!
 //! - [`constants`]: Tuning constants such as the minimum spacing between requests.
 
+use std::time::Duration;
+
 use async_trait::async_trait;
 
-use crate::shared::rate_limiter::implementations::governor::GovernorRateLimiter;
+use crate::shared::rate_limiter::implementations::adaptive::{
+    AdaptiveRateLimiter, RateLimiterMetrics,
+};
 use crate::shared::rate_limiter::traits::RateLimiter;
+use crate::shared::status_code::StatusCode;
 
 use self::constants::MIN_REQUEST_INTERVAL;
 
@@ -20,8 +25,10 @@ pub mod constants;
 /// The SEC EDGAR API enforces a limit of 10 requests per second per originating IP; exceeding it
 /// risks the IP being throttled or temporarily blocked. This limiter paces callers to one every
 /// [`MIN_REQUEST_INTERVAL`] (≈ 9 requests/second), keeping throughput safely under that ceiling.
+/// When the SEC throttles anyway, answering `429` or `403`, the pace slows down and then recovers
+/// gradually once the throttling stops.
 ///
-/// Wraps a generic [`GovernorRateLimiter`], supplying only the SEC-specific pacing policy. It is a
+/// Wraps a generic [`AdaptiveRateLimiter`], supplying only the SEC-specific pacing policy. It is a
 /// **single-process, single-machine, in-memory** limiter — not distributed — so its budget is
 /// enforced only among clones that share one instance within a single process.
 ///
@@ -32,7 +39,7 @@ pub mod constants;
 /// clones.
 #[derive(Debug, Clone)]
 pub struct SecRateLimiter {
-    inner: GovernorRateLimiter,
+    inner: AdaptiveRateLimiter,
 }
 
 impl SecRateLimiter {
@@ -47,16 +54,35 @@ impl SecRateLimiter {
     /// ```
     #[must_use]
     pub fn new() -> Self {
+        Self::with_min_interval(MIN_REQUEST_INTERVAL)
+    }
+
+    /// Creates a new [`SecRateLimiter`] pacing callers to one every `min_interval` instead of
+    /// [`MIN_REQUEST_INTERVAL`].
+    ///
+    /// An interval shorter than the default risks exceeding the SEC's request-rate ceiling.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_interval` is zero.
+    #[must_use]
+    pub fn with_min_interval(min_interval: Duration) -> Self {
         Self {
-            inner: GovernorRateLimiter::new(MIN_REQUEST_INTERVAL),
+            inner: AdaptiveRateLimiter::new(min_interval),
         }
     }
 
     /// Returns a reference to the underlying generic limiter.
     #[must_use]
-    pub const fn inner(&self) -> &GovernorRateLimiter {
+    pub const fn inner(&self) -> &AdaptiveRateLimiter {
         &self.inner
     }
+
+    /// Returns a snapshot of the current pace and the throttling responses recorded so far.
+    #[must_use]
+    pub fn metrics(&self) -> RateLimiterMetrics {
+        self.inner.metrics()
+    }
 }
 
 impl Default for SecRateLimiter {
@@ -70,6 +96,10 @@ impl RateLimiter for SecRateLimiter {
     async fn await_turn(&self) {
         self.inner.await_turn().await;
     }
+
+    fn record_response(&self, status_code: &StatusCode) {
+        self.inner.record_response(status_code);
+    }
 }
 
 #[cfg(test)]
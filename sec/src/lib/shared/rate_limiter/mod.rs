@@ -4,18 +4,21 @@
 //!
 //! The limiter is split into a trait layer so the library is not bound to any one rate-limiting
 //! crate: a [`RateLimiter`] contract, a generic [`GovernorRateLimiter`] backed by the `governor`
-//! crate, and a [`SecRateLimiter`] that configures the generic limiter for the SEC EDGAR API's
-//! request-rate ceiling. This layering keeps the pacing mechanism reusable while confining the
+//! crate, an [`AdaptiveRateLimiter`] adjusting its pace to throttling responses, and a
+//! [`SecRateLimiter`] that configures the adaptive limiter for the SEC EDGAR API's request-rate
+//! ceiling. This layering keeps the pacing mechanism reusable while confining the
 //! SEC-specific policy to one type, and lets tests substitute a fake that never blocks.
 //!
 //! ## Modules
 //!
 //! - [`traits`]: The [`RateLimiter`] contract.
-//! - [`implementations`]: The generic [`GovernorRateLimiter`] and the SEC-configured
-//!   [`SecRateLimiter`].
+//! - [`implementations`]: The generic [`GovernorRateLimiter`] and [`AdaptiveRateLimiter`], and the
+//!   SEC-configured [`SecRateLimiter`].
 
 pub mod implementations;
 pub mod traits;
 
-pub use implementations::{GovernorRateLimiter, SecRateLimiter};
+pub use implementations::{
+    AdaptiveRateLimiter, GovernorRateLimiter, RateLimiterMetrics, SecRateLimiter,
+};
 pub use traits::RateLimiter;
@@ -2,6 +2,8 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 
+use crate::shared::status_code::StatusCode;
+
 /// A rate limiter: paces callers so they do not exceed an allowed request rate.
 ///
 /// Abstracts over concrete rate-limiting strategies so the domain layer is not bound to any
@@ -13,4 +15,10 @@ pub trait RateLimiter: Send + Sync + Debug {
     /// Returns immediately when a permit is already available; otherwise it yields until the
     /// next permit becomes available, without busy-waiting.
     async fn await_turn(&self);
+
+    /// Records the status of a response to a request this limiter paced.
+    ///
+    /// Lets an adaptive limiter react to throttling signals; fixed-rate limiters ignore it, which
+    /// is the default.
+    fn record_response(&self, _status_code: &StatusCode) {}
 }
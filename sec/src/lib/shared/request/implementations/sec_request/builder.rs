@@ -24,6 +24,8 @@
 //! assert_eq!(result, expected_result);
 //! ```
 
+use reqwest::Url;
+
 use crate::shared::cik::Cik;
 use crate::shared::request::implementations::sec_request::{SecRequest, SecRequestType};
 
@@ -50,7 +52,10 @@ impl SecRequestBuilder {
     /// Selects the [`FetchAllCompanyFacts`](SecRequestType::FetchAllCompanyFacts) request kind.
     #[must_use]
     pub const fn all_company_facts(self) -> AllCompanyFactsBuilder<NoCik> {
-        AllCompanyFactsBuilder { cik: NoCik }
+        AllCompanyFactsBuilder {
+            cik: NoCik,
+            url_prefix: None,
+        }
     }
 }
 
@@ -67,13 +72,29 @@ impl Default for SecRequestBuilder {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AllCompanyFactsBuilder<C> {
     cik: C,
+    url_prefix: Option<Url>,
+}
+
+impl<C> AllCompanyFactsBuilder<C> {
+    /// Targets `url_prefix` instead of the SEC's company-facts endpoint, e.g. a mirror or a local
+    /// test server; the zero-padded CIK and `.json` are appended to it.
+    #[must_use]
+    pub fn url_prefix(self, url_prefix: Url) -> Self {
+        Self {
+            url_prefix: Some(url_prefix),
+            ..self
+        }
+    }
 }
 
 impl AllCompanyFactsBuilder<NoCik> {
     /// Sets the CIK, unlocking [`build`](AllCompanyFactsBuilder::build).
     #[must_use]
-    pub const fn cik(self, cik: Cik) -> AllCompanyFactsBuilder<Cik> {
-        AllCompanyFactsBuilder { cik }
+    pub fn cik(self, cik: Cik) -> AllCompanyFactsBuilder<Cik> {
+        AllCompanyFactsBuilder {
+            cik,
+            url_prefix: self.url_prefix,
+        }
     }
 }
 
@@ -98,7 +119,10 @@ impl AllCompanyFactsBuilder<Cik> {
     #[must_use]
     pub fn build(self) -> SecRequest {
         let request_type = SecRequestType::FetchAllCompanyFacts { cik: self.cik };
-        SecRequest::from_request_type(request_type)
+        match &self.url_prefix {
+            Some(url_prefix) => SecRequest::from_request_type_with_prefix(request_type, url_prefix),
+            None => SecRequest::from_request_type(request_type),
+        }
     }
 }
 
@@ -130,6 +154,27 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_append_cik_to_custom_prefix_when_url_prefix_is_set() {
+        let cik = Cik::new("0001234567").expect("Hardcoded CIK should be valid");
+        let url_prefix = Url::parse("http://localhost:8080/companyfacts/CIK")
+            .expect("Hardcoded URL should always be valid");
+
+        let expected_result = Url::parse("http://localhost:8080/companyfacts/CIK0001234567.json")
+            .expect("Hardcoded URL should always be valid");
+
+        let result = SecRequest::builder()
+            .all_company_facts()
+            .url_prefix(url_prefix)
+            .cik(cik)
+            .build()
+            .inner()
+            .url()
+            .clone();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_produce_get_method_when_building_fetch_all_company_facts_request() {
         let cik = Cik::new("0001234567").expect("Hardcoded CIK should be valid");
@@ -10,8 +10,9 @@
 //! - [`constants`]: The endpoint URL fragments.
 
 use reqwest::{Method, Request, Url};
+use serde::de::Error as _;
 use serde::ser::SerializeStruct;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::shared::{cik::Cik, request::SecRequest as SecRequestTrait};
 
@@ -41,6 +42,27 @@ impl Serialize for SecRequest {
     }
 }
 
+/// Rebuilds the request from the `url` and `method` fields emitted by its [`Serialize`] impl.
+///
+/// SEC requests carry neither headers nor a body of their own (the client supplies the
+/// `User-Agent`), so the two fields are enough to restore an equivalent request.
+impl<'de> Deserialize<'de> for SecRequest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct SerializedSecRequest {
+            url: String,
+            method: String,
+        }
+
+        let serialized = SerializedSecRequest::deserialize(deserializer)?;
+        let url = Url::parse(&serialized.url).map_err(D::Error::custom)?;
+        let method = Method::from_bytes(serialized.method.as_bytes()).map_err(D::Error::custom)?;
+        Ok(Self {
+            inner: Request::new(method, url),
+        })
+    }
+}
+
 impl SecRequest {
     /// Creates a new [`SecRequestBuilder`] for constructing an [`SecRequest`].
     ///
@@ -70,15 +92,27 @@ impl SecRequest {
         self.inner
     }
 
-    /// Creates an [`SecRequest`] from a fully-formed [`SecRequestType`].
+    /// Creates an [`SecRequest`] from a fully-formed [`SecRequestType`], targeting the SEC's
+    /// own endpoints.
     pub(crate) fn from_request_type(request_type: SecRequestType) -> Self {
+        let url_prefix =
+            Url::parse(SEC_COMPANY_FACTS_URL_PREFIX).expect("Hardcoded URL should always be valid");
+        Self::from_request_type_with_prefix(request_type, &url_prefix)
+    }
+
+    /// Creates an [`SecRequest`] from a fully-formed [`SecRequestType`], with the CIK appended
+    /// to `url_prefix` instead of the SEC's own company-facts endpoint.
+    pub(crate) fn from_request_type_with_prefix(
+        request_type: SecRequestType,
+        url_prefix: &Url,
+    ) -> Self {
         match request_type {
             SecRequestType::FetchAllCompanyFacts { cik } => {
-                let url =
-                    format!("{SEC_COMPANY_FACTS_URL_PREFIX}{cik}{SEC_COMPANY_FACTS_URL_SUFFIX}");
+                let url = format!("{url_prefix}{cik}{SEC_COMPANY_FACTS_URL_SUFFIX}");
                 let request = Request::new(
                     Method::GET,
-                    Url::parse(&url).expect("Hardcoded URL should always be valid"),
+                    Url::parse(&url)
+                        .expect("A URL prefix followed by a CIK should always be valid"),
                 );
                 Self { inner: request }
             }
@@ -209,6 +243,34 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_restore_equal_request_when_deserializing_serialized_request() {
+        let cik = Cik::new("0001234567").expect("Hardcoded CIK should be valid");
+        let request = SecRequest::builder().all_company_facts().cik(cik).build();
+        let serialized =
+            serde_json::to_value(&request).expect("SecRequest should serialize to JSON");
+
+        let expected_result = (request, Method::GET);
+
+        let result: SecRequest =
+            serde_json::from_value(serialized).expect("A serialized SecRequest should deserialize");
+        let method = result.inner().method().clone();
+        let result = (result, method);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_when_url_is_invalid() {
+        let serialized = serde_json::json!({ "url": "not a url", "method": "GET" });
+
+        let expected_result = true;
+
+        let result = serde_json::from_value::<SecRequest>(serialized).is_err();
+
+        assert_eq!(result, expected_result);
+    }
+
     fn assert_send<T: Send>() {}
     fn assert_sync<T: Sync>() {}
     fn assert_unpin<T: Unpin>() {}
@@ -5,14 +5,14 @@
 use std::fmt::{self, Display, Formatter};
 use std::hash::{DefaultHasher, Hash, Hasher};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A precomputed `u64` digest of a response body.
 ///
 /// Enables cheap `Hash` and `Ord` for [`SecResponse`](super::SecResponse) without re-serializing
 /// a large JSON body. The digest is taken over the *raw* body text at construction (before JSON
 /// parsing), so it reflects the exact bytes received.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct BodyDigest(u64);
 
 impl BodyDigest {
@@ -20,7 +20,8 @@ impl BodyDigest {
     ///
     /// This is the only construction path — guaranteeing that two responses with identical
     /// raw body text always produce equal digests. Without this, re-serialized JSON could
-    /// differ in whitespace or key order, breaking equality and hashing.
+    /// differ in whitespace or key order, breaking equality and hashing. Deserializing a
+    /// checkpointed digest restores a value originally computed here, so the guarantee holds.
     ///
     /// # Examples
     ///
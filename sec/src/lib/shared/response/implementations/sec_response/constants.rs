@@ -0,0 +1,9 @@
+//! # SEC Response Constants
+//!
+//! Limits applied when reading an SEC response body.
+
+/// Largest response body accepted unless configured otherwise, in bytes.
+///
+/// 256 MiB leaves ample room for the company facts of the largest filers while stopping a runaway
+/// or maliciously compressed body before it exhausts memory.
+pub const DEFAULT_MAX_BODY_SIZE: u64 = 256 * 1024 * 1024;
@@ -26,6 +26,21 @@ impl InvalidSecResponse {
     pub const fn new(reason: ErrorReason) -> Self {
         Self { reason }
     }
+
+    /// Returns `true` if the same request may succeed when sent again.
+    ///
+    /// Rate limiting (429), server errors (5xx), and interrupted body reads are transient; any
+    /// other validation failure would repeat on every attempt.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        match &self.reason {
+            ErrorReason::InvalidStatusCode { status_code } => status_code.is_transient(),
+            ErrorReason::FailedBodyRead { .. } => true,
+            ErrorReason::InvalidContentType { .. }
+            | ErrorReason::InvalidBody { .. }
+            | ErrorReason::BodyTooLarge { .. } => false,
+        }
+    }
 }
 
 /// Enum representing the reason why an HTTP response failed SEC validation.
@@ -52,6 +67,11 @@ pub enum ErrorReason {
         /// A description of the read failure.
         details: String,
     },
+    /// The body, once decompressed, is larger than the configured limit.
+    BodyTooLarge {
+        /// The largest body accepted, in bytes.
+        limit: u64,
+    },
 }
 
 impl fmt::Display for ErrorReason {
@@ -75,6 +95,9 @@ impl fmt::Display for ErrorReason {
             Self::FailedBodyRead { details } => {
                 write!(f, "Failed to read response body: '{details}'")
             }
+            Self::BodyTooLarge { limit } => {
+                write!(f, "Response body exceeds the limit of {limit} bytes")
+            }
         }
     }
 }
@@ -144,4 +167,56 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_be_transient_when_status_code_is_too_many_requests() {
+        let error = InvalidSecResponse::new(ErrorReason::InvalidStatusCode {
+            status_code: StatusCode::TooManyRequests,
+        });
+
+        let expected_result = true;
+
+        let result = error.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_be_transient_when_status_code_is_server_error() {
+        let error = InvalidSecResponse::new(ErrorReason::InvalidStatusCode {
+            status_code: StatusCode::ServiceUnavailable,
+        });
+
+        let expected_result = true;
+
+        let result = error.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_be_transient_when_status_code_is_not_found() {
+        let error = InvalidSecResponse::new(ErrorReason::InvalidStatusCode {
+            status_code: StatusCode::NotFound,
+        });
+
+        let expected_result = false;
+
+        let result = error.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_be_transient_when_body_is_invalid() {
+        let error = InvalidSecResponse::new(ErrorReason::InvalidBody {
+            details: "expected value at line 1 column 1".to_string(),
+        });
+
+        let expected_result = false;
+
+        let result = error.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
 }
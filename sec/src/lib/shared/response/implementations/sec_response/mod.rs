@@ -6,7 +6,9 @@
 //! ## Modules
 //!
 //! - [`body_digest`]: The [`BodyDigest`] backing efficient `Hash`/`Ord`.
+//! - [`constants`]: The default limit on the size of a response body.
 //! - [`error`]: The [`InvalidSecResponse`] error raised during validation.
+//! - [`snapshot`]: The [`SecResponseSnapshot`] keeping the full response for checkpoints.
 
 use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
@@ -17,16 +19,22 @@ use serde::{Serialize, Serializer};
 
 use crate::shared::content_type::ContentType;
 use crate::shared::headers::Headers;
+use crate::shared::response_cache::CacheStatus;
 use crate::shared::status_code::StatusCode;
 use crate::shared::url::Url;
 
 use super::super::traits::SecResponse as SecResponseTrait;
 
 use self::body_digest::BodyDigest;
+use self::constants::DEFAULT_MAX_BODY_SIZE;
 use self::error::{ErrorReason, InvalidSecResponse};
 
 pub mod body_digest;
+pub mod constants;
 pub mod error;
+pub mod snapshot;
+
+pub use snapshot::SecResponseSnapshot;
 
 /// A validated SEC API response.
 ///
@@ -35,6 +43,9 @@ pub mod error;
 /// invariants. Built from a raw response via
 /// [`from_inner`](crate::shared::response::SecResponse::from_inner), or from parts via
 /// [`SecResponse::from_parts`].
+///
+/// A response also records its [`CacheStatus`], i.e. whether it was downloaded or served from a
+/// response cache. It is metadata only: two responses differing in nothing else compare equal.
 #[derive(Debug, Clone)]
 pub struct SecResponse {
     url: Url,
@@ -43,6 +54,7 @@ pub struct SecResponse {
     status_code: StatusCode,
     body: serde_json::Value,
     body_digest: BodyDigest,
+    cache_status: CacheStatus,
 }
 
 impl PartialEq for SecResponse {
@@ -105,27 +117,155 @@ impl SecResponse {
             status_code,
             body,
             body_digest,
+            cache_status: CacheStatus::default(),
         }
     }
 
+    /// Validates `inner` like [`from_inner`](SecResponseTrait::from_inner), additionally rejecting
+    /// a body larger than `max_body_size` bytes once decompressed.
+    ///
+    /// The body is read chunk by chunk, so an oversized body is abandoned as soon as it crosses
+    /// the limit instead of being buffered whole.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidSecResponse`] if the status is not a success, the content type is not
+    /// JSON, the body cannot be read, is larger than `max_body_size`
+    /// ([`ErrorReason::BodyTooLarge`]), or is not valid JSON.
+    pub async fn from_inner_with_limit(
+        mut inner: reqwest::Response,
+        max_body_size: u64,
+    ) -> Result<Self, InvalidSecResponse> {
+        let url = Url::from(inner.url().clone());
+        let status_code = StatusCode::from(inner.status());
+        let raw_headers: HashMap<String, String> = inner
+            .headers()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
+            .collect();
+        let headers = Headers::new(raw_headers);
+        let content_type = headers.content_type().clone();
+
+        validate_head(status_code, &content_type)?;
+
+        let body_bytes = read_body(&mut inner, max_body_size).await?;
+        let body_text = std::str::from_utf8(&body_bytes).map_err(|e| {
+            InvalidSecResponse::new(ErrorReason::InvalidBody {
+                details: e.to_string(),
+            })
+        })?;
+
+        let body_digest = BodyDigest::from_body_text(body_text);
+
+        let body = serde_json::from_str(body_text).map_err(|e| {
+            InvalidSecResponse::new(ErrorReason::InvalidBody {
+                details: e.to_string(),
+            })
+        })?;
+
+        Ok(Self {
+            url,
+            headers,
+            content_type,
+            status_code,
+            body,
+            body_digest,
+            cache_status: CacheStatus::default(),
+        })
+    }
+
     /// Returns the precomputed body digest.
     #[must_use]
     pub const fn body_digest(&self) -> BodyDigest {
         self.body_digest
     }
+
+    /// Returns how the response cache took part in producing the response.
+    #[must_use]
+    pub const fn cache_status(&self) -> CacheStatus {
+        self.cache_status
+    }
+
+    /// Returns the same response, recorded as produced with `cache_status`.
+    #[must_use]
+    pub fn with_cache_status(self, cache_status: CacheStatus) -> Self {
+        Self {
+            cache_status,
+            ..self
+        }
+    }
 }
 
 impl Serialize for SecResponse {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut state = serializer.serialize_struct("SecResponse", 4)?;
+        let mut state = serializer.serialize_struct("SecResponse", 5)?;
         state.serialize_field("url", &self.url.to_string())?;
         state.serialize_field("status_code", &self.status_code.to_string())?;
         state.serialize_field("content_type", &self.content_type.to_string())?;
         state.serialize_field("headers", &self.headers)?;
+        state.serialize_field("cache_status", &self.cache_status)?;
         state.end()
     }
 }
 
+/// Checks that a response with `status_code` and `content_type` may carry an SEC JSON body.
+///
+/// # Errors
+///
+/// Returns an [`InvalidSecResponse`] if the status is not a success or the content type is not
+/// JSON.
+pub(crate) fn validate_head(
+    status_code: StatusCode,
+    content_type: &ContentType,
+) -> Result<(), InvalidSecResponse> {
+    if !status_code.is_success() {
+        return Err(InvalidSecResponse::new(ErrorReason::InvalidStatusCode {
+            status_code,
+        }));
+    }
+
+    if *content_type != ContentType::Json {
+        return Err(InvalidSecResponse::new(ErrorReason::InvalidContentType {
+            content_type: content_type.clone(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Reads the body of `inner`, failing as soon as it grows past `max_body_size` bytes.
+async fn read_body(
+    inner: &mut reqwest::Response,
+    max_body_size: u64,
+) -> Result<Vec<u8>, InvalidSecResponse> {
+    let too_large = || {
+        InvalidSecResponse::new(ErrorReason::BodyTooLarge {
+            limit: max_body_size,
+        })
+    };
+    // Only known when the body is not compressed; a compressed body is checked as it inflates.
+    if inner
+        .content_length()
+        .is_some_and(|length| length > max_body_size)
+    {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = inner.chunk().await.map_err(|e| {
+        InvalidSecResponse::new(ErrorReason::FailedBodyRead {
+            details: e.to_string(),
+        })
+    })? {
+        let length = u64::try_from(body.len() + chunk.len()).unwrap_or(u64::MAX);
+        if length > max_body_size {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
 impl Display for SecResponse {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{} {}", self.status_code, self.url)
@@ -142,50 +282,7 @@ impl SecResponseTrait for SecResponse {
     type Error = InvalidSecResponse;
 
     async fn from_inner(inner: Self::Inner) -> Result<Self, Self::Error> {
-        let url = Url::from(inner.url().clone());
-        let status_code = StatusCode::from(inner.status());
-        let raw_headers: HashMap<String, String> = inner
-            .headers()
-            .iter()
-            .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("").to_string()))
-            .collect();
-        let headers = Headers::new(raw_headers);
-        let content_type = headers.content_type().clone();
-
-        if !status_code.is_success() {
-            return Err(InvalidSecResponse::new(ErrorReason::InvalidStatusCode {
-                status_code,
-            }));
-        }
-
-        if content_type != ContentType::Json {
-            return Err(InvalidSecResponse::new(ErrorReason::InvalidContentType {
-                content_type,
-            }));
-        }
-
-        let body_text = inner.text().await.map_err(|e| {
-            InvalidSecResponse::new(ErrorReason::FailedBodyRead {
-                details: e.to_string(),
-            })
-        })?;
-
-        let body_digest = BodyDigest::from_body_text(&body_text);
-
-        let body = serde_json::from_str(&body_text).map_err(|e| {
-            InvalidSecResponse::new(ErrorReason::InvalidBody {
-                details: e.to_string(),
-            })
-        })?;
-
-        Ok(Self {
-            url,
-            headers,
-            content_type,
-            status_code,
-            body,
-            body_digest,
-        })
+        Self::from_inner_with_limit(inner, DEFAULT_MAX_BODY_SIZE).await
     }
 
     fn url(&self) -> &Self::Url {
@@ -0,0 +1,119 @@
+//! # SEC Response Snapshot
+//!
+//! Provides the [`SecResponseSnapshot`], a lossless serializable form of an [`SecResponse`] used
+//! in checkpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::content_type::ContentType;
+use crate::shared::headers::Headers;
+use crate::shared::response_cache::CacheStatus;
+use crate::shared::status_code::StatusCode;
+use crate::shared::url::Url;
+
+use super::SecResponse;
+use super::body_digest::BodyDigest;
+
+/// A lossless, serializable representation of an [`SecResponse`].
+///
+/// [`SecResponse`]'s own [`Serialize`] impl omits the body to keep stream logs small; this
+/// snapshot keeps it, along with the original [`BodyDigest`], so a restored response compares
+/// equal to the one that was received.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecResponseSnapshot {
+    /// The URL the response was received from.
+    pub url: Url,
+    /// The validated response headers.
+    pub headers: Headers,
+    /// The response content type.
+    pub content_type: ContentType,
+    /// The response status code.
+    pub status_code: StatusCode,
+    /// The parsed JSON body.
+    pub body: serde_json::Value,
+    /// The digest of the raw body text as originally received.
+    pub body_digest: BodyDigest,
+    /// How the response cache took part in producing the response; `miss` if absent.
+    #[serde(default)]
+    pub cache_status: CacheStatus,
+}
+
+impl From<&SecResponse> for SecResponseSnapshot {
+    fn from(response: &SecResponse) -> Self {
+        Self {
+            url: response.url.clone(),
+            headers: response.headers.clone(),
+            content_type: response.content_type.clone(),
+            status_code: response.status_code,
+            body: response.body.clone(),
+            body_digest: response.body_digest,
+            cache_status: response.cache_status,
+        }
+    }
+}
+
+/// Restores the response without re-running validation: a snapshot is only ever taken of an
+/// already-validated [`SecResponse`].
+impl From<SecResponseSnapshot> for SecResponse {
+    fn from(snapshot: SecResponseSnapshot) -> Self {
+        Self {
+            url: snapshot.url,
+            headers: snapshot.headers,
+            content_type: snapshot.content_type,
+            status_code: snapshot.status_code,
+            body: snapshot.body,
+            body_digest: snapshot.body_digest,
+            cache_status: snapshot.cache_status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::shared::response::traits::sec::SecResponse as SecResponseTrait;
+
+    fn sample_response() -> SecResponse {
+        SecResponse::from_parts(
+            Url::from_string(
+                "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json".into(),
+            )
+            .expect("Hardcoded URL should always be valid"),
+            Headers::new(HashMap::new()),
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({ "cik": 1_067_983, "entityName": "BERKSHIRE HATHAWAY INC", "facts": {} }),
+        )
+    }
+
+    #[test]
+    fn should_restore_equal_response_when_round_tripping_through_json() {
+        let response = sample_response();
+        let serialized = serde_json::to_string(&SecResponseSnapshot::from(&response))
+            .expect("A response snapshot should always serialize");
+
+        let expected_result = response;
+
+        let result = SecResponse::from(
+            serde_json::from_str::<SecResponseSnapshot>(&serialized)
+                .expect("A serialized response snapshot should always deserialize"),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_body_when_capturing_snapshot() {
+        let response = sample_response();
+
+        let expected_result = response.body().clone();
+
+        let result = SecResponseSnapshot::from(&response).body;
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,50 @@
+//! # Cache Status
+//!
+//! Provides the [`CacheStatus`] recording how the response cache took part in producing a
+//! response.
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// How the response cache took part in producing a response.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheStatus {
+    /// No usable cached response existed; the full body was downloaded.
+    #[default]
+    Miss,
+    /// A cached response young enough to be served without contacting the SEC was used.
+    Hit,
+    /// A cached response was confirmed by the SEC as unchanged and used.
+    Revalidated,
+}
+
+impl Display for CacheStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Miss => write!(f, "miss"),
+            Self::Hit => write!(f, "hit"),
+            Self::Revalidated => write!(f, "revalidated"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_serialize_like_display_when_serializing_to_json() {
+        let expected_result = serde_json::json!(CacheStatus::Revalidated.to_string());
+
+        let result = serde_json::to_value(CacheStatus::Revalidated)
+            .expect("A cache status should always serialize");
+
+        assert_eq!(result, expected_result);
+    }
+}
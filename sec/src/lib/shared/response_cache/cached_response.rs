@@ -0,0 +1,171 @@
+//! # Cached Response
+//!
+//! Provides the [`CachedResponse`], the entry a [`ResponseCache`](super::ResponseCache) stores
+//! per request URL.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::response::implementations::sec_response::body_digest::BodyDigest;
+use crate::shared::response::implementations::sec_response::{SecResponse, SecResponseSnapshot};
+
+use super::CacheStatus;
+
+/// A validated SEC response kept by a response cache, with the time it was stored.
+///
+/// Holds the lossless [`SecResponseSnapshot`], so a response served from the cache compares
+/// equal to the one originally received.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    stored_at: DateTime<Utc>,
+    response: SecResponseSnapshot,
+}
+
+impl CachedResponse {
+    /// Creates a [`CachedResponse`] keeping `response`, stored at `stored_at`.
+    #[must_use]
+    pub fn new(response: &SecResponse, stored_at: DateTime<Utc>) -> Self {
+        Self {
+            stored_at,
+            response: SecResponseSnapshot::from(response),
+        }
+    }
+
+    /// Returns the time the response was stored or last revalidated.
+    #[must_use]
+    pub const fn stored_at(&self) -> DateTime<Utc> {
+        self.stored_at
+    }
+
+    /// Returns the same entry, stored or revalidated at `stored_at`.
+    #[must_use]
+    pub fn restamped(self, stored_at: DateTime<Utc>) -> Self {
+        Self { stored_at, ..self }
+    }
+
+    /// Returns the `ETag` the response was received with, if any.
+    #[must_use]
+    pub fn etag(&self) -> Option<&str> {
+        self.response.headers.etag()
+    }
+
+    /// Returns the `Last-Modified` date the response was received with, if any.
+    #[must_use]
+    pub fn last_modified(&self) -> Option<&str> {
+        self.response.headers.last_modified()
+    }
+
+    /// Returns the digest of the body of the response as originally received.
+    #[must_use]
+    pub const fn body_digest(&self) -> BodyDigest {
+        self.response.body_digest
+    }
+
+    /// Returns `true` if the entry was stored less than `max_age` before `now`.
+    ///
+    /// An entry stored after `now`, e.g. by a machine whose clock runs ahead, is never fresh.
+    #[must_use]
+    pub fn is_fresh(&self, max_age: Duration, now: DateTime<Utc>) -> bool {
+        (now - self.stored_at)
+            .to_std()
+            .is_ok_and(|age| age < max_age)
+    }
+
+    /// Restores the cached response, recording that it was produced with `cache_status`.
+    #[must_use]
+    pub fn into_response(self, cache_status: CacheStatus) -> SecResponse {
+        SecResponse::from(self.response).with_cache_status(cache_status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::shared::content_type::ContentType;
+    use crate::shared::headers::Headers;
+    use crate::shared::status_code::StatusCode;
+    use crate::shared::url::Url;
+
+    fn stored_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0)
+            .single()
+            .expect("A hardcoded timestamp should always be valid")
+    }
+
+    fn sample_entry() -> CachedResponse {
+        let headers = Headers::new(HashMap::from([
+            ("etag".to_string(), "\"abc\"".to_string()),
+            (
+                "last-modified".to_string(),
+                "Wed, 31 Dec 2025 18:00:00 GMT".to_string(),
+            ),
+        ]));
+        let response = SecResponse::from_parts(
+            Url::from_string(
+                "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json".into(),
+            )
+            .expect("Hardcoded URL should always be valid"),
+            headers,
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({ "cik": 1_067_983 }),
+        );
+        CachedResponse::new(&response, stored_at())
+    }
+
+    #[test]
+    fn should_expose_validators_of_response_when_cached() {
+        let entry = sample_entry();
+
+        let expected_result = (Some("\"abc\""), Some("Wed, 31 Dec 2025 18:00:00 GMT"));
+
+        let result = (entry.etag(), entry.last_modified());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_be_fresh_when_younger_than_max_age() {
+        let entry = sample_entry();
+        let now = stored_at() + chrono::Duration::seconds(30);
+
+        let expected_result = (true, false);
+
+        let result = (
+            entry.is_fresh(Duration::from_secs(60), now),
+            entry.is_fresh(Duration::from_secs(30), now),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_be_fresh_when_stored_in_future() {
+        let entry = sample_entry();
+        let now = stored_at() - chrono::Duration::seconds(1);
+
+        let expected_result = false;
+
+        let result = entry.is_fresh(Duration::from_secs(60), now);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_record_cache_status_when_restoring_response() {
+        let expected_result = CacheStatus::Hit;
+
+        let result = sample_entry()
+            .into_response(CacheStatus::Hit)
+            .cache_status();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,11 @@
+//! # Response Cache Constants
+//!
+//! Defaults of the included response caches.
+
+use std::num::NonZeroUsize;
+
+/// Number of responses an [`InMemoryResponseCache`](super::InMemoryResponseCache) keeps unless
+/// configured otherwise.
+///
+/// Company-facts payloads run to several megabytes each, so the default stays small.
+pub const DEFAULT_IN_MEMORY_CAPACITY: NonZeroUsize = NonZeroUsize::new(64).unwrap();
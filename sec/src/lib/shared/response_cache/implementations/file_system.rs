@@ -0,0 +1,236 @@
+//! # File System Response Cache
+//!
+//! Provides the [`FileSystemResponseCache`], a [`ResponseCache`] that stores each cached response
+//! as a JSON file in a directory, so responses are revalidated rather than re-downloaded across
+//! runs.
+
+use std::fmt::Write as _;
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tempfile::NamedTempFile;
+
+use crate::shared::response_cache::traits::ResponseCache;
+use crate::shared::response_cache::{CachedResponse, ResponseCacheError};
+
+/// A [`ResponseCache`] storing one JSON file per request URL in a directory.
+///
+/// The directory is created on first store. Each write goes to a temporary file of its own that
+/// is then renamed over the previous entry, so neither a crash mid-write nor concurrent stores of
+/// the same URL leave a truncated entry behind.
+///
+/// Every byte of a URL other than an ASCII alphanumeric or `-` is escaped as `_` and two hex
+/// digits in file names, so URLs cannot escape the directory, and different URLs never share an
+/// entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystemResponseCache {
+    directory: PathBuf,
+}
+
+impl FileSystemResponseCache {
+    /// Creates a new [`FileSystemResponseCache`] storing responses in `directory`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec::shared::response_cache::FileSystemResponseCache;
+    ///
+    /// let cache = FileSystemResponseCache::new("cache");
+    ///
+    /// let expected_result = std::path::Path::new("cache");
+    ///
+    /// let result = cache.directory();
+    ///
+    /// assert_eq!(result, expected_result);
+    /// ```
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Returns the directory the responses are stored in.
+    #[must_use]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the path of the cache file for `url`.
+    #[must_use]
+    pub fn path_for(&self, url: &str) -> PathBuf {
+        self.directory.join(file_name_for(url))
+    }
+}
+
+/// Returns the name of the JSON file kept for `url`, with every byte other than an ASCII
+/// alphanumeric or `-` escaped as `_` followed by its two uppercase hex digits.
+///
+/// The escape character is itself escaped, so the encoding is injective: different URLs always
+/// map to different file names.
+pub(crate) fn file_name_for(url: &str) -> String {
+    let mut file_stem = String::with_capacity(url.len());
+    for byte in url.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'-' {
+            file_stem.push(char::from(byte));
+        } else {
+            let _ = write!(file_stem, "_{byte:02X}");
+        }
+    }
+    format!("{file_stem}.json")
+}
+
+/// Writes `contents` to `path` in `directory`, creating the directory if needed.
+///
+/// The contents go to a uniquely named temporary file in `directory` that is renamed over `path`
+/// once complete, so concurrent writes of the same `path` never share a temporary file, and the
+/// last rename wins whole.
+pub(crate) async fn write_atomically(
+    directory: &Path,
+    path: &Path,
+    contents: Vec<u8>,
+) -> io::Result<()> {
+    tokio::fs::create_dir_all(directory).await?;
+    let directory = directory.to_path_buf();
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let mut file = NamedTempFile::new_in(&directory)?;
+        file.write_all(&contents)?;
+        file.persist(&path)?;
+        Ok(())
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+#[async_trait]
+impl ResponseCache for FileSystemResponseCache {
+    async fn load(&self, url: &str) -> Result<Option<CachedResponse>, ResponseCacheError> {
+        match tokio::fs::read(self.path_for(url)).await {
+            Ok(contents) => Ok(Some(serde_json::from_slice(&contents)?)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn store(&self, url: &str, entry: &CachedResponse) -> Result<(), ResponseCacheError> {
+        let contents = serde_json::to_vec(entry)?;
+        write_atomically(&self.directory, &self.path_for(url), contents).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::shared::content_type::ContentType;
+    use crate::shared::headers::Headers;
+    use crate::shared::response::implementations::sec_response::SecResponse;
+    use crate::shared::status_code::StatusCode;
+    use crate::shared::url::Url;
+
+    const URL: &str = "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json";
+
+    fn temporary_cache() -> FileSystemResponseCache {
+        FileSystemResponseCache::new(
+            std::env::temp_dir().join(format!("sec-response-cache-{}", Uuid::new_v4())),
+        )
+    }
+
+    #[test]
+    fn should_escape_url_into_file_name_when_building_path_for_url() {
+        let cache = FileSystemResponseCache::new("cache");
+
+        let expected_result = PathBuf::from("cache").join(
+            "https_3A_2F_2Fdata_2Esec_2Egov_2Fapi_2Fxbrl_2Fcompanyfacts_2FCIK0001067983_2Ejson.json",
+        );
+
+        let result = cache.path_for(URL);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_build_different_file_names_when_urls_differ_only_in_escaped_characters() {
+        let expected_result = false;
+
+        let result = file_name_for("https://example.com/a_b")
+            == file_name_for("https://example.com/a.b")
+            || file_name_for("https://example.com/a_2Eb")
+                == file_name_for("https://example.com/a.b");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_load_stored_entry_when_url_was_stored() {
+        let cache = temporary_cache();
+        let response = SecResponse::from_parts(
+            Url::from_string(URL.to_string()).expect("Hardcoded URL should always be valid"),
+            Headers::new(HashMap::from([("etag".to_string(), "\"abc\"".to_string())])),
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({ "cik": 1_067_983 }),
+        );
+        let entry = CachedResponse::new(&response, Utc::now());
+
+        let expected_result = Some(entry.clone());
+
+        cache
+            .store(URL, &entry)
+            .await
+            .expect("Storing to a temporary directory should always succeed");
+        let result = cache
+            .load(URL)
+            .await
+            .expect("Loading a stored entry should always succeed");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_load_stored_entry_when_same_url_is_stored_concurrently() {
+        let cache = temporary_cache();
+        let response = SecResponse::from_parts(
+            Url::from_string(URL.to_string()).expect("Hardcoded URL should always be valid"),
+            Headers::new(HashMap::new()),
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({ "cik": 1_067_983 }),
+        );
+        let entry = CachedResponse::new(&response, Utc::now());
+
+        let expected_result = Some(entry.clone());
+
+        let stores = (0..8).map(|_| cache.store(URL, &entry));
+        for stored in futures_util::future::join_all(stores).await {
+            stored.expect("Concurrent stores of the same URL should always succeed");
+        }
+        let result = cache
+            .load(URL)
+            .await
+            .expect("Loading a stored entry should always succeed");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_none_when_url_was_never_stored() {
+        let cache = temporary_cache();
+
+        let expected_result = None;
+
+        let result = cache
+            .load(URL)
+            .await
+            .expect("Loading from a missing directory should not fail");
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,217 @@
+//! # In-Memory Response Cache
+//!
+//! Provides the [`InMemoryResponseCache`], a [`ResponseCache`] keeping the most recently used
+//! responses in memory.
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, PoisonError};
+
+use async_trait::async_trait;
+
+use crate::shared::response_cache::constants::DEFAULT_IN_MEMORY_CAPACITY;
+use crate::shared::response_cache::traits::ResponseCache;
+use crate::shared::response_cache::{CachedResponse, ResponseCacheError};
+
+/// A [`ResponseCache`] keeping up to `capacity` responses in memory, evicting the least recently
+/// used one when full.
+///
+/// Both loading and storing count as a use. The entries live only as long as the cache, so
+/// responses are revalidated rather than re-downloaded within one process only.
+#[derive(Debug)]
+pub struct InMemoryResponseCache {
+    capacity: NonZeroUsize,
+    entries: Mutex<RecencyMap>,
+}
+
+/// The entries of an [`InMemoryResponseCache`], each tagged with the tick of its last use.
+#[derive(Debug, Default)]
+struct RecencyMap {
+    entries: HashMap<String, (u64, CachedResponse)>,
+    tick: u64,
+}
+
+impl RecencyMap {
+    /// Advances and returns the use counter.
+    const fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+impl InMemoryResponseCache {
+    /// Creates a new [`InMemoryResponseCache`] keeping up to `capacity` responses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use sec::shared::response_cache::InMemoryResponseCache;
+    ///
+    /// let capacity = NonZeroUsize::new(8).expect("Eight should always be non-zero");
+    /// let cache = InMemoryResponseCache::new(capacity);
+    ///
+    /// let expected_result = capacity;
+    ///
+    /// let result = cache.capacity();
+    ///
+    /// assert_eq!(result, expected_result);
+    /// ```
+    #[must_use]
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(RecencyMap::default()),
+        }
+    }
+
+    /// Returns the number of responses the cache keeps at most.
+    #[must_use]
+    pub const fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    /// Returns the number of responses currently cached.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if no response is cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // A panic while holding the lock cannot leave the map half-updated, so a poisoned lock is
+    // safe to keep using.
+    fn lock(&self) -> std::sync::MutexGuard<'_, RecencyMap> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Creates a cache keeping up to [`DEFAULT_IN_MEMORY_CAPACITY`] responses.
+impl Default for InMemoryResponseCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_IN_MEMORY_CAPACITY)
+    }
+}
+
+#[async_trait]
+impl ResponseCache for InMemoryResponseCache {
+    async fn load(&self, url: &str) -> Result<Option<CachedResponse>, ResponseCacheError> {
+        let mut map = self.lock();
+        let tick = map.next_tick();
+        Ok(map.entries.get_mut(url).map(|(last_used, entry)| {
+            *last_used = tick;
+            entry.clone()
+        }))
+    }
+
+    async fn store(&self, url: &str, entry: &CachedResponse) -> Result<(), ResponseCacheError> {
+        let mut map = self.lock();
+        let tick = map.next_tick();
+        map.entries.insert(url.to_string(), (tick, entry.clone()));
+        if map.entries.len() > self.capacity.get()
+            && let Some(least_recent) = map
+                .entries
+                .iter()
+                .min_by_key(|(_, (last_used, _))| *last_used)
+                .map(|(url, _)| url.clone())
+        {
+            map.entries.remove(&least_recent);
+        }
+        drop(map);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::shared::content_type::ContentType;
+    use crate::shared::headers::Headers;
+    use crate::shared::response::implementations::sec_response::SecResponse;
+    use crate::shared::status_code::StatusCode;
+    use crate::shared::url::Url;
+
+    fn sample_entry(url: &str) -> CachedResponse {
+        let response = SecResponse::from_parts(
+            Url::from_string(url.to_string()).expect("Hardcoded URL should always be valid"),
+            Headers::new(HashMap::new()),
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({}),
+        );
+        CachedResponse::new(&response, Utc::now())
+    }
+
+    fn cache_of_two() -> InMemoryResponseCache {
+        InMemoryResponseCache::new(NonZeroUsize::new(2).expect("Two should always be non-zero"))
+    }
+
+    #[tokio::test]
+    async fn should_load_stored_entry_when_url_was_stored() {
+        let cache = InMemoryResponseCache::default();
+        let entry = sample_entry("https://example.com/a");
+
+        let expected_result = Some(entry.clone());
+
+        cache
+            .store("https://example.com/a", &entry)
+            .await
+            .expect("Storing in memory should always succeed");
+        let result = cache
+            .load("https://example.com/a")
+            .await
+            .expect("Loading from memory should always succeed");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_evict_least_recently_used_entry_when_full() {
+        let cache = cache_of_two();
+        for url in ["https://example.com/a", "https://example.com/b"] {
+            cache
+                .store(url, &sample_entry(url))
+                .await
+                .expect("Storing in memory should always succeed");
+        }
+        cache
+            .load("https://example.com/a")
+            .await
+            .expect("Loading from memory should always succeed");
+        cache
+            .store(
+                "https://example.com/c",
+                &sample_entry("https://example.com/c"),
+            )
+            .await
+            .expect("Storing in memory should always succeed");
+
+        let expected_result = (true, false, 2);
+
+        let result = (
+            cache
+                .load("https://example.com/a")
+                .await
+                .expect("Loading from memory should always succeed")
+                .is_some(),
+            cache
+                .load("https://example.com/b")
+                .await
+                .expect("Loading from memory should always succeed")
+                .is_some(),
+            cache.len(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,14 @@
+//! # Response Cache Implementations
+//!
+//! Concrete caches implementing the [`traits`](super::traits) contract.
+//!
+//! ## Modules
+//!
+//! - [`file_system`]: The [`FileSystemResponseCache`], storing one JSON file per URL.
+//! - [`in_memory`]: The [`InMemoryResponseCache`], keeping the most recently used responses.
+
+pub mod file_system;
+pub mod in_memory;
+
+pub use file_system::FileSystemResponseCache;
+pub use in_memory::InMemoryResponseCache;
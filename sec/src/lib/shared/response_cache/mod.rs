@@ -0,0 +1,41 @@
+//! # Response Cache
+//!
+//! Provides the response cache the [`SecClient`] revalidates SEC responses against, so a
+//! company-facts payload that has not changed since the last run is not downloaded again.
+//!
+//! A cached response is stored under its request URL together with the time it was stored. Before
+//! sending a request, the client looks the URL up: an entry younger than the client's maximum age
+//! is served without contacting the SEC at all; an older one is revalidated with a conditional
+//! request carrying its `ETag` (`If-None-Match`) and `Last-Modified` (`If-Modified-Since`). A
+//! `304 Not Modified` reply, or a full reply whose [`BodyDigest`] matches the cached one, confirms
+//! the cached response; any other successful reply replaces it. Every response records how the
+//! cache took part in its [`CacheStatus`].
+//!
+//! Caches are pluggable through the [`ResponseCache`] trait; an in-memory LRU
+//! [`InMemoryResponseCache`] and an on-disk [`FileSystemResponseCache`] are included.
+//!
+//! ## Modules
+//!
+//! - [`cache_status`]: The [`CacheStatus`] a response records.
+//! - [`cached_response`]: The [`CachedResponse`] entry a cache stores per URL.
+//! - [`constants`]: The default capacity of the in-memory cache.
+//! - [`response_cache_error`]: The [`ResponseCacheError`] returned when a cache operation fails.
+//! - [`traits`]: The [`ResponseCache`] contract.
+//! - [`implementations`]: The [`InMemoryResponseCache`] and the [`FileSystemResponseCache`].
+//!
+//! [`SecClient`]: crate::shared::http_client::implementations::sec_client::SecClient
+//! [`BodyDigest`]: crate::shared::response::implementations::sec_response::body_digest::BodyDigest
+
+pub mod cache_status;
+pub mod cached_response;
+pub mod constants;
+pub mod implementations;
+pub mod response_cache_error;
+pub mod traits;
+
+pub use cache_status::CacheStatus;
+pub use cached_response::CachedResponse;
+pub use constants::DEFAULT_IN_MEMORY_CAPACITY;
+pub use implementations::{FileSystemResponseCache, InMemoryResponseCache};
+pub use response_cache_error::ResponseCacheError;
+pub use traits::ResponseCache;
@@ -0,0 +1,98 @@
+//! # Response Cache Errors
+//!
+//! Provides the [`ResponseCacheError`] returned when a cached response cannot be loaded or
+//! stored, and the [`ErrorReason`] describing why.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Error indicating that a response cache operation failed.
+///
+/// Wraps the [`ErrorReason`] distinguishing an entry that could not be (de)serialized from a
+/// cache that could not be read or written.
+#[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error("[ResponseCacheError] Response cache operation failed, Caused by: {reason}")]
+pub struct ResponseCacheError {
+    /// The reason why the response cache operation failed.
+    pub reason: ErrorReason,
+}
+
+impl ResponseCacheError {
+    /// Creates a new [`ResponseCacheError`].
+    #[must_use]
+    pub const fn new(reason: ErrorReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Enum representing the reason why a response cache operation failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorReason {
+    /// The cached entry could not be serialized or deserialized.
+    Serialization {
+        /// A human-readable description of the serialization failure.
+        details: String,
+    },
+    /// The cache failed to read or write an entry.
+    Storage {
+        /// A human-readable description of the storage failure.
+        details: String,
+    },
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Serialization { details } => write!(
+                f,
+                "[Serialization] Cached response (de)serialization failed, Reason: '{details}'"
+            ),
+            Self::Storage { details } => {
+                write!(
+                    f,
+                    "[Storage] Response cache storage failed, Reason: '{details}'"
+                )
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for ResponseCacheError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::new(ErrorReason::Serialization {
+            details: e.to_string(),
+        })
+    }
+}
+
+impl From<std::io::Error> for ResponseCacheError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorReason::Storage {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_format_display_as_expected_when_storage_failed() {
+        let reason = ErrorReason::Storage {
+            details: "permission denied".to_string(),
+        };
+        let response_cache_error = ResponseCacheError::new(reason.clone());
+
+        let expected_result =
+            format!("[ResponseCacheError] Response cache operation failed, Caused by: {reason}");
+
+        let result = format!("{response_cache_error}");
+
+        assert_eq!(result, expected_result);
+    }
+}
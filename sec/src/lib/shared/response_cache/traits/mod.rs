@@ -0,0 +1,11 @@
+//! # Response Cache Traits
+//!
+//! The storage contract abstracting where cached responses are kept.
+//!
+//! ## Modules
+//!
+//! - [`response_cache`]: The [`ResponseCache`] trait abstracting a concrete storage backend.
+
+pub mod response_cache;
+
+pub use response_cache::ResponseCache;
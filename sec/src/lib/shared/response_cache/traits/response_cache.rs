@@ -0,0 +1,27 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::shared::response_cache::{CachedResponse, ResponseCacheError};
+
+/// A response store: keeps the latest [`CachedResponse`] per request URL.
+///
+/// Abstracts over concrete storage backends so the client is not bound to any one of them.
+/// Storing under an existing URL replaces its entry. A cache may evict entries at any time; a
+/// missing entry only costs a full download.
+#[async_trait]
+pub trait ResponseCache: Send + Sync + Debug {
+    /// Loads the entry stored under `url`, or `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ResponseCacheError`] if a stored entry cannot be read or deserialized.
+    async fn load(&self, url: &str) -> Result<Option<CachedResponse>, ResponseCacheError>;
+
+    /// Stores `entry` under `url`, replacing any entry already stored there.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ResponseCacheError`] if the entry cannot be serialized or stored.
+    async fn store(&self, url: &str, entry: &CachedResponse) -> Result<(), ResponseCacheError>;
+}
@@ -0,0 +1,26 @@
+//! # Fixture Mode
+//!
+//! Provides the [`FixtureMode`] deciding whether a client replays or records its fixtures.
+
+use std::fmt::{self, Display, Formatter};
+
+/// Whether a [`ResponseFixtures`](super::ResponseFixtures) directory answers requests or captures
+/// their responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FixtureMode {
+    /// Answer every request from its recorded response, never contacting the SEC; a request
+    /// without one fails.
+    Replay,
+    /// Send every request to the SEC as usual and record each response it answers with,
+    /// replacing any earlier recording.
+    Record,
+}
+
+impl Display for FixtureMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Replay => write!(f, "replay"),
+            Self::Record => write!(f, "record"),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! # Response Fixtures
+//!
+//! Provides the [`ResponseFixtures`] directory a [`SecClient`] replays recorded SEC responses
+//! from, or records live SEC responses into, so whole pipelines can be tested deterministically
+//! and without network access.
+//!
+//! Every fixture is the lossless [`SecResponseSnapshot`] of one response, stored as a
+//! pretty-printed JSON file named after its request URL the way
+//! [`FileSystemResponseCache`](crate::shared::response_cache::FileSystemResponseCache) names its
+//! entries. A directory is recorded by running the pipeline once against the SEC with a client in
+//! [`FixtureMode::Record`], and replayed by every later run with a client in
+//! [`FixtureMode::Replay`].
+//!
+//! ## Modules
+//!
+//! - [`fixture_mode`]: The [`FixtureMode`] deciding whether fixtures are replayed or recorded.
+//! - [`response_fixture_error`]: The [`ResponseFixtureError`] returned when a fixture cannot be
+//!   replayed or recorded.
+//!
+//! [`SecClient`]: crate::shared::http_client::implementations::sec_client::SecClient
+
+pub mod fixture_mode;
+pub mod response_fixture_error;
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub use fixture_mode::FixtureMode;
+pub use response_fixture_error::ResponseFixtureError;
+
+use crate::shared::response::implementations::sec_response::{SecResponse, SecResponseSnapshot};
+use crate::shared::response_cache::implementations::file_system::{
+    file_name_for, write_atomically,
+};
+
+use self::response_fixture_error::ErrorReason;
+
+/// A directory of recorded SEC responses, one JSON file per request URL, and whether it is
+/// replayed or recorded.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::response_fixtures::{FixtureMode, ResponseFixtures};
+///
+/// let fixtures = ResponseFixtures::replay("tests/fixtures/sec");
+///
+/// let expected_result = FixtureMode::Replay;
+///
+/// let result = fixtures.mode();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ResponseFixtures {
+    directory: PathBuf,
+    mode: FixtureMode,
+}
+
+impl ResponseFixtures {
+    /// Creates a new [`ResponseFixtures`] keeping its fixtures in `directory`, used in `mode`.
+    pub fn new(directory: impl Into<PathBuf>, mode: FixtureMode) -> Self {
+        Self {
+            directory: directory.into(),
+            mode,
+        }
+    }
+
+    /// Creates a new [`ResponseFixtures`] answering requests from the fixtures in `directory`.
+    pub fn replay(directory: impl Into<PathBuf>) -> Self {
+        Self::new(directory, FixtureMode::Replay)
+    }
+
+    /// Creates a new [`ResponseFixtures`] recording responses into `directory`, which is created
+    /// on the first recording.
+    pub fn record(directory: impl Into<PathBuf>) -> Self {
+        Self::new(directory, FixtureMode::Record)
+    }
+
+    /// Returns the directory the fixtures are kept in.
+    #[must_use]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns whether the fixtures are replayed or recorded.
+    #[must_use]
+    pub const fn mode(&self) -> FixtureMode {
+        self.mode
+    }
+
+    /// Returns the path of the fixture for `url`.
+    #[must_use]
+    pub fn path_for(&self, url: &str) -> PathBuf {
+        self.directory.join(file_name_for(url))
+    }
+
+    /// Restores the response recorded for `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ResponseFixtureError`] if no response was recorded for `url`
+    /// ([`ErrorReason::Missing`]), or its fixture cannot be read or deserialized.
+    pub async fn load(&self, url: &str) -> Result<SecResponse, ResponseFixtureError> {
+        let contents = match tokio::fs::read(self.path_for(url)).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(ResponseFixtureError::new(ErrorReason::Missing {
+                    url: url.to_string(),
+                }));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let snapshot: SecResponseSnapshot = serde_json::from_slice(&contents)?;
+        Ok(SecResponse::from(snapshot))
+    }
+
+    /// Records `response` as the response to `url`, replacing any earlier recording.
+    ///
+    /// The fixture is written to a temporary file of its own that is then renamed over the
+    /// previous one, so neither an interrupted recording nor concurrent recordings of the same
+    /// URL leave a truncated fixture behind.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ResponseFixtureError`] if the fixture cannot be serialized or written.
+    pub async fn store(
+        &self,
+        url: &str,
+        response: &SecResponse,
+    ) -> Result<(), ResponseFixtureError> {
+        let contents = serde_json::to_vec_pretty(&SecResponseSnapshot::from(response))?;
+        write_atomically(&self.directory, &self.path_for(url), contents).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::shared::content_type::ContentType;
+    use crate::shared::headers::Headers;
+    use crate::shared::status_code::StatusCode;
+    use crate::shared::url::Url;
+
+    const URL: &str = "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json";
+
+    fn temporary_fixtures() -> ResponseFixtures {
+        ResponseFixtures::record(
+            std::env::temp_dir().join(format!("sec-response-fixtures-{}", Uuid::new_v4())),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_replay_recorded_response_when_url_was_recorded() {
+        let fixtures = temporary_fixtures();
+        let response = SecResponse::from_parts(
+            Url::from_string(URL.to_string()).expect("Hardcoded URL should always be valid"),
+            Headers::new(HashMap::from([("etag".to_string(), "\"abc\"".to_string())])),
+            ContentType::Json,
+            StatusCode::Ok,
+            serde_json::json!({ "cik": 1_067_983 }),
+        );
+
+        let expected_result = response.clone();
+
+        fixtures
+            .store(URL, &response)
+            .await
+            .expect("Recording to a temporary directory should always succeed");
+        let result = ResponseFixtures::replay(fixtures.directory())
+            .load(URL)
+            .await
+            .expect("Replaying a recorded response should always succeed");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_fail_with_missing_fixture_when_url_was_never_recorded() {
+        let fixtures = temporary_fixtures();
+
+        let expected_result = ResponseFixtureError::new(ErrorReason::Missing {
+            url: URL.to_string(),
+        });
+
+        let result = fixtures
+            .load(URL)
+            .await
+            .expect_err("Replaying an unrecorded URL should always fail");
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,104 @@
+//! # Response Fixture Errors
+//!
+//! Provides the [`ResponseFixtureError`] returned when a recorded response cannot be replayed or
+//! recorded, and the [`ErrorReason`] describing why.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Error indicating that a response fixture could not be replayed or recorded.
+///
+/// Wraps the [`ErrorReason`] distinguishing a request that was never recorded from a fixture that
+/// could not be (de)serialized, read, or written.
+#[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error("[ResponseFixtureError] Response fixture operation failed, Caused by: {reason}")]
+pub struct ResponseFixtureError {
+    /// The reason why the response fixture operation failed.
+    pub reason: ErrorReason,
+}
+
+impl ResponseFixtureError {
+    /// Creates a new [`ResponseFixtureError`].
+    #[must_use]
+    pub const fn new(reason: ErrorReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Enum representing the reason why a response fixture operation failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorReason {
+    /// No response was recorded for the requested URL.
+    Missing {
+        /// The URL no response was recorded for.
+        url: String,
+    },
+    /// The fixture could not be serialized or deserialized.
+    Serialization {
+        /// A human-readable description of the serialization failure.
+        details: String,
+    },
+    /// The fixture could not be read or written.
+    Storage {
+        /// A human-readable description of the storage failure.
+        details: String,
+    },
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing { url } => {
+                write!(f, "[Missing] No response was recorded for '{url}'")
+            }
+            Self::Serialization { details } => write!(
+                f,
+                "[Serialization] Response fixture (de)serialization failed, Reason: '{details}'"
+            ),
+            Self::Storage { details } => {
+                write!(
+                    f,
+                    "[Storage] Response fixture storage failed, Reason: '{details}'"
+                )
+            }
+        }
+    }
+}
+
+impl From<serde_json::Error> for ResponseFixtureError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::new(ErrorReason::Serialization {
+            details: e.to_string(),
+        })
+    }
+}
+
+impl From<std::io::Error> for ResponseFixtureError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorReason::Storage {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_name_url_when_displaying_missing_fixture() {
+        let error = ResponseFixtureError::new(ErrorReason::Missing {
+            url: "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json".to_string(),
+        });
+
+        let expected_result = "[ResponseFixtureError] Response fixture operation failed, Caused by: [Missing] No response was recorded for 'https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json'";
+
+        let result = error.to_string();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,7 @@
+use std::time::Duration;
+
+/// Delay before the first retry of a failed state.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound on the delay between two attempts, however many retries have been made.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(30);
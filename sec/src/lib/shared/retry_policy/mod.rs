@@ -0,0 +1,282 @@
+//! # Retry Policy
+//!
+//! Provides the [`RetryPolicy`] the stream driver consults when a state's computation fails.
+//!
+//! A policy answers two questions: *whether* a failed attempt is retried — the state's retry
+//! budget is left and the error is classified as retryable — and *how long* to wait first. Delays
+//! grow exponentially from an initial backoff up to a cap, with jitter so that many pipelines
+//! failing at once do not retry in lockstep.
+//!
+//! Each state's [`Context`](crate::traits::state_machine::state::Context) hands out its policy,
+//! built from its `max_retries` budget by default.
+//!
+//! ## Modules
+//!
+//! - [`constants`]: The default backoff bounds.
+
+use std::time::Duration;
+
+use crate::error::State as StateError;
+
+pub mod constants;
+
+use constants::{DEFAULT_INITIAL_BACKOFF, DEFAULT_MAX_BACKOFF};
+
+/// Decides whether, and after what delay, a failed state computation is retried.
+///
+/// Retries are counted from one: the first retry waits [`initial_backoff`](Self::initial_backoff),
+/// and every further retry doubles the delay up to [`max_backoff`](Self::max_backoff). With
+/// jitter enabled, each delay is drawn uniformly from its upper half.
+///
+/// Which errors are worth retrying is decided by a classifier, [`StateError::is_retryable`] unless
+/// replaced through [`with_classifier`](Self::with_classifier).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use sec::error::State as StateError;
+/// use sec::shared::retry_policy::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(3)
+///     .with_initial_backoff(Duration::from_millis(100))
+///     .with_jitter(false);
+///
+/// let expected_result = (Duration::from_millis(400), false);
+///
+/// let result = (policy.backoff(3), policy.should_retry(1, &StateError::InvalidInput));
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    is_retryable: fn(&StateError) -> bool,
+}
+
+impl RetryPolicy {
+    /// Creates a policy allowing up to `max_retries` retries with the default backoff bounds,
+    /// jitter, and [`StateError::is_retryable`] as classifier.
+    #[must_use]
+    pub const fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            jitter: true,
+            is_retryable: StateError::is_retryable,
+        }
+    }
+
+    /// Creates a policy that never retries.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Sets the delay before the first retry.
+    #[must_use]
+    pub const fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound on the delay between two attempts.
+    #[must_use]
+    pub const fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Enables or disables jitter.
+    #[must_use]
+    pub const fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Replaces the classifier deciding which errors are retryable.
+    #[must_use]
+    pub const fn with_classifier(mut self, is_retryable: fn(&StateError) -> bool) -> Self {
+        self.is_retryable = is_retryable;
+        self
+    }
+
+    /// Returns the maximum number of retries after the first attempt.
+    #[must_use]
+    pub const fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the delay before the first retry.
+    #[must_use]
+    pub const fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Returns the upper bound on the delay between two attempts.
+    #[must_use]
+    pub const fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Returns `true` if delays are jittered.
+    #[must_use]
+    pub const fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Returns `true` if `error` may be followed by retry number `retry` (counted from one).
+    #[must_use]
+    pub fn should_retry(&self, retry: u32, error: &StateError) -> bool {
+        retry <= self.max_retries && (self.is_retryable)(error)
+    }
+
+    /// Returns the delay before retry number `retry` (counted from one).
+    #[must_use]
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let half = backoff / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Creates a policy that never retries.
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::error::state_machine::state::FailedRequestExecution;
+    use crate::shared::http_client::implementations::sec_client::error::{
+        ErrorReason, FailedSecRequest,
+    };
+
+    fn transient_error() -> StateError {
+        StateError::FailedRequestExecution(FailedRequestExecution::new(
+            "Execute SEC Request",
+            FailedSecRequest::new(ErrorReason::FailedRequestExecution {
+                details: "connection reset".to_string(),
+            }),
+        ))
+    }
+
+    #[test]
+    fn should_retry_transient_error_when_budget_is_left() {
+        let policy = RetryPolicy::new(2);
+
+        let expected_result = true;
+
+        let result = policy.should_retry(2, &transient_error());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_retry_when_budget_is_exhausted() {
+        let policy = RetryPolicy::new(2);
+
+        let expected_result = false;
+
+        let result = policy.should_retry(3, &transient_error());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_retry_when_error_is_not_retryable() {
+        let policy = RetryPolicy::new(2);
+
+        let expected_result = false;
+
+        let result = policy.should_retry(1, &StateError::InvalidInput);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_retry_any_error_when_classifier_accepts_everything() {
+        let policy = RetryPolicy::new(1).with_classifier(|_| true);
+
+        let expected_result = true;
+
+        let result = policy.should_retry(1, &StateError::InvalidInput);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_never_retry_when_policy_is_none() {
+        let policy = RetryPolicy::none();
+
+        let expected_result = false;
+
+        let result = policy.should_retry(1, &transient_error());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_double_backoff_for_each_retry_when_jitter_is_disabled() {
+        let policy = RetryPolicy::new(5)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_jitter(false);
+
+        let expected_result = vec![
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(400),
+        ];
+
+        let result: Vec<_> = (1..=3).map(|retry| policy.backoff(retry)).collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_cap_backoff_at_max_backoff_when_retries_grow() {
+        let policy = RetryPolicy::new(100)
+            .with_initial_backoff(Duration::from_secs(1))
+            .with_max_backoff(Duration::from_secs(5))
+            .with_jitter(false);
+
+        let expected_result = Duration::from_secs(5);
+
+        let result = policy.backoff(100);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_jittered_backoff_within_upper_half_when_jitter_is_enabled() {
+        let policy = RetryPolicy::new(1).with_initial_backoff(Duration::from_millis(100));
+
+        let expected_result = true;
+
+        let result = (0..100).all(|_| {
+            let backoff = policy.backoff(1);
+            backoff >= Duration::from_millis(50) && backoff <= Duration::from_millis(100)
+        });
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -4,14 +4,14 @@
 
 use std::fmt::{self, Display, Formatter};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// An HTTP status code from an SEC API response.
 ///
 /// Models the codes relevant to SEC interactions as explicit variants so match arms can handle
 /// cases like rate-limiting (429) or not-found (404) without raw integer comparisons. Any other
 /// valid code is captured by `Other`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum StatusCode {
     /// 100 Continue.
@@ -23,6 +23,9 @@ pub enum StatusCode {
     /// 301 Moved Permanently.
     MovedPermanently,
 
+    /// 304 Not Modified.
+    NotModified,
+
     /// 400 Bad Request.
     BadRequest,
 
@@ -67,6 +70,7 @@ impl StatusCode {
             100 => Self::Continue,
             200 => Self::Ok,
             301 => Self::MovedPermanently,
+            304 => Self::NotModified,
             400 => Self::BadRequest,
             403 => Self::Forbidden,
             404 => Self::NotFound,
@@ -84,6 +88,7 @@ impl StatusCode {
             Self::Continue => 100,
             Self::Ok => 200,
             Self::MovedPermanently => 301,
+            Self::NotModified => 304,
             Self::BadRequest => 400,
             Self::Forbidden => 403,
             Self::NotFound => 404,
@@ -123,6 +128,13 @@ impl StatusCode {
     pub const fn is_server_error(&self) -> bool {
         matches!(self.as_u16(), 500..=599)
     }
+
+    /// Returns `true` if the same request may be answered differently when sent again: the server
+    /// is throttling (429) or failing (500–599).
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::TooManyRequests) || self.is_server_error()
+    }
 }
 
 impl From<reqwest::StatusCode> for StatusCode {
@@ -176,6 +188,17 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_return_not_modified_when_code_is_304() {
+        let code: u16 = 304;
+
+        let expected_result = StatusCode::NotModified;
+
+        let result = StatusCode::from_u16(code);
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_return_bad_request_when_code_is_400() {
         let code: u16 = 400;
@@ -367,4 +390,26 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_be_transient_when_server_throttles() {
+        let status_code = StatusCode::TooManyRequests;
+
+        let expected_result = true;
+
+        let result = status_code.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_be_transient_when_resource_is_missing() {
+        let status_code = StatusCode::NotFound;
+
+        let expected_result = false;
+
+        let result = status_code.is_transient();
+
+        assert_eq!(result, expected_result);
+    }
 }
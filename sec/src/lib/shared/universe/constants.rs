@@ -0,0 +1,12 @@
+//! # Universe Constants
+//!
+//! The CIK universe bundled with the library.
+
+/// The S&P 500 companies as a CSV universe with `cik`, `ticker`, and `name` columns, sourced from
+/// SEC EDGAR.
+///
+/// Source: <https://www.sec.gov/files/company_tickers.json>
+///
+/// Also lists two test CIKs for error handling verification: `ABC`, which is rejected up front,
+/// and `9999999999`, which is well-formed but unknown to the SEC.
+pub const SP500_UNIVERSE: &str = include_str!("../../../../data/universes/sp500.csv");
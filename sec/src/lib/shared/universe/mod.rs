@@ -0,0 +1,516 @@
+//! # CIK Universe
+//!
+//! Provides the [`CikUniverse`], the list of companies a batch runs, read from a plain list, a
+//! CSV file, or the SEC's `company_tickers.json` format instead of being hardcoded.
+//!
+//! Every CIK is validated through [`Cik::new`] while the universe is parsed. Malformed entries do
+//! not fail the whole universe; they are collected as [`RejectedEntry`]s, so they can be reported
+//! before the batch starts. A CIK listed more than once is kept once, with the details of its
+//! first listing, e.g. for companies with several share classes.
+//!
+//! ## Modules
+//!
+//! - [`constants`]: The bundled [`SP500_UNIVERSE`].
+//! - [`universe_args`]: The [`UniverseArgs`] selecting a universe from command-line arguments.
+//! - [`universe_error`]: The [`UniverseError`] returned when a universe cannot be loaded.
+//! - [`universe_filter`]: The [`UniverseFilter`] narrowing a universe down by ticker, exchange, or
+//!   CIK range.
+//! - [`universe_format`]: The [`UniverseFormat`]s a universe can be read from.
+
+pub mod constants;
+pub mod universe_args;
+pub mod universe_error;
+pub mod universe_filter;
+pub mod universe_format;
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use serde::Deserialize;
+
+pub use constants::SP500_UNIVERSE;
+pub use universe_args::UniverseArgs;
+pub use universe_error::UniverseError;
+pub use universe_filter::UniverseFilter;
+pub use universe_format::UniverseFormat;
+
+use self::universe_error::ErrorReason;
+use crate::shared::cik::{Cik, CikError};
+
+/// One company of a CIK universe.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UniverseEntry {
+    /// The validated CIK of the company.
+    pub cik: Cik,
+    /// The company's ticker symbol, if the universe lists one.
+    pub ticker: Option<String>,
+    /// The company's name, if the universe lists one.
+    pub name: Option<String>,
+    /// The exchange the company is listed on, if the universe lists one.
+    pub exchange: Option<String>,
+}
+
+/// An entry of a CIK universe whose CIK failed validation.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RejectedEntry {
+    /// The line of the entry, counted from one, or its position among the records of a JSON
+    /// document.
+    pub position: usize,
+    /// The CIK as it was listed.
+    pub raw_cik: String,
+    /// Why the CIK is invalid.
+    pub error: CikError,
+}
+
+/// The validated list of companies a batch runs.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::universe::{CikUniverse, UniverseFormat};
+///
+/// let universe = CikUniverse::parse("320193\nABC # not a CIK\n1067983\n", UniverseFormat::PlainList)
+///     .expect("A plain list should always parse");
+///
+/// let expected_result = (2, vec!["ABC".to_string()]);
+///
+/// let result = (
+///     universe.len(),
+///     universe.rejected().iter().map(|rejected| rejected.raw_cik.clone()).collect::<Vec<_>>(),
+/// );
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CikUniverse {
+    entries: Vec<UniverseEntry>,
+    rejected: Vec<RejectedEntry>,
+}
+
+impl CikUniverse {
+    /// Parses the universe listed in `contents` in the given `format`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UniverseError`] ([`ErrorReason::MalformedDocument`]) if `contents` does not
+    /// have the shape `format` requires; malformed CIKs are collected as [`RejectedEntry`]s
+    /// instead.
+    pub fn parse(contents: &str, format: UniverseFormat) -> Result<Self, UniverseError> {
+        let listed = match format {
+            UniverseFormat::PlainList => parse_plain_list(contents),
+            UniverseFormat::Csv => parse_csv(contents)?,
+            UniverseFormat::CompanyTickers => parse_company_tickers(contents)?,
+        };
+
+        let mut universe = Self::default();
+        let mut seen = HashSet::new();
+        for listed in listed {
+            match Cik::new(&listed.raw_cik) {
+                Ok(cik) => {
+                    if seen.insert(cik.clone()) {
+                        universe.entries.push(UniverseEntry {
+                            cik,
+                            ticker: listed.ticker,
+                            name: listed.name,
+                            exchange: listed.exchange,
+                        });
+                    }
+                }
+                Err(error) => universe.rejected.push(RejectedEntry {
+                    position: listed.position,
+                    raw_cik: listed.raw_cik,
+                    error,
+                }),
+            }
+        }
+        Ok(universe)
+    }
+
+    /// Reads and parses the universe in the file at `path`, detecting its format unless one is
+    /// given.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UniverseError`] if the file cannot be read or does not have the shape its
+    /// format requires.
+    pub async fn load(
+        path: impl AsRef<Path>,
+        format: Option<UniverseFormat>,
+    ) -> Result<Self, UniverseError> {
+        let path = path.as_ref();
+        let contents = tokio::fs::read_to_string(path).await?;
+        let format = format.unwrap_or_else(|| UniverseFormat::detect(Some(path), &contents));
+        Self::parse(&contents, format)
+    }
+
+    /// Returns the bundled [`SP500_UNIVERSE`].
+    ///
+    /// # Panics
+    ///
+    /// Never panics in practice: the bundled universe is a well-formed CSV document, which is
+    /// covered by a unit test.
+    #[must_use]
+    pub fn sp500() -> Self {
+        Self::parse(SP500_UNIVERSE, UniverseFormat::Csv)
+            .expect("The bundled S&P 500 universe should always be a well-formed CSV document")
+    }
+
+    /// Returns the universe reduced to the entries passing `filter`, keeping its rejected
+    /// entries.
+    #[must_use]
+    pub fn filter(mut self, filter: &UniverseFilter) -> Self {
+        self.entries.retain(|entry| filter.matches(entry));
+        self
+    }
+
+    /// Returns the valid entries, in the order they were listed.
+    #[must_use]
+    pub fn entries(&self) -> &[UniverseEntry] {
+        &self.entries
+    }
+
+    /// Returns the entries whose CIK failed validation, in the order they were listed.
+    #[must_use]
+    pub fn rejected(&self) -> &[RejectedEntry] {
+        &self.rejected
+    }
+
+    /// Returns the number of valid entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the universe has no valid entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Consumes the universe and returns the CIKs of its valid entries, in the order they were
+    /// listed.
+    pub fn into_ciks(self) -> impl Iterator<Item = Cik> + Send + 'static {
+        self.entries.into_iter().map(|entry| entry.cik)
+    }
+}
+
+/// An entry as listed in a universe document, before its CIK is validated.
+struct ListedEntry {
+    position: usize,
+    raw_cik: String,
+    ticker: Option<String>,
+    name: Option<String>,
+    exchange: Option<String>,
+}
+
+impl ListedEntry {
+    const fn new(position: usize, raw_cik: String) -> Self {
+        Self {
+            position,
+            raw_cik,
+            ticker: None,
+            name: None,
+            exchange: None,
+        }
+    }
+}
+
+/// Lists one CIK per non-blank line, ignoring everything after a `#`.
+fn parse_plain_list(contents: &str) -> Vec<ListedEntry> {
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let raw_cik = line.split('#').next().unwrap_or_default().trim();
+            (!raw_cik.is_empty()).then(|| ListedEntry::new(index + 1, raw_cik.to_string()))
+        })
+        .collect()
+}
+
+/// Lists the rows of a CSV document below its header row, skipping rows with a blank `cik`.
+fn parse_csv(contents: &str) -> Result<Vec<ListedEntry>, UniverseError> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let header = lines
+        .next()
+        .map(|(_, header)| split_csv_line(header))
+        .unwrap_or_default();
+    let column = |names: &[&str]| {
+        header.iter().position(|column| {
+            names
+                .iter()
+                .any(|name| column.trim().eq_ignore_ascii_case(name))
+        })
+    };
+    let Some(cik_column) = column(&["cik", "cik_str"]) else {
+        return Err(UniverseError::new(ErrorReason::MalformedDocument {
+            details: "the CSV header names no 'cik' column".to_string(),
+        }));
+    };
+    let ticker_column = column(&["ticker"]);
+    let name_column = column(&["name", "title"]);
+    let exchange_column = column(&["exchange"]);
+
+    Ok(lines
+        .filter_map(|(index, line)| {
+            let fields = split_csv_line(line);
+            let field = |column: Option<usize>| {
+                column
+                    .and_then(|column| fields.get(column))
+                    .map(|field| field.trim())
+                    .filter(|field| !field.is_empty())
+                    .map(str::to_string)
+            };
+            field(Some(cik_column)).map(|raw_cik| ListedEntry {
+                position: index + 1,
+                raw_cik,
+                ticker: field(ticker_column),
+                name: field(name_column),
+                exchange: field(exchange_column),
+            })
+        })
+        .collect())
+}
+
+/// Splits one CSV line into its fields, unquoting quoted fields.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// A CIK as the SEC lists it: a number in `company_tickers.json`, sometimes a string elsewhere.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawCik {
+    Number(u64),
+    Text(String),
+}
+
+impl From<RawCik> for String {
+    fn from(raw_cik: RawCik) -> Self {
+        match raw_cik {
+            RawCik::Number(number) => number.to_string(),
+            RawCik::Text(text) => text,
+        }
+    }
+}
+
+/// A record of the SEC's `company_tickers.json`.
+#[derive(Deserialize)]
+struct CompanyTicker {
+    cik_str: RawCik,
+    ticker: Option<String>,
+    title: Option<String>,
+}
+
+/// The SEC's `company_tickers_exchange.json`: column names and one row per company.
+#[derive(Deserialize)]
+struct CompanyTickersExchange {
+    fields: Vec<String>,
+    data: Vec<Vec<serde_json::Value>>,
+}
+
+/// Lists the records of either SEC company tickers document, in the order the SEC numbers them.
+fn parse_company_tickers(contents: &str) -> Result<Vec<ListedEntry>, UniverseError> {
+    if let Ok(document) = serde_json::from_str::<CompanyTickersExchange>(contents) {
+        return parse_company_tickers_exchange(&document);
+    }
+
+    let records: BTreeMap<String, CompanyTicker> = serde_json::from_str(contents)?;
+    let mut records: Vec<_> = records.into_iter().collect();
+    records.sort_by_key(|(key, _)| key.parse::<usize>().unwrap_or(usize::MAX));
+    Ok(records
+        .into_iter()
+        .enumerate()
+        .map(|(index, (_, record))| ListedEntry {
+            ticker: record.ticker,
+            name: record.title,
+            ..ListedEntry::new(index + 1, record.cik_str.into())
+        })
+        .collect())
+}
+
+/// Lists the rows of a `company_tickers_exchange.json` document.
+fn parse_company_tickers_exchange(
+    document: &CompanyTickersExchange,
+) -> Result<Vec<ListedEntry>, UniverseError> {
+    let column = |name: &str| document.fields.iter().position(|field| field == name);
+    let Some(cik_column) = column("cik") else {
+        return Err(UniverseError::new(ErrorReason::MalformedDocument {
+            details: "the company tickers fields name no 'cik' column".to_string(),
+        }));
+    };
+    let (ticker_column, name_column, exchange_column) =
+        (column("ticker"), column("name"), column("exchange"));
+
+    document
+        .data
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let text = |column: Option<usize>| {
+                column
+                    .and_then(|column| row.get(column))
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string)
+            };
+            let raw_cik = row
+                .get(cik_column)
+                .cloned()
+                .map(serde_json::from_value::<RawCik>)
+                .transpose()?
+                .ok_or_else(|| {
+                    UniverseError::new(ErrorReason::MalformedDocument {
+                        details: format!("company tickers row {} has no 'cik'", index + 1),
+                    })
+                })?;
+            Ok(ListedEntry {
+                ticker: text(ticker_column),
+                name: text(name_column),
+                exchange: text(exchange_column),
+                ..ListedEntry::new(index + 1, raw_cik.into())
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::shared::cik::InvalidCikReason;
+
+    fn cik(cik: &str) -> Cik {
+        Cik::new(cik).expect("Hardcoded test CIKs should always be valid")
+    }
+
+    #[test]
+    fn should_report_malformed_entries_with_their_line_when_parsing_plain_list() {
+        let universe = CikUniverse::parse("320193\n\n# comment\nABC\n", UniverseFormat::PlainList)
+            .expect("A plain list should always parse");
+
+        let expected_result = vec![RejectedEntry {
+            position: 4,
+            raw_cik: "ABC".to_string(),
+            error: CikError::new(InvalidCikReason::ContainsNonNumericCharacters, "ABC"),
+        }];
+
+        let result = universe.rejected().to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_unquote_fields_when_parsing_csv() {
+        let contents = "Ticker,CIK,Name\nADSK,769397,\"Autodesk, Inc.\"\n";
+        let universe =
+            CikUniverse::parse(contents, UniverseFormat::Csv).expect("A CSV should always parse");
+
+        let expected_result = vec![UniverseEntry {
+            cik: cik("769397"),
+            ticker: Some("ADSK".to_string()),
+            name: Some("Autodesk, Inc.".to_string()),
+            exchange: None,
+        }];
+
+        let result = universe.entries().to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_csv_header_names_no_cik_column() {
+        let expected_result = UniverseError::new(ErrorReason::MalformedDocument {
+            details: "the CSV header names no 'cik' column".to_string(),
+        });
+
+        let result = CikUniverse::parse("ticker,name\nAAPL,Apple\n", UniverseFormat::Csv)
+            .expect_err("A CSV without a CIK column should never parse");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_list_records_in_sec_order_when_parsing_company_tickers() {
+        let contents = r#"{
+            "10": {"cik_str": 1067983, "ticker": "BRK-B", "title": "BERKSHIRE HATHAWAY INC"},
+            "0": {"cik_str": 320193, "ticker": "AAPL", "title": "Apple Inc."},
+            "2": {"cik_str": 789019, "ticker": "MSFT", "title": "MICROSOFT CORP"}
+        }"#;
+        let universe = CikUniverse::parse(contents, UniverseFormat::CompanyTickers)
+            .expect("A company tickers document should always parse");
+
+        let expected_result = vec![cik("320193"), cik("789019"), cik("1067983")];
+
+        let result = universe.into_ciks().collect::<Vec<_>>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_read_exchange_when_parsing_company_tickers_exchange() {
+        let contents = r#"{
+            "fields": ["cik", "name", "ticker", "exchange"],
+            "data": [[320193, "Apple Inc.", "AAPL", "Nasdaq"]]
+        }"#;
+        let universe = CikUniverse::parse(contents, UniverseFormat::CompanyTickers)
+            .expect("A company tickers exchange document should always parse");
+
+        let expected_result = vec![UniverseEntry {
+            cik: cik("320193"),
+            ticker: Some("AAPL".to_string()),
+            name: Some("Apple Inc.".to_string()),
+            exchange: Some("Nasdaq".to_string()),
+        }];
+
+        let result = universe.entries().to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_first_listing_when_cik_is_listed_twice() {
+        let contents = "cik,ticker\n1652044,GOOGL\n1652044,GOOG\n";
+        let universe =
+            CikUniverse::parse(contents, UniverseFormat::Csv).expect("A CSV should always parse");
+
+        let expected_result = vec![Some("GOOGL".to_string())];
+
+        let result = universe
+            .entries()
+            .iter()
+            .map(|entry| entry.ticker.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_bundled_universe_when_loading_sp500() {
+        let universe = CikUniverse::sp500();
+
+        let expected_result = (466, 1);
+
+        let result = (universe.len(), universe.rejected().len());
+
+        assert_eq!(result, expected_result);
+    }
+}
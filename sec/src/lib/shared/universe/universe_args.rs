@@ -0,0 +1,255 @@
+//! # Universe Arguments
+//!
+//! Provides the [`UniverseArgs`] the batch binaries select their CIK universe with, parsed from
+//! their command-line arguments.
+
+use std::path::PathBuf;
+
+use tokio::io::AsyncReadExt;
+
+use super::universe_error::{ErrorReason, UniverseError};
+use super::{CikUniverse, UniverseFilter, UniverseFormat};
+use crate::shared::cik::Cik;
+
+/// Where a CIK universe is read from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UniverseSource {
+    /// The bundled [`SP500_UNIVERSE`](super::SP500_UNIVERSE).
+    #[default]
+    Bundled,
+    /// A file on local disk.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+/// The CIK universe a batch runs, as selected on the command line.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::universe::UniverseArgs;
+/// use sec::shared::universe::universe_args::UniverseSource;
+///
+/// let args = UniverseArgs::parse(["--ciks", "-", "--ticker", "AAPL,MSFT"].map(String::from))
+///     .expect("Well-formed arguments should always parse");
+///
+/// let expected_result = UniverseSource::Stdin;
+///
+/// let result = args.source;
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UniverseArgs {
+    /// Where the universe is read from.
+    pub source: UniverseSource,
+    /// The format of the universe, or `None` to detect it.
+    pub format: Option<UniverseFormat>,
+    /// The entries of the universe to run.
+    pub filter: UniverseFilter,
+}
+
+impl UniverseArgs {
+    /// Describes the arguments [`parse`](Self::parse) accepts.
+    pub const USAGE: &str = "\
+Options:
+  --ciks, --input <PATH|->
+                          Read the CIK universe from PATH, or from stdin if '-'
+                          (default: the bundled S&P 500 universe)
+  --format <FORMAT>       Format of the universe: 'plain', 'csv', or 'company-tickers'
+                          (default: detected)
+  --ticker <TICKERS>      Only run companies with one of the comma-separated tickers
+  --exchange <EXCHANGES>  Only run companies listed on one of the comma-separated exchanges
+  --cik-range <FROM-TO>   Only run CIKs from FROM to TO, inclusive; repeatable";
+
+    /// Parses the command-line arguments `args`, without the program name.
+    ///
+    /// Options may be given as `--option value` or `--option=value`; `--input` is an alias of
+    /// `--ciks`, and `--ticker`, `--exchange`, and `--cik-range` may be repeated.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UniverseError`] ([`ErrorReason::InvalidArgument`]) if an argument is unknown,
+    /// lacks its value, or has an invalid one, e.g. a CIK range bound that is not a valid CIK.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, UniverseError> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (option, inline_value) = match arg.split_once('=') {
+                Some((option, value)) => (option.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| invalid_argument(&option, "missing value"))
+            };
+            match option.as_str() {
+                "--ciks" | "--input" => {
+                    parsed.source = match value()?.as_str() {
+                        "-" => UniverseSource::Stdin,
+                        path => UniverseSource::File(PathBuf::from(path)),
+                    };
+                }
+                "--format" => parsed.format = Some(value()?.parse()?),
+                "--ticker" => {
+                    for ticker in split_list(&value()?) {
+                        parsed.filter = parsed.filter.with_ticker(ticker);
+                    }
+                }
+                "--exchange" => {
+                    for exchange in split_list(&value()?) {
+                        parsed.filter = parsed.filter.with_exchange(exchange);
+                    }
+                }
+                "--cik-range" => {
+                    let cik_range = value()?;
+                    let (from, to) = cik_range
+                        .split_once('-')
+                        .unwrap_or((&cik_range, &cik_range));
+                    let bound = |bound: &str| {
+                        Cik::new(bound.trim())
+                            .map_err(|e| invalid_argument(&option, &e.to_string()))
+                    };
+                    parsed.filter = parsed.filter.with_cik_range(bound(from)?..=bound(to)?);
+                }
+                _ => return Err(invalid_argument(&option, "unknown option")),
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// Reads the selected universe, detecting its format unless one was given, and applies the
+    /// filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UniverseError`] if the universe cannot be read or does not have the shape its
+    /// format requires.
+    pub async fn load(&self) -> Result<CikUniverse, UniverseError> {
+        let universe = match &self.source {
+            UniverseSource::Bundled => CikUniverse::sp500(),
+            UniverseSource::File(path) => CikUniverse::load(path, self.format).await?,
+            UniverseSource::Stdin => {
+                let mut contents = String::new();
+                tokio::io::stdin().read_to_string(&mut contents).await?;
+                let format = self
+                    .format
+                    .unwrap_or_else(|| UniverseFormat::detect(None, &contents));
+                CikUniverse::parse(&contents, format)?
+            }
+        };
+        Ok(universe.filter(&self.filter))
+    }
+}
+
+/// Returns the non-blank items of the comma-separated `list`.
+fn split_list(list: &str) -> impl Iterator<Item = &str> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+fn invalid_argument(argument: &str, details: &str) -> UniverseError {
+    UniverseError::new(ErrorReason::InvalidArgument {
+        argument: argument.to_string(),
+        details: details.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<UniverseArgs, UniverseError> {
+        UniverseArgs::parse(args.iter().map(ToString::to_string))
+    }
+
+    fn cik(cik: &str) -> Cik {
+        Cik::new(cik).expect("Hardcoded test CIKs should always be valid")
+    }
+
+    #[test]
+    fn should_use_bundled_universe_when_no_arguments_are_given() {
+        let expected_result = UniverseArgs::default();
+
+        let result = parse(&[]).expect("No arguments should always parse");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_build_filter_when_parsing_filter_options() {
+        let expected_result = UniverseArgs {
+            source: UniverseSource::File(PathBuf::from("ciks.csv")),
+            format: Some(UniverseFormat::Csv),
+            filter: UniverseFilter::new()
+                .with_ticker("AAPL")
+                .with_ticker("MSFT")
+                .with_exchange("NYSE")
+                .with_cik_range(cik("1")..=cik("1000"))
+                .with_cik_range(cik("320193")..=cik("320193")),
+        };
+
+        let result = parse(&[
+            "--ciks=ciks.csv",
+            "--format",
+            "csv",
+            "--ticker",
+            "AAPL, MSFT",
+            "--exchange=NYSE",
+            "--cik-range",
+            "1-1000",
+            "--cik-range",
+            "320193",
+        ])
+        .expect("Well-formed arguments should always parse");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_cik_range_bound_is_malformed() {
+        let expected_result = Err(ErrorReason::InvalidArgument {
+            argument: "--cik-range".to_string(),
+            details: Cik::new("ABC")
+                .expect_err("A non-numeric CIK should never be valid")
+                .to_string(),
+        });
+
+        let result = parse(&["--cik-range", "1-ABC"]).map_err(|e| e.reason);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_when_option_lacks_value() {
+        let expected_result = Err(ErrorReason::InvalidArgument {
+            argument: "--ticker".to_string(),
+            details: "missing value".to_string(),
+        });
+
+        let result = parse(&["--ticker"]).map_err(|e| e.reason);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_apply_filter_when_loading_universe() {
+        let args = parse(&["--ticker", "AAPL"]).expect("Well-formed arguments should always parse");
+        let universe = args
+            .load()
+            .await
+            .expect("The bundled universe should always load");
+
+        let expected_result = vec![cik("320193")];
+
+        let result = universe.into_ciks().collect::<Vec<_>>();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,126 @@
+//! # Universe Errors
+//!
+//! Provides the [`UniverseError`] returned when a CIK universe cannot be read, parsed, or
+//! configured, and the [`ErrorReason`] describing why.
+//!
+//! Malformed CIKs inside an otherwise readable universe are not errors; they are collected as
+//! [`RejectedEntry`](super::RejectedEntry)s so a whole batch is not lost to one bad line.
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Error indicating that a CIK universe could not be loaded.
+///
+/// Wraps the [`ErrorReason`] distinguishing an unreadable source, a document that does not have
+/// the expected shape, and invalid command-line arguments.
+#[derive(Debug, Error, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[error("[UniverseError] Loading the CIK universe failed, Caused by: {reason}")]
+pub struct UniverseError {
+    /// The reason why loading the universe failed.
+    pub reason: ErrorReason,
+}
+
+impl UniverseError {
+    /// Creates a new [`UniverseError`].
+    #[must_use]
+    pub const fn new(reason: ErrorReason) -> Self {
+        Self { reason }
+    }
+}
+
+/// Enum representing the reason why loading a CIK universe failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorReason {
+    /// The file or stdin holding the universe could not be read.
+    Storage {
+        /// A human-readable description of the storage failure.
+        details: String,
+    },
+    /// The document does not have the shape its format requires, e.g. a CSV without a `cik`
+    /// column.
+    MalformedDocument {
+        /// A human-readable description of what is wrong with the document.
+        details: String,
+    },
+    /// A command-line argument is unknown, lacks its value, or has an invalid one.
+    InvalidArgument {
+        /// The offending argument.
+        argument: String,
+        /// A human-readable description of what is wrong with the argument.
+        details: String,
+    },
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Storage { details } => write!(
+                f,
+                "[Storage] CIK universe could not be read, Reason: '{details}'"
+            ),
+            Self::MalformedDocument { details } => write!(
+                f,
+                "[MalformedDocument] CIK universe is malformed, Reason: '{details}'"
+            ),
+            Self::InvalidArgument { argument, details } => write!(
+                f,
+                "[InvalidArgument] Argument '{argument}' is invalid, Reason: '{details}'"
+            ),
+        }
+    }
+}
+
+impl From<serde_json::Error> for UniverseError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::new(ErrorReason::MalformedDocument {
+            details: e.to_string(),
+        })
+    }
+}
+
+impl From<std::io::Error> for UniverseError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorReason::Storage {
+            details: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_format_display_as_expected_when_argument_is_invalid() {
+        let reason = ErrorReason::InvalidArgument {
+            argument: "--format".to_string(),
+            details: "unknown format 'xml'".to_string(),
+        };
+        let universe_error = UniverseError::new(reason.clone());
+
+        let expected_result =
+            format!("[UniverseError] Loading the CIK universe failed, Caused by: {reason}");
+
+        let result = format!("{universe_error}");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_map_to_malformed_document_reason_when_converting_from_json_error() {
+        let json_error = serde_json::from_str::<serde_json::Value>("{")
+            .expect_err("Truncated JSON should never parse");
+
+        let expected_result = ErrorReason::MalformedDocument {
+            details: json_error.to_string(),
+        };
+
+        let result = UniverseError::from(json_error).reason;
+
+        assert_eq!(result, expected_result);
+    }
+}
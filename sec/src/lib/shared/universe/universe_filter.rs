@@ -0,0 +1,153 @@
+//! # Universe Filter
+//!
+//! Provides the [`UniverseFilter`] narrowing a CIK universe down by ticker, exchange, or CIK
+//! range.
+
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+
+use super::UniverseEntry;
+use crate::shared::cik::Cik;
+
+/// Selects the entries of a CIK universe to run.
+///
+/// Each kind of criterion is optional: an entry passes if it matches any of the tickers, any of
+/// the exchanges, and any of the CIK ranges given, ignoring kinds without criteria. Tickers and
+/// exchanges compare case-insensitively; entries lacking a ticker or exchange never match a
+/// criterion on it. An empty filter passes every entry.
+///
+/// # Examples
+///
+/// ```
+/// use sec::shared::cik::Cik;
+/// use sec::shared::universe::{CikUniverse, UniverseFilter, UniverseFormat};
+///
+/// let universe = CikUniverse::parse("cik,ticker\n320193,AAPL\n789019,MSFT\n", UniverseFormat::Csv)
+///     .expect("A well-formed CSV should always parse");
+/// let filter = UniverseFilter::new().with_ticker("msft");
+///
+/// let expected_result = vec![Cik::new("789019").expect("A hardcoded valid CIK should always parse")];
+///
+/// let result = universe.filter(&filter).into_ciks().collect::<Vec<_>>();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UniverseFilter {
+    tickers: BTreeSet<String>,
+    exchanges: BTreeSet<String>,
+    cik_ranges: Vec<RangeInclusive<Cik>>,
+}
+
+impl UniverseFilter {
+    /// Creates a [`UniverseFilter`] passing every entry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also passes entries listed under `ticker`.
+    #[must_use]
+    pub fn with_ticker(mut self, ticker: impl AsRef<str>) -> Self {
+        self.tickers
+            .insert(ticker.as_ref().trim().to_ascii_uppercase());
+        self
+    }
+
+    /// Also passes entries listed on `exchange`.
+    #[must_use]
+    pub fn with_exchange(mut self, exchange: impl AsRef<str>) -> Self {
+        self.exchanges
+            .insert(exchange.as_ref().trim().to_ascii_uppercase());
+        self
+    }
+
+    /// Also passes entries whose CIK lies within `cik_range`, bounds included.
+    #[must_use]
+    pub fn with_cik_range(mut self, cik_range: RangeInclusive<Cik>) -> Self {
+        self.cik_ranges.push(cik_range);
+        self
+    }
+
+    /// Returns `true` if the filter has no criteria and so passes every entry.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tickers.is_empty() && self.exchanges.is_empty() && self.cik_ranges.is_empty()
+    }
+
+    /// Returns `true` if `entry` passes the filter.
+    #[must_use]
+    pub fn matches(&self, entry: &UniverseEntry) -> bool {
+        let matches_any = |criteria: &BTreeSet<String>, value: Option<&String>| {
+            criteria.is_empty()
+                || value.is_some_and(|value| criteria.contains(&value.to_ascii_uppercase()))
+        };
+
+        matches_any(&self.tickers, entry.ticker.as_ref())
+            && matches_any(&self.exchanges, entry.exchange.as_ref())
+            && (self.cik_ranges.is_empty()
+                || self
+                    .cik_ranges
+                    .iter()
+                    .any(|cik_range| cik_range.contains(&entry.cik)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn entry(cik: &str, ticker: Option<&str>, exchange: Option<&str>) -> UniverseEntry {
+        UniverseEntry {
+            cik: Cik::new(cik).expect("Hardcoded test CIKs should always be valid"),
+            ticker: ticker.map(str::to_string),
+            name: None,
+            exchange: exchange.map(str::to_string),
+        }
+    }
+
+    fn cik(cik: &str) -> Cik {
+        Cik::new(cik).expect("Hardcoded test CIKs should always be valid")
+    }
+
+    #[test]
+    fn should_pass_every_entry_when_filter_is_empty() {
+        let filter = UniverseFilter::new();
+
+        let expected_result = true;
+
+        let result = filter.matches(&entry("320193", None, None));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_require_every_kind_of_criterion_when_combining_criteria() {
+        let filter = UniverseFilter::new()
+            .with_exchange("NYSE")
+            .with_cik_range(cik("1")..=cik("500000"));
+
+        let expected_result = vec![true, false, false];
+
+        let result = vec![
+            filter.matches(&entry("320193", None, Some("nyse"))),
+            filter.matches(&entry("789019", None, Some("NYSE"))),
+            filter.matches(&entry("320193", None, Some("Nasdaq"))),
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_reject_entries_without_ticker_when_filtering_by_ticker() {
+        let filter = UniverseFilter::new().with_ticker("AAPL");
+
+        let expected_result = false;
+
+        let result = filter.matches(&entry("320193", None, None));
+
+        assert_eq!(result, expected_result);
+    }
+}
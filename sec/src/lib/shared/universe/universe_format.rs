@@ -0,0 +1,140 @@
+//! # Universe Format
+//!
+//! Provides the [`UniverseFormat`]s a CIK universe can be read from, and their detection from a
+//! file name or the document itself.
+
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+use super::universe_error::{ErrorReason, UniverseError};
+
+/// The format of a document listing a CIK universe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UniverseFormat {
+    /// One CIK per line. Blank lines and everything after a `#` are ignored.
+    PlainList,
+    /// Comma-separated values with a header row naming a `cik` column and, optionally, `ticker`,
+    /// `name` (or `title`), and `exchange` columns.
+    Csv,
+    /// The SEC's `company_tickers.json` (an object of `cik_str`/`ticker`/`title` records) or
+    /// `company_tickers_exchange.json` (`fields` and `data` rows) format.
+    CompanyTickers,
+}
+
+impl UniverseFormat {
+    /// Detects the format of `contents`, read from `path` if it came from a file.
+    ///
+    /// A `.json` or `.csv` extension decides; otherwise a document starting with `{` is taken
+    /// for [`CompanyTickers`](Self::CompanyTickers), one whose first line names a `cik` column
+    /// for [`Csv`](Self::Csv), and anything else for a [`PlainList`](Self::PlainList).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec::shared::universe::UniverseFormat;
+    ///
+    /// let expected_result = UniverseFormat::Csv;
+    ///
+    /// let result = UniverseFormat::detect(None, "cik,ticker\n320193,AAPL\n");
+    ///
+    /// assert_eq!(result, expected_result);
+    /// ```
+    #[must_use]
+    pub fn detect(path: Option<&Path>, contents: &str) -> Self {
+        match path
+            .and_then(Path::extension)
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("json") => return Self::CompanyTickers,
+            Some("csv") => return Self::Csv,
+            _ => {}
+        }
+
+        let trimmed = contents.trim_start();
+        if trimmed.starts_with('{') {
+            return Self::CompanyTickers;
+        }
+        let first_line = trimmed.lines().next().unwrap_or_default();
+        if first_line
+            .split(',')
+            .any(|column| column.trim().eq_ignore_ascii_case("cik"))
+        {
+            Self::Csv
+        } else {
+            Self::PlainList
+        }
+    }
+}
+
+impl Display for UniverseFormat {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::PlainList => write!(f, "plain"),
+            Self::Csv => write!(f, "csv"),
+            Self::CompanyTickers => write!(f, "company-tickers"),
+        }
+    }
+}
+
+impl FromStr for UniverseFormat {
+    type Err = UniverseError;
+
+    /// Parses `plain`, `csv`, or `company-tickers`, as displayed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UniverseError`] ([`ErrorReason::InvalidArgument`]) for any other name.
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "plain" => Ok(Self::PlainList),
+            "csv" => Ok(Self::Csv),
+            "company-tickers" => Ok(Self::CompanyTickers),
+            unknown => Err(UniverseError::new(ErrorReason::InvalidArgument {
+                argument: "--format".to_string(),
+                details: format!(
+                    "unknown format '{unknown}', expected 'plain', 'csv', or 'company-tickers'"
+                ),
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn should_prefer_extension_when_detecting_format_of_file() {
+        let expected_result = UniverseFormat::CompanyTickers;
+
+        let result = UniverseFormat::detect(Some(Path::new("company_tickers.json")), "320193\n");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_detect_plain_list_when_first_line_names_no_cik_column() {
+        let expected_result = UniverseFormat::PlainList;
+
+        let result = UniverseFormat::detect(None, "# S&P 500\n320193\n1067983\n");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_round_trip_when_parsing_displayed_format() {
+        let expected_result = UniverseFormat::CompanyTickers;
+
+        let result = UniverseFormat::CompanyTickers
+            .to_string()
+            .parse::<UniverseFormat>()
+            .expect("A displayed format should always parse");
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -10,7 +10,7 @@
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use self::url_error::{InvalidUrlReason, UrlError};
 
@@ -20,8 +20,9 @@ pub mod url_error;
 /// A string validated as a parseable URL.
 ///
 /// A newtype over [`String`] whose inner value is validated at construction, so holding a `Url`
-/// guarantees it parses.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+/// guarantees it parses. Deserializing goes through the same validation.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct Url {
     value: String,
 }
@@ -86,6 +87,25 @@ impl Url {
     }
 }
 
+impl TryFrom<String> for Url {
+    type Error = UrlError;
+
+    /// Delegates to [`Url::from_string`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UrlError`] if the input is not a valid URL.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_string(value)
+    }
+}
+
+impl From<Url> for String {
+    fn from(url: Url) -> Self {
+        url.value
+    }
+}
+
 impl Display for Url {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{}", self.value)
@@ -223,6 +243,29 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_restore_equal_url_when_round_tripping_through_json() {
+        let url = Url::from_str("https://data.sec.gov/submissions/CIK0001067983.json")
+            .expect("A hardcoded valid URL string should always parse successfully");
+        let serialized = serde_json::to_string(&url).expect("A URL should always serialize");
+
+        let expected_result = url;
+
+        let result: Url =
+            serde_json::from_str(&serialized).expect("A serialized URL should deserialize");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_when_url_is_malformed() {
+        let expected_result = true;
+
+        let result = serde_json::from_str::<Url>("\"not a url\"").is_err();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_preserve_original_owned_string_in_error_when_parsing_fails() {
         let invalid_url = String::from("not a url");
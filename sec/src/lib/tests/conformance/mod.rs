@@ -0,0 +1,330 @@
+//! # State Conformance Kit
+//!
+//! Provides the [`state_conformance!`] macro, which generates the property-based checks every SEC
+//! state must pass, and the generic checks it is built from.
+//!
+//! Given a [`proptest`] strategy producing freshly constructed states, the macro checks that:
+//!
+//! - the state implements the SEC [`State`] trait and [`Serialize`],
+//! - its output is `None` before it has been computed,
+//! - every listed transition fails with
+//!   [`MissingOutput`](crate::error::state_machine::transition::MissingOutput) before the output
+//!   has been computed,
+//! - `Eq`, `Ord`, `PartialOrd`, and `Hash` agree with each other for the state, its input data,
+//!   and its context,
+//! - serializing the state, its input data, or its context never fails.
+//!
+//! The input data and context are checked against the generic [`state_maschine`] contracts of
+//! [`SMStateData`] and [`SMContext`], so the checks apply to any state of the framework.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! state_conformance! {
+//!     state: ValidateCikFormat = arb_validate_cik_format(),
+//!     transitions: [PrepareSecRequest],
+//! }
+//! ```
+
+use std::cmp::Ordering;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::LazyLock;
+
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use serde::Serialize;
+use state_maschine::prelude::{Context as SMContext, State as SMState, StateData as SMStateData};
+
+use crate::error::state_machine::transition::Transition as TransitionError;
+use crate::shared::cik::Cik;
+use crate::shared::environment::PipelineEnvironment;
+use crate::traits::state_machine::state::State;
+use crate::traits::state_machine::transition::TryFromState;
+
+/// The number of generated cases each conformance property is checked against.
+pub const CASES: u32 = 64;
+
+/// The environment transitions are checked in, built once since creating its client is costly.
+static ENVIRONMENT: LazyLock<PipelineEnvironment> = LazyLock::new(PipelineEnvironment::default);
+
+/// Generates a valid [`Cik`] from ten arbitrary digits.
+///
+/// # Panics
+///
+/// Never panics in practice: ten digits always form a valid [`Cik`].
+pub fn arb_cik() -> impl Strategy<Value = Cik> {
+    "[0-9]{10}"
+        .prop_map(|digits| Cik::new(&digits).expect("Ten digits should always form a valid CIK"))
+}
+
+/// Compiles only if `S` is a SEC [`State`] whose data can be serialized for logging.
+pub const fn implements_sec_state<S: State + Serialize>() {}
+
+/// Checks that `state` reports no output, as every state must before computing it.
+///
+/// # Errors
+///
+/// Returns a [`TestCaseError`] if the state already holds output.
+pub fn check_no_output_before_compute<S: SMState>(state: &S) -> Result<(), TestCaseError> {
+    prop_assert!(state.output_data().is_none());
+    prop_assert!(!state.has_output_data_been_computed());
+    Ok(())
+}
+
+/// Checks that transitioning `state` into `N` fails with a
+/// [`MissingOutput`](TransitionError::MissingOutput) error.
+///
+/// # Errors
+///
+/// Returns a [`TestCaseError`] if the transition succeeds or fails for another reason.
+pub fn check_missing_output<S, N>(state: S) -> Result<(), TestCaseError>
+where
+    N: TryFromState<S>,
+{
+    let result = N::try_from_state(state, &ENVIRONMENT);
+    prop_assert!(matches!(result, Err(TransitionError::MissingOutput(_))));
+    Ok(())
+}
+
+/// Checks that `Eq`, `Ord`, `PartialOrd`, and `Hash` agree for `a` and `b`, and for `a` and its
+/// clone.
+///
+/// # Errors
+///
+/// Returns a [`TestCaseError`] naming the first contract the values break.
+pub fn check_consistent_ordering<T: Clone + Ord + Hash + std::fmt::Debug>(
+    a: &T,
+    b: &T,
+) -> Result<(), TestCaseError> {
+    let clone = a.clone();
+    prop_assert_eq!(a, &clone, "A clone should equal its original");
+    for (left, right) in [(a, b), (a, &clone)] {
+        prop_assert_eq!(
+            left == right,
+            left.cmp(right) == Ordering::Equal,
+            "`Eq` and `Ord` should agree"
+        );
+        prop_assert_eq!(
+            left.partial_cmp(right),
+            Some(left.cmp(right)),
+            "`PartialOrd` and `Ord` should agree"
+        );
+        prop_assert_eq!(
+            left.cmp(right),
+            right.cmp(left).reverse(),
+            "`Ord` should be antisymmetric"
+        );
+        if left == right {
+            prop_assert_eq!(
+                hash_of(left),
+                hash_of(right),
+                "Equal values should hash equally"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `value` serializes into JSON without error.
+///
+/// # Errors
+///
+/// Returns a [`TestCaseError`] carrying the serialization error.
+pub fn check_serializes<T: Serialize>(value: &T) -> Result<(), TestCaseError> {
+    serde_json::to_value(value)
+        .map(|_| ())
+        .map_err(|e| TestCaseError::fail(format!("Serialization should never fail: {e}")))
+}
+
+/// Checks the [`SMStateData`] contract for `a` and `b`: consistent ordering, serialization, and
+/// [`state`](SMStateData::state) returning the data itself.
+///
+/// # Errors
+///
+/// Returns a [`TestCaseError`] naming the first contract the data breaks.
+pub fn check_state_data<D: SMStateData + Serialize>(a: &D, b: &D) -> Result<(), TestCaseError> {
+    check_consistent_ordering(a, b)?;
+    check_serializes(a)?;
+    prop_assert_eq!(a.state(), a);
+    Ok(())
+}
+
+/// Checks the [`SMContext`] contract for `a` and `b`: consistent ordering, serialization, and
+/// [`context`](SMContext::context) returning the context itself.
+///
+/// # Errors
+///
+/// Returns a [`TestCaseError`] naming the first contract the context breaks.
+pub fn check_context<C: SMContext + Serialize>(a: &C, b: &C) -> Result<(), TestCaseError> {
+    check_consistent_ordering(a, b)?;
+    check_serializes(a)?;
+    prop_assert_eq!(a.context(), a);
+    Ok(())
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Generates a `conformance` test module checking a SEC state against the contracts listed in the
+/// [module docs](self).
+///
+/// The strategy must produce states whose output has not been computed yet. The optional
+/// `transitions` list names every state the checked state converts into through
+/// [`TryFromState`]. The state, its input data, and its context must implement [`Serialize`].
+///
+/// # Syntax
+///
+/// ```text
+/// state_conformance! {
+///     state: StateType = strategy_expression,
+///     transitions: [NextState, OtherNextState],
+/// }
+/// ```
+macro_rules! state_conformance {
+    (
+        state: $state:ty = $strategy:expr
+        $(, transitions: [$($next:ty),* $(,)?])? $(,)?
+    ) => {
+        mod conformance {
+            use ::proptest::prelude::*;
+            use ::state_maschine::prelude::State as _;
+
+            use super::*;
+            use $crate::tests::conformance;
+
+            #[test]
+            const fn should_implement_sec_state_contract_when_checking_conformance() {
+                conformance::implements_sec_state::<$state>();
+            }
+
+            proptest! {
+                #![proptest_config(ProptestConfig::with_cases(conformance::CASES))]
+
+                #[test]
+                fn should_have_no_output_when_output_has_not_been_computed(state in $strategy) {
+                    conformance::check_no_output_before_compute::<$state>(&state)?;
+                }
+
+                $(
+                    #[test]
+                    fn should_fail_with_missing_output_when_transitioning_before_computing(
+                        state in $strategy,
+                    ) {
+                        $(
+                            conformance::check_missing_output::<$state, $next>(state.clone())?;
+                        )*
+                    }
+                )?
+
+                #[test]
+                fn should_compare_consistently_when_comparing_states(
+                    a in $strategy,
+                    b in $strategy,
+                ) {
+                    conformance::check_consistent_ordering::<$state>(&a, &b)?;
+                }
+
+                #[test]
+                fn should_uphold_state_data_contract_when_comparing_input_data(
+                    a in $strategy,
+                    b in $strategy,
+                ) {
+                    conformance::check_state_data(a.input_data(), b.input_data())?;
+                }
+
+                #[test]
+                fn should_uphold_context_contract_when_comparing_contexts(
+                    a in $strategy,
+                    b in $strategy,
+                ) {
+                    conformance::check_context(a.context_data(), b.context_data())?;
+                }
+
+                #[test]
+                fn should_serialize_without_error_when_serializing_state(state in $strategy) {
+                    conformance::check_serializes::<$state>(&state)?;
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use state_conformance;
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use state_maschine::prelude::AsyncState as _;
+
+    use super::*;
+    use crate::tests::fixtures::sample_sec_state::{
+        SampleSecState, SampleSecStateContext, SampleSecStateInput,
+    };
+
+    fn arb_sample_sec_state() -> impl Strategy<Value = SampleSecState> {
+        (any::<String>(), any::<String>()).prop_map(|(input, context)| {
+            SampleSecState::new(
+                SampleSecStateInput::new(&input),
+                SampleSecStateContext::new(&context),
+            )
+        })
+    }
+
+    state_conformance! {
+        state: SampleSecState = arb_sample_sec_state(),
+    }
+
+    /// Orders by `rank` but compares by `label`, breaking the `Eq`/`Ord` agreement.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct InconsistentOrdering {
+        rank: u8,
+        label: &'static str,
+    }
+
+    impl PartialOrd for InconsistentOrdering {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for InconsistentOrdering {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.rank.cmp(&other.rank)
+        }
+    }
+
+    #[test]
+    fn should_reject_values_when_eq_and_ord_disagree() {
+        let a = InconsistentOrdering {
+            rank: 1,
+            label: "a",
+        };
+        let b = InconsistentOrdering {
+            rank: 1,
+            label: "b",
+        };
+
+        let expected_result = true;
+
+        let result = check_consistent_ordering(&a, &b).is_err();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_reject_state_when_output_has_already_been_computed() {
+        let mut state = SampleSecState::default();
+        state
+            .try_compute_output_data()
+            .expect("The sample state should always compute its output");
+
+        let expected_result = true;
+
+        let result = check_no_output_before_compute(&state).is_err();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -5,16 +5,21 @@
 //! ## Modules
 //!
 //! - [`sample_http_client`]: Fake HTTP and SEC clients.
+//! - [`sample_pipeline_launcher`]: A pipeline launcher running sample streaming pipelines per CIK.
 //! - [`sample_rate_limiter`]: A fake rate limiter that never blocks.
+//! - [`sample_repository`]: An in-memory repository recording what it persists.
 //! - [`sample_request`]: Fake inner and SEC requests.
 //! - [`sample_response`]: Fake inner and SEC responses.
 //! - [`sample_sec_state`]: A minimal sample [`State`](crate::traits::state_machine::state::State).
 //! - [`sample_sec_super_state`]: A sample super-state wrapping [`sample_sec_state`].
 //! - [`sample_streaming_super_state`]: A multi-state super-state for streaming tests.
+//! - [`sample_parallel_super_state`]: A parallel super-state fanning out to streaming test states.
 //! - [`data`]: Raw JSON fixtures (e.g. captured SEC responses).
 
 pub mod sample_http_client;
+pub mod sample_pipeline_launcher;
 pub mod sample_rate_limiter;
+pub mod sample_repository;
 pub mod sample_request;
 pub mod sample_response;
 
@@ -22,4 +27,6 @@ pub mod sample_sec_state;
 pub mod sample_sec_super_state;
 pub mod sample_streaming_super_state;
 
+pub mod sample_parallel_super_state;
+
 pub mod data;
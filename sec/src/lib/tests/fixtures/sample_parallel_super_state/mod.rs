@@ -0,0 +1,59 @@
+//! # Sample Parallel Super State Fixture
+//!
+//! A [`FanOut`] over [`SampleStateA`] children for testing the [`ParallelSuperState`]: it joins
+//! the children into the number that completed, then continues with the terminal
+//! [`SampleStreamingSuperState`] at [`SampleStateC`].
+
+use crate::error::State as StateError;
+use crate::error::state_machine::transition::Transition as TransitionError;
+use crate::tests::fixtures::sample_streaming_super_state::{
+    SampleStateA, SampleStateC, SampleStreamingSuperState,
+};
+use crate::traits::state_machine::parallel::{FanOut, ParallelSuperState};
+
+/// Joins [`SampleStateA`] children into the number that completed.
+///
+/// The join fails if children were fanned out to but none of them completed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SampleFanOut;
+
+impl FanOut for SampleFanOut {
+    type Child = SampleStateA;
+    type Output = usize;
+    type NextState = SampleStateC;
+    type Next = SampleStreamingSuperState<SampleStateC>;
+
+    fn super_state_name(&self) -> &'static str {
+        "Sample Parallel SuperState"
+    }
+
+    fn join(
+        &self,
+        results: Vec<Result<Self::Child, StateError>>,
+    ) -> Result<Self::Output, StateError> {
+        let completed = results.iter().filter(|result| result.is_ok()).count();
+        if completed == 0 && !results.is_empty() {
+            return Err(StateError::InvalidInput);
+        }
+        Ok(completed)
+    }
+
+    fn into_next(self, _output: Self::Output) -> Result<Self::Next, TransitionError> {
+        Ok(SampleStreamingSuperState::<SampleStateC>::default())
+    }
+}
+
+/// Creates a [`ParallelSuperState`] fanning out to one [`SampleStateA`] per entry of `failing`,
+/// each forced to fail if its entry is `true`.
+#[must_use]
+pub fn sample_parallel_super_state(failing: &[bool]) -> ParallelSuperState<SampleFanOut> {
+    let children = failing
+        .iter()
+        .map(|&force_compute_error| {
+            let mut child = SampleStateA::new();
+            child.force_compute_error = force_compute_error;
+            child
+        })
+        .collect();
+    ParallelSuperState::new(SampleFanOut, children)
+}
@@ -0,0 +1,85 @@
+//! # Sample Pipeline Launcher
+//!
+//! Provides [`SamplePipelineLauncher`], a [`PipelineLauncher`] running a
+//! [`SampleStreamingSuperState`] per CIK, for testing batches without network access.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use state_maschine::prelude::StateMachine as SMStateMachine;
+use uuid::Uuid;
+
+use crate::shared::batch::{PipelineLauncher, PipelineOutcome};
+use crate::tests::fixtures::sample_streaming_super_state::{
+    SampleStateA, SampleStreamingSuperState,
+};
+use crate::traits::state_machine::stream::{
+    IntoStateMachineStream, StateMachineStream, StreamOptions,
+};
+
+/// Counts the pipelines in flight and the most that ever were at once.
+#[derive(Debug, Default)]
+pub struct InFlightGauge {
+    current: AtomicUsize,
+    max: AtomicUsize,
+}
+
+impl InFlightGauge {
+    /// Returns the most pipelines that were ever in flight at once.
+    #[must_use]
+    pub fn max(&self) -> usize {
+        self.max.load(Ordering::SeqCst)
+    }
+
+    fn enter(&self) {
+        let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max.fetch_max(current, Ordering::SeqCst);
+    }
+
+    fn exit(&self) {
+        self.current.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// A [`PipelineLauncher`] running a [`SampleStreamingSuperState`] per CIK, failing the first state
+/// of the CIKs it was told to fail.
+#[derive(Debug, Default)]
+pub struct SamplePipelineLauncher {
+    failing: HashSet<String>,
+    in_flight: Arc<InFlightGauge>,
+}
+
+impl SamplePipelineLauncher {
+    /// Makes the pipelines of `ciks` fail in their first state.
+    #[must_use]
+    pub fn failing<'a>(mut self, ciks: impl IntoIterator<Item = &'a str>) -> Self {
+        self.failing.extend(ciks.into_iter().map(str::to_string));
+        self
+    }
+
+    /// Returns the gauge counting this launcher's pipelines in flight.
+    #[must_use]
+    pub fn in_flight(&self) -> Arc<InFlightGauge> {
+        Arc::clone(&self.in_flight)
+    }
+}
+
+#[async_trait]
+impl PipelineLauncher for SamplePipelineLauncher {
+    async fn launch(&self, cik: &str, options: StreamOptions) -> (Uuid, StateMachineStream) {
+        self.in_flight.enter();
+        // Yields once, so the batch gets to launch its other pipelines concurrently.
+        tokio::task::yield_now().await;
+
+        let execution_id = Uuid::new_v4();
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().force_compute_error = self.failing.contains(cik);
+        (execution_id, sm.into_stream_with(execution_id, options))
+    }
+
+    async fn finish(&self, _outcome: &PipelineOutcome) {
+        self.in_flight.exit();
+    }
+}
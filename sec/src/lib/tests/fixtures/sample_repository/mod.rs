@@ -0,0 +1,42 @@
+//! # Sample Repository
+//!
+//! Provides [`SampleRepository`], an in-memory [`Repository`] that records every persisted CIK,
+//! for testing code that depends on a repository without a database.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use storage::{Repository, WriteError};
+
+/// An in-memory [`Repository`] recording every persisted record in insertion order.
+#[derive(Debug, Default)]
+pub struct SampleRepository {
+    persisted: Mutex<Vec<String>>,
+}
+
+impl SampleRepository {
+    /// Returns a clone of every record persisted so far, in insertion order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock was poisoned by a panicking writer.
+    pub fn persisted(&self) -> Vec<String> {
+        self.persisted
+            .lock()
+            .expect("The sample repository lock should never be poisoned")
+            .clone()
+    }
+}
+
+#[async_trait]
+impl Repository for SampleRepository {
+    type Record = String;
+
+    async fn persist(&self, record: Self::Record) -> Result<(), WriteError> {
+        self.persisted
+            .lock()
+            .expect("The sample repository lock should never be poisoned")
+            .push(record);
+        Ok(())
+    }
+}
@@ -19,11 +19,13 @@
 //! - [`crate::tests::fixtures::sample_sec_state`]: Parent module for the sample state fixture.
 use std::fmt;
 
+use serde::Serialize;
+
 use state_maschine::prelude::Context as SMContext;
 
 use crate::traits::state_machine::state::Context;
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
 /// State context for the `SampleSecState` fixture.
 pub struct SampleSecStateContext {
     pub data: String,
@@ -17,12 +17,14 @@
 //! - [`crate::traits::state_machine::state::StateData`]: Trait for state data integration.
 use std::fmt;
 
+use serde::Serialize;
+
 use state_maschine::prelude::StateData as SMStateData;
 
 use crate::error::State as StateError;
 use crate::traits::state_machine::state::StateData;
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
 /// Output data for the `SampleSecState` fixture.
 pub struct SampleSecStateOutput {
     pub output_data: String,
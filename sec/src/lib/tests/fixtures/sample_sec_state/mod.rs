@@ -47,10 +47,12 @@
 
 use std::fmt;
 
-use async_trait::async_trait;
-use state_maschine::prelude::State as SMState;
+use serde::Serialize;
+
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
+use crate::shared::executor::TokioExecutor;
 use crate::traits::state_machine::state::State;
 
 pub mod context;
@@ -60,7 +62,7 @@ pub use context::SampleSecStateContext;
 pub use data::SampleSecStateInput;
 pub use data::SampleSecStateOutput;
 
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
 pub struct SampleSecState {
     input: SampleSecStateInput,
     context: SampleSecStateContext,
@@ -78,8 +80,10 @@ impl SampleSecState {
     }
 }
 
-#[async_trait]
-impl State for SampleSecState {
+impl SMAsyncState for SampleSecState {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
         self.output = Some(SampleSecStateOutput {
             output_data: "Hello World!".to_string(),
@@ -88,6 +92,8 @@ impl State for SampleSecState {
     }
 }
 
+impl State for SampleSecState {}
+
 impl SMState for SampleSecState {
     type InputData = SampleSecStateInput;
     type OutputData = SampleSecStateOutput;
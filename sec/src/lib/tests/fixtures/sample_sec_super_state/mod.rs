@@ -5,13 +5,15 @@
 
 use std::fmt;
 
-use async_trait::async_trait;
 use state_maschine::prelude::{
-    State as SMState, StateMachine as SMStateMachine, SuperState as SMSuperState,
+    AsyncState as SMAsyncState, AsyncStateMachine as SMAsyncStateMachine,
+    AsyncSuperState as SMAsyncSuperState, State as SMState, StateMachine as SMStateMachine,
+    SuperState as SMSuperState,
 };
 
 use crate::error::State as StateError;
 use crate::prelude::*;
+use crate::shared::executor::TokioExecutor;
 use crate::tests::fixtures::sample_sec_state::SampleSecState;
 
 pub mod sample_sec_super_state_context;
@@ -46,16 +48,17 @@ impl<S: State> fmt::Display for SampleSecSuperState<S> {
     }
 }
 
-#[async_trait]
-impl<S: State> State for SampleSecSuperState<S> {
+impl<S: State> SMAsyncState for SampleSecSuperState<S> {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
-        self.current_state
-            .compute_output_data_async()
-            .await
-            .map_err(Into::into)
+        self.current_state.compute_output_data_async().await
     }
 }
 
+impl<S: State> State for SampleSecSuperState<S> {}
+
 impl<S: State> SMState for SampleSecSuperState<S> {
     type InputData = SampleSecSuperStateData;
     type OutputData = SampleSecSuperStateData;
@@ -103,6 +106,14 @@ impl<S: State> SMStateMachine<S> for SampleSecSuperState<S> {
 
 impl<S: State> StateMachine<S> for SampleSecSuperState<S> {}
 
+impl<S: State> SMAsyncStateMachine<S> for SampleSecSuperState<S> {}
+
 impl<S: State> SMSuperState<S> for SampleSecSuperState<S> {}
 
-impl<S: State> SuperState<S> for SampleSecSuperState<S> {}
+impl<S: State> SMAsyncSuperState<S> for SampleSecSuperState<S> {}
+
+impl<S: State> SuperState<S> for SampleSecSuperState<S> {
+    fn super_state_name(&self) -> &'static str {
+        "Sample SEC SuperState"
+    }
+}
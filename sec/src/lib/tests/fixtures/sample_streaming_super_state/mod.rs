@@ -1,35 +1,46 @@
 //! # Sample Streaming Super State Fixture
 //!
-//! A minimal 3-state linear pipeline (`StateA → StateB → StateC`) for testing the
-//! [`IntoStateMachineStream`] blanket impl and [`NonTerminal`] trait.
+//! A minimal pipeline (`StateA → StateB → StateC`, or `StateA → StateC` when
+//! [`SampleStateA::skip_state_b`] is set) for testing the [`IntoStateMachineStream`] blanket impl
+//! and the [`Branching`] and [`NonTerminal`] traits. While [`SampleStateA::backoffs`] is
+//! non-zero, `StateA` first loops through the `StateA → StateD → StateA` cycle.
 
 use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
 
-use async_trait::async_trait;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use state_maschine::prelude::{
-    State as SMState, StateMachine as SMStateMachine, SuperState as SMSuperState,
-    Transition as SMTransition,
+    AsyncState as SMAsyncState, AsyncStateMachine as SMAsyncStateMachine,
+    AsyncSuperState as SMAsyncSuperState, State as SMState, StateMachine as SMStateMachine,
+    SuperState as SMSuperState, Transition as SMTransition,
 };
 
 use crate::error::State as StateError;
-use crate::error::state_machine::transition::Transition as TransitionError;
+use crate::error::state_machine::transition::{MissingOutput, Transition as TransitionError};
 use crate::prelude::*;
+use crate::shared::checkpoint::{Checkpoint, CheckpointError};
+use crate::shared::executor::TokioExecutor;
+use crate::shared::retry_policy::RetryPolicy;
 use crate::traits::state_machine::state::StateData;
 
 pub mod state_a;
 pub mod state_b;
 pub mod state_c;
+pub mod state_d;
 
-pub use state_a::SampleStateA;
+pub use state_a::{SampleStateA, SampleStateANext, SampleStateAOutput};
 pub use state_b::SampleStateB;
 pub use state_c::SampleStateC;
+pub use state_d::SampleStateD;
 
 // --- Shared data/context unit types ---
 
-/// Unit struct for super state data — no actual data needed for streaming tests.
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
-pub struct SampleStreamingData;
+/// Empty struct for super state data — no actual data needed for streaming tests.
+///
+/// Braced rather than a unit struct: a unit struct serializes to `null`, which would make a
+/// computed `Some` output indistinguishable from `None` in a checkpoint.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
+pub struct SampleStreamingData {}
 
 impl StateData for SampleStreamingData {
     fn update_state(&mut self, _updates: Self::UpdateType) -> Result<(), StateError> {
@@ -48,12 +59,25 @@ impl SMStateData for SampleStreamingData {
 }
 
 /// Unit struct for super state context — no actual context needed for streaming tests.
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
+///
+/// Allows two retries without backoff, so retry tests run instantly, and bounds each computation
+/// to a short timeout, so timeout tests do too.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
 pub struct SampleStreamingContext;
 
 impl Context for SampleStreamingContext {
     fn max_retries(&self) -> u32 {
-        0
+        2
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::new(self.max_retries())
+            .with_initial_backoff(Duration::ZERO)
+            .with_jitter(false)
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Some(Duration::from_millis(50))
     }
 }
 
@@ -70,7 +94,7 @@ impl SMContext for SampleStreamingContext {
 // --- Super state ---
 
 /// A minimal super state for testing streaming. Generic over the current inner state.
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
 pub struct SampleStreamingSuperState<S: State> {
     current_state: S,
     input: SampleStreamingData,
@@ -86,7 +110,7 @@ impl SampleStreamingSuperState<SampleStateA> {
     pub const fn new() -> Self {
         Self {
             current_state: SampleStateA::new(),
-            input: SampleStreamingData,
+            input: SampleStreamingData {},
             output: None,
             context: SampleStreamingContext,
             force_compute_error: false,
@@ -95,6 +119,24 @@ impl SampleStreamingSuperState<SampleStateA> {
     }
 }
 
+impl<S: Checkpointable<Snapshot = S>> SampleStreamingSuperState<S> {
+    /// Resumes the streaming super state at the state captured in `checkpoint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CheckpointError`] if the checkpoint does not hold an `S`.
+    pub fn resume_from(checkpoint: &Checkpoint) -> Result<Self, CheckpointError> {
+        Ok(Self {
+            current_state: checkpoint.restore(Ok)?,
+            input: SampleStreamingData {},
+            output: None,
+            context: SampleStreamingContext,
+            force_compute_error: false,
+            force_transition_error: false,
+        })
+    }
+}
+
 impl<S: State> Display for SampleStreamingSuperState<S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -105,19 +147,20 @@ impl<S: State> Display for SampleStreamingSuperState<S> {
     }
 }
 
-#[async_trait]
-impl<S: State> State for SampleStreamingSuperState<S> {
+impl<S: State> SMAsyncState for SampleStreamingSuperState<S> {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
         if self.force_compute_error {
             return Err(StateError::InvalidInput);
         }
-        self.current_state
-            .compute_output_data_async()
-            .await
-            .map_err(Into::into)
+        self.current_state.compute_output_data_async().await
     }
 }
 
+impl<S: State> State for SampleStreamingSuperState<S> {}
+
 impl<S: State> SMState for SampleStreamingSuperState<S> {
     type InputData = SampleStreamingData;
     type OutputData = SampleStreamingData;
@@ -161,9 +204,17 @@ impl<S: State> SMStateMachine<S> for SampleStreamingSuperState<S> {
 
 impl<S: State> StateMachine<S> for SampleStreamingSuperState<S> {}
 
+impl<S: State> SMAsyncStateMachine<S> for SampleStreamingSuperState<S> {}
+
 impl<S: State> SMSuperState<S> for SampleStreamingSuperState<S> {}
 
-impl<S: State> SuperState<S> for SampleStreamingSuperState<S> {}
+impl<S: State> SMAsyncSuperState<S> for SampleStreamingSuperState<S> {}
+
+impl<S: State> SuperState<S> for SampleStreamingSuperState<S> {
+    fn super_state_name(&self) -> &'static str {
+        "Sample Streaming SuperState"
+    }
+}
 
 // --- Transitions ---
 
@@ -187,7 +238,88 @@ impl Transition<SampleStateA, SampleStateB> for SampleStreamingSuperState<Sample
         }
         Ok(SampleStreamingSuperState {
             current_state: SampleStateB::new(),
-            input: SampleStreamingData,
+            input: SampleStreamingData {},
+            output: None,
+            context: SampleStreamingContext,
+            force_compute_error: false,
+            force_transition_error: false,
+        })
+    }
+}
+
+impl SMTransition<SampleStateA, SampleStateC> for SampleStreamingSuperState<SampleStateA> {
+    type NewStateMachine = SampleStreamingSuperState<SampleStateC>;
+
+    fn transition_to_next_state(self) -> Result<Self::NewStateMachine, &'static str> {
+        Err("Use transition_to_next_state_sec() for rich error handling")
+    }
+}
+
+impl Transition<SampleStateA, SampleStateC> for SampleStreamingSuperState<SampleStateA> {
+    fn transition_to_next_state_sec(self) -> Result<Self::NewStateMachine, TransitionError> {
+        if self.force_transition_error {
+            return Err(TransitionError::FailedContextConversion(
+                crate::error::state_machine::transition::FailedContextConversion::new(
+                    "SampleStateA",
+                    "SampleStateC",
+                ),
+            ));
+        }
+        Ok(SampleStreamingSuperState {
+            current_state: SampleStateC::new(),
+            input: SampleStreamingData {},
+            output: None,
+            context: SampleStreamingContext,
+            force_compute_error: false,
+            force_transition_error: false,
+        })
+    }
+}
+
+impl SMTransition<SampleStateA, SampleStateD> for SampleStreamingSuperState<SampleStateA> {
+    type NewStateMachine = SampleStreamingSuperState<SampleStateD>;
+
+    fn transition_to_next_state(self) -> Result<Self::NewStateMachine, &'static str> {
+        Err("Use transition_to_next_state_sec() for rich error handling")
+    }
+}
+
+impl Transition<SampleStateA, SampleStateD> for SampleStreamingSuperState<SampleStateA> {
+    fn transition_to_next_state_sec(self) -> Result<Self::NewStateMachine, TransitionError> {
+        if self.force_transition_error {
+            return Err(TransitionError::FailedContextConversion(
+                crate::error::state_machine::transition::FailedContextConversion::new(
+                    "SampleStateA",
+                    "SampleStateD",
+                ),
+            ));
+        }
+        Ok(SampleStreamingSuperState {
+            current_state: SampleStateD::new(self.current_state.backoffs.saturating_sub(1)),
+            input: SampleStreamingData {},
+            output: None,
+            context: SampleStreamingContext,
+            force_compute_error: false,
+            force_transition_error: false,
+        })
+    }
+}
+
+impl SMTransition<SampleStateD, SampleStateA> for SampleStreamingSuperState<SampleStateD> {
+    type NewStateMachine = SampleStreamingSuperState<SampleStateA>;
+
+    fn transition_to_next_state(self) -> Result<Self::NewStateMachine, &'static str> {
+        Err("Use transition_to_next_state_sec() for rich error handling")
+    }
+}
+
+impl Transition<SampleStateD, SampleStateA> for SampleStreamingSuperState<SampleStateD> {
+    fn transition_to_next_state_sec(self) -> Result<Self::NewStateMachine, TransitionError> {
+        let mut state_a = SampleStateA::new();
+        state_a.backoffs = self.current_state.backoffs;
+        Ok(SampleStreamingSuperState {
+            current_state: state_a,
+            input: SampleStreamingData {},
             output: None,
             context: SampleStreamingContext,
             force_compute_error: false,
@@ -216,7 +348,7 @@ impl Transition<SampleStateB, SampleStateC> for SampleStreamingSuperState<Sample
         }
         Ok(SampleStreamingSuperState {
             current_state: SampleStateC::new(),
-            input: SampleStreamingData,
+            input: SampleStreamingData {},
             output: None,
             context: SampleStreamingContext,
             force_compute_error: false,
@@ -227,9 +359,62 @@ impl Transition<SampleStateB, SampleStateC> for SampleStreamingSuperState<Sample
 
 // --- Streaming ---
 
-impl NonTerminal for SampleStreamingSuperState<SampleStateA> {
+/// The possible successors of [`SampleStateA`].
+#[derive(Debug)]
+pub enum SampleStateABranch {
+    StateB(SampleStreamingSuperState<SampleStateB>),
+    StateC(SampleStreamingSuperState<SampleStateC>),
+    StateD(SampleStreamingSuperState<SampleStateD>),
+}
+
+impl IntoStateMachineStream for SampleStateABranch {
+    fn into_stream_with(
+        self,
+        execution_id: uuid::Uuid,
+        options: StreamOptions,
+    ) -> StateMachineStream {
+        match self {
+            Self::StateB(sm) => sm.into_stream_with(execution_id, options),
+            Self::StateC(sm) => sm.into_stream_with(execution_id, options),
+            Self::StateD(sm) => sm.into_stream_with(execution_id, options),
+        }
+    }
+}
+
+impl Branching for SampleStreamingSuperState<SampleStateA> {
     type Current = SampleStateA;
-    type Next = SampleStateB;
+    type Branch = SampleStateABranch;
+
+    fn select_branch(self) -> Result<Self::Branch, TransitionError> {
+        let next = self
+            .current_state
+            .output_data()
+            .ok_or_else(|| MissingOutput::new("Sample State A", "Sample State B, C, or D"))?
+            .next;
+        match next {
+            SampleStateANext::StateB => {
+                Transition::<SampleStateA, SampleStateB>::transition_to_next_state_sec(self)
+                    .map(SampleStateABranch::StateB)
+            }
+            SampleStateANext::StateC => {
+                Transition::<SampleStateA, SampleStateC>::transition_to_next_state_sec(self)
+                    .map(SampleStateABranch::StateC)
+            }
+            SampleStateANext::StateD => {
+                Transition::<SampleStateA, SampleStateD>::transition_to_next_state_sec(self)
+                    .map(SampleStateABranch::StateD)
+            }
+        }
+    }
+
+    fn branch_state_name(branch: &Self::Branch) -> Option<String> {
+        let state_name = match branch {
+            SampleStateABranch::StateB(sm) => sm.current_state().state_name().to_string(),
+            SampleStateABranch::StateC(sm) => sm.current_state().state_name().to_string(),
+            SampleStateABranch::StateD(sm) => sm.current_state().state_name().to_string(),
+        };
+        Some(state_name)
+    }
 }
 
 impl NonTerminal for SampleStreamingSuperState<SampleStateB> {
@@ -237,52 +422,11 @@ impl NonTerminal for SampleStreamingSuperState<SampleStateB> {
     type Next = SampleStateC;
 }
 
-/// Terminal state — manual [`IntoStateMachineStream`] impl.
-impl IntoStateMachineStream for SampleStreamingSuperState<SampleStateC> {
-    fn into_stream(self, execution_id: uuid::Uuid) -> StateMachineStream {
-        Box::pin(async_stream::stream! {
-            use crate::traits::state_machine::stream::{StreamEvent, StreamItem, StreamError};
-
-            let mut sm = self;
-            let state_name = sm.current_state().state_name().to_string();
-
-            let state_start = std::time::Instant::now();
-
-            yield Ok(StreamItem {
-                event: StreamEvent::StateStarted,
-                state_name: state_name.clone(),
-                data: serde_json::to_value(sm.current_state()).unwrap_or_else(|e| {
-                    serde_json::json!({ "serialization_error": e.to_string() })
-                }),
-                event_duration: std::time::Duration::ZERO,
-            });
-
-            match sm.current_state_mut().compute_output_data_async().await {
-                Ok(()) => {
-                    yield Ok(StreamItem {
-                        event: StreamEvent::StateCompleted,
-                        state_name,
-                        data: serde_json::to_value(sm.current_state()).unwrap_or_else(|e| {
-                            serde_json::json!({ "serialization_error": e.to_string() })
-                        }),
-                        event_duration: state_start.elapsed(),
-                    });
-                }
-                Err(e) => {
-                    #[allow(clippy::useless_conversion)]
-                    let state_err: crate::error::State = e.into();
-                    let sm_error: crate::error::StateMachine = state_err.into();
-                    yield Err(StreamError {
-                        event: StreamEvent::StateFailed,
-                        execution_id,
-                        state_name: state_name.clone(),
-                        data: serde_json::to_value(sm.current_state()).unwrap_or_else(|e| {
-                            serde_json::json!({ "serialization_error": e.to_string() })
-                        }),
-                        source: sm_error,
-                    });
-                }
-            }
-        })
-    }
+impl NonTerminal for SampleStreamingSuperState<SampleStateD> {
+    type Current = SampleStateD;
+    type Next = SampleStateA;
+}
+
+impl Terminal for SampleStreamingSuperState<SampleStateC> {
+    type Current = SampleStateC;
 }
@@ -1,49 +1,141 @@
 use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
 
-use async_trait::async_trait;
-use serde::Serialize;
-use state_maschine::prelude::State as SMState;
+use serde::{Deserialize, Serialize};
+use state_maschine::prelude::{
+    AsyncState as SMAsyncState, State as SMState, StateData as SMStateData,
+};
 
 use crate::error::State as StateError;
-use crate::traits::state_machine::state::State;
+use crate::error::state_machine::state::FailedRequestExecution;
+use crate::shared::executor::TokioExecutor;
+use crate::shared::http_client::implementations::sec_client::error::{
+    ErrorReason, FailedSecRequest,
+};
+use crate::traits::state_machine::checkpoint::Checkpointable;
+use crate::traits::state_machine::state::{State, StateData};
 
 use super::{SampleStreamingContext, SampleStreamingData};
 
+/// The successor [`SampleStateA`] computes it continues with.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize,
+)]
+pub enum SampleStateANext {
+    /// Continue with [`SampleStateB`](super::SampleStateB).
+    #[default]
+    StateB,
+    /// Skip straight to [`SampleStateC`](super::SampleStateC).
+    StateC,
+    /// Back off through [`SampleStateD`](super::SampleStateD), which leads back to
+    /// [`SampleStateA`].
+    StateD,
+}
+
+/// The output of [`SampleStateA`]: the successor the pipeline continues with.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
+pub struct SampleStateAOutput {
+    pub next: SampleStateANext,
+}
+
+impl StateData for SampleStateAOutput {
+    fn update_state(&mut self, _updates: Self::UpdateType) -> Result<(), StateError> {
+        Ok(())
+    }
+}
+
+impl SMStateData for SampleStateAOutput {
+    type UpdateType = ();
+
+    fn state(&self) -> &Self {
+        self
+    }
+
+    fn update_state(&mut self, _updates: Self::UpdateType) {}
+}
+
 /// First state in the sample streaming pipeline.
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
+///
+/// Computes which state follows it: [`SampleStateD`](super::SampleStateD) while `backoffs` is
+/// non-zero, otherwise [`SampleStateC`](super::SampleStateC) when `skip_state_b` is set and
+/// [`SampleStateB`](super::SampleStateB) when it is not. Fails with a retryable error for as many
+/// computations as `transient_failures` says, then hangs for as many computations as
+/// `slow_computations` says so they exceed the context's timeout.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
 pub struct SampleStateA {
     input: SampleStreamingData,
     context: SampleStreamingContext,
-    output: Option<SampleStreamingData>,
+    output: Option<SampleStateAOutput>,
     pub force_compute_error: bool,
+    pub skip_state_b: bool,
+    pub backoffs: u32,
+    pub transient_failures: u32,
+    pub slow_computations: u32,
 }
 
 impl SampleStateA {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            input: SampleStreamingData,
+            input: SampleStreamingData {},
             context: SampleStreamingContext,
             output: None,
             force_compute_error: false,
+            skip_state_b: false,
+            backoffs: 0,
+            transient_failures: 0,
+            slow_computations: 0,
         }
     }
 }
 
-#[async_trait]
-impl State for SampleStateA {
+impl SMAsyncState for SampleStateA {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
         if self.force_compute_error {
             return Err(StateError::InvalidInput);
         }
-        self.output = Some(SampleStreamingData);
+        if self.transient_failures > 0 {
+            self.transient_failures -= 1;
+            return Err(FailedRequestExecution::new(
+                "Sample State A",
+                FailedSecRequest::new(ErrorReason::FailedRequestExecution {
+                    details: "Simulated transient failure".to_string(),
+                }),
+            )
+            .into());
+        }
+        if self.slow_computations > 0 {
+            self.slow_computations -= 1;
+            tokio::time::sleep(Duration::from_hours(1)).await;
+        }
+        let next = if self.backoffs > 0 {
+            SampleStateANext::StateD
+        } else if self.skip_state_b {
+            SampleStateANext::StateC
+        } else {
+            SampleStateANext::StateB
+        };
+        self.output = Some(SampleStateAOutput { next });
         Ok(())
     }
 }
 
+impl State for SampleStateA {}
+
+impl Checkpointable for SampleStateA {
+    type Snapshot = Self;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+}
+
 impl SMState for SampleStateA {
     type InputData = SampleStreamingData;
-    type OutputData = SampleStreamingData;
+    type OutputData = SampleStateAOutput;
     type Context = SampleStreamingContext;
 
     fn state_name(&self) -> impl ToString {
@@ -1,16 +1,17 @@
 use std::fmt::{self, Display, Formatter};
 
-use async_trait::async_trait;
-use serde::Serialize;
-use state_maschine::prelude::State as SMState;
+use serde::{Deserialize, Serialize};
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
+use crate::shared::executor::TokioExecutor;
+use crate::traits::state_machine::checkpoint::Checkpointable;
 use crate::traits::state_machine::state::State;
 
 use super::{SampleStreamingContext, SampleStreamingData};
 
 /// Second state in the sample streaming pipeline.
-#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
 pub struct SampleStateB {
     input: SampleStreamingData,
     context: SampleStreamingContext,
@@ -21,21 +22,33 @@ impl SampleStateB {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            input: SampleStreamingData,
+            input: SampleStreamingData {},
             context: SampleStreamingContext,
             output: None,
         }
     }
 }
 
-#[async_trait]
-impl State for SampleStateB {
+impl SMAsyncState for SampleStateB {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
     async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
-        self.output = Some(SampleStreamingData);
+        self.output = Some(SampleStreamingData {});
         Ok(())
     }
 }
 
+impl State for SampleStateB {}
+
+impl Checkpointable for SampleStateB {
+    type Snapshot = Self;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+}
+
 impl SMState for SampleStateB {
     type InputData = SampleStreamingData;
     type OutputData = SampleStreamingData;
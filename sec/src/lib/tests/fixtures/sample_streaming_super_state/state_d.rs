@@ -0,0 +1,87 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
+
+use crate::error::State as StateError;
+use crate::shared::executor::TokioExecutor;
+use crate::traits::state_machine::checkpoint::Checkpointable;
+use crate::traits::state_machine::state::State;
+
+use super::{SampleStreamingContext, SampleStreamingData};
+
+/// Backoff state in the sample streaming pipeline, closing the
+/// [`SampleStateA`](super::SampleStateA) → [`SampleStateD`] → [`SampleStateA`](super::SampleStateA)
+/// cycle.
+///
+/// Always transitions back to [`SampleStateA`](super::SampleStateA), handing it the `backoffs`
+/// still left.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord, Serialize, Deserialize)]
+pub struct SampleStateD {
+    input: SampleStreamingData,
+    context: SampleStreamingContext,
+    output: Option<SampleStreamingData>,
+    pub backoffs: u32,
+}
+
+impl SampleStateD {
+    #[must_use]
+    pub const fn new(backoffs: u32) -> Self {
+        Self {
+            input: SampleStreamingData {},
+            context: SampleStreamingContext,
+            output: None,
+            backoffs,
+        }
+    }
+}
+
+impl SMAsyncState for SampleStateD {
+    type Error = StateError;
+    type Executor = TokioExecutor;
+
+    async fn compute_output_data_async(&mut self) -> Result<(), StateError> {
+        self.output = Some(SampleStreamingData {});
+        Ok(())
+    }
+}
+
+impl State for SampleStateD {}
+
+impl Checkpointable for SampleStateD {
+    type Snapshot = Self;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+}
+
+impl SMState for SampleStateD {
+    type InputData = SampleStreamingData;
+    type OutputData = SampleStreamingData;
+    type Context = SampleStreamingContext;
+
+    fn state_name(&self) -> impl ToString {
+        "Sample State D"
+    }
+
+    fn compute_output_data(&mut self) {}
+
+    fn context_data(&self) -> &Self::Context {
+        &self.context
+    }
+
+    fn input_data(&self) -> &Self::InputData {
+        &self.input
+    }
+
+    fn output_data(&self) -> Option<&Self::OutputData> {
+        self.output.as_ref()
+    }
+}
+
+impl Display for SampleStateD {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "SampleStateD")
+    }
+}
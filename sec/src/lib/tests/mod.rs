@@ -5,5 +5,7 @@
 //! ## Modules
 //!
 //! - [`fixtures`]: Reusable sample states, clients, requests, and responses.
+//! - [`conformance`]: The [`state_conformance!`](conformance::state_conformance) macro checking a state against the SEC state contracts.
 
+pub mod conformance;
 pub mod fixtures;
@@ -0,0 +1,33 @@
+//! # Checkpointable States
+//!
+//! Provides the [`Checkpointable`] trait, which lets a state be captured into a
+//! [`Checkpoint`](crate::shared::checkpoint::Checkpoint) and later restored from it.
+//!
+//! ## Design
+//!
+//! A state's [`Serialize`] impl is tuned for stream logging and deliberately lossy (e.g. an
+//! [`SecResponse`](crate::shared::response::implementations::sec_response::SecResponse) omits its
+//! body). Checkpoints instead go through a dedicated [`Snapshot`](Checkpointable::Snapshot): a
+//! lossless, [`Deserialize`](serde::Deserialize)-able representation of everything needed to
+//! rebuild the state, minus live resources such as the HTTP client, which are supplied again on
+//! resume.
+//!
+//! Restoring a snapshot is done by the owning super-state's `resume_from` constructor, since only
+//! it knows which resources the state needs.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::state::State;
+
+/// A state that can be captured into a [`Checkpoint`](crate::shared::checkpoint::Checkpoint).
+///
+/// Every state streamed by [`IntoStateMachineStream`](super::stream::IntoStateMachineStream) is
+/// checkpointable, so a run can persist its progress after each completed state.
+pub trait Checkpointable: State {
+    /// The lossless, serializable representation of this state.
+    type Snapshot: Serialize + DeserializeOwned + Send;
+
+    /// Captures the state's input, context, and (if computed) output as a [`Self::Snapshot`].
+    fn snapshot(&self) -> Self::Snapshot;
+}
@@ -0,0 +1,45 @@
+//! # Stream Middleware
+//!
+//! Provides the [`StreamMiddleware`] trait, which hooks cross-cutting concerns (timing, tracing
+//! spans, metrics, audit logs) into the lifecycle of every state a stream drives.
+//!
+//! ## Design
+//!
+//! Middleware is registered on the [`StreamOptions`](super::stream::StreamOptions) a stream is
+//! driven with (see [`StreamOptions::with_middleware`](super::stream::StreamOptions::with_middleware)).
+//! Since the options are forwarded through the entire chain of states, the hooks fire for every
+//! state, including the inner states of nested super-states.
+//!
+//! Each hook receives the same [`StreamItem`] or [`StreamError`] the stream is about to yield, so
+//! middleware observes exactly what a consumer of the stream observes, but without having to wrap
+//! or fork the driver. Hooks are awaited before the item is yielded, in registration order.
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use super::stream::{StreamError, StreamItem};
+
+/// Hooks invoked by the stream driver around each state it drives.
+///
+/// Every hook has a no-op default, so an implementation only overrides the ones it needs. Hooks
+/// cannot alter or abort the stream; a middleware that fails should record the failure itself.
+#[async_trait]
+pub trait StreamMiddleware: Send + Sync + Debug {
+    /// Called when a state is about to begin computation, with its
+    /// [`StateStarted`](super::stream::StreamEvent::StateStarted) item.
+    async fn before_state(&self, _execution_id: uuid::Uuid, _item: &StreamItem) {}
+
+    /// Called when a state has completed computation, with its
+    /// [`StateCompleted`](super::stream::StreamEvent::StateCompleted) item.
+    async fn after_state(&self, _execution_id: uuid::Uuid, _item: &StreamItem) {}
+
+    /// Called when the stream ends with an error: a final state failure or timeout, a failed
+    /// transition, or a cancellation.
+    async fn on_error(&self, _execution_id: uuid::Uuid, _error: &StreamError) {}
+
+    /// Called when a state machine has transitioned to its next state, with the
+    /// [`TransitionCompleted`](super::stream::StreamEvent::TransitionCompleted) item. The item is
+    /// attributed to the state left behind; its data names the state entered under `"to"`.
+    async fn on_transition(&self, _execution_id: uuid::Uuid, _item: &StreamItem) {}
+}
@@ -12,12 +12,26 @@
 //! - [`super_state`]: The [`SuperState`](super_state::SuperState) trait for hierarchical states.
 //! - [`transition`]: The [`Transition`](transition::Transition) trait for moving between states.
 //! - [`stream`]: Converting a state machine into an async event stream.
+//! - [`checkpoint`]: The [`Checkpointable`](checkpoint::Checkpointable) trait for persisting state progress.
+//! - [`middleware`]: The [`StreamMiddleware`](middleware::StreamMiddleware) trait for hooking into a stream's state lifecycle.
+//! - [`payload`]: The [`StatePayload`](payload::StatePayload) carrying the typed data of a stream event.
+//! - [`parallel`]: The [`ParallelSuperState`](parallel::ParallelSuperState) computing several child states concurrently.
+//! - [`state_path`]: The [`StatePath`](state_path::StatePath) locating a state within its super-states.
+//! - [`sequence`]: Macros generating the transitions of a super-state's linear state sequence.
 
 use std::fmt::Display;
 
-use state_maschine::prelude::StateMachine as SMStateMachine;
+use state_maschine::prelude::{
+    AsyncStateMachine as SMAsyncStateMachine, StateMachine as SMStateMachine,
+};
 
+pub mod checkpoint;
+pub mod middleware;
+pub mod parallel;
+pub mod payload;
+pub mod sequence;
 pub mod state;
+pub mod state_path;
 pub mod stream;
 pub mod super_state;
 pub mod transition;
@@ -26,13 +40,14 @@ use state::State;
 
 /// Marker trait for SEC state machines, refining the generic [`SMStateMachine`] to SEC states.
 ///
-/// Adds no methods; it bundles the bounds an SEC machine must satisfy ([`Display`] plus a state
-/// type implementing the SEC [`State`] trait) into one nameable contract.
+/// Adds no methods; it bundles the bounds an SEC machine must satisfy ([`Display`], async driving
+/// via [`SMAsyncStateMachine`], plus a state type implementing the SEC [`State`] trait) into one
+/// nameable contract.
 ///
 /// # Type Parameters
 ///
 /// - `S`: The active state type. Must implement [`State`].
-pub trait StateMachine<S>: SMStateMachine<S> + Display
+pub trait StateMachine<S>: SMStateMachine<S> + SMAsyncStateMachine<S> + Display
 where
     S: State,
 {
@@ -0,0 +1,586 @@
+//! # Parallel Super-States
+//!
+//! Provides the [`ParallelSuperState`], which fans out to several child states, computes them
+//! concurrently, and fans their results back in to a single output, along with the [`FanOut`]
+//! trait that defines one and the [`FailurePolicy`] it is driven with.
+//!
+//! ## Design
+//!
+//! A regular super-state wraps exactly one current state. A [`ParallelSuperState`] instead holds a
+//! list of children of the same state type (e.g. one request state per SEC endpoint) and streams
+//! them all at once: every child goes through the same lifecycle as a state driven by
+//! [`IntoStateMachineStream`] (retries, timeouts, cancellation, middleware), and the events of all
+//! children are interleaved into the parent stream as they happen. Child events are nested one
+//! level below the parallel super-state in their [`StatePath`](super::state_path::StatePath).
+//!
+//! Once every child has finished, [`FanOut::join`] aggregates them into one output, and
+//! [`FanOut::into_next`] hands that output to the machine the stream continues with.
+//!
+//! The [`FailurePolicy`] decides what a failing child does to its siblings:
+//! [`FailFast`](FailurePolicy::FailFast) ends the stream with the child's error and abandons the
+//! siblings still running, while [`CollectAll`](FailurePolicy::CollectAll) lets them finish and
+//! passes the failure to [`FanOut::join`], reported by a [`StreamEvent::ChildFailed`] item.
+//!
+//! A parallel super-state is not checkpointed; a run resumed past it does not recompute it, and a
+//! run resumed before it fans out again.
+
+use std::time::Instant;
+
+use futures_util::StreamExt;
+use state_maschine::prelude::{State as SMState, StateMachine as SMStateMachine};
+
+use super::payload::{Payload, StatePayload};
+use super::state::{Context, State};
+use super::stream::{
+    IntoStateMachineStream, StateMachineStream, StreamError, StreamEvent, StreamItem,
+    StreamOptions, compute_with_timeout, failure_event, schedule_retry, state_data, wait_for_retry,
+};
+use crate::error::State as StateError;
+use crate::error::StateMachine as StateMachineError;
+use crate::error::state_machine::transition::Transition as TransitionError;
+
+/// How a [`ParallelSuperState`] reacts to a child that fails for good.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FailurePolicy {
+    /// Ends the stream with the first child failure, abandoning the children still running.
+    #[default]
+    FailFast,
+    /// Lets every child finish and passes all results, failures included, to [`FanOut::join`].
+    CollectAll,
+}
+
+/// Defines a [`ParallelSuperState`]: what its children are, how their results are joined, and
+/// which machine the joined output is handed to.
+///
+/// # Associated Types
+///
+/// - `Child`: The state every child is an instance of.
+/// - `Output`: The aggregated output of all children. A cloneable [`Payload`] for event data.
+/// - `NextState`: The state the machine the stream continues with starts at.
+/// - `Next`: The machine the stream continues with once the children are joined.
+pub trait FanOut: Send + 'static {
+    /// The state every child is an instance of.
+    type Child: State + Payload + Clone;
+
+    /// The aggregated output of all children.
+    type Output: Payload + Clone;
+
+    /// The state the machine the stream continues with starts at.
+    type NextState: State;
+
+    /// The machine the stream continues with once the children are joined.
+    type Next: SMStateMachine<Self::NextState> + IntoStateMachineStream + Send + 'static;
+
+    /// Returns the name of the parallel super-state (e.g. `"Fetch SuperState"`), used in the
+    /// [`StatePath`](super::state_path::StatePath) of its events and of its children's.
+    fn super_state_name(&self) -> &'static str;
+
+    /// Aggregates the children, in the order they were fanned out to, into a single output.
+    ///
+    /// Each entry is the computed child, or the error it finally failed with. Under
+    /// [`FailurePolicy::FailFast`] every entry is `Ok`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if the results cannot be joined (e.g. too many children failed).
+    fn join(
+        &self,
+        results: Vec<Result<Self::Child, StateError>>,
+    ) -> Result<Self::Output, StateError>;
+
+    /// Creates the machine the stream continues with from the joined `output`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransitionError`] if the next machine cannot be created from `output`.
+    fn into_next(self, output: Self::Output) -> Result<Self::Next, TransitionError>;
+}
+
+/// A super-state that computes several child states concurrently and joins their results.
+///
+/// Streamed through [`IntoStateMachineStream`] like any other machine; see the
+/// [module documentation](self) for the events it yields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParallelSuperState<F: FanOut> {
+    fan_out: F,
+    children: Vec<F::Child>,
+    failure_policy: FailurePolicy,
+}
+
+impl<F: FanOut> ParallelSuperState<F> {
+    /// Creates a [`ParallelSuperState`] fanning out to `children`, with the default
+    /// [`FailurePolicy::FailFast`].
+    #[must_use]
+    pub const fn new(fan_out: F, children: Vec<F::Child>) -> Self {
+        Self {
+            fan_out,
+            children,
+            failure_policy: FailurePolicy::FailFast,
+        }
+    }
+
+    /// Drives the children with `failure_policy`.
+    #[must_use]
+    pub const fn with_failure_policy(mut self, failure_policy: FailurePolicy) -> Self {
+        self.failure_policy = failure_policy;
+        self
+    }
+
+    /// Returns the children the super-state fans out to.
+    #[must_use]
+    pub fn children(&self) -> &[F::Child] {
+        &self.children
+    }
+
+    /// Returns the policy the children are driven with.
+    #[must_use]
+    pub const fn failure_policy(&self) -> FailurePolicy {
+        self.failure_policy
+    }
+}
+
+/// What a single child's stream reports to its parallel super-state.
+enum ChildEvent<C> {
+    /// An event to forward to the parent stream.
+    Item(StreamItem),
+    /// The child at the index finished computing.
+    Completed(usize, C),
+    /// The child at the index failed for good; its failure is collected for the join.
+    Failed(usize, StateError),
+    /// The child failed in a way that ends the whole stream.
+    Abort(StreamError),
+}
+
+/// Drives the child at `index` through its lifecycle, mirroring the blanket
+/// [`IntoStateMachineStream`] impl for a single state.
+fn child_events<C>(
+    index: usize,
+    mut child: C,
+    execution_id: uuid::Uuid,
+    options: StreamOptions,
+    failure_policy: FailurePolicy,
+) -> std::pin::Pin<Box<dyn futures_core::Stream<Item = ChildEvent<C>> + Send>>
+where
+    C: State + Payload + Clone,
+{
+    Box::pin(async_stream::stream! {
+        let state_name = child.state_name().to_string();
+        let path = options.parent_path().child(state_name.clone());
+        let state_start = Instant::now();
+
+        let started = StreamItem {
+            event: StreamEvent::StateStarted,
+            state_name: state_name.clone(),
+            path: path.clone(),
+            sequence: options.next_sequence(),
+            data: state_data(&child),
+            event_duration: std::time::Duration::ZERO,
+        };
+        options.notify_before_state(execution_id, &started).await;
+        yield ChildEvent::Item(started);
+
+        let retry_policy = child.context_data().retry_policy();
+        let timeout = child.context_data().timeout();
+        let mut retry = 0;
+        let compute_err: Option<StateMachineError> = loop {
+            if child.has_output_data_been_computed() {
+                break None;
+            }
+            let result =
+                compute_with_timeout(child.compute_output_data_async(), timeout, &state_name).await;
+            match result {
+                Ok(()) => break None,
+                Err(state_err) => {
+                    retry += 1;
+                    match schedule_retry(&retry_policy, retry, &state_err, &path, &options, state_start) {
+                        Some((item, backoff)) => {
+                            yield ChildEvent::Item(item);
+                            if !wait_for_retry(backoff, options.cancellation_token()).await {
+                                break Some(StateMachineError::Cancelled);
+                            }
+                        }
+                        None => break Some(state_err.into()),
+                    }
+                }
+            }
+        };
+
+        match compute_err {
+            None => {
+                let completed = StreamItem {
+                    event: StreamEvent::StateCompleted,
+                    state_name,
+                    path,
+                    sequence: options.next_sequence(),
+                    data: state_data(&child),
+                    event_duration: state_start.elapsed(),
+                };
+                options.notify_after_state(execution_id, &completed).await;
+                yield ChildEvent::Item(completed);
+                yield ChildEvent::Completed(index, child);
+            }
+            Some(StateMachineError::State(state_err)) if failure_policy == FailurePolicy::CollectAll => {
+                yield ChildEvent::Item(StreamItem {
+                    event: StreamEvent::ChildFailed,
+                    state_name,
+                    path,
+                    sequence: options.next_sequence(),
+                    data: StatePayload::new(serde_json::json!({ "error": state_err.to_string() })),
+                    event_duration: state_start.elapsed(),
+                });
+                yield ChildEvent::Failed(index, state_err);
+            }
+            Some(sm_error) => {
+                let error = StreamError {
+                    event: failure_event(&sm_error),
+                    execution_id,
+                    state_name,
+                    path,
+                    sequence: options.next_sequence(),
+                    data: state_data(&child),
+                    source: sm_error,
+                };
+                options.notify_error(execution_id, &error).await;
+                yield ChildEvent::Abort(error);
+            }
+        }
+    })
+}
+
+/// Yields `StateStarted` for the parallel super-state, the interleaved events of its children,
+/// `StateCompleted` with the joined output, and `TransitionCompleted`, notifying the registered
+/// [`StreamMiddleware`](super::middleware::StreamMiddleware) along the way, then chains the next
+/// machine's stream with the same `execution_id` and [`StreamOptions`].
+impl<F: FanOut> IntoStateMachineStream for ParallelSuperState<F> {
+    #[allow(clippy::too_many_lines)]
+    fn into_stream_with(
+        self,
+        execution_id: uuid::Uuid,
+        options: StreamOptions,
+    ) -> StateMachineStream {
+        Box::pin(async_stream::stream! {
+            let Self { fan_out, children, failure_policy } = self;
+            let state_name = fan_out.super_state_name().to_string();
+            let path = options.parent_path().child(state_name.clone());
+
+            if options.cancellation_token().is_cancelled() {
+                let error = StreamError::cancelled(execution_id, path, options.next_sequence());
+                options.notify_error(execution_id, &error).await;
+                yield Err(error);
+                return;
+            }
+
+            let state_start = Instant::now();
+            let started = StreamItem {
+                event: StreamEvent::StateStarted,
+                state_name: state_name.clone(),
+                path: path.clone(),
+                sequence: options.next_sequence(),
+                data: state_data(&children),
+                event_duration: std::time::Duration::ZERO,
+            };
+            options.notify_before_state(execution_id, &started).await;
+            yield Ok(started);
+
+            // Fan out: interleave the children's events as they happen
+            let child_options = options.clone().with_parent_path(path.clone());
+            let mut results: Vec<Option<Result<F::Child, StateError>>> =
+                children.iter().map(|_| None).collect();
+            let mut events = futures_util::stream::select_all(
+                children.into_iter().enumerate().map(|(index, child)| {
+                    child_events(index, child, execution_id, child_options.clone(), failure_policy)
+                }),
+            );
+            while let Some(event) = events.next().await {
+                match event {
+                    ChildEvent::Item(item) => yield Ok(item),
+                    ChildEvent::Completed(index, child) => results[index] = Some(Ok(child)),
+                    ChildEvent::Failed(index, state_err) => results[index] = Some(Err(state_err)),
+                    ChildEvent::Abort(error) => {
+                        yield Err(error);
+                        return;
+                    }
+                }
+            }
+
+            // Fan in
+            let results = results.into_iter().flatten().collect();
+            let output = match fan_out.join(results) {
+                Ok(output) => output,
+                Err(state_err) => {
+                    let sm_error: StateMachineError = state_err.into();
+                    let error = StreamError {
+                        event: failure_event(&sm_error),
+                        execution_id,
+                        state_name,
+                        path,
+                        sequence: options.next_sequence(),
+                        data: StatePayload::default(),
+                        source: sm_error,
+                    };
+                    options.notify_error(execution_id, &error).await;
+                    yield Err(error);
+                    return;
+                }
+            };
+
+            let completed = StreamItem {
+                event: StreamEvent::StateCompleted,
+                state_name: state_name.clone(),
+                path: path.clone(),
+                sequence: options.next_sequence(),
+                data: state_data(&output),
+                event_duration: state_start.elapsed(),
+            };
+            options.notify_after_state(execution_id, &completed).await;
+            yield Ok(completed);
+
+            // Transition
+            let transition_start = Instant::now();
+            match fan_out.into_next(output) {
+                Ok(next) => {
+                    let transitioned = StreamItem {
+                        event: StreamEvent::TransitionCompleted,
+                        state_name,
+                        path,
+                        sequence: options.next_sequence(),
+                        data: StatePayload::new(serde_json::json!({ "to": next.current_state().state_name().to_string() })),
+                        event_duration: transition_start.elapsed(),
+                    };
+                    options.notify_transition(execution_id, &transitioned).await;
+                    yield Ok(transitioned);
+
+                    // Chain: forward same execution_id and options
+                    let mut rest = std::pin::pin!(next.into_stream_with(execution_id, options));
+                    while let Some(item) = rest.next().await {
+                        yield item;
+                    }
+                }
+                Err(e) => {
+                    let error = StreamError {
+                        event: StreamEvent::TransitionFailed,
+                        execution_id,
+                        state_name,
+                        path,
+                        sequence: options.next_sequence(),
+                        data: StatePayload::default(),
+                        source: e.into(),
+                    };
+                    options.notify_error(execution_id, &error).await;
+                    yield Err(error);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+    use pretty_assertions::assert_eq;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{FailurePolicy, ParallelSuperState};
+    use crate::tests::fixtures::sample_parallel_super_state::{
+        SampleFanOut, sample_parallel_super_state,
+    };
+    use crate::traits::state_machine::stream::{
+        IntoStateMachineStream, StreamError, StreamEvent, StreamItem, StreamOptions,
+    };
+
+    async fn collect(
+        parallel: ParallelSuperState<SampleFanOut>,
+    ) -> Vec<Result<StreamItem, StreamError>> {
+        parallel.into_stream(uuid::Uuid::new_v4()).collect().await
+    }
+
+    #[tokio::test]
+    async fn should_nest_child_events_below_parallel_super_state_when_streaming() {
+        let parallel = sample_parallel_super_state(&[false, false]);
+
+        let expected_result = vec![
+            "Sample Parallel SuperState > Sample State A".to_string(),
+            "Sample Parallel SuperState > Sample State A".to_string(),
+        ];
+
+        let result: Vec<String> = collect(parallel)
+            .await
+            .into_iter()
+            .map(|item| item.expect("Every event of succeeding children should succeed"))
+            .filter(|item| {
+                item.event == StreamEvent::StateCompleted
+                    && item.path.root() == Some("Sample Parallel SuperState")
+                    && item.path.depth() == 1
+            })
+            .map(|item| item.path.to_string())
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_join_children_into_output_when_all_children_complete() {
+        let parallel = sample_parallel_super_state(&[false, false, false]);
+
+        let expected_result = Some(3);
+
+        let result = collect(parallel)
+            .await
+            .into_iter()
+            .filter_map(Result::ok)
+            .find(|item| {
+                item.event == StreamEvent::StateCompleted
+                    && item.state_name == "Sample Parallel SuperState"
+            })
+            .and_then(|item| item.data.downcast_ref::<usize>().copied());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_continue_with_next_machine_when_children_are_joined() {
+        let parallel = sample_parallel_super_state(&[false]);
+
+        let expected_result = vec![
+            (
+                StreamEvent::StateStarted,
+                "Sample Parallel SuperState".to_string(),
+            ),
+            (StreamEvent::StateStarted, "Sample State A".to_string()),
+            (StreamEvent::StateCompleted, "Sample State A".to_string()),
+            (
+                StreamEvent::StateCompleted,
+                "Sample Parallel SuperState".to_string(),
+            ),
+            (
+                StreamEvent::TransitionCompleted,
+                "Sample Parallel SuperState".to_string(),
+            ),
+            (StreamEvent::StateStarted, "Sample State C".to_string()),
+            (StreamEvent::StateCompleted, "Sample State C".to_string()),
+        ];
+
+        let result: Vec<(StreamEvent, String)> = collect(parallel)
+            .await
+            .into_iter()
+            .map(|item| item.expect("Every event of a succeeding child should succeed"))
+            .map(|item| (item.event, item.state_name))
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_number_interleaved_events_consecutively_when_streaming() {
+        let parallel = sample_parallel_super_state(&[false, false]);
+
+        let expected_result = (0..9).collect::<Vec<u64>>();
+
+        let result: Vec<u64> = collect(parallel)
+            .await
+            .into_iter()
+            .map(|item| item.expect("Every event of succeeding children should succeed"))
+            .map(|item| item.sequence)
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_end_with_child_error_when_child_fails_under_fail_fast() {
+        let parallel = sample_parallel_super_state(&[false, true]);
+
+        let expected_result = Some((
+            StreamEvent::StateFailed,
+            "Sample Parallel SuperState > Sample State A".to_string(),
+        ));
+
+        let result = collect(parallel)
+            .await
+            .into_iter()
+            .last()
+            .and_then(Result::err)
+            .map(|error| (error.event, error.path.to_string()));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_join_remaining_children_when_child_fails_under_collect_all() {
+        let parallel = sample_parallel_super_state(&[true, false, false])
+            .with_failure_policy(FailurePolicy::CollectAll);
+
+        let expected_result = (1, Some(2));
+
+        let items: Vec<StreamItem> = collect(parallel)
+            .await
+            .into_iter()
+            .map(|item| item.expect("A collected child failure should not end the stream"))
+            .collect();
+        let child_failures = items
+            .iter()
+            .filter(|item| item.event == StreamEvent::ChildFailed)
+            .count();
+        let output = items
+            .into_iter()
+            .find(|item| {
+                item.event == StreamEvent::StateCompleted
+                    && item.state_name == "Sample Parallel SuperState"
+            })
+            .and_then(|item| item.data.downcast_ref::<usize>().copied());
+        let result = (child_failures, output);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_fail_parallel_super_state_when_join_fails() {
+        let parallel = sample_parallel_super_state(&[true, true])
+            .with_failure_policy(FailurePolicy::CollectAll);
+
+        let expected_result = Some((
+            StreamEvent::StateFailed,
+            "Sample Parallel SuperState".to_string(),
+        ));
+
+        let result = collect(parallel)
+            .await
+            .into_iter()
+            .last()
+            .and_then(Result::err)
+            .map(|error| (error.event, error.path.to_string()));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_yield_only_pipeline_cancelled_when_token_is_cancelled_before_streaming() {
+        let parallel = sample_parallel_super_state(&[false, false]);
+        let token = CancellationToken::new();
+        token.cancel();
+        let stream = parallel.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_cancellation_token(token),
+        );
+
+        let expected_result = vec![Some(StreamEvent::PipelineCancelled)];
+
+        let result: Vec<Option<StreamEvent>> = stream
+            .map(|item| item.err().map(|error| error.event))
+            .collect()
+            .await;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_default_to_fail_fast_when_created() {
+        let parallel = sample_parallel_super_state(&[]);
+
+        let expected_result = FailurePolicy::FailFast;
+
+        let result = parallel.failure_policy();
+
+        assert_eq!(result, expected_result);
+    }
+}
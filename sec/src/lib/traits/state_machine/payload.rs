@@ -0,0 +1,206 @@
+//! # State Payloads
+//!
+//! Provides the [`StatePayload`] carried by every [`StreamItem`](super::stream::StreamItem) and
+//! [`StreamError`](super::stream::StreamError), and the [`Payload`] bound on the values it holds.
+//!
+//! ## Design
+//!
+//! A stream chains many differently typed states, so its events cannot be generic over a single
+//! state type. Instead, the payload holds the typed value behind a shared, type-erased pointer:
+//! consumers that know which state an event relates to get the value back with
+//! [`downcast_ref`](StatePayload::downcast_ref) (e.g. a
+//! [`ParseCompanyFacts`](crate::implementations::states::transform::parse_company_facts::ParseCompanyFacts)
+//! state with its parsed `CompanyData`), without a JSON round trip.
+//!
+//! JSON is only produced at the logging edge, through [`to_json`](StatePayload::to_json) or the
+//! payload's [`Serialize`] impl, and a value that cannot be serialized is reported as an error
+//! there rather than being replaced by a placeholder.
+//!
+//! A payload deserialized from JSON (e.g. replayed from a
+//! [`Journal`](crate::shared::journal::Journal)) holds the [`serde_json::Value`] itself.
+
+use std::any::Any;
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A value that can be carried by a [`StatePayload`]: serializable for logging, and shareable
+/// across the tasks a stream is consumed on.
+///
+/// Implemented for every type meeting the bounds.
+pub trait Payload: Any + Serialize + Debug + Send + Sync {}
+
+impl<T: Any + Serialize + Debug + Send + Sync> Payload for T {}
+
+/// The object-safe view of a [`Payload`] held by a [`StatePayload`].
+trait ErasedPayload: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn to_json(&self) -> Result<serde_json::Value, serde_json::Error>;
+}
+
+impl<T: Payload> ErasedPayload for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+}
+
+/// The typed data attached to a stream event, e.g. the state the event relates to.
+///
+/// Cloning is cheap: the value is shared behind an `Arc`. The default payload holds
+/// [`serde_json::Value::Null`], for events that carry no data. Two payloads are equal if they
+/// serialize to the same JSON.
+///
+/// # Examples
+///
+/// ```
+/// use sec::traits::state_machine::payload::StatePayload;
+///
+/// let payload = StatePayload::new(42_u32);
+///
+/// let expected_result = Some(&42);
+///
+/// let result = payload.downcast_ref::<u32>();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Clone)]
+pub struct StatePayload {
+    value: Arc<dyn ErasedPayload>,
+}
+
+impl StatePayload {
+    /// Creates a new [`StatePayload`] holding `value`.
+    pub fn new<T: Payload>(value: T) -> Self {
+        Self {
+            value: Arc::new(value),
+        }
+    }
+
+    /// Returns the held value if it is a `T`, or `None` otherwise.
+    #[must_use]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.as_any().downcast_ref()
+    }
+
+    /// Serializes the held value into JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] if the value cannot be represented as JSON (e.g. a map with
+    /// non-string keys).
+    pub fn to_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        self.value.to_json()
+    }
+}
+
+impl Default for StatePayload {
+    fn default() -> Self {
+        Self::new(serde_json::Value::Null)
+    }
+}
+
+impl Debug for StatePayload {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl PartialEq for StatePayload {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.to_json(), other.to_json()) {
+            (Ok(this), Ok(other)) => this == other,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for StatePayload {}
+
+impl Serialize for StatePayload {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StatePayload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::tests::fixtures::sample_streaming_super_state::SampleStateA;
+
+    #[test]
+    fn should_return_typed_state_when_downcasting_to_held_type() {
+        let state = SampleStateA::new();
+        let payload = StatePayload::new(state.clone());
+
+        let expected_result = Some(&state);
+
+        let result = payload.downcast_ref::<SampleStateA>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_none_when_downcasting_to_other_type() {
+        let payload = StatePayload::new(SampleStateA::new());
+
+        let expected_result = None;
+
+        let result = payload.downcast_ref::<String>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_explicitly_when_value_cannot_be_serialized() {
+        let payload = StatePayload::new(BTreeMap::from([((1, 2), "non-string key")]));
+
+        let expected_result = true;
+
+        let result = payload.to_json().is_err();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_equal_original_when_deserializing_serialized_payload() {
+        let payload = StatePayload::new(SampleStateA::new());
+
+        let expected_result = payload.clone();
+
+        let result: StatePayload = serde_json::from_value(
+            serde_json::to_value(&payload).expect("A fixture state should always serialize"),
+        )
+        .expect("Serialized JSON should always deserialize into a payload");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serialize_as_null_when_payload_is_default() {
+        let expected_result = serde_json::Value::Null;
+
+        let result = StatePayload::default()
+            .to_json()
+            .expect("A null payload should always serialize");
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,193 @@
+//! # State Sequences
+//!
+//! Provides the [`state_transition!`](crate::state_transition) and
+//! [`state_sequence!`](crate::state_sequence) macros, which generate the boilerplate of a
+//! super-state stepping through a linear sequence of states.
+//!
+//! [`state_transition!`](crate::state_transition) generates the
+//! [`TryFromState`](super::transition::TryFromState) conversion from one state into the next;
+//! only the mapping from the source's output and context to the target's input and context is
+//! written by hand, taking run-wide resources from the super-state's environment.
+//! [`state_sequence!`](crate::state_sequence) generates a super-state's
+//! [`NonTerminal`](super::stream::NonTerminal) and [`Transition`](super::transition::Transition)
+//! impls for every consecutive pair of states, plus the [`Terminal`](super::stream::Terminal) impl
+//! of its last state when the sequence ends the pipeline. From the same list it generates the super-state's
+//! [`Topology`](state_maschine::prelude::Topology), so the rendered diagrams cannot drift from the
+//! transitions that actually exist.
+//!
+//! Edges that leave a super-state (e.g. Extract → Transform) or branch at runtime are still
+//! written by hand.
+
+/// Generates the [`TryFromState`](super::transition::TryFromState) conversion that transitions
+/// one state into the next.
+///
+/// The source state's output is required: if it has not been computed, the conversion fails with
+/// a [`MissingOutput`](crate::error::state_machine::transition::MissingOutput) error naming both
+/// states. Otherwise, the closure-like mapping receives the source's output and context, and
+/// optionally the super-state's [`PipelineEnvironment`](crate::shared::environment::PipelineEnvironment),
+/// and returns the target's `(input, context)`, which are passed to the target's `new`
+/// constructor. The conversion is generated for environments holding any repository.
+///
+/// Both states must provide `into_parts(self) -> (Input, Option<Output>, Context)` and
+/// `new(input, context)`, as every SEC state does.
+///
+/// # Syntax
+///
+/// ```text
+/// state_transition! {
+///     from: SourceState = SOURCE_STATE_NAME,
+///     to: TargetState = TARGET_STATE_NAME,
+///     |output, context| (target_input, target_context)
+/// }
+/// state_transition! {
+///     from: SourceState = SOURCE_STATE_NAME,
+///     to: TargetState = TARGET_STATE_NAME,
+///     |output, context, environment| (target_input, target_context)
+/// }
+/// ```
+#[macro_export]
+macro_rules! state_transition {
+    (
+        from: $from:ty = $from_name:expr,
+        to: $to:ty = $to_name:expr,
+        |$output:ident, $context:ident| $mapping:expr $(,)?
+    ) => {
+        $crate::state_transition! {
+            from: $from = $from_name,
+            to: $to = $to_name,
+            |$output, $context, _environment| $mapping
+        }
+    };
+    (
+        from: $from:ty = $from_name:expr,
+        to: $to:ty = $to_name:expr,
+        |$output:ident, $context:ident, $environment:ident| $mapping:expr $(,)?
+    ) => {
+        impl<R: $crate::shared::environment::RepositorySlot>
+            $crate::traits::state_machine::transition::TryFromState<$from, R> for $to
+        {
+            fn try_from_state(
+                state: $from,
+                $environment: &$crate::shared::environment::PipelineEnvironment<R>,
+            ) -> ::core::result::Result<Self, $crate::error::state_machine::transition::Transition>
+            {
+                let (_input, $output, $context) = state.into_parts();
+                let $output = $output.ok_or_else(|| {
+                    $crate::error::state_machine::transition::MissingOutput::new(
+                        $from_name, $to_name,
+                    )
+                })?;
+
+                let (new_input, new_context) = $mapping;
+
+                Ok(Self::new(new_input, new_context))
+            }
+        }
+    };
+}
+
+/// Generates the streaming and transition impls of a super-state's linear state sequence.
+///
+/// For every consecutive pair `From => To`, the super-state at `From` becomes
+/// [`NonTerminal`](crate::traits::state_machine::stream::NonTerminal) with `To` as its successor
+/// and gets a [`Transition`](crate::traits::state_machine::transition::Transition) (plus the
+/// placeholder `state_maschine` transition) to the super-state at `To`. A trailing `; terminal`
+/// makes the super-state at the last state
+/// [`Terminal`](crate::traits::state_machine::stream::Terminal).
+///
+/// The super-state also gets a [`Topology`](state_maschine::prelude::Topology) impl, at every
+/// state, describing the sequence as a graph named after the given diagram identifier: from the
+/// first state, through each consecutive pair, to the last state, which exits the super-state.
+///
+/// The super-state must be generic over its state and the repository `R` of its environment, and
+/// provide an inherent `try_advance<N>(self) -> Result<SuperState<N, R>, TransitionError>` that
+/// converts its current state into `N` through
+/// [`TryFromState`](super::transition::TryFromState) (see
+/// [`state_transition!`](crate::state_transition)) and carries its own context over.
+///
+/// # Syntax
+///
+/// ```text
+/// state_sequence!(SuperState = "Name": FirstState => SecondState => LastState);
+/// state_sequence!(SuperState = "Name": FirstState => LastState; terminal);
+/// ```
+#[macro_export]
+macro_rules! state_sequence {
+    (@edges $sm:ident, $end:ident; $from:ident => $to:ident $(=> $rest:ident)*) => {
+        impl<R: $crate::shared::environment::RepositorySlot>
+            $crate::traits::state_machine::stream::NonTerminal for $sm<$from, R>
+        {
+            type Current = $from;
+            type Next = $to;
+        }
+
+        impl<R: $crate::shared::environment::RepositorySlot>
+            $crate::traits::state_machine::transition::Transition<$from, $to> for $sm<$from, R>
+        {
+            fn transition_to_next_state_sec(
+                self,
+            ) -> ::core::result::Result<
+                Self::NewStateMachine,
+                $crate::error::state_machine::transition::Transition,
+            > {
+                self.try_advance()
+            }
+        }
+
+        impl<R: $crate::shared::environment::RepositorySlot>
+            $crate::prelude::SMTransition<$from, $to> for $sm<$from, R>
+        {
+            type NewStateMachine = $sm<$to, R>;
+
+            fn transition_to_next_state(
+                self,
+            ) -> ::core::result::Result<Self::NewStateMachine, &'static str> {
+                // Placeholder implementation - use transition_to_next_state_sec() for actual functionality
+                Err(
+                    "Use transition_to_next_state_sec() for SEC-specific transitions with rich error handling",
+                )
+            }
+        }
+
+        $crate::state_sequence!(@edges $sm, $end; $to $(=> $rest)*);
+    };
+    (@edges $sm:ident, terminal; $last:ident) => {
+        impl<R: $crate::shared::environment::RepositorySlot>
+            $crate::traits::state_machine::stream::Terminal for $sm<$last, R>
+        {
+            type Current = $last;
+        }
+    };
+    (@edges $sm:ident, open; $last:ident) => {};
+    (@topology $sm:ident, $name:literal; $($state:ident)=>+) => {
+        impl<S, R> $crate::prelude::Topology for $sm<S, R>
+        where
+            S: $crate::traits::state_machine::state::State,
+            R: $crate::shared::environment::RepositorySlot,
+        {
+            fn topology() -> $crate::prelude::StateGraph {
+                let states = [$(::core::stringify!($state)),+];
+                let graph = states
+                    .iter()
+                    .fold($crate::prelude::StateGraph::new($name), |graph, state| {
+                        graph.with_state(state)
+                    });
+                let graph = states
+                    .windows(2)
+                    .fold(graph, |graph, pair| graph.with_transition(pair[0], pair[1]));
+
+                graph
+                    .with_initial(states[0])
+                    .with_terminal(states[states.len() - 1])
+            }
+        }
+    };
+    ($sm:ident = $name:literal: $($state:ident)=>+; terminal) => {
+        $crate::state_sequence!(@edges $sm, terminal; $($state)=>+);
+        $crate::state_sequence!(@topology $sm, $name; $($state)=>+);
+    };
+    ($sm:ident = $name:literal: $($state:ident)=>+) => {
+        $crate::state_sequence!(@edges $sm, open; $($state)=>+);
+        $crate::state_sequence!(@topology $sm, $name; $($state)=>+);
+    };
+}
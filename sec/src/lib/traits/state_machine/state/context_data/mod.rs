@@ -1,19 +1,24 @@
 //! # Context Trait
 //!
-//! Provides the [`Context`] trait for an SEC state's context, adding a retry budget to the
-//! generic [`state_maschine`] context.
+//! Provides the [`Context`] trait for an SEC state's context, adding a retry budget and a
+//! computation timeout to the generic [`state_maschine`] context.
 //!
 //! Context is the environmental information surrounding a state (shared client, retry policy,
 //! configuration) that can influence how a computation behaves — e.g., different retry budgets
 //! or credentials could yield different results for the same input. It outlives any single
 //! input/output cycle and is generally not expected to be mutated by transitions (although possible).
 
+use std::time::Duration;
+
 use state_maschine::prelude::Context as SMContext;
 
-/// An SEC state's context, exposing a retry budget.
+use crate::shared::retry_policy::RetryPolicy;
+
+/// An SEC state's context, exposing a retry budget and a computation timeout.
 ///
 /// Refines the generic [`SMContext`] with the retry limit SEC states consult, and provides
-/// [`can_retry`](Context::can_retry) on top of it.
+/// [`can_retry`](Context::can_retry), [`retry_policy`](Context::retry_policy), and
+/// [`timeout`](Context::timeout) on top of it.
 pub trait Context: SMContext {
     /// Returns `true` if the state may still be retried, given its retry budget.
     fn can_retry(&self) -> bool {
@@ -22,6 +27,24 @@ pub trait Context: SMContext {
 
     /// Returns the maximum number of times the state may be retried.
     fn max_retries(&self) -> u32;
+
+    /// Returns the policy the stream driver follows when the state's computation fails.
+    ///
+    /// Defaults to a [`RetryPolicy`] with this context's [`max_retries`](Context::max_retries)
+    /// budget; override it to tune the backoff or the classification of retryable errors.
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::new(self.max_retries())
+    }
+
+    /// Returns how long a single computation of the state may run before the stream driver
+    /// abandons it with a [`StateTimedOut`](crate::error::state_machine::state::StateTimedOut)
+    /// error.
+    ///
+    /// Defaults to `None`, which lets the computation run unbounded. A timed out computation is
+    /// retried like any other retryable failure.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -111,4 +134,29 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_take_max_retries_from_context_when_using_default_retry_policy() {
+        let context = SampleSecStateContext {
+            max_retries: 4,
+            ..SampleSecStateContext::default()
+        };
+
+        let expected_result = 4;
+
+        let result = super::Context::retry_policy(&context).max_retries();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_bound_computation_when_using_default_timeout() {
+        let context = SampleSecStateContext::default();
+
+        let expected_result = None;
+
+        let result = super::Context::timeout(&context);
+
+        assert_eq!(result, expected_result);
+    }
 }
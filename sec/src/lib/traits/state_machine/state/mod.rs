@@ -13,9 +13,7 @@
 
 use std::fmt::Display;
 
-use async_trait::async_trait;
-
-use state_maschine::prelude::State as SMState;
+use state_maschine::prelude::{AsyncState as SMAsyncState, State as SMState};
 
 use crate::error::State as StateError;
 
@@ -28,16 +26,14 @@ pub use state_data::StateData;
 /// An SEC pipeline state, computing its output asynchronously with domain-typed errors.
 ///
 /// Refines the generic [`SMState`] with the two needs of SEC processing: async output computation
-/// (states perform network I/O) and failures expressed as [`StateError`]s rather than panics.
-#[async_trait]
-pub trait State: SMState + Display {
-    /// Computes and stores the state's output data.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error convertible into a [`StateError`] if the computation fails.
-    async fn compute_output_data_async(&mut self) -> Result<(), impl Into<StateError>>;
-}
+/// (states perform network I/O) and failures expressed as [`StateError`]s rather than panics. Both
+/// come from [`SMAsyncState`], whose
+/// [`compute_output_data_async`](SMAsyncState::compute_output_data_async) is fixed to return a
+/// [`StateError`].
+///
+/// Its context is always an SEC [`Context`], so the stream driver can read every state's retry
+/// policy.
+pub trait State: SMState<Context: Context> + SMAsyncState<Error = StateError> + Display {}
 
 #[cfg(test)]
 mod tests {
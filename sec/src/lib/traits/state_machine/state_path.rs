@@ -0,0 +1,168 @@
+//! # State Paths
+//!
+//! Provides the [`StatePath`] type, which locates a state within the hierarchy of super-states
+//! that drive it.
+//!
+//! ## Design
+//!
+//! A path lists the names of the enclosing super-states from the outermost inwards, followed by the
+//! state itself, e.g. `Extract SuperState > Validate CIK Format`. Every
+//! [`StreamItem`](super::stream::StreamItem) carries the path of the state it relates to, so log
+//! consumers can group events per phase (the [`root`](StatePath::root) of the path) without
+//! parsing formatted state names.
+//!
+//! Paths nest: a stream driven with a [parent path](super::stream::StreamOptions::with_parent_path)
+//! appends its own segments to it.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The location of a state within the hierarchy of super-states that drive it.
+///
+/// Serializes as the list of its segments, outermost first.
+///
+/// # Examples
+///
+/// ```
+/// use sec::traits::state_machine::state_path::StatePath;
+///
+/// let path = StatePath::new()
+///     .child("Extract SuperState")
+///     .child("Validate CIK Format");
+///
+/// let expected_result = "Extract SuperState > Validate CIK Format";
+///
+/// let result = path.to_string();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StatePath {
+    segments: Vec<String>,
+}
+
+impl StatePath {
+    /// Creates an empty [`StatePath`], the path enclosing a top-level state machine.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Returns a new path one level below this one, ending in `segment`.
+    #[must_use]
+    pub fn child(&self, segment: impl Into<String>) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(segment.into());
+        Self { segments }
+    }
+
+    /// Returns the segments of the path, outermost first.
+    #[must_use]
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Returns how many super-states enclose the last segment: `0` for a top-level state, `1` for
+    /// a state inside a top-level super-state, and so on.
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.segments.len().saturating_sub(1)
+    }
+
+    /// Returns the outermost segment, typically the pipeline phase, or `None` for an empty path.
+    #[must_use]
+    pub fn root(&self) -> Option<&str> {
+        self.segments.first().map(String::as_str)
+    }
+
+    /// Returns the innermost segment, the state the path leads to, or `None` for an empty path.
+    #[must_use]
+    pub fn leaf(&self) -> Option<&str> {
+        self.segments.last().map(String::as_str)
+    }
+}
+
+impl fmt::Display for StatePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.segments.join(" > "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn extract_path() -> StatePath {
+        StatePath::new()
+            .child("Extract SuperState")
+            .child("Validate CIK Format")
+    }
+
+    #[test]
+    fn should_append_segment_when_child_is_called() {
+        let expected_result = vec![
+            "Extract SuperState".to_string(),
+            "Validate CIK Format".to_string(),
+        ];
+
+        let result = extract_path().segments().to_vec();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_one_when_state_is_inside_top_level_super_state() {
+        let expected_result = 1;
+
+        let result = extract_path().depth();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_zero_depth_when_path_is_empty() {
+        let expected_result = 0;
+
+        let result = StatePath::new().depth();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_outermost_segment_when_root_is_called() {
+        let path = extract_path();
+
+        let expected_result = Some("Extract SuperState");
+
+        let result = path.root();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_innermost_segment_when_leaf_is_called() {
+        let path = extract_path();
+
+        let expected_result = Some("Validate CIK Format");
+
+        let result = path.leaf();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serialize_as_list_of_segments() {
+        let expected_result = serde_json::json!(["Extract SuperState", "Validate CIK Format"]);
+
+        let result = serde_json::to_value(extract_path())
+            .expect("Serializing a state path should always succeed");
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -1,43 +1,122 @@
 //! # State Machine Stream
 //!
 //! Provides [`IntoStateMachineStream`] for driving a state machine to completion as an async
-//! stream of events, along with the [`NonTerminal`] marker trait and the [`StateMachineStream`]
-//! type alias.
+//! stream of events, along with the [`NonTerminal`], [`Terminal`], and [`Branching`] traits, the
+//! [`StreamOptions`] a stream is driven with, and the [`StateMachineStream`] type alias.
 //!
 //! ## Design
 //!
-//! [`NonTerminal`] marks a state machine at a given state as non-terminal, declaring its current
-//! state and its successor. A blanket [`IntoStateMachineStream`] impl then covers any machine that
-//! is [`NonTerminal`] and has a [`Transition`] to that successor, recursively streaming the
-//! successor too. Only terminal states (those with no [`NonTerminal`] impl) need a manual impl.
+//! [`Branching`] declares the possible successors of a state machine at a given state and picks
+//! one at runtime. A blanket [`IntoStateMachineStream`] impl covers any [`Branching`] machine:
+//! it computes the current state, selects the branch, and recursively streams whichever successor
+//! was chosen.
+//!
+//! [`NonTerminal`] is the common single-successor case: it declares the current state and its
+//! successor, and any machine that is [`NonTerminal`] and has a [`Transition`] to that successor
+//! is [`Branching`] through a blanket impl. [`Terminal`] declares a machine's final state; it is
+//! [`NonTerminal`] with [`End`] as its [`Successor`], so the same blanket impls stream it and the
+//! stream ends once its state completes. No machine needs a manual [`IntoStateMachineStream`] impl.
+//!
+//! The [`state_sequence!`](crate::state_sequence) macro declares a super-state's linear state
+//! sequence, generating its [`NonTerminal`], [`Terminal`], and [`Transition`] impls.
+//!
+//! Each branch is still reached through a typed [`Transition`], so an edge that has no
+//! [`Transition`] impl remains a compile error; only the *choice* between declared edges is made
+//! at runtime.
+//!
+//! ## Retries
+//!
+//! A failed computation is retried as long as the state's
+//! [`RetryPolicy`] allows it, taken from its
+//! [`Context::retry_policy`]. Every retry is announced by a [`StreamEvent::StateRetried`] item
+//! before the driver backs off and recomputes; only the final failure becomes a
+//! [`StreamEvent::StateFailed`] error.
+//!
+//! ## Timeouts and Cancellation
+//!
+//! A computation that runs longer than its [`Context::timeout`] is abandoned with a
+//! [`StateTimedOut`] error, which is retried like any other retryable failure; a final timeout
+//! becomes a [`StreamEvent::StateTimedOut`] error.
+//!
+//! Cancellation is cooperative: once the [`CancellationToken`] in the [`StreamOptions`] is
+//! cancelled, the driver lets the in-flight computation finish, checkpoints it, and then ends the
+//! stream with a [`StreamEvent::PipelineCancelled`] error before the next state starts. A pending
+//! retry backoff is cut short instead of being waited out.
+//!
+//! ## Checkpoints
+//!
+//! Streams driven with a [`CheckpointWriter`] (see [`StreamOptions::with_checkpoint_writer`])
+//! capture every completed state into a [`Checkpoint`] and write it through the writer. States whose
+//! output is already computed (e.g. restored from a checkpoint) are not recomputed, so a resumed
+//! machine picks up right where the interrupted run stopped.
+//!
+//! ## Middleware
+//!
+//! Every [`StreamMiddleware`] registered through [`StreamOptions::with_middleware`] is notified
+//! before and after each state, on each transition, and when the stream ends with an error. The
+//! hooks are awaited right before the corresponding item is yielded.
 
 use std::fmt;
+use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 
 use state_maschine::prelude::{State as SMState, StateMachine as SMStateMachine};
 
-use super::state::State;
+use super::checkpoint::Checkpointable;
+use super::middleware::StreamMiddleware;
+use super::payload::{Payload, StatePayload};
+use super::state::{Context, State};
+use super::state_path::StatePath;
+use super::super_state::SuperState;
 use super::transition::Transition;
+use crate::error::State as StateError;
+use crate::error::StateMachine as StateMachineError;
+use crate::error::state_machine::state::StateTimedOut;
+use crate::error::state_machine::transition::Transition as TransitionError;
+use crate::shared::checkpoint::{Checkpoint, CheckpointWriter};
+use crate::shared::retry_policy::RetryPolicy;
 
 /// Events that occur during state machine stream execution.
 ///
 /// Each variant represents a specific moment in the state machine lifecycle.
-/// Displays as `snake_case` for structured logging compatibility.
+/// Displays and serializes as `snake_case` for structured logging compatibility.
 #[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum StreamEvent {
     /// A state is about to begin computation.
     StateStarted,
     /// A state has successfully completed computation.
     StateCompleted,
+    /// A state's computation has failed and will be retried after a backoff.
+    StateRetried,
     /// A state's computation has failed.
     StateFailed,
+    /// A state's computation exceeded its timeout on its final attempt; earlier timeouts are
+    /// retried like any other retryable failure.
+    StateTimedOut,
     /// A transition to the next state has completed successfully.
+    #[serde(rename = "state_transition_completed")]
     TransitionCompleted,
     /// A transition to the next state has failed.
+    #[serde(rename = "state_transition_failed")]
     TransitionFailed,
+    /// A checkpoint of a completed state has been written.
+    CheckpointSaved,
+    /// A checkpoint of a completed state could not be written; the run continues without it.
+    CheckpointFailed,
+    /// The pipeline was cancelled and stopped before its final state.
+    PipelineCancelled,
+    /// A child of a parallel super-state failed; the failure is collected for the join instead of
+    /// ending the stream.
+    ChildFailed,
 }
 
 impl fmt::Display for StreamEvent {
@@ -45,25 +124,36 @@ impl fmt::Display for StreamEvent {
         match self {
             Self::StateStarted => write!(f, "state_started"),
             Self::StateCompleted => write!(f, "state_completed"),
+            Self::StateRetried => write!(f, "state_retried"),
             Self::StateFailed => write!(f, "state_failed"),
+            Self::StateTimedOut => write!(f, "state_timed_out"),
             Self::TransitionCompleted => write!(f, "state_transition_completed"),
             Self::TransitionFailed => write!(f, "state_transition_failed"),
+            Self::CheckpointSaved => write!(f, "checkpoint_saved"),
+            Self::CheckpointFailed => write!(f, "checkpoint_failed"),
+            Self::PipelineCancelled => write!(f, "pipeline_cancelled"),
+            Self::ChildFailed => write!(f, "child_failed"),
         }
     }
 }
 
 /// A structured item yielded by the state machine stream on success.
 ///
-/// Carries the event type, state name, serialized state data, and duration
-/// for structured logging.
-#[derive(Debug, Clone)]
+/// Carries the event type, state name and path, sequence number, typed state payload, and
+/// duration for structured logging. Round-trips through serde, so items can be recorded into a
+/// [`Journal`](crate::shared::journal::Journal) and replayed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StreamItem {
     /// What happened in the state machine lifecycle.
     pub event: StreamEvent,
     /// The name of the state this event relates to.
     pub state_name: String,
-    /// Serialized state data at the time of the event.
-    pub data: serde_json::Value,
+    /// The path of super-states leading to the state this event relates to, ending in the state.
+    pub path: StatePath,
+    /// The position of this event among all events of the execution, counted from zero.
+    pub sequence: u64,
+    /// The data attached to the event, e.g. a copy of the state at the time of the event.
+    pub data: StatePayload,
     /// How long since the start of this state. Measured from the beginning of the state's
     /// lifecycle in the stream; includes compute time but not consumer wait time.
     pub event_duration: std::time::Duration,
@@ -72,7 +162,7 @@ pub struct StreamItem {
 /// Error yielded by the state machine stream on failure.
 ///
 /// Wraps the existing [`StateMachine`](crate::error::StateMachine) error with stream context
-/// (execution ID, state name, event type, serialized state data).
+/// (execution ID, state name and path, sequence number, event type, typed state payload).
 #[derive(Debug, thiserror::Error)]
 #[error("[StreamError][{execution_id}] '{event}' in '{state_name}', Caused by: {source}")]
 pub struct StreamError {
@@ -82,13 +172,34 @@ pub struct StreamError {
     pub execution_id: uuid::Uuid,
     /// The name of the state where the error occurred.
     pub state_name: String,
-    /// Serialized state data at the time of the error.
-    pub data: serde_json::Value,
+    /// The path of super-states leading to the state where the error occurred.
+    pub path: StatePath,
+    /// The position of this error among all events of the execution, counted from zero.
+    pub sequence: u64,
+    /// The data attached to the error, e.g. a copy of the state at the time of the error.
+    pub data: StatePayload,
     /// The underlying state machine error.
     #[source]
     pub source: crate::error::StateMachine,
 }
 
+impl StreamError {
+    /// Creates the [`StreamEvent::PipelineCancelled`] error that ends a cancelled stream before
+    /// the state at `path` starts.
+    #[must_use]
+    pub fn cancelled(execution_id: uuid::Uuid, path: StatePath, sequence: u64) -> Self {
+        Self {
+            event: StreamEvent::PipelineCancelled,
+            execution_id,
+            state_name: path.leaf().unwrap_or_default().to_string(),
+            path,
+            sequence,
+            data: StatePayload::default(),
+            source: StateMachineError::Cancelled,
+        }
+    }
+}
+
 /// A boxed, `Send`-able stream of state machine execution results.
 ///
 /// Each item is `Ok(StreamItem)` for successful events or `Err(StreamError)` for failures.
@@ -98,17 +209,276 @@ pub type StateMachineStream = Pin<Box<dyn Stream<Item = Result<StreamItem, Strea
 ///
 /// Each impl represents one edge in the transition graph, pinning down the current state and its
 /// successor for streaming. A machine may have multiple [`Transition`] impls (branching or circular
-/// graphs); [`NonTerminal`] picks the one the streaming path follows.
+/// graphs); [`NonTerminal`] statically picks the one the streaming path follows. To choose between
+/// them at runtime, implement [`Branching`] instead.
 ///
 /// # Associated Types
 ///
-/// - `Current`: The state the machine is at now. Must be [`State`] and serializable for event data.
-/// - `Next`: The successor state the stream transitions to.
+/// - `Current`: The state the machine is at now. Must be [`State`] and a cloneable [`Payload`] for event data.
+/// - `Next`: The [`Successor`] the stream continues with: a [`State`] the machine has a
+///   [`Transition`] to, or [`End`] for [`Terminal`] machines.
 pub trait NonTerminal {
     /// The state this state machine is currently at.
-    type Current: State + serde::Serialize;
+    type Current: State + Payload + Clone;
     /// The successor state that the stream will transition to.
-    type Next: State;
+    type Next;
+}
+
+/// Marker trait declaring that a state machine is at its final state.
+///
+/// Every [`Terminal`] machine is [`NonTerminal`] with [`End`] as its successor, so the blanket
+/// impls stream it like any other state and end the stream once it completes (and is
+/// checkpointed).
+///
+/// # Associated Types
+///
+/// - `Current`: The state the machine is at now. Must be [`State`] and a cloneable [`Payload`] for event data.
+pub trait Terminal {
+    /// The state this state machine is currently at.
+    type Current: State + Payload + Clone;
+}
+
+impl<SM: Terminal> NonTerminal for SM {
+    type Current = <SM as Terminal>::Current;
+    type Next = End;
+}
+
+/// The successor of a [`Terminal`] machine: the end of the stream.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct End;
+
+/// Streams nothing; reaching [`End`] completes the stream.
+impl IntoStateMachineStream for End {
+    fn into_stream_with(
+        self,
+        _execution_id: uuid::Uuid,
+        _options: StreamOptions,
+    ) -> StateMachineStream {
+        Box::pin(futures_util::stream::empty())
+    }
+}
+
+/// The successor a [`NonTerminal`] machine `SM` continues with once its current state completes.
+///
+/// Implemented for every [`State`] that `SM` has a [`Transition`] to, and for [`End`]. Dispatching
+/// on the successor type is what lets a single blanket [`Branching`] impl cover both
+/// [`NonTerminal`] and [`Terminal`] machines.
+pub trait Successor<SM> {
+    /// The state machine the stream continues with.
+    type Branch: IntoStateMachineStream + Send + 'static;
+
+    /// Consumes the machine and moves it on to this successor.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransitionError`] if the transition fails.
+    fn follow(state_machine: SM) -> Result<Self::Branch, TransitionError>;
+
+    /// Returns the name of the state `branch` starts in, or `None` if it ends the stream.
+    fn branch_state_name(branch: &Self::Branch) -> Option<String>;
+}
+
+impl<SM, N> Successor<SM> for N
+where
+    N: State,
+    SM: NonTerminal + Transition<<SM as NonTerminal>::Current, N>,
+    SM::NewStateMachine: IntoStateMachineStream + Send + 'static,
+{
+    type Branch = SM::NewStateMachine;
+
+    fn follow(state_machine: SM) -> Result<Self::Branch, TransitionError> {
+        state_machine.transition_to_next_state_sec()
+    }
+
+    fn branch_state_name(branch: &Self::Branch) -> Option<String> {
+        Some(branch.current_state().state_name().to_string())
+    }
+}
+
+impl<SM> Successor<SM> for End {
+    type Branch = Self;
+
+    fn follow(_state_machine: SM) -> Result<Self::Branch, TransitionError> {
+        Ok(Self)
+    }
+
+    fn branch_state_name(_branch: &Self::Branch) -> Option<String> {
+        None
+    }
+}
+
+/// Declares that a state machine at its current state picks its successor at runtime.
+///
+/// `Branch` is typically an enum with one variant per possible successor machine, each produced
+/// by a typed [`Transition`] and streamed by dispatching to the chosen variant. Every
+/// [`NonTerminal`] machine is [`Branching`] with exactly one branch, which is [`End`] for
+/// [`Terminal`] machines.
+///
+/// # Associated Types
+///
+/// - `Current`: The state the machine is at now. Must be [`State`] and a cloneable [`Payload`] for event data.
+/// - `Branch`: The successor machine chosen by [`select_branch`](Branching::select_branch).
+pub trait Branching: Sized {
+    /// The state this state machine is currently at.
+    type Current: State + Payload + Clone;
+    /// The successor state machine the stream continues with.
+    type Branch: IntoStateMachineStream + Send + 'static;
+
+    /// Consumes the machine and transitions along the edge chosen from the current state's
+    /// output.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransitionError`] if the chosen transition fails.
+    fn select_branch(self) -> Result<Self::Branch, TransitionError>;
+
+    /// Returns the name of the state the chosen branch starts in, for event data, or `None` if
+    /// the branch ends the stream.
+    fn branch_state_name(branch: &Self::Branch) -> Option<String>;
+}
+
+impl<SM> Branching for SM
+where
+    SM: NonTerminal,
+    <SM as NonTerminal>::Next: Successor<SM>,
+{
+    type Current = <SM as NonTerminal>::Current;
+    type Branch = <<SM as NonTerminal>::Next as Successor<SM>>::Branch;
+
+    fn select_branch(self) -> Result<Self::Branch, TransitionError> {
+        <<SM as NonTerminal>::Next as Successor<SM>>::follow(self)
+    }
+
+    fn branch_state_name(branch: &Self::Branch) -> Option<String> {
+        <<SM as NonTerminal>::Next as Successor<SM>>::branch_state_name(branch)
+    }
+}
+
+/// Options a state machine stream is driven with.
+///
+/// Forwarded unchanged through the entire chain of states, including across nested super states.
+/// The defaults disable checkpointing, register no [`StreamMiddleware`], use a
+/// [`CancellationToken`] that is never cancelled, and place the stream at the top of the
+/// [`StatePath`] hierarchy.
+///
+/// The options also number the events of an execution. Clones share that numbering, so every
+/// stream of one execution must be driven with (clones of) the same options.
+///
+/// # Examples
+///
+/// ```
+/// use sec::traits::state_machine::stream::StreamOptions;
+/// use tokio_util::sync::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let options = StreamOptions::new().with_cancellation_token(token.clone());
+///
+/// token.cancel();
+///
+/// let expected_result = true;
+///
+/// let result = options.cancellation_token().is_cancelled();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StreamOptions {
+    checkpoint_writer: Option<CheckpointWriter>,
+    cancellation_token: CancellationToken,
+    middleware: Vec<Arc<dyn StreamMiddleware>>,
+    parent_path: StatePath,
+    sequence: Arc<AtomicU64>,
+}
+
+impl StreamOptions {
+    /// Creates [`StreamOptions`] without checkpointing and with a fresh [`CancellationToken`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes a checkpoint through `checkpoint_writer` after every completed state.
+    #[must_use]
+    pub fn with_checkpoint_writer(mut self, checkpoint_writer: CheckpointWriter) -> Self {
+        self.checkpoint_writer = Some(checkpoint_writer);
+        self
+    }
+
+    /// Stops the stream before the next state once `cancellation_token` is cancelled.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = cancellation_token;
+        self
+    }
+
+    /// Registers `middleware` to be notified of every state's lifecycle. Middleware is notified in
+    /// registration order.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Arc<dyn StreamMiddleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Nests the stream below `parent_path`: the paths of its states start with its segments.
+    #[must_use]
+    pub fn with_parent_path(mut self, parent_path: StatePath) -> Self {
+        self.parent_path = parent_path;
+        self
+    }
+
+    /// Returns the writer completed states are checkpointed through, if any.
+    #[must_use]
+    pub const fn checkpoint_writer(&self) -> Option<&CheckpointWriter> {
+        self.checkpoint_writer.as_ref()
+    }
+
+    /// Returns the token that cancels the stream.
+    #[must_use]
+    pub const fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
+    /// Returns the registered middleware, in registration order.
+    #[must_use]
+    pub fn middleware(&self) -> &[Arc<dyn StreamMiddleware>] {
+        &self.middleware
+    }
+
+    /// Returns the path the stream's states are nested below.
+    #[must_use]
+    pub const fn parent_path(&self) -> &StatePath {
+        &self.parent_path
+    }
+
+    /// Claims the sequence number of the next event of the execution.
+    #[must_use]
+    pub fn next_sequence(&self) -> u64 {
+        self.sequence.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub(crate) async fn notify_before_state(&self, execution_id: uuid::Uuid, item: &StreamItem) {
+        for middleware in &self.middleware {
+            middleware.before_state(execution_id, item).await;
+        }
+    }
+
+    pub(crate) async fn notify_after_state(&self, execution_id: uuid::Uuid, item: &StreamItem) {
+        for middleware in &self.middleware {
+            middleware.after_state(execution_id, item).await;
+        }
+    }
+
+    pub(crate) async fn notify_error(&self, execution_id: uuid::Uuid, error: &StreamError) {
+        for middleware in &self.middleware {
+            middleware.on_error(execution_id, error).await;
+        }
+    }
+
+    pub(crate) async fn notify_transition(&self, execution_id: uuid::Uuid, item: &StreamItem) {
+        for middleware in &self.middleware {
+            middleware.on_transition(execution_id, item).await;
+        }
+    }
 }
 
 /// Trait for converting a state machine into an async [`StateMachineStream`].
@@ -116,98 +486,310 @@ pub trait NonTerminal {
 /// Implementors produce a stream that drives the state machine through its phases,
 /// yielding structured [`StreamItem`]s on success and [`StreamError`]s on failure.
 pub trait IntoStateMachineStream {
-    /// Consumes the state machine and returns a stream of execution results.
+    /// Consumes the state machine and returns a stream of execution results, driven with the
+    /// given [`StreamOptions`].
+    ///
+    /// The `execution_id` identifies this pipeline run; like `options`, it is forwarded through
+    /// the entire chain of states, including across nested super states. A super-state carrying
+    /// its own [`execution_id`](SuperState::execution_id) is streamed under that ID instead.
+    fn into_stream_with(
+        self,
+        execution_id: uuid::Uuid,
+        options: StreamOptions,
+    ) -> StateMachineStream;
+
+    /// Consumes the state machine and returns a stream of execution results, driven with the
+    /// default [`StreamOptions`].
     ///
     /// The `execution_id` identifies this pipeline run and is forwarded through
     /// the entire chain of states, including across nested super states.
-    fn into_stream(self, execution_id: uuid::Uuid) -> StateMachineStream;
+    fn into_stream(self, execution_id: uuid::Uuid) -> StateMachineStream
+    where
+        Self: Sized,
+    {
+        self.into_stream_with(execution_id, StreamOptions::default())
+    }
+}
+
+/// Awaits `computation` of `state_name`, abandoning it with a [`StateTimedOut`] error once it
+/// runs longer than `timeout`.
+///
+/// A `timeout` of `None` awaits the computation unbounded.
+///
+/// # Errors
+///
+/// Returns the computation's own [`StateError`], or [`StateError::StateTimedOut`] if it did not
+/// finish in time.
+pub(crate) async fn compute_with_timeout<F>(
+    computation: F,
+    timeout: Option<Duration>,
+    state_name: &str,
+) -> Result<(), StateError>
+where
+    F: Future<Output = Result<(), StateError>>,
+{
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, computation)
+            .await
+            .unwrap_or_else(|_| Err(StateTimedOut::new(state_name, timeout).into())),
+        None => computation.await,
+    }
+}
+
+/// Waits out the `backoff` before a retry, returning `false` if `cancellation_token` is
+/// cancelled first.
+pub(crate) async fn wait_for_retry(
+    backoff: Duration,
+    cancellation_token: &CancellationToken,
+) -> bool {
+    tokio::select! {
+        () = cancellation_token.cancelled() => false,
+        () = tokio::time::sleep(backoff) => true,
+    }
+}
+
+/// Returns the event a stream reports when a state ends with `error`.
+///
+/// Cancellation ends the stream with [`StreamEvent::PipelineCancelled`], a final timeout with
+/// [`StreamEvent::StateTimedOut`], and every other error with [`StreamEvent::StateFailed`].
+#[must_use]
+pub const fn failure_event(error: &StateMachineError) -> StreamEvent {
+    match error {
+        StateMachineError::Cancelled => StreamEvent::PipelineCancelled,
+        StateMachineError::State(StateError::StateTimedOut(_)) => StreamEvent::StateTimedOut,
+        _ => StreamEvent::StateFailed,
+    }
+}
+
+/// Consults `retry_policy` after a computation of the state at `path` failed with `error`, where
+/// `retry` is the number of the retry that would follow (counted from one).
+///
+/// Returns the [`StreamEvent::StateRetried`] item to yield, numbered through `options`, and the
+/// backoff to wait before recomputing, or `None` if the failure is final.
+#[must_use]
+pub(crate) fn schedule_retry(
+    retry_policy: &RetryPolicy,
+    retry: u32,
+    error: &StateError,
+    path: &StatePath,
+    options: &StreamOptions,
+    state_start: std::time::Instant,
+) -> Option<(StreamItem, std::time::Duration)> {
+    if !retry_policy.should_retry(retry, error) {
+        return None;
+    }
+
+    let backoff = retry_policy.backoff(retry);
+    let item = StreamItem {
+        event: StreamEvent::StateRetried,
+        state_name: path.leaf().unwrap_or_default().to_string(),
+        path: path.clone(),
+        sequence: options.next_sequence(),
+        data: StatePayload::new(serde_json::json!({
+            "retry": retry,
+            "max_retries": retry_policy.max_retries(),
+            "backoff_ms": u64::try_from(backoff.as_millis()).unwrap_or(u64::MAX),
+            "error": error.to_string(),
+        })),
+        event_duration: state_start.elapsed(),
+    };
+    Some((item, backoff))
+}
+
+/// Captures `state` and writes it through `checkpoint_writer`, reporting the outcome as a
+/// [`StreamItem`] attributed to the state at `path` and numbered `sequence`.
+///
+/// Yields [`StreamEvent::CheckpointSaved`] on success and [`StreamEvent::CheckpointFailed`]
+/// otherwise. A failed checkpoint only costs resume granularity, so it is reported as an item
+/// rather than a [`StreamError`] and does not end the stream.
+pub(crate) async fn write_checkpoint<S: Checkpointable>(
+    checkpoint_writer: &CheckpointWriter,
+    execution_id: uuid::Uuid,
+    path: StatePath,
+    sequence: u64,
+    state: &S,
+) -> StreamItem {
+    let state_name = path.leaf().unwrap_or_default().to_string();
+    let checkpoint_start = std::time::Instant::now();
+    let result = match Checkpoint::capture(execution_id, state) {
+        Ok(checkpoint) => checkpoint_writer
+            .write(&checkpoint)
+            .await
+            .map(|()| checkpoint),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(checkpoint) => StreamItem {
+            event: StreamEvent::CheckpointSaved,
+            state_name,
+            path,
+            sequence,
+            data: StatePayload::new(serde_json::json!({
+                "key": checkpoint_writer.key(),
+                "state": checkpoint.state_name(),
+            })),
+            event_duration: checkpoint_start.elapsed(),
+        },
+        Err(e) => StreamItem {
+            event: StreamEvent::CheckpointFailed,
+            state_name,
+            path,
+            sequence,
+            data: StatePayload::new(serde_json::json!({
+                "key": checkpoint_writer.key(),
+                "error": e.to_string(),
+            })),
+            event_duration: checkpoint_start.elapsed(),
+        },
+    }
 }
 
-/// Blanket implementation for any state machine at a non-terminal state. Yields
-/// [`StreamItem`]s for `StateStarted`, `StateCompleted`, and `TransitionCompleted`,
-/// then chains the next state machine's stream with the same `execution_id`.
+/// Captures a copy of `state` as the payload of a stream event.
+pub(crate) fn state_data<S: Payload + Clone>(state: &S) -> StatePayload {
+    StatePayload::new(state.clone())
+}
+
+/// Blanket implementation for any [`Branching`] state machine. Yields [`StreamItem`]s for
+/// `StateStarted`, a `StateRetried` per retry, `StateCompleted`, optionally `CheckpointSaved`, and
+/// `TransitionCompleted`, notifying the registered [`StreamMiddleware`] along the way, then
+/// chains the selected branch's stream with the same `execution_id` and [`StreamOptions`]. A
+/// branch that ends the stream (see [`End`]) yields no `TransitionCompleted`.
 impl<SM> IntoStateMachineStream for SM
 where
-    SM: NonTerminal + Transition<SM::Current, SM::Next> + Send + 'static,
-    SM::NewStateMachine: IntoStateMachineStream + Send + 'static,
+    SM: Branching + SuperState<<SM as Branching>::Current> + Send + 'static,
+    <SM as Branching>::Current: Checkpointable,
 {
-    fn into_stream(self, execution_id: uuid::Uuid) -> StateMachineStream {
+    #[allow(clippy::too_many_lines)]
+    fn into_stream_with(
+        self,
+        execution_id: uuid::Uuid,
+        options: StreamOptions,
+    ) -> StateMachineStream {
         Box::pin(async_stream::stream! {
             let mut sm = self;
+            let execution_id = sm.execution_id().unwrap_or(execution_id);
             let state_name = sm.current_state().state_name().to_string();
+            let path = options.parent_path().child(sm.super_state_name()).child(state_name.clone());
+
+            if options.cancellation_token().is_cancelled() {
+                let error = StreamError::cancelled(execution_id, path, options.next_sequence());
+                options.notify_error(execution_id, &error).await;
+                yield Err(error);
+                return;
+            }
+
             let state_start = std::time::Instant::now();
 
             // StateStarted
-            yield Ok(StreamItem {
+            let started = StreamItem {
                 event: StreamEvent::StateStarted,
                 state_name: state_name.clone(),
-                data: serde_json::to_value(sm.current_state()).unwrap_or_else(|e| {
-                    serde_json::json!({ "serialization_error": e.to_string() })
-                }),
+                path: path.clone(),
+                sequence: options.next_sequence(),
+                data: state_data(sm.current_state()),
                 event_duration: std::time::Duration::ZERO,
-            });
-
-            // Compute: time it, convert error immediately to release the mutable borrow on sm
-            let compute_err: Option<crate::error::StateMachine> = {
-                let result = sm.current_state_mut().compute_output_data_async().await;
+            };
+            options.notify_before_state(execution_id, &started).await;
+            yield Ok(started);
+
+            // Compute (skipped if restored with output) within the state's timeout, retrying as
+            // its policy allows: convert the final error immediately to release the mutable
+            // borrow on sm
+            let retry_policy = sm.current_state().context_data().retry_policy();
+            let timeout = sm.current_state().context_data().timeout();
+            let mut retry = 0;
+            let compute_err: Option<StateMachineError> = loop {
+                let result = compute_with_timeout(sm.run_async(), timeout, &state_name).await;
                 match result {
-                    Ok(()) => None,
-                    Err(e) => {
-                        let state_err: crate::error::State = e.into();
-                        Some(state_err.into())
+                    Ok(()) => break None,
+                    Err(state_err) => {
+                        retry += 1;
+                        match schedule_retry(&retry_policy, retry, &state_err, &path, &options, state_start) {
+                            Some((item, backoff)) => {
+                                yield Ok(item);
+                                if !wait_for_retry(backoff, options.cancellation_token()).await {
+                                    break Some(StateMachineError::Cancelled);
+                                }
+                            }
+                            None => break Some(state_err.into()),
+                        }
                     }
                 }
             };
 
             if let Some(sm_error) = compute_err {
-                yield Err(StreamError {
-                    event: StreamEvent::StateFailed,
+                let error = StreamError {
+                    event: failure_event(&sm_error),
                     execution_id,
                     state_name: state_name.clone(),
-                    data: serde_json::to_value(sm.current_state()).unwrap_or_else(|e| {
-                        serde_json::json!({ "serialization_error": e.to_string() })
-                    }),
+                    path,
+                    sequence: options.next_sequence(),
+                    data: state_data(sm.current_state()),
                     source: sm_error,
-                });
+                };
+                options.notify_error(execution_id, &error).await;
+                yield Err(error);
                 return;
             }
 
-            yield Ok(StreamItem {
+            let completed = StreamItem {
                 event: StreamEvent::StateCompleted,
                 state_name: state_name.clone(),
-                data: serde_json::to_value(sm.current_state()).unwrap_or_else(|e| {
-                    serde_json::json!({ "serialization_error": e.to_string() })
-                }),
+                path: path.clone(),
+                sequence: options.next_sequence(),
+                data: state_data(sm.current_state()),
                 event_duration: state_start.elapsed(),
-            });
+            };
+            options.notify_after_state(execution_id, &completed).await;
+            yield Ok(completed);
+
+            // Checkpoint the completed state before leaving it
+            if let Some(writer) = options.checkpoint_writer() {
+                let sequence = options.next_sequence();
+                yield Ok(
+                    write_checkpoint(writer, execution_id, path.clone(), sequence, sm.current_state())
+                        .await,
+                );
+            }
 
             // Transition
             let from_name = state_name;
             let transition_start = std::time::Instant::now();
-            match sm.transition_to_next_state_sec() {
+            match sm.select_branch() {
                 Ok(next) => {
-                    let to_name = next.current_state().state_name().to_string();
-                    yield Ok(StreamItem {
+                    let Some(to_name) = SM::branch_state_name(&next) else {
+                        return;
+                    };
+                    let transitioned = StreamItem {
                         event: StreamEvent::TransitionCompleted,
                         state_name: from_name,
-                        data: serde_json::json!({ "to": to_name }),
+                        path,
+                        sequence: options.next_sequence(),
+                        data: StatePayload::new(serde_json::json!({ "to": to_name })),
                         event_duration: transition_start.elapsed(),
-                    });
+                    };
+                    options.notify_transition(execution_id, &transitioned).await;
+                    yield Ok(transitioned);
 
-                    // Chain: forward same execution_id
-                    let mut rest = std::pin::pin!(next.into_stream(execution_id));
+                    // Chain: forward same execution_id and options
+                    let mut rest = std::pin::pin!(next.into_stream_with(execution_id, options));
                     while let Some(item) = futures_util::StreamExt::next(&mut rest).await {
                         yield item;
                     }
                 }
                 Err(e) => {
-                    yield Err(StreamError {
+                    let error = StreamError {
                         event: StreamEvent::TransitionFailed,
                         execution_id,
                         state_name: from_name,
-                        data: serde_json::Value::Null,
+                        path,
+                        sequence: options.next_sequence(),
+                        data: StatePayload::default(),
                         source: e.into(),
-                    });
+                    };
+                    options.notify_error(execution_id, &error).await;
+                    yield Err(error);
                     return;
                 }
             }
@@ -217,18 +799,25 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
     use std::{fmt::Debug, hash::Hash};
 
     use futures_util::StreamExt;
     use pretty_assertions::assert_eq;
+    use tokio_util::sync::CancellationToken;
 
     use crate::prelude::*;
+    use crate::shared::checkpoint::{
+        Checkpoint, CheckpointSink, CheckpointWriter, FileSystemCheckpointSink,
+    };
     use crate::tests::fixtures::sample_streaming_super_state::{
-        SampleStateA, SampleStateC, SampleStreamingContext, SampleStreamingData,
+        SampleStateA, SampleStateB, SampleStateC, SampleStreamingContext, SampleStreamingData,
         SampleStreamingSuperState,
     };
 
     use super::IntoStateMachineStream;
+    use super::StatePath;
+    use super::StreamMiddleware;
 
     // --- Functional tests ---
 
@@ -294,7 +883,7 @@ mod tests {
     fn should_return_default_input_data_when_in_initial_streaming_state() {
         let sm = SampleStreamingSuperState::<SampleStateA>::new();
 
-        let expected_result = &SampleStreamingData;
+        let expected_result = &SampleStreamingData {};
 
         let result = sm.input_data();
 
@@ -434,6 +1023,684 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    // --- Branching tests ---
+
+    #[tokio::test]
+    async fn should_follow_branch_to_state_c_when_state_a_skips_state_b() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().skip_state_b = true;
+        let execution_id = uuid::Uuid::new_v4();
+        let mut stream = std::pin::pin!(sm.into_stream(execution_id));
+
+        let expected_result = vec!["Sample State A".to_string(), "Sample State C".to_string()];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event in the branching fixture should succeed");
+            if item.event == super::StreamEvent::StateStarted {
+                result.push(item.state_name);
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_loop_through_cycle_then_leave_it_when_state_a_backs_off() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().backoffs = 2;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = vec![
+            "Sample State A".to_string(),
+            "Sample State D".to_string(),
+            "Sample State A".to_string(),
+            "Sample State D".to_string(),
+            "Sample State A".to_string(),
+            "Sample State B".to_string(),
+            "Sample State C".to_string(),
+        ];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event in the cyclic fixture should succeed");
+            if item.event == super::StreamEvent::StateStarted {
+                result.push(item.state_name);
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_with_missing_output_when_selecting_branch_before_computing() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+
+        let expected_result = true;
+
+        let result = matches!(
+            sm.select_branch(),
+            Err(crate::error::state_machine::transition::Transition::MissingOutput(_))
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_report_chosen_branch_in_transition_data_when_branching() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().skip_state_b = true;
+        let execution_id = uuid::Uuid::new_v4();
+        let mut stream = std::pin::pin!(sm.into_stream(execution_id));
+
+        let expected_result = Some(serde_json::json!({ "to": "Sample State C" }));
+
+        let mut result = None;
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event in the branching fixture should succeed");
+            if item.event == super::StreamEvent::TransitionCompleted {
+                result = item.data.to_json().ok();
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_yield_five_items_when_branching_past_state_b() {
+        // (Started + Completed + Transition) x 1 branching + (Started + Completed) x 1 terminal = 5
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().skip_state_b = true;
+        let execution_id = uuid::Uuid::new_v4();
+        let mut stream = std::pin::pin!(sm.into_stream(execution_id));
+
+        let expected_result = 5;
+
+        let mut count = 0;
+        while let Some(result) = stream.next().await {
+            result.expect("Each event in the branching fixture should succeed");
+            count += 1;
+        }
+        let result = count;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_select_single_branch_when_state_machine_is_non_terminal() {
+        let mut sm = SampleStreamingSuperState::<SampleStateB>::default();
+        sm.compute_output_data_async()
+            .await
+            .expect("Hardcoded fixture state should always compute output successfully");
+
+        let expected_result = Some("Sample State C".to_string());
+
+        let branch = sm
+            .select_branch()
+            .expect("Fixture transition from state B should always succeed");
+        let result =
+            <SampleStreamingSuperState<SampleStateB> as Branching>::branch_state_name(&branch);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_select_end_without_state_name_when_state_machine_is_terminal() {
+        let sm = SampleStreamingSuperState::<SampleStateC>::default();
+
+        let expected_result = None;
+
+        let branch = sm
+            .select_branch()
+            .expect("Selecting the end of a terminal machine should always succeed");
+        let result =
+            <SampleStreamingSuperState<SampleStateC> as Branching>::branch_state_name(&branch);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_end_stream_after_state_completed_when_state_machine_is_terminal() {
+        let sm = SampleStreamingSuperState::<SampleStateC>::default();
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = vec![
+            super::StreamEvent::StateStarted,
+            super::StreamEvent::StateCompleted,
+        ];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event of the terminal fixture state should succeed");
+            result.push(item.event);
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    // --- Retry tests ---
+
+    #[tokio::test]
+    async fn should_yield_state_retried_before_completing_when_transient_failure_recovers() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().transient_failures = 1;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = vec![
+            super::StreamEvent::StateStarted,
+            super::StreamEvent::StateRetried,
+            super::StreamEvent::StateCompleted,
+        ];
+
+        let mut result = Vec::new();
+        for _ in 0..3 {
+            let item = stream
+                .next()
+                .await
+                .expect("A recovering fixture stream should yield at least three items")
+                .expect("A recovering fixture stream should not fail");
+            result.push(item.event);
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_report_retry_number_and_budget_in_retried_item_data() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().transient_failures = 2;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = vec![
+            serde_json::json!({ "retry": 1, "max_retries": 2 }),
+            serde_json::json!({ "retry": 2, "max_retries": 2 }),
+        ];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("A fixture stream recovering within budget should not fail");
+            if item.event == super::StreamEvent::StateRetried {
+                let data = item
+                    .data
+                    .to_json()
+                    .expect("Retry data should always serialize");
+                result.push(serde_json::json!({
+                    "retry": data["retry"],
+                    "max_retries": data["max_retries"],
+                }));
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_yield_state_failed_when_retry_budget_is_exhausted() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().transient_failures = 3;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = (
+            vec![
+                super::StreamEvent::StateStarted,
+                super::StreamEvent::StateRetried,
+                super::StreamEvent::StateRetried,
+            ],
+            Some(super::StreamEvent::StateFailed),
+        );
+
+        let mut events = Vec::new();
+        let mut failure = None;
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(item) => events.push(item.event),
+                Err(e) => failure = Some(e.event),
+            }
+        }
+        let result = (events, failure);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_when_error_is_not_retryable() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().force_compute_error = true;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = 0;
+
+        let mut result = 0;
+        while let Some(Ok(item)) = stream.next().await {
+            if item.event == super::StreamEvent::StateRetried {
+                result += 1;
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    // --- Timeout and cancellation tests ---
+
+    #[tokio::test]
+    async fn should_retry_and_complete_when_single_computation_times_out() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().slow_computations = 1;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = vec![
+            super::StreamEvent::StateStarted,
+            super::StreamEvent::StateRetried,
+            super::StreamEvent::StateCompleted,
+        ];
+
+        let mut result = Vec::new();
+        for _ in 0..3 {
+            let item = stream
+                .next()
+                .await
+                .expect("A fixture stream recovering from a timeout should yield three items")
+                .expect("A fixture stream recovering from a timeout should not fail");
+            result.push(item.event);
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_yield_state_timed_out_when_every_computation_times_out() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().slow_computations = 3;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = Some(super::StreamEvent::StateTimedOut);
+
+        let mut result = None;
+        while let Some(item) = stream.next().await {
+            if let Err(e) = item {
+                result = Some(e.event);
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_yield_only_pipeline_cancelled_when_token_is_cancelled_before_streaming() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let token = CancellationToken::new();
+        token.cancel();
+        let mut stream = std::pin::pin!(sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_cancellation_token(token)
+        ));
+
+        let expected_result = (super::StreamEvent::PipelineCancelled, true);
+
+        let err = stream
+            .next()
+            .await
+            .expect("A cancelled stream should yield a PipelineCancelled error")
+            .unwrap_err();
+        let terminated = stream.next().await.is_none();
+        let result = (err.event, terminated);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_stop_before_next_state_when_cancelled_while_streaming() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let token = CancellationToken::new();
+        let mut stream = std::pin::pin!(sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_cancellation_token(token.clone())
+        ));
+
+        let expected_result = vec![
+            (
+                super::StreamEvent::StateStarted,
+                "Sample State A".to_string(),
+            ),
+            (
+                super::StreamEvent::StateCompleted,
+                "Sample State A".to_string(),
+            ),
+            (
+                super::StreamEvent::TransitionCompleted,
+                "Sample State A".to_string(),
+            ),
+            (
+                super::StreamEvent::PipelineCancelled,
+                "Sample State B".to_string(),
+            ),
+        ];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(item) => {
+                    if item.event == super::StreamEvent::StateCompleted {
+                        token.cancel();
+                    }
+                    result.push((item.event, item.state_name));
+                }
+                Err(e) => result.push((e.event, e.state_name)),
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    // --- Middleware tests ---
+
+    #[derive(Debug, Default)]
+    struct RecordingMiddleware {
+        label: &'static str,
+        calls: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl RecordingMiddleware {
+        fn record(&self, hook: &str, state_name: &str) {
+            self.calls
+                .lock()
+                .expect("The recording middleware's lock should never be poisoned")
+                .push(format!("{}{hook}: {state_name}", self.label));
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls
+                .lock()
+                .expect("The recording middleware's lock should never be poisoned")
+                .clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl StreamMiddleware for RecordingMiddleware {
+        async fn before_state(&self, _execution_id: uuid::Uuid, item: &StreamItem) {
+            self.record("before_state", &item.state_name);
+        }
+
+        async fn after_state(&self, _execution_id: uuid::Uuid, item: &StreamItem) {
+            self.record("after_state", &item.state_name);
+        }
+
+        async fn on_error(&self, _execution_id: uuid::Uuid, error: &StreamError) {
+            self.record("on_error", &error.state_name);
+        }
+
+        async fn on_transition(&self, _execution_id: uuid::Uuid, item: &StreamItem) {
+            self.record("on_transition", &item.state_name);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_invoke_hooks_for_every_state_when_middleware_is_registered() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let middleware = Arc::new(RecordingMiddleware::default());
+        let stream = sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_middleware(middleware.clone()),
+        );
+        stream.for_each(|_| async {}).await;
+
+        let expected_result = vec![
+            "before_state: Sample State A",
+            "after_state: Sample State A",
+            "on_transition: Sample State A",
+            "before_state: Sample State B",
+            "after_state: Sample State B",
+            "on_transition: Sample State B",
+            "before_state: Sample State C",
+            "after_state: Sample State C",
+        ];
+
+        let result = middleware.calls();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_invoke_on_error_when_state_fails_with_middleware_registered() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().force_compute_error = true;
+        let middleware = Arc::new(RecordingMiddleware::default());
+        let stream = sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_middleware(middleware.clone()),
+        );
+        stream.for_each(|_| async {}).await;
+
+        let expected_result = vec!["before_state: Sample State A", "on_error: Sample State A"];
+
+        let result = middleware.calls();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_invoke_middleware_in_registration_order_when_several_are_registered() {
+        let sm = SampleStreamingSuperState::<SampleStateC>::default();
+        let first = Arc::new(RecordingMiddleware {
+            label: "first ",
+            ..RecordingMiddleware::default()
+        });
+        let second = Arc::new(RecordingMiddleware {
+            label: "second ",
+            calls: first.calls.clone(),
+        });
+        let stream = sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new()
+                .with_middleware(first.clone())
+                .with_middleware(second),
+        );
+        stream.for_each(|_| async {}).await;
+
+        let expected_result = vec![
+            "first before_state: Sample State C",
+            "second before_state: Sample State C",
+            "first after_state: Sample State C",
+            "second after_state: Sample State C",
+        ];
+
+        let result = first.calls();
+
+        assert_eq!(result, expected_result);
+    }
+
+    // --- State path tests ---
+
+    #[tokio::test]
+    async fn should_nest_state_paths_below_super_state_when_streaming() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = vec![
+            "Sample Streaming SuperState > Sample State A".to_string(),
+            "Sample Streaming SuperState > Sample State B".to_string(),
+            "Sample Streaming SuperState > Sample State C".to_string(),
+        ];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event in the fixture stream should succeed");
+            if item.event == super::StreamEvent::StateStarted {
+                result.push(item.path.to_string());
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_number_events_consecutively_when_streaming_across_states() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = (0..8).collect::<Vec<u64>>();
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event in the fixture stream should succeed");
+            result.push(item.sequence);
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_prefix_state_paths_with_parent_path_when_nested() {
+        let sm = SampleStreamingSuperState::<SampleStateC>::default();
+        let mut stream = std::pin::pin!(sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_parent_path(StatePath::new().child("Sample Pipeline"))
+        ));
+
+        let expected_result = (Some("Sample Pipeline".to_string()), 2);
+
+        let item = stream
+            .next()
+            .await
+            .expect("A fixture stream should always yield a first item")
+            .expect("The first item of a fixture stream should always succeed");
+        let result = (item.path.root().map(str::to_string), item.path.depth());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_carry_state_path_and_sequence_when_stream_fails() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.current_state_mut().force_compute_error = true;
+        let mut stream = std::pin::pin!(sm.into_stream(uuid::Uuid::new_v4()));
+
+        let expected_result = Some((
+            "Sample Streaming SuperState > Sample State A".to_string(),
+            1,
+        ));
+
+        let mut result = None;
+        while let Some(item) = stream.next().await {
+            if let Err(e) = item {
+                result = Some((e.path.to_string(), e.sequence));
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    // --- Checkpoint tests ---
+
+    fn temporary_checkpoint_writer() -> (Arc<FileSystemCheckpointSink>, CheckpointWriter) {
+        let sink = Arc::new(FileSystemCheckpointSink::new(
+            std::env::temp_dir().join(format!("sec-stream-checkpoints-{}", uuid::Uuid::new_v4())),
+        ));
+        let writer = CheckpointWriter::new(sink.clone(), "sample");
+        (sink, writer)
+    }
+
+    #[tokio::test]
+    async fn should_yield_checkpoint_saved_after_each_completed_state_when_checkpointing() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let (_sink, writer) = temporary_checkpoint_writer();
+        let mut stream = std::pin::pin!(sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_checkpoint_writer(writer)
+        ));
+
+        let expected_result = vec![
+            super::StreamEvent::StateStarted,
+            super::StreamEvent::StateCompleted,
+            super::StreamEvent::CheckpointSaved,
+            super::StreamEvent::TransitionCompleted,
+            super::StreamEvent::StateStarted,
+            super::StreamEvent::StateCompleted,
+            super::StreamEvent::CheckpointSaved,
+            super::StreamEvent::TransitionCompleted,
+            super::StreamEvent::StateStarted,
+            super::StreamEvent::StateCompleted,
+            super::StreamEvent::CheckpointSaved,
+        ];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event in the checkpointed fixture stream should succeed");
+            result.push(item.event);
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_hold_last_completed_state_in_sink_when_checkpointed_stream_finishes() {
+        let sm = SampleStreamingSuperState::<SampleStateA>::new();
+        let (sink, writer) = temporary_checkpoint_writer();
+        let stream = sm.into_stream_with(
+            uuid::Uuid::new_v4(),
+            StreamOptions::new().with_checkpoint_writer(writer),
+        );
+        stream.for_each(|_| async {}).await;
+
+        let expected_result = "Sample State C";
+
+        let checkpoint = sink
+            .load("sample")
+            .await
+            .expect("Loading from a temporary directory should always succeed")
+            .expect("A finished checkpointed stream should always leave a checkpoint behind");
+        let result = checkpoint.state_name();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_skip_recomputation_when_resuming_from_completed_state_checkpoint() {
+        let mut sm = SampleStreamingSuperState::<SampleStateA>::new();
+        sm.compute_output_data_async()
+            .await
+            .expect("Hardcoded fixture state should always compute output successfully");
+        sm.current_state_mut().force_compute_error = true;
+        let checkpoint = Checkpoint::capture(uuid::Uuid::new_v4(), sm.current_state())
+            .expect("A fixture state should always be capturable");
+        let resumed = SampleStreamingSuperState::<SampleStateA>::resume_from(&checkpoint)
+            .expect("A checkpoint of state A should always restore state A");
+        let mut stream = std::pin::pin!(resumed.into_stream(checkpoint.execution_id()));
+
+        let expected_result = true;
+
+        let mut all_ok = true;
+        while let Some(item) = stream.next().await {
+            all_ok &= item.is_ok();
+        }
+        let result = all_ok;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_start_at_checkpointed_state_when_resuming_stream() {
+        let sm = SampleStreamingSuperState::<SampleStateB>::default();
+        let checkpoint = Checkpoint::capture(uuid::Uuid::new_v4(), sm.current_state())
+            .expect("A fixture state should always be capturable");
+        let resumed = SampleStreamingSuperState::<SampleStateB>::resume_from(&checkpoint)
+            .expect("A checkpoint of state B should always restore state B");
+        let mut stream = std::pin::pin!(resumed.into_stream(checkpoint.execution_id()));
+
+        let expected_result = vec!["Sample State B".to_string(), "Sample State C".to_string()];
+
+        let mut result = Vec::new();
+        while let Some(item) = stream.next().await {
+            let item = item.expect("Each event in the resumed fixture stream should succeed");
+            if item.event == super::StreamEvent::StateStarted {
+                result.push(item.state_name);
+            }
+        }
+
+        assert_eq!(result, expected_result);
+    }
+
     // --- Trait compliance: StateMachineStream ---
     // Note: StateMachineStream is Send but NOT Sync. Async streams hold mutable
     // state across await points. You move a stream to a consumer, not share it.
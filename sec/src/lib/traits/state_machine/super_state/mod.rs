@@ -21,6 +21,22 @@ pub trait SuperState<S>: State + StateMachine<S>
 where
     S: State,
 {
+    /// Returns the name of the super-state itself, independent of its current inner state (e.g.
+    /// `"Extract SuperState"`).
+    ///
+    /// Used as the super-state's segment in the [`StatePath`](super::state_path::StatePath) of
+    /// each of its inner states.
+    fn super_state_name(&self) -> &'static str;
+
+    /// Returns the ID of the pipeline run the super-state belongs to, if it carries one.
+    ///
+    /// A super-state that returns an ID is always streamed under it, whichever ID its stream is
+    /// opened with, so its events and checkpoints record the same run as its
+    /// [`PipelineEnvironment`](crate::shared::environment::PipelineEnvironment). Returns `None` by
+    /// default, streaming under the ID the stream is opened with.
+    fn execution_id(&self) -> Option<uuid::Uuid> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +67,17 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_return_name_without_inner_state_when_super_state_name_is_called() {
+        let super_state = SampleSecSuperState::<SampleSecState>::new();
+
+        let expected_result = "Sample SEC SuperState";
+
+        let result = super_state.super_state_name();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_return_inner_state_name_when_accessing_current_state() {
         let super_state = SampleSecSuperState::<SampleSecState>::new();
@@ -4,10 +4,14 @@
 //!
 //! It refines the generic [`state_maschine`] transition with an SEC-specific method that reports
 //! rich [`TransitionError`]s instead of the framework's static strings.
+//!
+//! Also provides [`TryFromState`], the conversion of one inner state into the next that a
+//! super-state performs with its run's [`PipelineEnvironment`] at hand.
 
 use state_maschine::prelude::Transition as SMTransition;
 
 use crate::error::state_machine::transition::Transition as TransitionError;
+use crate::shared::environment::{NoRepository, PipelineEnvironment};
 use crate::traits::state_machine::state::State;
 
 /// A transition from source state `T` to target state `U`.
@@ -36,3 +40,31 @@ where
     /// be carried across.
     fn transition_to_next_state_sec(self) -> Result<Self::NewStateMachine, TransitionError>;
 }
+
+/// Fallible conversion of the source state `S` into `Self`, its successor within a super-state.
+///
+/// The super-state passes its [`PipelineEnvironment`] along, so the successor takes the run-wide
+/// resources it needs (e.g. the client) from there rather than from fields of the source state.
+/// Usually implemented through [`state_transition!`](crate::state_transition), for environments
+/// holding any repository; a successor that reads the repository implements it by hand for
+/// `R: Repository` only.
+///
+/// # Type Parameters
+///
+/// - `S`: The source state.
+/// - `R`: The repository of the environment, or [`NoRepository`] if the run persists nothing.
+///
+/// [`NoRepository`]: crate::shared::environment::NoRepository
+pub trait TryFromState<S, R = NoRepository>: Sized {
+    /// Converts `state` into its successor.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransitionError`] if the successor cannot be built, e.g.
+    /// [`MissingOutput`](crate::error::state_machine::transition::MissingOutput) when `state` has
+    /// not computed its output yet.
+    fn try_from_state(
+        state: S,
+        environment: &PipelineEnvironment<R>,
+    ) -> Result<Self, TransitionError>;
+}
@@ -4,6 +4,7 @@ use futures_util::StreamExt;
 use sec::implementations::states::extract::ExtractSuperState;
 use sec::implementations::states::extract::validate_cik_format::ValidateCikFormat;
 use sec::prelude::*;
+use sec::shared::environment::PipelineEnvironment;
 use sec::shared::http_client::implementations::sec_client::SecClient;
 use uuid::Uuid;
 
@@ -67,7 +68,8 @@ impl Pipeline {
 
     pub async fn run(self) -> Result<(), StreamError> {
         let execution_id = Uuid::new_v4();
-        let state = ExtractSuperState::<ValidateCikFormat>::new(self.raw_cik, self.sec_client);
+        let environment = PipelineEnvironment::new(self.sec_client).with_execution_id(execution_id);
+        let state = ExtractSuperState::<ValidateCikFormat>::new(self.raw_cik, environment);
         let mut stream = pin!(state.into_stream(execution_id));
 
         while let Some(result) = stream.next().await {
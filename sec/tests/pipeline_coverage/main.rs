@@ -3,10 +3,18 @@
 //! Runs the full Extract → Transform pipeline against SEC EDGAR to measure
 //! pipeline quality and track progress as XBRL aliases are expanded.
 //!
+//! The live tests are ignored by default. The hermetic test replays the company-facts
+//! fixtures in `tests/pipeline_coverage/fixtures` instead, so it runs without network access.
+//! The checked-in fixture is a hand-written Apple document rather than a recording; see
+//! `FIXTURE_CIKS`.
+//!
 //! ## Running
 //! ```sh
-//! # Run both tests sequentially with live output
+//! # Run the live tests sequentially with live output
 //! cargo test --test pipeline_coverage -- --ignored --nocapture --test-threads=1
+//!
+//! # Re-record the fixtures of the must-pass companies from the live SEC API
+//! cargo test --test pipeline_coverage should_record_fixtures -- --ignored
 //! ```
 
 mod builder;
@@ -18,8 +26,10 @@ use std::io::Write;
 use futures_util::StreamExt;
 
 use builder::Pipeline;
-use constants::{MUST_PASS_CIKS, SP500_CIKS};
+use constants::{FIXTURE_CIKS, FIXTURES_DIRECTORY, MUST_PASS_CIKS};
 use sec::shared::http_client::implementations::sec_client::SecClient;
+use sec::shared::response_fixtures::ResponseFixtures;
+use sec::shared::universe::CikUniverse;
 
 /// Writes directly to stderr, bypassing the test framework's output capture.
 fn write_progress(msg: &str) {
@@ -27,35 +37,16 @@ fn write_progress(msg: &str) {
     let _ = std::io::stderr().flush();
 }
 
-/// Looks up the company name for a CIK in the must-pass list.
-///
-/// Returns "Unknown" if the CIK is not in the list.
-fn lookup_must_pass_name(cik: &str) -> &str {
-    MUST_PASS_CIKS
-        .iter()
-        .find(|(c, _)| *c == cik)
-        .map_or("Unknown", |(_, name)| name)
-}
-
-/// Minimum number of S&P 500 companies that must succeed.
-/// Increase this threshold as XBRL aliases are expanded.
-const MINIMUM_SUCCESS_THRESHOLD: usize = 270;
-
-// ---------------------------------------------------------------------------
-// Clean end-to-end test — mirrors the production binary
-// ---------------------------------------------------------------------------
-
-#[tokio::test(flavor = "multi_thread")]
-#[ignore = "Hits the live SEC API for must-pass companies (~10 seconds)"]
-async fn should_succeed_for_must_pass_companies() {
-    let sec_client = SecClient::default();
-    let ciks: Vec<&str> = MUST_PASS_CIKS.iter().map(|(cik, _)| *cik).collect();
+/// Runs the full pipeline for every CIK of `ciks` with `sec_client`, returning a description of
+/// every failure.
+async fn failures_of(sec_client: &SecClient, ciks: &[(&str, &str)]) -> Vec<String> {
     let stream_results: Vec<_> = futures_util::stream::iter(ciks.iter())
-        .map(|cik| {
+        .map(|(cik, name)| {
             let client = sec_client.clone();
             async move {
                 (
                     *cik,
+                    *name,
                     Pipeline::builder()
                         .cik(*cik)
                         .sec_client(client)
@@ -69,17 +60,57 @@ async fn should_succeed_for_must_pass_companies() {
         .collect()
         .await;
 
+    stream_results
+        .iter()
+        .filter_map(|(cik, name, r)| r.as_ref().err().map(|e| format!("{name} (CIK {cik}): {e}")))
+        .collect()
+}
+
+/// Minimum number of S&P 500 companies that must succeed.
+/// Increase this threshold as XBRL aliases are expanded.
+const MINIMUM_SUCCESS_THRESHOLD: usize = 270;
+
+// ---------------------------------------------------------------------------
+// Clean end-to-end test — mirrors the production binary
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "Hits the live SEC API for must-pass companies (~10 seconds)"]
+async fn should_succeed_for_must_pass_companies() {
+    let sec_client = SecClient::default();
+
     let expected_result: Vec<String> = vec![];
 
-    let result: Vec<String> = stream_results
-        .iter()
-        .filter_map(|(cik, r)| {
-            r.as_ref().err().map(|e| {
-                let name = lookup_must_pass_name(cik);
-                format!("{name} (CIK {cik}): {e}")
-            })
-        })
-        .collect();
+    let result = failures_of(&sec_client, MUST_PASS_CIKS).await;
+
+    assert_eq!(result, expected_result);
+}
+
+// ---------------------------------------------------------------------------
+// Hermetic test — replays response fixtures, no network access
+// ---------------------------------------------------------------------------
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_succeed_for_companies_with_fixtures() {
+    let sec_client =
+        SecClient::default().with_response_fixtures(ResponseFixtures::replay(FIXTURES_DIRECTORY));
+
+    let expected_result: Vec<String> = vec![];
+
+    let result = failures_of(&sec_client, FIXTURE_CIKS).await;
+
+    assert_eq!(result, expected_result);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "Hits the live SEC API and overwrites the fixtures (~10 seconds)"]
+async fn should_record_fixtures_for_must_pass_companies() {
+    let sec_client =
+        SecClient::default().with_response_fixtures(ResponseFixtures::record(FIXTURES_DIRECTORY));
+
+    let expected_result: Vec<String> = vec![];
+
+    let result = failures_of(&sec_client, MUST_PASS_CIKS).await;
 
     assert_eq!(result, expected_result);
 }
@@ -95,7 +126,7 @@ async fn should_meet_threshold_for_sp500_companies() {
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     let sec_client = SecClient::default();
-    let ciks: Vec<&str> = SP500_CIKS.to_vec();
+    let ciks: Vec<String> = CikUniverse::sp500().into_ciks().map(String::from).collect();
     let total = ciks.len();
     let completed = AtomicUsize::new(0);
     let successes = AtomicUsize::new(0);
@@ -111,7 +142,7 @@ async fn should_meet_threshold_for_sp500_companies() {
             let client = sec_client.clone();
             async move {
                 let result = Pipeline::builder()
-                    .cik(*cik)
+                    .cik(cik.as_str())
                     .sec_client(client)
                     .build()
                     .run()
@@ -127,7 +158,7 @@ async fn should_meet_threshold_for_sp500_companies() {
                         failures
                             .lock()
                             .expect("Mutex should not be poisoned")
-                            .push((cik.to_string(), e.to_string()));
+                            .push((cik.clone(), e.to_string()));
                     }
                 }
             }
@@ -10,7 +10,13 @@
 //!
 //! - [`state_machine`]: The core traits: [`StateMachine`](state_machine::StateMachine),
 //!   [`State`](state_machine::state::State), [`SuperState`](state_machine::super_state::SuperState),
-//!   and [`Transition`](state_machine::transition::Transition).
+//!   and [`Transition`](state_machine::transition::Transition), plus their async counterparts
+//!   [`AsyncStateMachine`](state_machine::async_state_machine::AsyncStateMachine),
+//!   [`AsyncState`](state_machine::state::AsyncState), and
+//!   [`AsyncSuperState`](state_machine::super_state::AsyncSuperState). The
+//!   [`Executor`](state_machine::executor::Executor) trait bridges async states into synchronous
+//!   code, and the [`Topology`](state_machine::topology::Topology) trait exposes a machine's static structure
+//!   for rendering as a diagram.
 //! - [`prelude`]: Re-exports of those traits for convenient glob import.
 
 pub mod prelude;
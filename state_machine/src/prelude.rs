@@ -5,8 +5,15 @@
 //! Bring them all into scope with `use state_maschine::prelude::*`.
 
 pub use crate::state_machine::StateMachine;
+pub use crate::state_machine::async_state_machine::AsyncStateMachine;
+pub use crate::state_machine::executor::Executor;
+pub use crate::state_machine::executor::ParkingExecutor;
+pub use crate::state_machine::state::AsyncState;
 pub use crate::state_machine::state::Context;
 pub use crate::state_machine::state::State;
 pub use crate::state_machine::state::StateData;
+pub use crate::state_machine::super_state::AsyncSuperState;
 pub use crate::state_machine::super_state::SuperState;
+pub use crate::state_machine::topology::StateGraph;
+pub use crate::state_machine::topology::Topology;
 pub use crate::state_machine::transition::Transition;
@@ -0,0 +1,149 @@
+//! # Async State Machine Trait
+//!
+//! Provides the [`AsyncStateMachine`] trait: the async counterpart of
+//! [`StateMachine::run`] and [`StateMachine::advance_state`] for machines over
+//! [`AsyncState`]s.
+//!
+//! Both methods come with default implementations built on
+//! [`compute_output_data_async`](AsyncState::compute_output_data_async), so generic code can
+//! drive any async machine with `.await` without knowing how the machine is wired internally.
+
+use super::StateMachine;
+use super::state::AsyncState;
+
+/// A [`StateMachine`] whose current state is advanced asynchronously.
+///
+/// Failures of the current state surface as its [`AsyncState::Error`] instead of panics.
+/// Implementors usually rely on the defaults and only override them to add behaviour around the
+/// inner computation.
+///
+/// # Type Parameters
+///
+/// - `S`: The active state type. Must implement [`AsyncState`].
+pub trait AsyncStateMachine<S: AsyncState>: StateMachine<S> + Send {
+    /// Runs the state machine until its current state has computed its output.
+    ///
+    /// Does nothing if the output is already present, so repeated calls are cheap.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current state's error if computing its output fails.
+    fn run_async(&mut self) -> impl Future<Output = Result<(), S::Error>> + Send {
+        async move {
+            if self.current_state().has_output_data_been_computed() {
+                return Ok(());
+            }
+            self.advance_state_async().await
+        }
+    }
+
+    /// Advances the state machine by computing the output of its current state.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current state's error if computing its output fails.
+    fn advance_state_async(&mut self) -> impl Future<Output = Result<(), S::Error>> + Send {
+        async move { self.current_state_mut().compute_output_data_async().await }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_machine::state::State;
+    use crate::tests::fixtures::{
+        SampleAsyncState, SampleAsyncStateError, SimpleAsyncStateMachine,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn should_return_true_when_async_state_machine_has_advanced_and_computed_the_output() {
+        let mut sample_state_machine = SimpleAsyncStateMachine::default();
+
+        let expected_result = true;
+
+        sample_state_machine
+            .advance_state_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+        let result = sample_state_machine
+            .current_state()
+            .has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_true_when_async_state_machine_has_run_to_completion() {
+        let mut sample_state_machine = SimpleAsyncStateMachine::default();
+
+        let expected_result = true;
+
+        sample_state_machine
+            .run_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+        let result = sample_state_machine
+            .current_state()
+            .has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_error_when_running_async_state_machine_with_failing_state() {
+        let mut sample_state_machine = SimpleAsyncStateMachine::new(SampleAsyncState::failing());
+
+        let expected_result = Err(SampleAsyncStateError);
+
+        let result = sample_state_machine.run_async().await;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_not_recompute_output_when_running_async_state_machine_that_already_completed() {
+        let mut sample_state_machine = SimpleAsyncStateMachine::default();
+        sample_state_machine
+            .run_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+        sample_state_machine
+            .current_state_mut()
+            .set_should_fail(true);
+
+        let expected_result = Ok(());
+
+        let result = sample_state_machine.run_async().await;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_same_output_data_even_when_async_state_machine_has_advanced_different_amount_of_times()
+     {
+        let mut sm1 = SimpleAsyncStateMachine::default();
+        sm1.advance_state_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+        let mut sm2 = SimpleAsyncStateMachine::default();
+        sm2.advance_state_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+        sm2.advance_state_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+
+        let expected_result = sm1.current_state().output_data();
+
+        let result = sm2.current_state().output_data();
+
+        assert_eq!(result, expected_result);
+    }
+
+    const fn implements_send<T: Send>() {}
+    #[test]
+    const fn should_implement_send_when_implementing_async_state_machine_trait() {
+        implements_send::<SimpleAsyncStateMachine>();
+    }
+}
@@ -0,0 +1,140 @@
+//! # Executor
+//!
+//! Provides the [`Executor`] trait that bridges async state computations into synchronous code,
+//! and the [`ParkingExecutor`], a dependency-free implementation for futures that need no
+//! runtime.
+//!
+//! The framework does not depend on any async runtime, so it cannot know what a state's future
+//! needs to make progress: a pure computation can be polled on the calling thread, while a future
+//! doing I/O may need the reactor of a specific runtime. Each
+//! [`AsyncState`](crate::state_machine::state::AsyncState) therefore names the executor able to
+//! drive it, and [`try_compute_output_data`](crate::state_machine::state::AsyncState::try_compute_output_data)
+//! blocks on that executor, returning the computation's error instead of panicking.
+
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+/// Drives a future to completion from synchronous code.
+///
+/// The `Send` bounds let implementations move the future to, and its output back from, another
+/// thread, e.g. to run it on a dedicated runtime instead of blocking one it was called from.
+pub trait Executor {
+    /// Blocks the calling thread until `future` completes, and returns its output.
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send,
+        F::Output: Send;
+}
+
+/// An [`Executor`] that polls the future on the calling thread, parking the thread until the
+/// future is woken.
+///
+/// Suitable for futures that need no runtime services (no reactor or timer), such as pure
+/// computations exposed through an async interface.
+///
+/// # Examples
+///
+/// ```
+/// use state_maschine::prelude::*;
+///
+/// let expected_result = 42;
+///
+/// let result = ParkingExecutor.block_on(async { 42 });
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParkingExecutor;
+
+/// Wakes a parked thread.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+impl Executor for ParkingExecutor {
+    fn block_on<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send,
+        F::Output: Send,
+    {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = TaskContext::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// A future that is pending until another thread completes it and wakes the waiting task.
+    struct CompletedElsewhere {
+        sender: Option<mpsc::Sender<u32>>,
+        receiver: mpsc::Receiver<u32>,
+    }
+
+    impl Future for CompletedElsewhere {
+        type Output = u32;
+
+        fn poll(mut self: std::pin::Pin<&mut Self>, context: &mut TaskContext<'_>) -> Poll<u32> {
+            if let Ok(value) = self.receiver.try_recv() {
+                return Poll::Ready(value);
+            }
+            if let Some(sender) = self.sender.take() {
+                let waker = context.waker().clone();
+                thread::spawn(move || {
+                    thread::sleep(Duration::from_millis(10));
+                    sender
+                        .send(7)
+                        .expect("The waiting future should still hold the receiver");
+                    waker.wake();
+                });
+            }
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn should_return_output_when_blocking_on_ready_future() {
+        let expected_result = "done";
+
+        let result = ParkingExecutor.block_on(async { "done" });
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_resume_polling_when_future_is_woken_from_another_thread() {
+        let (sender, receiver) = mpsc::channel();
+        let future = CompletedElsewhere {
+            sender: Some(sender),
+            receiver,
+        };
+
+        let expected_result = 7;
+
+        let result = ParkingExecutor.block_on(future);
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -7,12 +7,21 @@
 //!
 //! ## Modules
 //!
+//! - [`async_state_machine`]: The [`AsyncStateMachine`](async_state_machine::AsyncStateMachine)
+//!   trait for driving machines over async states.
+//! - [`executor`]: The [`Executor`](executor::Executor) trait bridging async state computations
+//!   into synchronous code.
 //! - [`state`]: The [`State`] trait plus its context and state-data traits.
 //! - [`super_state`]: The [`SuperState`](super_state::SuperState) trait for hierarchical states.
+//! - [`topology`]: The [`Topology`](topology::Topology) trait for introspecting a machine's static
+//!   structure, and its rendering to Mermaid and Graphviz DOT diagrams.
 //! - [`transition`]: The [`Transition`](transition::Transition) trait for moving between states.
 
+pub mod async_state_machine;
+pub mod executor;
 pub mod state;
 pub mod super_state;
+pub mod topology;
 pub mod transition;
 
 use crate::state_machine::state::State;
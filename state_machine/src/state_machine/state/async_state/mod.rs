@@ -0,0 +1,204 @@
+//! # Async State Trait
+//!
+//! Provides the [`AsyncState`] trait for states whose output is computed asynchronously and can
+//! fail.
+//!
+//! States that perform I/O implement [`AsyncState`] so an async runtime can drive them with
+//! `.await`, instead of blocking a thread inside the synchronous
+//! [`compute_output_data`](State::compute_output_data). Synchronous callers use
+//! [`try_compute_output_data`](AsyncState::try_compute_output_data) instead, which blocks on the
+//! state's [`Executor`] and reports failures as errors.
+
+use std::fmt::Display;
+
+use super::State;
+use crate::state_machine::executor::Executor;
+
+/// A [`State`] whose output is computed asynchronously, reporting failures as `Self::Error`.
+///
+/// The crate does not depend on any async runtime: the returned future is plain
+/// [`Future`] + [`Send`], so implementors may use `async fn` and callers may poll it on any
+/// executor. The state names the [`Executor`] able to drive it from synchronous code.
+///
+/// # Associated Types
+///
+/// - `Error`: The error returned when the output cannot be computed.
+/// - `Executor`: The executor [`try_compute_output_data`](AsyncState::try_compute_output_data)
+///   blocks on, e.g. [`ParkingExecutor`](crate::state_machine::executor::ParkingExecutor) for
+///   states needing no runtime.
+pub trait AsyncState: State {
+    /// The error returned when the output cannot be computed.
+    type Error;
+
+    /// The executor driving the state's computation from synchronous code.
+    type Executor: Executor + Default;
+
+    /// Computes the output data from the input and stores it on the state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::Error` if the output cannot be computed.
+    fn compute_output_data_async(&mut self)
+    -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Computes the output data synchronously, blocking on the state's
+    /// [`Executor`](AsyncState::Executor) until it completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::Error` if the output cannot be computed.
+    fn try_compute_output_data(&mut self) -> Result<(), Self::Error>
+    where
+        Self::Error: Send,
+    {
+        self.try_compute_output_data_on(&Self::Executor::default())
+    }
+
+    /// Computes the output data synchronously, blocking on `executor` until it completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::Error` if the output cannot be computed.
+    fn try_compute_output_data_on(&mut self, executor: &impl Executor) -> Result<(), Self::Error>
+    where
+        Self::Error: Send,
+    {
+        executor.block_on(self.compute_output_data_async())
+    }
+
+    /// Computes the output data synchronously like
+    /// [`try_compute_output_data`](AsyncState::try_compute_output_data), for implementing the
+    /// infallible [`compute_output_data`](State::compute_output_data) of an async state.
+    ///
+    /// Prefer [`try_compute_output_data`](AsyncState::try_compute_output_data), which reports a
+    /// failed computation as an error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the computation returns an error.
+    fn compute_output_data_blocking(&mut self)
+    where
+        Self::Error: Display + Send,
+    {
+        if let Err(error) = self.try_compute_output_data() {
+            panic!("compute_output_data failed: {error}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_machine::executor::ParkingExecutor;
+    use crate::tests::fixtures::{SampleAsyncState, SampleAsyncStateError};
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn should_return_true_when_async_state_has_computed_the_output() {
+        let mut sample_state = SampleAsyncState::default();
+
+        let expected_result = true;
+
+        sample_state
+            .compute_output_data_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+        let result = sample_state.has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_return_error_when_async_state_is_configured_to_fail() {
+        let mut sample_state = SampleAsyncState::failing();
+
+        let expected_result = Err(SampleAsyncStateError);
+
+        let result = sample_state.compute_output_data_async().await;
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_not_store_output_when_async_computation_fails() {
+        let mut sample_state = SampleAsyncState::failing();
+
+        let expected_result = false;
+
+        let _ = sample_state.compute_output_data_async().await;
+        let result = sample_state.has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[tokio::test]
+    async fn should_not_change_input_data_when_computing_output_data_async() {
+        let mut sample_state = SampleAsyncState::default();
+
+        let expected_result = &sample_state.input_data().clone();
+
+        sample_state
+            .compute_output_data_async()
+            .await
+            .expect("Default sample async state should always compute its output");
+        let result = sample_state.input_data();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_true_when_state_has_computed_the_output_synchronously() {
+        let mut sample_state = SampleAsyncState::default();
+
+        let expected_result = true;
+
+        sample_state
+            .try_compute_output_data()
+            .expect("Default sample async state should always compute its output");
+        let result = sample_state.has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_error_instead_of_panicking_when_synchronous_computation_fails() {
+        let mut sample_state = SampleAsyncState::failing();
+
+        let expected_result = Err(SampleAsyncStateError);
+
+        let result = sample_state.try_compute_output_data();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_store_output_when_computing_output_data_blocking() {
+        let mut sample_state = SampleAsyncState::default();
+
+        let expected_result = true;
+
+        sample_state.compute_output_data_blocking();
+        let result = sample_state.has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    #[should_panic(expected = "compute_output_data failed: sample async state failed")]
+    fn should_panic_when_computing_output_data_blocking_fails() {
+        let mut sample_state = SampleAsyncState::failing();
+
+        sample_state.compute_output_data_blocking();
+    }
+
+    #[tokio::test]
+    async fn should_compute_output_synchronously_when_called_inside_current_thread_runtime() {
+        let mut sample_state = SampleAsyncState::default();
+
+        let expected_result = Ok(());
+
+        let result = sample_state.try_compute_output_data_on(&ParkingExecutor);
+
+        assert_eq!(result, expected_result);
+    }
+}
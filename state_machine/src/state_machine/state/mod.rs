@@ -9,14 +9,17 @@
 //!
 //! ## Modules
 //!
+//! - [`async_state`]: The [`AsyncState`] trait for states computing their output asynchronously.
 //! - [`context`]: The [`Context`] trait for a state's context.
 //! - [`state_data`]: The [`StateData`] trait for a state's input/output data.
 
 use std::{fmt::Debug, hash::Hash};
 
+pub mod async_state;
 pub mod context;
 pub mod state_data;
 
+pub use async_state::AsyncState;
 pub use context::Context;
 pub use state_data::StateData;
 
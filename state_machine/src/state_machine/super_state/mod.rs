@@ -4,9 +4,14 @@
 //! machine.
 //!
 //! This is what lets a group of states appear as a single state to an outer machine while
-//! internally driving its own sub-states.
+//! internally driving its own sub-states. [`AsyncSuperState`] is the counterpart for super-states
+//! whose inner states are driven asynchronously.
 
-use super::{StateMachine, state::State};
+use super::{
+    StateMachine,
+    async_state_machine::AsyncStateMachine,
+    state::{AsyncState, State},
+};
 
 /// A state that is itself a state machine over sub-states.
 ///
@@ -18,6 +23,16 @@ use super::{StateMachine, state::State};
 /// - `S`: The active inner state type. Must implement [`State`].
 pub trait SuperState<S: State>: StateMachine<S> + State {}
 
+/// A super-state whose inner states are driven asynchronously.
+///
+/// Bundles the [`AsyncState`] and [`AsyncStateMachine`] bounds, so the super-state can be awaited
+/// as one state in a parent machine and await its own inner states.
+///
+/// # Type Parameters
+///
+/// - `S`: The active inner state type. Must implement [`AsyncState`].
+pub trait AsyncSuperState<S: AsyncState>: AsyncStateMachine<S> + AsyncState {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,438 @@
+//! # Topology
+//!
+//! Provides the [`Topology`] trait, through which a state machine exposes its static structure,
+//! and the [`StateGraph`] describing that structure.
+//!
+//! A machine's transitions are encoded in the type system, so they cannot be enumerated at
+//! runtime. [`Topology`] restates them as data — the states, the transitions between them, the
+//! initial and terminal states, and the nesting of super-states — so the structure can be
+//! inspected and rendered, either as a Mermaid `stateDiagram-v2` ([`StateGraph::to_mermaid`]) or
+//! as a Graphviz DOT digraph ([`StateGraph::to_dot`]).
+//!
+//! State names double as diagram identifiers, so they should be valid identifiers in both formats
+//! (e.g. `ValidateCikFormat`) and unique within the whole graph.
+
+/// Indentation of one nesting level in rendered diagrams.
+const INDENT: &str = "    ";
+
+/// A state machine whose static structure can be introspected.
+///
+/// The topology describes every state the machine can be in, not just the current one, so it is
+/// available without an instance.
+pub trait Topology {
+    /// Returns the graph of states and transitions the machine can move through.
+    fn topology() -> StateGraph;
+}
+
+/// A node of a [`StateGraph`]: a plain state, or a super-state with an inner graph of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateNode {
+    /// A plain state, identified by its name.
+    State(&'static str),
+    /// A super-state, identified by the name of its inner graph.
+    SuperState(StateGraph),
+}
+
+impl StateNode {
+    /// Returns the name identifying the node within its graph.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::State(name) => name,
+            Self::SuperState(graph) => graph.name(),
+        }
+    }
+}
+
+/// The static structure of a state machine: its states, the transitions between them, and which
+/// of them it starts and ends in.
+///
+/// # Examples
+///
+/// ```
+/// use state_maschine::state_machine::topology::StateGraph;
+///
+/// let graph = StateGraph::new("Traffic Light")
+///     .with_state("Red")
+///     .with_state("Green")
+///     .with_transition("Red", "Green")
+///     .with_initial("Red")
+///     .with_terminal("Green");
+///
+/// let expected_result = "\
+/// ---
+/// title: \"Traffic Light\"
+/// ---
+/// stateDiagram-v2
+///     direction LR
+///     [*] --> Red
+///     Red --> Green
+///     Green --> [*]";
+///
+/// let result = graph.to_mermaid();
+///
+/// assert_eq!(result, expected_result);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateGraph {
+    name: &'static str,
+    states: Vec<StateNode>,
+    transitions: Vec<(&'static str, &'static str)>,
+    initial: Option<&'static str>,
+    terminal: Vec<&'static str>,
+}
+
+impl StateGraph {
+    /// Creates an empty [`StateGraph`] named `name`.
+    ///
+    /// The name titles the diagram of a top-level graph and identifies a nested graph within its
+    /// parent.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            states: Vec::new(),
+            transitions: Vec::new(),
+            initial: None,
+            terminal: Vec::new(),
+        }
+    }
+
+    /// Adds a plain state named `name`.
+    #[must_use]
+    pub fn with_state(mut self, name: &'static str) -> Self {
+        self.states.push(StateNode::State(name));
+        self
+    }
+
+    /// Adds a super-state, identified by the name of its inner `graph`.
+    #[must_use]
+    pub fn with_super_state(mut self, graph: Self) -> Self {
+        self.states.push(StateNode::SuperState(graph));
+        self
+    }
+
+    /// Adds a transition from the state named `from` to the state named `to`.
+    #[must_use]
+    pub fn with_transition(mut self, from: &'static str, to: &'static str) -> Self {
+        self.transitions.push((from, to));
+        self
+    }
+
+    /// Marks the state named `name` as the one the machine starts in.
+    #[must_use]
+    pub const fn with_initial(mut self, name: &'static str) -> Self {
+        self.initial = Some(name);
+        self
+    }
+
+    /// Marks the state named `name` as one the machine can end in.
+    ///
+    /// For a nested graph, ending means leaving the super-state through one of its parent's
+    /// transitions.
+    #[must_use]
+    pub fn with_terminal(mut self, name: &'static str) -> Self {
+        self.terminal.push(name);
+        self
+    }
+
+    /// Returns the name of the graph.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the states of the graph, in the order they were added.
+    #[must_use]
+    pub const fn states(&self) -> &[StateNode] {
+        self.states.as_slice()
+    }
+
+    /// Returns the transitions of the graph as `(from, to)` pairs, in the order they were added.
+    #[must_use]
+    pub const fn transitions(&self) -> &[(&'static str, &'static str)] {
+        self.transitions.as_slice()
+    }
+
+    /// Returns the name of the initial state, if one is marked.
+    #[must_use]
+    pub const fn initial(&self) -> Option<&'static str> {
+        self.initial
+    }
+
+    /// Returns the names of the terminal states.
+    #[must_use]
+    pub const fn terminal(&self) -> &[&'static str] {
+        self.terminal.as_slice()
+    }
+
+    /// Renders the graph as a Mermaid `stateDiagram-v2`, titled with the graph's name.
+    ///
+    /// Each nested graph becomes a composite state with its own initial and terminal markers.
+    /// Plain states that no transition touches are declared on a line of their own so they still
+    /// appear in the diagram.
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let mut lines = vec![
+            "---".to_string(),
+            format!("title: \"{}\"", self.name),
+            "---".to_string(),
+            "stateDiagram-v2".to_string(),
+            format!("{INDENT}direction LR"),
+        ];
+        self.push_mermaid_body(1, &mut lines);
+        lines.join("\n")
+    }
+
+    /// Renders the graph as a Graphviz DOT digraph named after the graph.
+    ///
+    /// Each nested graph becomes a cluster subgraph. Transitions into or out of a super-state are
+    /// drawn from its terminal marker and into its initial marker, clipped at the cluster border.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec![
+            format!("digraph \"{}\" {{", self.name),
+            format!("{INDENT}rankdir=LR;"),
+            format!("{INDENT}compound=true;"),
+            format!("{INDENT}node [shape=box, style=rounded];"),
+        ];
+        self.push_dot_body("", 1, &mut lines);
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn push_mermaid_body(&self, depth: usize, lines: &mut Vec<String>) {
+        let indent = INDENT.repeat(depth);
+        if let Some(initial) = self.initial {
+            lines.push(format!("{indent}[*] --> {initial}"));
+        }
+        for state in &self.states {
+            match state {
+                StateNode::State(name) if !self.is_connected(name) => {
+                    lines.push(format!("{indent}{name}"));
+                }
+                StateNode::State(_) => {}
+                StateNode::SuperState(graph) => {
+                    lines.push(format!("{indent}state {} {{", graph.name));
+                    graph.push_mermaid_body(depth + 1, lines);
+                    lines.push(format!("{indent}}}"));
+                }
+            }
+        }
+        for (from, to) in &self.transitions {
+            lines.push(format!("{indent}{from} --> {to}"));
+        }
+        for terminal in &self.terminal {
+            lines.push(format!("{indent}{terminal} --> [*]"));
+        }
+    }
+
+    fn push_dot_body(&self, scope: &str, depth: usize, lines: &mut Vec<String>) {
+        let indent = INDENT.repeat(depth);
+        if self.initial.is_some() {
+            lines.push(format!("{indent}\"{scope}__start\" [shape=point];"));
+        }
+        for state in &self.states {
+            match state {
+                StateNode::State(name) => lines.push(format!("{indent}\"{name}\";")),
+                StateNode::SuperState(graph) => {
+                    lines.push(format!("{indent}subgraph \"cluster_{}\" {{", graph.name));
+                    lines.push(format!("{indent}{INDENT}label=\"{}\";", graph.name));
+                    graph.push_dot_body(graph.name, depth + 1, lines);
+                    lines.push(format!("{indent}}}"));
+                }
+            }
+        }
+        if !self.terminal.is_empty() {
+            lines.push(format!(
+                "{indent}\"{scope}__end\" [shape=doublecircle, label=\"\", width=0.15];"
+            ));
+        }
+        if let Some(initial) = self.initial {
+            let start = (format!("{scope}__start"), None);
+            lines.push(dot_edge(&indent, &start, &self.dot_entry(initial)));
+        }
+        for (from, to) in &self.transitions {
+            lines.push(dot_edge(&indent, &self.dot_exit(from), &self.dot_entry(to)));
+        }
+        for terminal in &self.terminal {
+            let end = (format!("{scope}__end"), None);
+            lines.push(dot_edge(&indent, &self.dot_exit(terminal), &end));
+        }
+    }
+
+    /// Returns whether the plain state named `name` is the source or target of any edge.
+    fn is_connected(&self, name: &str) -> bool {
+        self.initial == Some(name)
+            || self.terminal.contains(&name)
+            || self
+                .transitions
+                .iter()
+                .any(|(from, to)| *from == name || *to == name)
+    }
+
+    fn super_state(&self, name: &str) -> Option<&Self> {
+        self.states.iter().find_map(|state| match state {
+            StateNode::SuperState(graph) if graph.name == name => Some(graph),
+            _ => None,
+        })
+    }
+
+    /// Returns the DOT node a transition into the state named `name` points at, plus the cluster
+    /// to clip the edge at if the state is a super-state.
+    fn dot_entry(&self, name: &str) -> (String, Option<String>) {
+        self.super_state(name).map_or_else(
+            || (name.to_string(), None),
+            |graph| {
+                let node = if graph.initial.is_some() {
+                    format!("{name}__start")
+                } else {
+                    graph
+                        .states
+                        .first()
+                        .map_or_else(|| name.to_string(), |state| state.name().to_string())
+                };
+                (node, Some(format!("cluster_{name}")))
+            },
+        )
+    }
+
+    /// Returns the DOT node a transition out of the state named `name` starts from, plus the
+    /// cluster to clip the edge at if the state is a super-state.
+    fn dot_exit(&self, name: &str) -> (String, Option<String>) {
+        self.super_state(name).map_or_else(
+            || (name.to_string(), None),
+            |graph| {
+                let node = if graph.terminal.is_empty() {
+                    graph
+                        .states
+                        .last()
+                        .map_or_else(|| name.to_string(), |state| state.name().to_string())
+                } else {
+                    format!("{name}__end")
+                };
+                (node, Some(format!("cluster_{name}")))
+            },
+        )
+    }
+}
+
+/// Formats a DOT edge between two `(node, cluster)` endpoints, clipping it at their clusters.
+fn dot_edge(
+    indent: &str,
+    (tail, tail_cluster): &(String, Option<String>),
+    (head, head_cluster): &(String, Option<String>),
+) -> String {
+    let attributes: Vec<String> = [
+        tail_cluster
+            .as_ref()
+            .map(|cluster| format!("ltail=\"{cluster}\"")),
+        head_cluster
+            .as_ref()
+            .map(|cluster| format!("lhead=\"{cluster}\"")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if attributes.is_empty() {
+        format!("{indent}\"{tail}\" -> \"{head}\";")
+    } else {
+        format!(
+            "{indent}\"{tail}\" -> \"{head}\" [{}];",
+            attributes.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixtures::HierarchicalStateMachine;
+    use crate::tests::fixtures::sample_hierarchical_state_machine::{
+        FirstInnerState, SampleSuperState,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_render_super_state_as_composite_state_when_rendering_hierarchical_state_machine_to_mermaid()
+     {
+        let expected_result = "\
+---
+title: \"Hierarchical State Machine\"
+---
+stateDiagram-v2
+    direction LR
+    [*] --> SampleSuperState
+    state SampleSuperState {
+        [*] --> FirstInnerState
+        FirstInnerState --> SecondInnerState
+        SecondInnerState --> [*]
+    }
+    SampleSuperState --> SampleState
+    SampleState --> [*]";
+
+        let result =
+            HierarchicalStateMachine::<SampleSuperState<FirstInnerState>>::topology().to_mermaid();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_render_super_state_as_cluster_when_rendering_hierarchical_state_machine_to_dot() {
+        let expected_result = r#"digraph "Hierarchical State Machine" {
+    rankdir=LR;
+    compound=true;
+    node [shape=box, style=rounded];
+    "__start" [shape=point];
+    subgraph "cluster_SampleSuperState" {
+        label="SampleSuperState";
+        "SampleSuperState__start" [shape=point];
+        "FirstInnerState";
+        "SecondInnerState";
+        "SampleSuperState__end" [shape=doublecircle, label="", width=0.15];
+        "SampleSuperState__start" -> "FirstInnerState";
+        "FirstInnerState" -> "SecondInnerState";
+        "SecondInnerState" -> "SampleSuperState__end";
+    }
+    "SampleState";
+    "__end" [shape=doublecircle, label="", width=0.15];
+    "__start" -> "SampleSuperState__start" [lhead="cluster_SampleSuperState"];
+    "SampleSuperState__end" -> "SampleState" [ltail="cluster_SampleSuperState"];
+    "SampleState" -> "__end";
+}"#;
+
+        let result =
+            HierarchicalStateMachine::<SampleSuperState<FirstInnerState>>::topology().to_dot();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_declare_state_on_its_own_line_when_no_transition_touches_it() {
+        let graph = StateGraph::new("Lonely").with_state("Idle");
+
+        let expected_result = "\
+---
+title: \"Lonely\"
+---
+stateDiagram-v2
+    direction LR
+    Idle";
+
+        let result = graph.to_mermaid();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_inner_graph_name_when_node_is_super_state() {
+        let node = StateNode::SuperState(StateGraph::new("Inner"));
+
+        let expected_result = "Inner";
+
+        let result = node.name();
+
+        assert_eq!(result, expected_result);
+    }
+}
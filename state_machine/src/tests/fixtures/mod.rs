@@ -1,6 +1,8 @@
+pub mod sample_async_state;
 pub mod sample_hierarchical_state_machine;
 pub mod sample_state;
 pub mod sample_transition;
+pub mod simple_async_state_machine;
 pub mod simple_state_machine;
 
 pub use sample_state::{
@@ -10,6 +12,9 @@ pub use sample_state::{
 
 pub use simple_state_machine::SimpleStateMachine;
 
+pub use sample_async_state::{SampleAsyncState, SampleAsyncStateError};
+pub use simple_async_state_machine::SimpleAsyncStateMachine;
+
 pub use sample_transition::{
     complex_state_machine::ComplexStateMachine, first_state::FirstState, second_state::SecondState,
 };
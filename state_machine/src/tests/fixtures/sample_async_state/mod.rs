@@ -0,0 +1,74 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::state_machine::executor::ParkingExecutor;
+use crate::state_machine::state::{AsyncState, State};
+use crate::tests::fixtures::{SampleStateContext, SampleStateData};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct SampleAsyncStateError;
+
+impl Display for SampleAsyncStateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "sample async state failed")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct SampleAsyncState {
+    input: SampleStateData,
+    output: Option<SampleStateData>,
+    context_data: SampleStateContext,
+    should_fail: bool,
+}
+
+impl SampleAsyncState {
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::default()
+        }
+    }
+
+    pub const fn set_should_fail(&mut self, should_fail: bool) {
+        self.should_fail = should_fail;
+    }
+}
+
+impl State for SampleAsyncState {
+    type InputData = SampleStateData;
+    type OutputData = SampleStateData;
+    type Context = SampleStateContext;
+
+    fn state_name(&self) -> impl ToString {
+        "Sample Async State"
+    }
+
+    fn input_data(&self) -> &SampleStateData {
+        &self.input
+    }
+
+    fn compute_output_data(&mut self) {
+        self.output = Some(SampleStateData::default());
+    }
+
+    fn output_data(&self) -> Option<&SampleStateData> {
+        self.output.as_ref()
+    }
+
+    fn context_data(&self) -> &SampleStateContext {
+        &self.context_data
+    }
+}
+
+impl AsyncState for SampleAsyncState {
+    type Error = SampleAsyncStateError;
+    type Executor = ParkingExecutor;
+
+    async fn compute_output_data_async(&mut self) -> Result<(), SampleAsyncStateError> {
+        if self.should_fail {
+            return Err(SampleAsyncStateError);
+        }
+        self.output = Some(SampleStateData::default());
+        Ok(())
+    }
+}
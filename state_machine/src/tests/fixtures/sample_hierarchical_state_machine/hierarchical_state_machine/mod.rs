@@ -1,4 +1,9 @@
-use crate::state_machine::{StateMachine, state::State, transition::Transition};
+use crate::state_machine::{
+    StateMachine,
+    state::State,
+    topology::{StateGraph, Topology},
+    transition::Transition,
+};
 
 use super::{FirstInnerState, SampleState, SampleSuperState, SecondInnerState};
 
@@ -66,3 +71,21 @@ impl Transition<SampleSuperState<SecondInnerState>, SampleState>
         })
     }
 }
+
+impl<S: State> Topology for HierarchicalStateMachine<S> {
+    fn topology() -> StateGraph {
+        let sample_super_state = StateGraph::new("SampleSuperState")
+            .with_state("FirstInnerState")
+            .with_state("SecondInnerState")
+            .with_transition("FirstInnerState", "SecondInnerState")
+            .with_initial("FirstInnerState")
+            .with_terminal("SecondInnerState");
+
+        StateGraph::new("Hierarchical State Machine")
+            .with_super_state(sample_super_state)
+            .with_state("SampleState")
+            .with_transition("SampleSuperState", "SampleState")
+            .with_initial("SampleSuperState")
+            .with_terminal("SampleState")
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Debug;
+
+use crate::state_machine::{StateMachine, async_state_machine::AsyncStateMachine, state::State};
+use crate::tests::fixtures::SampleAsyncState;
+
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct SimpleAsyncStateMachine {
+    current_state: SampleAsyncState,
+}
+
+impl SimpleAsyncStateMachine {
+    pub const fn new(current_state: SampleAsyncState) -> Self {
+        Self { current_state }
+    }
+}
+
+impl StateMachine<SampleAsyncState> for SimpleAsyncStateMachine {
+    fn current_state(&self) -> &SampleAsyncState {
+        &self.current_state
+    }
+
+    fn current_state_mut(&mut self) -> &mut SampleAsyncState {
+        &mut self.current_state
+    }
+
+    fn run(&mut self) {
+        if !self.current_state().has_output_data_been_computed() {
+            self.advance_state();
+        }
+    }
+
+    fn advance_state(&mut self) {
+        self.current_state_mut().compute_output_data();
+    }
+}
+
+impl AsyncStateMachine<SampleAsyncState> for SimpleAsyncStateMachine {}